@@ -5,9 +5,7 @@
 use eframe::egui;
 
 use crate::app::dialogs::host::DialogId;
-use crate::core::{
-    Action as ShortcutAction, BottomPanelTab, RightInspectorTab, WorkbenchActivity, constants,
-};
+use crate::core::{Action as ShortcutAction, BottomPanelTab, RightInspectorTab, WorkbenchActivity};
 use crate::data::DatabaseType;
 use crate::ui::{self, FocusArea};
 
@@ -54,6 +52,9 @@ pub(in crate::app) enum AppAction {
     CloseActiveQueryTab,
     RunCurrentSql,
     CancelQuery,
+    ToggleManualCommit,
+    CommitTransaction,
+    RollbackTransaction,
     ClearCommandLine,
     ClearSearch,
     AddFilter,
@@ -74,6 +75,7 @@ pub(in crate::app) enum AppAction {
     OpenLearningSample,
     EnsureLearningSample { reset: bool, notify: bool },
     ConfirmPendingDelete,
+    ConfirmTransactionDiscard,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -180,6 +182,8 @@ pub(in crate::app) struct ActionContext {
     pub show_sql_editor: bool,
     pub show_er_diagram: bool,
     pub can_confirm_pending_delete: bool,
+    pub can_confirm_transaction_discard: bool,
+    pub has_open_transaction: bool,
 }
 
 impl ActionContext {
@@ -211,6 +215,13 @@ impl ActionContext {
             show_er_diagram: app.state.show_er_diagram,
             can_confirm_pending_delete: app.state.show_delete_confirm
                 && app.state.pending_delete_target.is_some(),
+            can_confirm_transaction_discard: app.state.show_transaction_discard_confirm
+                && app.state.pending_transaction_discard.is_some(),
+            has_open_transaction: app
+                .session
+                .tab_manager
+                .get_active()
+                .is_some_and(|tab| tab.open_transaction().is_some()),
         }
     }
 
@@ -620,6 +631,43 @@ const COMMANDS: &[CommandDescriptor] = &[
         None,
         &["cancel", "stop", "abort", "query", "取消", "停止"],
     ),
+    CommandDescriptor::new(
+        "toggle_manual_commit",
+        "切换手动提交",
+        "当前标签页在自动提交与手动提交之间切换；手动提交时语句在独占连接的事务中执行。",
+        "查询",
+        CommandScope::Editor,
+        AppAction::ToggleManualCommit,
+        None,
+        &[
+            "transaction",
+            "manual commit",
+            "autocommit",
+            "begin",
+            "事务",
+            "手动提交",
+        ],
+    ),
+    CommandDescriptor::new(
+        "commit_transaction",
+        "提交事务",
+        "提交当前标签页未提交的事务。",
+        "查询",
+        CommandScope::Editor,
+        AppAction::CommitTransaction,
+        None,
+        &["commit", "transaction", "提交", "事务"],
+    ),
+    CommandDescriptor::new(
+        "rollback_transaction",
+        "回滚事务",
+        "回滚当前标签页未提交的事务。",
+        "查询",
+        CommandScope::Editor,
+        AppAction::RollbackTransaction,
+        None,
+        &["rollback", "transaction", "undo", "回滚", "事务"],
+    ),
     CommandDescriptor::new(
         "query_selected_table",
         "查询当前表",
@@ -1111,6 +1159,13 @@ fn availability_for_action(context: &ActionContext, action: AppAction) -> Action
                 ActionAvailability::disabled("当前没有待确认的删除操作")
             }
         }
+        AppAction::ConfirmTransactionDiscard => {
+            if context.can_confirm_transaction_discard {
+                ActionAvailability::enabled()
+            } else {
+                ActionAvailability::disabled("当前没有待确认的事务丢弃操作")
+            }
+        }
         AppAction::OpenExportDialog => {
             if context.has_result {
                 ActionAvailability::enabled()
@@ -1169,6 +1224,20 @@ fn availability_for_action(context: &ActionContext, action: AppAction) -> Action
                 ActionAvailability::disabled("当前没有正在执行的查询")
             }
         }
+        AppAction::ToggleManualCommit => {
+            if context.has_open_transaction {
+                ActionAvailability::disabled("请先提交或回滚当前事务")
+            } else {
+                ActionAvailability::enabled()
+            }
+        }
+        AppAction::CommitTransaction | AppAction::RollbackTransaction => {
+            if context.has_open_transaction {
+                ActionAvailability::enabled()
+            } else {
+                ActionAvailability::disabled("当前标签页没有未提交的事务")
+            }
+        }
         AppAction::ClearCommandLine => {
             if context.has_sql {
                 ActionAvailability::enabled()
@@ -1422,6 +1491,18 @@ impl DbManagerApp {
                 self.cancel_active_query();
                 Vec::new()
             }
+            AppAction::ToggleManualCommit => {
+                self.toggle_manual_commit();
+                Vec::new()
+            }
+            AppAction::CommitTransaction => {
+                self.end_active_transaction(true);
+                Vec::new()
+            }
+            AppAction::RollbackTransaction => {
+                self.end_active_transaction(false);
+                Vec::new()
+            }
             AppAction::ClearCommandLine => {
                 self.set_active_sql(String::new());
                 self.session.notifications.dismiss_all();
@@ -1511,13 +1592,19 @@ impl DbManagerApp {
                         format!("PRAGMA table_info('{}');", escaped)
                     }
                     DatabaseType::PostgreSQL => {
-                        let escaped = table.replace('\'', "''");
+                        let (schema, table_name) =
+                            crate::domain::identifier::IdentifierDialect::PostgreSql
+                                .split_table_ref(&table);
+                        let schema_filter = schema
+                            .map(|schema| format!("'{}'", schema.replace('\'', "''")))
+                            .unwrap_or_else(|| "current_schema()".to_string());
                         format!(
                             "SELECT column_name, data_type, is_nullable, column_default \
                              FROM information_schema.columns \
-                             WHERE table_name = '{}' \
+                             WHERE table_schema = {} AND table_name = '{}' \
                              ORDER BY ordinal_position;",
-                            escaped
+                            schema_filter,
+                            table_name.replace('\'', "''")
                         )
                     }
                     DatabaseType::MySQL => {
@@ -1551,6 +1638,10 @@ impl DbManagerApp {
                 self.confirm_pending_delete();
                 Vec::new()
             }
+            AppAction::ConfirmTransactionDiscard => {
+                self.confirm_transaction_discard();
+                Vec::new()
+            }
         }
     }
 
@@ -1560,12 +1651,8 @@ impl DbManagerApp {
         };
         self.switch_grid_workspace(Some(table.clone()));
 
-        let query_sql = match ui::quote_identifier(&table, self.is_mysql()) {
-            Ok(quoted_table) => format!(
-                "SELECT * FROM {} LIMIT {};",
-                quoted_table,
-                constants::database::DEFAULT_QUERY_LIMIT
-            ),
+        let query_sql = match self.begin_table_paging(&table) {
+            Ok(sql) => sql,
             Err(error) => {
                 self.session
                     .notifications
@@ -1727,6 +1814,8 @@ mod tests {
             show_sql_editor: false,
             show_er_diagram: false,
             can_confirm_pending_delete: false,
+            can_confirm_transaction_discard: false,
+            has_open_transaction: false,
         }
     }
 
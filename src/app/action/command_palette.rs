@@ -7,7 +7,8 @@ use crate::ui::{LocalShortcut, consume_local_shortcut, local_shortcut_text, loca
 use eframe::egui;
 
 use super::DbManagerApp;
-use super::action_system::{AppAction, search_commands};
+use super::action_system::{AppAction, CommandMatch, search_commands};
+use crate::core::FilterPreset;
 
 const MAX_VISIBLE_COMMANDS: usize = 12;
 const COMMAND_PALETTE_VIEWPORT_MARGIN: f32 = 32.0;
@@ -25,6 +26,16 @@ enum CommandPaletteKeyAction {
     Dismiss,
 }
 
+/// 面板中的一行：静态命令，或当前表上保存的筛选预设
+enum PaletteEntry {
+    Command(CommandMatch),
+    FilterPreset {
+        index: usize,
+        title: String,
+        subtitle: String,
+    },
+}
+
 #[derive(Debug, Clone, Default)]
 pub(in crate::app) struct CommandPaletteState {
     pub open: bool,
@@ -60,6 +71,7 @@ impl DbManagerApp {
         let request_focus = self.command_palette_state.request_focus;
         let mut close_palette = false;
         let mut action_to_execute: Option<AppAction> = None;
+        let mut preset_to_apply: Option<usize> = None;
         let mut disabled_reason: Option<&'static str> = None;
         let content_rect = ctx.input(|input| input.content_rect());
         let (min_width, default_width, max_width) = command_palette_widths(content_rect.width());
@@ -98,7 +110,15 @@ impl DbManagerApp {
                     selected_index = 0;
                 }
 
-                let matches = search_commands(&command_context, &query);
+                let presets = self.filter_presets_for_active_table();
+                let matches: Vec<PaletteEntry> = matching_filter_presets(&presets, &query)
+                    .into_iter()
+                    .chain(
+                        search_commands(&command_context, &query)
+                            .into_iter()
+                            .map(PaletteEntry::Command),
+                    )
+                    .collect();
                 clamp_selection(&mut selected_index, matches.len());
 
                 if let Some(action) = consume_palette_key_action(ui) {
@@ -110,15 +130,19 @@ impl DbManagerApp {
                         CommandPaletteKeyAction::Prev => {
                             move_selection(&mut selected_index, -1, matches.len());
                         }
-                        CommandPaletteKeyAction::Confirm => {
-                            if let Some(entry) = matches.get(selected_index) {
+                        CommandPaletteKeyAction::Confirm => match matches.get(selected_index) {
+                            Some(PaletteEntry::Command(entry)) => {
                                 if entry.availability.enabled {
                                     action_to_execute = Some(entry.descriptor.action);
                                 } else {
                                     disabled_reason = entry.availability.reason;
                                 }
                             }
-                        }
+                            Some(PaletteEntry::FilterPreset { index, .. }) => {
+                                preset_to_apply = Some(*index);
+                            }
+                            None => {}
+                        },
                     }
                 }
 
@@ -137,29 +161,37 @@ impl DbManagerApp {
                                 matches.iter().take(MAX_VISIBLE_COMMANDS).enumerate()
                             {
                                 let selected = index == selected_index;
-                                let shortcut = self
-                                    .shortcut_label_for_action(entry.descriptor.action)
-                                    .filter(|label| !label.is_empty())
-                                    .unwrap_or_default();
-                                let suffix = if shortcut.is_empty() {
-                                    String::new()
-                                } else {
-                                    format!("    {}", shortcut)
+                                let (label, enabled) = match entry {
+                                    PaletteEntry::Command(entry) => {
+                                        let shortcut = self
+                                            .shortcut_label_for_action(entry.descriptor.action)
+                                            .filter(|label| !label.is_empty())
+                                            .unwrap_or_default();
+                                        let suffix = if shortcut.is_empty() {
+                                            String::new()
+                                        } else {
+                                            format!("    {}", shortcut)
+                                        };
+                                        let disabled = entry
+                                            .availability
+                                            .reason
+                                            .map(|reason| format!("    {}", reason))
+                                            .unwrap_or_default();
+                                        let label = format!(
+                                            "{}{}\n{} · {}{}",
+                                            entry.descriptor.title,
+                                            suffix,
+                                            entry.descriptor.scope.label(),
+                                            entry.descriptor.subtitle,
+                                            disabled
+                                        );
+                                        (label, entry.availability.enabled)
+                                    }
+                                    PaletteEntry::FilterPreset {
+                                        title, subtitle, ..
+                                    } => (format!("{}\n筛选预设 · {}", title, subtitle), true),
                                 };
-                                let disabled = entry
-                                    .availability
-                                    .reason
-                                    .map(|reason| format!("    {}", reason))
-                                    .unwrap_or_default();
-                                let label = format!(
-                                    "{}{}\n{} · {}{}",
-                                    entry.descriptor.title,
-                                    suffix,
-                                    entry.descriptor.scope.label(),
-                                    entry.descriptor.subtitle,
-                                    disabled
-                                );
-                                let text_color = if entry.availability.enabled {
+                                let text_color = if enabled {
                                     ui.visuals().text_color()
                                 } else {
                                     ui.visuals().weak_text_color()
@@ -183,10 +215,17 @@ impl DbManagerApp {
                                     selected_index = index;
                                 }
                                 if response.clicked() {
-                                    if entry.availability.enabled {
-                                        action_to_execute = Some(entry.descriptor.action);
-                                    } else {
-                                        disabled_reason = entry.availability.reason;
+                                    match entry {
+                                        PaletteEntry::Command(entry) => {
+                                            if entry.availability.enabled {
+                                                action_to_execute = Some(entry.descriptor.action);
+                                            } else {
+                                                disabled_reason = entry.availability.reason;
+                                            }
+                                        }
+                                        PaletteEntry::FilterPreset { index, .. } => {
+                                            preset_to_apply = Some(*index);
+                                        }
                                     }
                                 }
                             }
@@ -236,12 +275,44 @@ impl DbManagerApp {
             return;
         }
 
+        if let Some(index) = preset_to_apply {
+            self.close_dialog(DialogId::CommandPalette);
+            self.apply_filter_preset(index);
+            return;
+        }
+
         if close_palette {
             self.close_dialog(DialogId::CommandPalette);
         }
     }
 }
 
+/// 与查询匹配的筛选预设
+///
+/// 查询为空时不列出预设，避免挤占常用命令；每个关键字都需出现在预设名、表名或
+/// “筛选预设 / filter preset”中。
+fn matching_filter_presets(presets: &[(usize, &FilterPreset)], query: &str) -> Vec<PaletteEntry> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    presets
+        .iter()
+        .filter(|(_, preset)| {
+            let haystack =
+                format!("{} {} 筛选预设 filter preset", preset.name, preset.table).to_lowercase();
+            query
+                .split_whitespace()
+                .all(|token| haystack.contains(token))
+        })
+        .map(|(index, preset)| PaletteEntry::FilterPreset {
+            index: *index,
+            title: preset.name.clone(),
+            subtitle: format!("{} · {} 个条件", preset.table, preset.conditions.len()),
+        })
+        .collect()
+}
+
 fn command_palette_widths(viewport_width: f32) -> (f32, f32, f32) {
     let usable = (viewport_width - COMMAND_PALETTE_VIEWPORT_MARGIN).max(280.0);
     let max_width = usable.min(COMMAND_PALETTE_MAX_WIDTH);
@@ -293,10 +364,12 @@ fn move_selection(selected_index: &mut usize, delta: isize, len: usize) {
 #[cfg(test)]
 mod tests {
     use super::{
-        AppAction, CommandPaletteKeyAction, DbManagerApp, command_palette_list_height,
-        command_palette_widths, consume_palette_key_action, search_commands,
+        AppAction, CommandPaletteKeyAction, DbManagerApp, PaletteEntry,
+        command_palette_list_height, command_palette_widths, consume_palette_key_action,
+        matching_filter_presets, search_commands,
     };
     use crate::app::dialogs::host::DialogId;
+    use crate::core::FilterPreset;
     use crate::data::{Connection, ConnectionConfig, DatabaseType};
     use crate::domain::result::{ResultColumn, ResultCompleteness, ResultSet};
     use crate::domain::value::{DbTypeFamily, DbTypeInfo, DbValue};
@@ -379,6 +452,28 @@ mod tests {
         assert_eq!(max_width, 720.0);
     }
 
+    #[test]
+    fn filter_presets_match_by_name_or_keyword_but_not_on_empty_query() {
+        let preset = FilterPreset {
+            name: "Paid orders".to_string(),
+            connection: "demo".to_string(),
+            database: None,
+            table: "orders".to_string(),
+            conditions: Vec::new(),
+        };
+        let presets = [(3, &preset)];
+
+        assert!(matching_filter_presets(&presets, "").is_empty());
+        assert!(matching_filter_presets(&presets, "unpaid").is_empty());
+        for query in ["paid", "preset orders", "筛选预设"] {
+            let matches = matching_filter_presets(&presets, query);
+            assert!(matches!(
+                matches.as_slice(),
+                [PaletteEntry::FilterPreset { index: 3, .. }]
+            ));
+        }
+    }
+
     #[test]
     fn command_palette_list_height_stays_within_bounds() {
         assert_eq!(command_palette_list_height(360.0), 180.0);
@@ -13,6 +13,7 @@ pub(crate) enum DialogId {
     Export,
     Import,
     DeleteConfirm,
+    TransactionDiscardConfirm,
     Help,
     About,
     WelcomeSetup,
@@ -34,6 +35,7 @@ impl DialogId {
             Self::Export => "dialog.export",
             Self::Import => "dialog.import",
             Self::DeleteConfirm => "dialog.confirm",
+            Self::TransactionDiscardConfirm => "dialog.transaction_discard",
             Self::Help => "dialog.help",
             Self::About => "dialog.about",
             Self::WelcomeSetup => "dialog.welcome_setup",
@@ -57,6 +59,7 @@ pub(in crate::app) struct DialogHostSnapshot {
     pub export: bool,
     pub import: bool,
     pub delete_confirm: bool,
+    pub transaction_discard_confirm: bool,
     pub help: bool,
     pub about: bool,
     pub welcome_setup: bool,
@@ -82,6 +85,10 @@ impl DialogHostSnapshot {
             (self.export, DialogId::Export),
             (self.import, DialogId::Import),
             (self.delete_confirm, DialogId::DeleteConfirm),
+            (
+                self.transaction_discard_confirm,
+                DialogId::TransactionDiscardConfirm,
+            ),
             (self.help, DialogId::Help),
             (self.about, DialogId::About),
             (self.welcome_setup, DialogId::WelcomeSetup),
@@ -107,6 +114,7 @@ impl DbManagerApp {
             DialogId::Export => self.state.show_export_dialog,
             DialogId::Import => self.state.show_import_dialog,
             DialogId::DeleteConfirm => self.state.show_delete_confirm,
+            DialogId::TransactionDiscardConfirm => self.state.show_transaction_discard_confirm,
             DialogId::Help => self.state.show_help,
             DialogId::About => self.state.show_about,
             DialogId::WelcomeSetup => self.state.show_welcome_setup_dialog,
@@ -128,6 +136,7 @@ impl DbManagerApp {
             export: self.state.show_export_dialog,
             import: self.state.show_import_dialog,
             delete_confirm: self.state.show_delete_confirm,
+            transaction_discard_confirm: self.state.show_transaction_discard_confirm,
             help: self.state.show_help,
             about: self.state.show_about,
             welcome_setup: self.state.show_welcome_setup_dialog,
@@ -169,6 +178,9 @@ impl DbManagerApp {
             DialogId::Export => self.state.show_export_dialog = true,
             DialogId::Import => self.state.show_import_dialog = true,
             DialogId::DeleteConfirm => self.state.show_delete_confirm = true,
+            DialogId::TransactionDiscardConfirm => {
+                self.state.show_transaction_discard_confirm = true
+            }
             DialogId::Help => self.state.show_help = true,
             DialogId::About => self.state.show_about = true,
             DialogId::WelcomeSetup => self.state.show_welcome_setup_dialog = true,
@@ -194,6 +206,7 @@ impl DbManagerApp {
             DialogId::Export,
             DialogId::Import,
             DialogId::DeleteConfirm,
+            DialogId::TransactionDiscardConfirm,
             DialogId::Help,
             DialogId::About,
             DialogId::History,
@@ -215,6 +228,10 @@ impl DbManagerApp {
             DialogId::Export => self.state.show_export_dialog = false,
             DialogId::Import => self.state.show_import_dialog = false,
             DialogId::DeleteConfirm => self.state.show_delete_confirm = false,
+            DialogId::TransactionDiscardConfirm => {
+                self.state.show_transaction_discard_confirm = false;
+                self.state.pending_transaction_discard = None;
+            }
             DialogId::Help => self.state.show_help = false,
             DialogId::About => self.state.show_about = false,
             DialogId::WelcomeSetup => self.state.show_welcome_setup_dialog = false,
@@ -141,6 +141,7 @@ pub(in crate::app) enum DialogScope {
     Export,
     Import,
     DeleteConfirm,
+    TransactionDiscardConfirm,
     Help,
     About,
     WelcomeSetup,
@@ -163,6 +164,7 @@ impl DialogScope {
             Self::Export => DialogId::Export.scope_path(),
             Self::Import => DialogId::Import.scope_path(),
             Self::DeleteConfirm => DialogId::DeleteConfirm.scope_path(),
+            Self::TransactionDiscardConfirm => DialogId::TransactionDiscardConfirm.scope_path(),
             Self::Help => DialogId::Help.scope_path(),
             Self::About => DialogId::About.scope_path(),
             Self::WelcomeSetup => DialogId::WelcomeSetup.scope_path(),
@@ -187,6 +189,7 @@ impl From<DialogId> for DialogScope {
             DialogId::Export => Self::Export,
             DialogId::Import => Self::Import,
             DialogId::DeleteConfirm => Self::DeleteConfirm,
+            DialogId::TransactionDiscardConfirm => Self::TransactionDiscardConfirm,
             DialogId::Help => Self::Help,
             DialogId::About => Self::About,
             DialogId::WelcomeSetup => Self::WelcomeSetup,
@@ -1000,6 +1003,9 @@ impl DbManagerApp {
                 DialogScope::About => self.close_dialog(DialogId::About),
                 DialogScope::History => self.close_dialog(DialogId::History),
                 DialogScope::DeleteConfirm => self.close_dialog(DialogId::DeleteConfirm),
+                DialogScope::TransactionDiscardConfirm => {
+                    self.close_dialog(DialogId::TransactionDiscardConfirm)
+                }
                 DialogScope::Keybindings => self.close_dialog(DialogId::Keybindings),
                 DialogScope::ToolbarActionsMenu => self.close_dialog(DialogId::ToolbarActionsMenu),
                 DialogScope::ToolbarCreateMenu => self.close_dialog(DialogId::ToolbarCreateMenu),
@@ -1232,6 +1238,13 @@ impl DbManagerApp {
             .tab_manager
             .get_active()
             .map(|tab| tab.id.clone());
+        if let Some(tab_id) = closing_tab_id.clone()
+            && self.defer_for_open_transactions(crate::state::TransactionDiscard::CloseTabs(vec![
+                tab_id,
+            ]))
+        {
+            return;
+        }
         if self.session.tab_manager.tabs.len() > 1
             && let Some(request_id) = self
                 .session
@@ -1365,6 +1378,19 @@ fn resolve_dialog_shortcut_fallback_with(
                 None
             }
         }
+        DialogScope::TransactionDiscardConfirm => {
+            if local_shortcut_triggered(LocalShortcut::DangerConfirm) {
+                Some(ResolvedInputAction::HandledApp(
+                    AppAction::ConfirmTransactionDiscard,
+                ))
+            } else if local_shortcut_triggered(LocalShortcut::DangerCancel) {
+                Some(ResolvedInputAction::HandledLocal(
+                    RouterLocalAction::CloseDialog(scope),
+                ))
+            } else {
+                None
+            }
+        }
         // 表单类对话框：Esc 关闭，补全键盘契约（修复审计 DLG-A2-2）。
         DialogScope::Connection
         | DialogScope::Export
@@ -1447,6 +1473,15 @@ fn scoped_keybinding_triggered_in_input(
     action: Action,
     input: &egui::InputState,
 ) -> bool {
+    // 表格 Normal 模式下撤销 / 重做优先，即使用户把它们绑定到与全局动作相同的键
+    if matches!(
+        input_context.focus_scope(),
+        FocusScope::Grid(GridFocusScope::Normal)
+    ) && ui::components::history_shortcut_pressed(input, keybindings)
+    {
+        return false;
+    }
+
     let scope_path = input_context.keymap_scope_path();
 
     if let Some(bindings) = keybindings.scoped_bindings_for_action(scope_path, action) {
@@ -2721,6 +2756,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dialog_transaction_discard_scope_routes_danger_confirm_to_its_own_action() {
+        let mut context = snapshot();
+        context.has_modal_dialog = true;
+        context.active_dialog = Some(DialogScope::TransactionDiscardConfirm);
+        context.focus_area = FocusArea::Dialog;
+
+        assert_eq!(
+            resolve_event_with_keybindings(context, key_event(Key::Y), &KeyBindings::default()),
+            ResolvedInputAction::HandledApp(AppAction::ConfirmTransactionDiscard)
+        );
+    }
+
     #[test]
     fn dialog_keybindings_scope_routes_dismiss_shortcut_to_close_dialog() {
         let mut context = snapshot();
@@ -3165,6 +3213,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn grid_normal_keeps_ctrl_r_for_er_and_leaves_redo_to_grid() {
+        let ctrl = |key| {
+            key_event_with_modifiers(
+                key,
+                Modifiers {
+                    ctrl: true,
+                    ..Modifiers::NONE
+                },
+            )
+        };
+
+        // 默认重做是 Ctrl+Y，不与切换 ER 图的 Ctrl+R 冲突
+        assert_eq!(
+            resolve_event_with_keybindings(snapshot(), ctrl(Key::R), &KeyBindings::default()),
+            ResolvedInputAction::HandledApp(AppAction::ToggleErDiagram)
+        );
+        assert_eq!(
+            resolve_event_with_keybindings(snapshot(), ctrl(Key::Y), &KeyBindings::default()),
+            ResolvedInputAction::NoOp
+        );
+    }
+
     #[test]
     fn er_diagram_geometry_navigation_keeps_app_selected_table_unchanged_until_open_selected() {
         let ctx = egui::Context::default();
@@ -270,7 +270,21 @@ impl DbManagerApp {
     }
 
     /// Dock tab 关闭时的清理：持久化状态、取消查询、移除工作区
-    pub(crate) fn on_dock_tab_close(&mut self, tab_index: usize) {
+    ///
+    /// Tab 有未提交的事务时改为弹出确认并返回 `false`，Tab 暂不关闭。
+    pub(crate) fn on_dock_tab_close(&mut self, tab_index: usize) -> bool {
+        if let Some(tab_id) = self
+            .session
+            .tab_manager
+            .tabs
+            .get(tab_index)
+            .map(|tab| tab.id.clone())
+            && self.defer_for_open_transactions(crate::state::TransactionDiscard::CloseTabs(vec![
+                tab_id,
+            ]))
+        {
+            return false;
+        }
         self.persist_active_tab_state_for_navigation();
         // Clone needed values before mutable operations
         let pending_id = self
@@ -294,6 +308,7 @@ impl DbManagerApp {
         }
         self.session.tab_manager.close_tab(tab_index);
         self.sync_from_active_tab();
+        true
     }
 
     #[cfg(test)]
@@ -357,27 +372,30 @@ impl DbManagerApp {
         self.sync_table_metadata();
     }
 
+    /// 当前活动连接与数据库对应的 SchemaCatalog（尚未加载时为 `None`）
+    pub(in crate::app) fn active_schema_catalog(
+        &self,
+    ) -> Option<&crate::domain::metadata::SchemaCatalog> {
+        let conn = self.session.manager.get_active()?;
+        let database = conn.selected_database.clone().or_else(|| {
+            if conn.config.database.is_empty() {
+                None
+            } else {
+                Some(conn.config.database.clone())
+            }
+        })?;
+        self.session.schema_catalogs.get(&(conn.id, database))
+    }
+
     /// 从 schema_catalogs 同步当前选中表的 TableMetadata 到 grid_state。
     fn sync_table_metadata(&mut self) {
-        if let Some(table_name) = self.state.selected_table.clone() {
-            let conn = self.session.manager.get_active();
-            let conn_id = conn.map(|c| c.id);
-            let database = conn.and_then(|c| {
-                c.selected_database.clone().or_else(|| {
-                    if c.config.database.is_empty() {
-                        None
-                    } else {
-                        Some(c.config.database.clone())
-                    }
-                })
-            });
-            if let (Some(conn_id), Some(database)) = (conn_id, database)
-                && let Some(catalog) = self.session.schema_catalogs.get(&(conn_id, database))
-                && let Some(tm) = catalog.table(&table_name)
-            {
-                self.state.grid_state.table_metadata = Some(std::sync::Arc::new(tm.clone()));
-                return;
-            }
+        if let Some(table_name) = self.state.selected_table.as_deref()
+            && let Some(tm) = self
+                .active_schema_catalog()
+                .and_then(|catalog| catalog.table(table_name))
+        {
+            self.state.grid_state.table_metadata = Some(std::sync::Arc::new(tm.clone()));
+            return;
         }
         self.state.grid_state.table_metadata = None;
     }
@@ -514,7 +532,7 @@ impl eframe::App for DbManagerApp {
 #[cfg(test)]
 mod tests {
     use super::{GridWorkspaceId, GridWorkspaceStore};
-    use crate::ui::DataGridState;
+    use crate::ui::{CellEdit, DataGridState};
 
     fn workspace(
         tab_id: &str,
@@ -534,13 +552,13 @@ mod tests {
     fn grid_workspace_store_keeps_tables_isolated() {
         let mut store = GridWorkspaceStore::default();
         let mut users_state = DataGridState::new();
-        users_state.new_rows.push(vec!["draft-user".to_string()]);
+        users_state.new_rows.push(vec!["draft-user".into()]);
         users_state.cursor = (5, 0);
 
         let mut orders_state = DataGridState::new();
         orders_state
             .new_rows
-            .push(vec!["draft-order".to_string(), "2".to_string()]);
+            .push(vec!["draft-order".into(), "2".into()]);
         orders_state.cursor = (8, 1);
 
         let users_id = workspace("tab-1", "local", Some("main"), "users");
@@ -569,9 +587,7 @@ mod tests {
 
         let clean_state = DataGridState::new();
         let mut dirty_state = DataGridState::new();
-        dirty_state
-            .modified_cells
-            .insert((0, 1), "edited".to_string());
+        dirty_state.modified_cells.insert((0, 1), "edited".into());
 
         store.save(
             workspace("tab-1", "local", Some("main"), "users"),
@@ -633,11 +649,11 @@ mod tests {
     fn grid_workspace_store_keeps_same_table_isolated_per_tab() {
         let mut store = GridWorkspaceStore::default();
         let mut left_tab = DataGridState::new();
-        left_tab.new_rows.push(vec!["draft-a".to_string()]);
+        left_tab.new_rows.push(vec!["draft-a".into()]);
         left_tab.cursor = (3, 0);
 
         let mut right_tab = DataGridState::new();
-        right_tab.new_rows.push(vec!["draft-b".to_string()]);
+        right_tab.new_rows.push(vec!["draft-b".into()]);
         right_tab.cursor = (9, 1);
 
         let left_id = workspace("tab-left", "local", Some("main"), "users");
@@ -654,9 +670,9 @@ mod tests {
             .expect("right tab workspace should exist");
 
         assert_eq!(restored_left.cursor, (3, 0));
-        assert_eq!(restored_left.new_rows[0][0], "draft-a");
+        assert_eq!(restored_left.new_rows[0][0], CellEdit::from("draft-a"));
         assert_eq!(restored_right.cursor, (9, 1));
-        assert_eq!(restored_right.new_rows[0][0], "draft-b");
+        assert_eq!(restored_right.new_rows[0][0], CellEdit::from("draft-b"));
     }
 
     #[test]
@@ -8,7 +8,7 @@ use crate::app::dialogs::host::DialogId;
 use crate::core::constants;
 use crate::data::{
     ConnectResult, ConnectionConfig, DatabaseType, DbError, connect_database, drop_database,
-    execute_typed, execute_typed_cancellable, get_tables_for_database, load_schema_catalog,
+    execute_typed, execute_typed_progressive, get_tables_for_database, load_schema_catalog,
     secret::SecretStore, ssh_tunnel::SSH_TUNNEL_MANAGER,
 };
 use crate::ui;
@@ -17,6 +17,14 @@ use super::DbManagerApp;
 use super::message::Message;
 
 fn prepare_tab_for_query_execution(tab: &mut crate::ui::QueryTab, sql: &str, request_id: u64) {
+    // 执行分页游标以外的 SQL 即退出分页浏览
+    if tab
+        .table_pager
+        .as_ref()
+        .is_some_and(|pager| !pager.owns_sql(sql))
+    {
+        tab.table_pager = None;
+    }
     tab.sql = sql.to_string();
     tab.result_set = None;
     tab.modified = false;
@@ -146,6 +154,7 @@ impl DbManagerApp {
             self.state.sidebar_panel_state.loading_routines = false;
             self.state.sidebar_panel_state.clear_triggers();
             self.state.sidebar_panel_state.clear_routines();
+            self.state.sidebar_panel_state.clear_schema_objects();
             self.session.refresh_connecting_flag();
 
             // SQLite：连接后立即加载 schema catalog（无选库步骤）
@@ -304,6 +313,7 @@ impl DbManagerApp {
         self.state.sidebar_panel_state.loading_routines = false;
         self.state.sidebar_panel_state.clear_triggers();
         self.state.sidebar_panel_state.clear_routines();
+        self.state.sidebar_panel_state.clear_schema_objects();
         self.session.refresh_connecting_flag();
         // TaskRegistry 注册（双通道迁移）
         let db_key = crate::session::task_registry::OperationKey::SelectDatabase {
@@ -413,6 +423,7 @@ impl DbManagerApp {
 
         self.session.manager.disconnect(&name);
         self.cancel_queries_for_connection(&name);
+        self.drop_pinned_sessions_for_connection(&name);
         self.session.pending_connect_requests.remove(&name);
         self.session.pending_database_requests.remove(&name);
         self.session
@@ -568,8 +579,9 @@ impl DbManagerApp {
         }
         let target_connection = conn.config.name.clone();
 
-        let use_backticks = matches!(conn.config.db_type, crate::data::DatabaseType::MySQL);
-        let quoted_table = match ui::quote_identifier(table, use_backticks) {
+        let dialect =
+            crate::domain::identifier::IdentifierDialect::from_db_type(conn.config.db_type);
+        let quoted_table = match ui::quote_table_identifier(table, dialect) {
             Ok(name) => name,
             Err(e) => {
                 self.session.notifications.error(format!("表名无效: {}", e));
@@ -616,11 +628,24 @@ impl DbManagerApp {
 
         let config = conn.config.clone();
         let tx = self.session.tx.clone();
+        let pinned_session = match self.pinned_session_for_active_tab(&active_name, &config) {
+            Ok(session) => session,
+            Err(message) => {
+                self.session.notifications.warning(message);
+                return None;
+            }
+        };
 
         tracing::info!(connection = %active_name, sql_length = sql.len(), "开始执行查询");
 
-        // 添加到命令历史
-        if self.session.command_history.first() != Some(&sql) {
+        // 添加到命令历史（翻页查询不计入）
+        let is_page_query = self
+            .session
+            .tab_manager
+            .get_active()
+            .and_then(|tab| tab.table_pager.as_ref())
+            .is_some_and(|pager| pager.owns_sql(&sql));
+        if !is_page_query && self.session.command_history.first() != Some(&sql) {
             self.session.command_history.insert(0, sql.clone());
             // 限制每个连接最多保存历史记录
             if self.session.command_history.len()
@@ -685,7 +710,32 @@ impl DbManagerApp {
             let start = Instant::now();
             let timeout_secs = constants::database::QUERY_TIMEOUT_SECS;
             let sql_for_exec = sql.clone();
-            let typed_fut = execute_typed_cancellable(&config, &sql_for_exec, &cancel_token);
+            // 第一批行到达即推送首屏，完整结果仍在 ExecutionFinished 中送达
+            let first_page_tx = tx.clone();
+            let first_page_key = query_key.clone();
+            let first_page_tab_id = tx_tab_id.clone();
+            let on_first_page = move |page| {
+                use crate::session::runtime_event::{RuntimeEvent, RuntimeOutcome};
+                let _ = first_page_tx.send(Message::RuntimeEvent(RuntimeEvent {
+                    task_id: query_task_id,
+                    key: first_page_key,
+                    outcome: RuntimeOutcome::ExecutionFirstPage {
+                        document: query_document_id,
+                        tab_id: first_page_tab_id,
+                        result: page,
+                    },
+                }));
+            };
+            // 手动提交模式在独占会话的事务中执行，不做首屏推送
+            let typed_fut = async {
+                match &pinned_session {
+                    Some(session) => session.execute(&sql_for_exec, &cancel_token).await,
+                    None => {
+                        execute_typed_progressive(&config, &sql_for_exec, &cancel_token, on_first_page)
+                            .await
+                    }
+                }
+            };
             let timeout_fut = sleep(Duration::from_secs(timeout_secs));
             let cancel_fut = cancel_token.cancelled();
             tokio::pin!(typed_fut);
@@ -0,0 +1,183 @@
+//! 结构化执行计划
+//!
+//! 编辑器的 Explain 动作不再把 EXPLAIN 文本当作普通结果，而是独立生成计划树，
+//! 结果写入当前 Tab 的 `query_plan`，由 Explain 面板渲染。
+//! 每次分析都登记为任务面板中的可取消任务，取消时经 `TaskRegistry` 通知执行路径。
+
+use std::time::Instant;
+
+use crate::core::{BottomPanelTab, constants};
+use crate::domain::plan::ExplainMode;
+use crate::session::tab::QueryPlanState;
+
+use super::{DbManagerApp, Message};
+
+/// Tab ID 对应的 Explain 任务键
+fn explain_key_for_tab(tab_id: &str) -> crate::session::task_registry::OperationKey {
+    let document = uuid::Uuid::parse_str(tab_id)
+        .map(crate::domain::ids::DocumentId::from)
+        .unwrap_or_else(|_| {
+            crate::domain::ids::DocumentId::from(uuid::Uuid::new_v5(
+                &uuid::Uuid::NAMESPACE_OID,
+                tab_id.as_bytes(),
+            ))
+        });
+    crate::session::task_registry::OperationKey::Explain { document }
+}
+
+impl DbManagerApp {
+    /// 为当前 SQL 生成执行计划并切换到 Explain 面板
+    ///
+    /// `ExplainMode::Analyze` 会真实执行语句，数据层保证事务始终回滚。
+    pub(in crate::app) fn explain_active_sql(&mut self, sql: String, mode: ExplainMode) {
+        let sql = sql.trim().to_string();
+        if sql.is_empty() {
+            return;
+        }
+        let Some(conn) = self.session.manager.get_active() else {
+            self.session.notifications.warning("请先连接数据库");
+            return;
+        };
+        let config = conn.config.clone();
+        let tx = self.session.tx.clone();
+
+        let mode = if mode == ExplainMode::Analyze
+            && config.db_type == crate::data::DatabaseType::SQLite
+        {
+            self.session
+                .notifications
+                .info("SQLite 不支持 EXPLAIN ANALYZE，已改为显示估算计划");
+            ExplainMode::Estimate
+        } else {
+            mode
+        };
+
+        let first_line = sql.lines().next().unwrap_or_default();
+        let description = match mode {
+            ExplainMode::Estimate => format!("EXPLAIN {}", first_line),
+            ExplainMode::Analyze => format!("EXPLAIN ANALYZE {}", first_line),
+        };
+        let progress_id = self.session.progress.start(description, true);
+
+        let tab = self.session.ensure_active_tab();
+        let tab_id = tab.id.clone();
+        let previous = tab.query_plan.replace(QueryPlanState::Loading {
+            sql: sql.clone(),
+            progress_id,
+        });
+        // 新请求会 supersede 同一 Tab 的旧任务，旧进度条目随之结束
+        if let Some(QueryPlanState::Loading {
+            progress_id: previous_progress,
+            ..
+        }) = previous
+        {
+            self.session.progress.finish(previous_progress);
+        }
+
+        let explain_key = explain_key_for_tab(&tab_id);
+        let (task_id, cancel_token) = self.session.task_registry.register(
+            explain_key.clone(),
+            crate::session::task_registry::TaskKind::Query,
+        );
+        let key_for_attach = explain_key.clone();
+        let cancel_token_for_attach = cancel_token.clone();
+
+        let handle = self.session.runtime.spawn(async move {
+            use tokio::time::{Duration, timeout};
+
+            let start = Instant::now();
+            let timeout_secs = constants::database::QUERY_TIMEOUT_SECS;
+            let result = timeout(
+                Duration::from_secs(timeout_secs),
+                crate::data::explain_query(&config, &sql, mode, &cancel_token),
+            )
+            .await
+            .map_err(|_| format!("生成执行计划超时 ({}秒)", timeout_secs))
+            .and_then(|r| r.map_err(|e| e.to_string()));
+            let elapsed_ms = start.elapsed().as_millis() as u64;
+
+            use crate::session::runtime_event::{RuntimeEvent, RuntimeOutcome};
+            let _ = tx.send(Message::RuntimeEvent(RuntimeEvent {
+                task_id,
+                key: explain_key,
+                outcome: RuntimeOutcome::PlanExplained {
+                    tab_id,
+                    sql,
+                    result,
+                    elapsed_ms,
+                },
+            }));
+        });
+        self.session.task_registry.attach(
+            task_id,
+            key_for_attach,
+            crate::session::task_registry::TaskKind::Query,
+            handle,
+            cancel_token_for_attach,
+        );
+
+        self.set_bottom_panel_visible(true);
+        self.set_bottom_panel_tab(BottomPanelTab::Explain);
+    }
+
+    pub(in crate::app) fn handle_plan_explained(
+        &mut self,
+        tab_id: String,
+        sql: String,
+        result: Result<crate::domain::plan::QueryPlan, String>,
+        elapsed_ms: u64,
+    ) {
+        let Some(tab) = self
+            .session
+            .tab_manager
+            .tabs
+            .iter_mut()
+            .find(|tab| tab.id == tab_id)
+        else {
+            return;
+        };
+        if let Some(QueryPlanState::Loading { progress_id, .. }) = tab.query_plan {
+            self.session.progress.finish(progress_id);
+        }
+        tab.query_plan = Some(match result {
+            Ok(plan) => QueryPlanState::Ready {
+                sql,
+                plan,
+                elapsed_ms,
+            },
+            Err(error) => {
+                tracing::warn!(error = %error, "生成执行计划失败");
+                self.session
+                    .notifications
+                    .error(format!("生成执行计划失败: {}", error));
+                QueryPlanState::Failed { sql, error }
+            }
+        });
+    }
+
+    /// 取消任务面板中的任务；执行计划任务同时经 `TaskRegistry` 取消
+    pub(in crate::app) fn cancel_progress_task(&mut self, progress_id: u64) {
+        self.session.progress.cancel(progress_id);
+
+        let Some(tab) = self.session.tab_manager.tabs.iter_mut().find(|tab| {
+            matches!(
+                tab.query_plan,
+                Some(QueryPlanState::Loading { progress_id: id, .. }) if id == progress_id
+            )
+        }) else {
+            return;
+        };
+        let key = explain_key_for_tab(&tab.id);
+        let sql = tab
+            .query_plan
+            .take()
+            .map(|state| state.sql().to_string())
+            .unwrap_or_default();
+        tab.query_plan = Some(QueryPlanState::Failed {
+            sql,
+            error: "已取消".to_string(),
+        });
+        self.session.task_registry.cancel_by_key(&key);
+        self.session.notifications.warning("已取消执行计划分析");
+    }
+}
@@ -187,6 +187,9 @@ impl DbManagerApp {
                 Message::RoutinesFetched(conn_name, db_name, request_id, result) => {
                     self.handle_routines_fetched(ctx, conn_name, db_name, request_id, result);
                 }
+                Message::TransactionEnded(conn_name, committed, result) => {
+                    self.handle_transaction_ended(conn_name, committed, result);
+                }
             }
         }
         if self.session.needs_repaint {
@@ -762,6 +765,12 @@ impl DbManagerApp {
             } => {
                 self.handle_grid_save_done(ctx, result, table, elapsed_ms);
             }
+            RuntimeOutcome::ExecutionFirstPage { tab_id, result, .. } => {
+                // 中间事件：任务仍在运行，不能标记完成
+                self.handle_query_first_page(tab_id, result);
+                self.session.needs_repaint = true;
+                return;
+            }
             RuntimeOutcome::ExecutionFinished {
                 sql,
                 connection_name: conn_name,
@@ -774,6 +783,34 @@ impl DbManagerApp {
                     ctx, sql, conn_name, tab_id, result, elapsed_ms,
                 );
             }
+            RuntimeOutcome::PlanExplained {
+                tab_id,
+                sql,
+                result,
+                elapsed_ms,
+            } => {
+                self.handle_plan_explained(tab_id, sql, result, elapsed_ms);
+            }
+            RuntimeOutcome::TableRowsEstimated {
+                tab_id,
+                table,
+                result,
+            } => {
+                self.handle_table_rows_estimated(tab_id, table, result);
+            }
+            RuntimeOutcome::ReferenceCandidatesLoaded {
+                table,
+                key_column,
+                search,
+                result,
+            } => {
+                let lookup = ui::ReferenceLookup {
+                    table,
+                    key_column,
+                    search,
+                };
+                self.handle_reference_candidates_loaded(lookup, result);
+            }
             RuntimeOutcome::CatalogLoaded {
                 connection_id,
                 database,
@@ -789,6 +826,17 @@ impl DbManagerApp {
                             Some(std::sync::Arc::new(tm.clone()));
                     }
                     self.session.autocomplete.set_from_catalog(&schema);
+                    if let Some(connection_name) = self.session.manager.active.clone()
+                        && self
+                            .session
+                            .manager
+                            .get_active()
+                            .is_some_and(|conn| conn.id == connection_id)
+                    {
+                        self.state
+                            .sidebar_panel_state
+                            .set_schema_objects(&connection_name, &schema);
+                    }
                     self.session
                         .schema_catalogs
                         .insert((connection_id, database), schema);
@@ -806,6 +854,35 @@ impl DbManagerApp {
         self.session.needs_repaint = true;
     }
 
+    /// 显示流式查询的首批结果，不改变执行状态。
+    fn handle_query_first_page(
+        &mut self,
+        tab_id: String,
+        result: crate::domain::result::ResultSet,
+    ) {
+        let Some(tab_index) = self
+            .session
+            .tab_manager
+            .tabs
+            .iter()
+            .position(|t| t.id == tab_id)
+        else {
+            return;
+        };
+        let first_page = std::sync::Arc::new(result);
+        if let Some(tab) = self.session.tab_manager.tabs.get_mut(tab_index) {
+            // 翻页查询只有一页，等完整结果裁剪后再显示
+            if !tab.executing || tab.table_pager.as_ref().is_some_and(|p| p.is_loading()) {
+                return;
+            }
+            tab.result_set = Some(first_page.clone());
+        }
+        if tab_index == self.session.tab_manager.active_index {
+            self.state.grid_state.result_set = Some(first_page);
+            self.reveal_bottom_panel_for_query(crate::core::BottomPanelTab::Results);
+        }
+    }
+
     /// 处理查询执行完成（T1 cutover — 替代 handle_query_done）。
     fn handle_query_execution_finished(
         &mut self,
@@ -851,11 +928,22 @@ impl DbManagerApp {
                         .notifications
                         .warning("检测到多条语句，仅显示第一条结果");
                 }
-                let (result_set, affected_rows) = match outcome.statements.first() {
+                let (mut result_set, affected_rows) = match outcome.statements.first() {
                     Some(StatementOutcome::ResultSet(rs)) => (Some(rs.clone()), None),
                     Some(StatementOutcome::AffectedRows { rows }) => (None, Some(*rows)),
                     _ => (None, None),
                 };
+                let page_request = match (
+                    result_set.as_mut(),
+                    self.session
+                        .tab_manager
+                        .tabs
+                        .get_mut(tab_index)
+                        .and_then(|tab| tab.table_pager.as_mut()),
+                ) {
+                    (Some(rs), Some(pager)) => pager.complete(&sql, rs),
+                    _ => None,
+                };
 
                 if sql_hints.is_create_database {
                     self.mark_onboarding_database_initialized();
@@ -914,6 +1002,15 @@ impl DbManagerApp {
                             .set_columns(table.clone(), arc.column_names());
                     }
                     self.state.grid_state.result_set = typed_arc;
+                    if let Some(request) = page_request {
+                        let row = match request {
+                            crate::core::PageRequest::Previous => row_count.saturating_sub(1),
+                            crate::core::PageRequest::First | crate::core::PageRequest::Next => 0,
+                        };
+                        self.state.grid_state.cursor.0 = row;
+                        self.state.grid_state.scroll_to_row = Some(row);
+                    }
+                    self.sync_grid_page_info();
                     self.reveal_bottom_panel_for_query(crate::core::BottomPanelTab::Results);
                     self.state.grid_state.rows_to_delete.clear();
                     self.persist_active_grid_workspace();
@@ -933,6 +1030,9 @@ impl DbManagerApp {
                     tab.pending_request_id = None;
                     tab.executing = false;
                     tab.last_error = Some(e.clone());
+                    if let Some(pager) = tab.table_pager.as_mut() {
+                        pager.abort();
+                    }
                 }
             }
         }
@@ -4,10 +4,14 @@
 
 pub(crate) mod database;
 pub(crate) mod er_diagram;
+pub(crate) mod explain;
 pub(crate) mod handler;
 pub(crate) mod message;
 pub(crate) mod metadata;
+pub(crate) mod reference_candidates;
 pub(crate) mod request_lifecycle;
+pub(crate) mod table_paging;
+pub(crate) mod transaction;
 
 pub(crate) use super::DbManagerApp;
 pub(crate) use message::Message;
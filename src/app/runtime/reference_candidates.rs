@@ -0,0 +1,388 @@
+//! 外键候选值加载
+//!
+//! Insert 模式编辑外键列时，表格的候选选择器请求被引用表中的行。
+//! 查询只取键列与显示列并限制行数，搜索文本以不区分大小写的包含条件匹配键值或显示列。
+//! 显示列按连接、数据库与被引用表保存在配置中，未设置时按列名推断。
+
+use crate::core::{ReferenceLabelColumn, constants};
+use crate::domain::identifier::IdentifierDialect;
+use crate::domain::metadata::TableMetadata;
+use crate::domain::result::{ResultColumn, ResultSet};
+use crate::domain::value::{DbTypeFamily, DbTypeInfo};
+use crate::ui;
+
+use super::{DbManagerApp, Message};
+
+/// 候选查询最多列出的行数
+const REFERENCE_CANDIDATE_LIMIT: usize = 50;
+/// 未设置显示列时优先使用的列名
+const DEFAULT_LABEL_COLUMNS: &[&str] = &[
+    "name",
+    "title",
+    "label",
+    "display_name",
+    "full_name",
+    "username",
+    "email",
+    "code",
+];
+
+/// 被引用表中可作为显示列的列（键列以外）
+fn label_options(table: &TableMetadata, key_column: &str) -> Vec<String> {
+    table
+        .columns
+        .iter()
+        .filter(|column| column.name != key_column)
+        .map(|column| column.name.clone())
+        .collect()
+}
+
+/// 默认显示列：常见的名称列，其次第一个文本列
+fn default_label_column(table: &TableMetadata, key_column: &str) -> Option<String> {
+    let candidates = || {
+        table
+            .columns
+            .iter()
+            .filter(move |column| column.name != key_column)
+    };
+    DEFAULT_LABEL_COLUMNS
+        .iter()
+        .find_map(|name| candidates().find(|column| column.name.eq_ignore_ascii_case(name)))
+        .or_else(|| candidates().find(|column| column.type_info.family == DbTypeFamily::Text))
+        .map(|column| column.name.clone())
+}
+
+/// 候选查询：键列与显示列按键排序，多取一行用于判断是否还有更多
+fn reference_candidates_sql(
+    lookup: &ui::ReferenceLookup,
+    key: &ResultColumn,
+    label: Option<&ResultColumn>,
+    dialect: IdentifierDialect,
+) -> Result<String, String> {
+    let table_ref = ui::quote_table_identifier(&lookup.table, dialect)?;
+    let columns: Vec<ResultColumn> = std::iter::once(key).chain(label).cloned().collect();
+
+    let filter = if lookup.search.is_empty() {
+        None
+    } else {
+        let filters: Vec<ui::ColumnFilter> = columns
+            .iter()
+            .map(|column| {
+                ui::ColumnFilter::new(column.name.clone())
+                    .with_operator(ui::FilterOperator::Contains)
+                    .with_value(lookup.search.clone())
+                    .with_logic(ui::FilterLogic::Or)
+            })
+            .collect();
+        ui::compile_filter_clause(&filters, &columns, dialect)?
+            .map(|clause| clause.inline_sql())
+            .transpose()?
+    };
+
+    let select = columns
+        .iter()
+        .map(|column| dialect.quote(&column.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Ok(format!(
+        "SELECT {} FROM {}{} ORDER BY {} LIMIT {};",
+        select,
+        table_ref,
+        filter
+            .map(|filter| format!(" WHERE {}", filter))
+            .unwrap_or_default(),
+        dialect.quote(&key.name),
+        REFERENCE_CANDIDATE_LIMIT + 1
+    ))
+}
+
+/// 把查询结果转换为候选行：第一列为键值，第二列（如有）为显示列
+fn candidates_from_result(
+    result: &ResultSet,
+    label_column: Option<String>,
+    label_options: Vec<String>,
+) -> ui::ReferenceCandidates {
+    let truncated = result.row_count > REFERENCE_CANDIDATE_LIMIT;
+    let rows = (0..result.row_count.min(REFERENCE_CANDIDATE_LIMIT))
+        .map(|row| ui::ReferenceCandidate {
+            key: result.cell(row, 0).display(),
+            label: (result.column_count() > 1).then(|| result.cell(row, 1).display()),
+        })
+        .collect();
+    ui::ReferenceCandidates {
+        label_column,
+        label_options,
+        rows,
+        truncated,
+    }
+}
+
+impl DbManagerApp {
+    /// 显示列设置的范围：(连接, 数据库)
+    fn reference_label_scope(&self) -> Option<(String, Option<String>)> {
+        let connection = self.session.manager.active.clone()?;
+        let database = self
+            .session
+            .manager
+            .get_active()
+            .and_then(|connection| connection.selected_database.clone());
+        Some((connection, database))
+    }
+
+    /// 被引用表的显示列：配置中的设置优先，否则按列名推断
+    fn reference_label_column(
+        &self,
+        lookup: &ui::ReferenceLookup,
+        table: &TableMetadata,
+    ) -> Option<String> {
+        let configured = self
+            .reference_label_scope()
+            .and_then(|(connection, database)| {
+                self.app_config
+                    .reference_label_columns
+                    .iter()
+                    .find(|setting| {
+                        setting.applies_to(&connection, database.as_deref(), &lookup.table)
+                    })
+            })
+            .map(|setting| setting.column.clone());
+        match configured {
+            // 配置的列已不存在时只显示键值
+            Some(column) => column.filter(|column| {
+                *column != lookup.key_column
+                    && table
+                        .columns
+                        .iter()
+                        .any(|candidate| candidate.name == *column)
+            }),
+            None => default_label_column(table, &lookup.key_column),
+        }
+    }
+
+    /// 异步加载外键候选值，结果以 `ReferenceCandidatesLoaded` 事件送回
+    pub(in crate::app) fn load_reference_candidates(&mut self, lookup: ui::ReferenceLookup) {
+        let Some(conn) = self.session.manager.get_active() else {
+            return;
+        };
+        let config = conn.config.clone();
+        let connection_id = conn.id;
+        let dialect = IdentifierDialect::from_db_type(config.db_type);
+
+        let table = self
+            .active_schema_catalog()
+            .and_then(|catalog| catalog.table(&lookup.table))
+            .cloned();
+        let column = |name: &str| ResultColumn {
+            name: name.to_string(),
+            type_info: table
+                .as_ref()
+                .and_then(|table| table.columns.iter().find(|column| column.name == name))
+                .map(|column| column.type_info.clone())
+                .unwrap_or(DbTypeInfo {
+                    family: DbTypeFamily::Other,
+                    native_name: String::new(),
+                    nullable: None,
+                }),
+        };
+        let label_column = table
+            .as_ref()
+            .and_then(|table| self.reference_label_column(&lookup, table));
+        let label_options = table
+            .as_ref()
+            .map(|table| label_options(table, &lookup.key_column))
+            .unwrap_or_default();
+
+        let sql = match reference_candidates_sql(
+            &lookup,
+            &column(&lookup.key_column),
+            label_column.as_deref().map(column).as_ref(),
+            dialect,
+        ) {
+            Ok(sql) => sql,
+            Err(error) => {
+                self.state
+                    .grid_state
+                    .reference_picker
+                    .set_result(&lookup, Err(error));
+                return;
+            }
+        };
+
+        let tx = self.session.tx.clone();
+        let candidates_key = crate::session::task_registry::OperationKey::Metadata {
+            connection: connection_id,
+            scope: crate::session::task_registry::MetadataScope::ReferenceCandidates,
+        };
+        let (task_id, _cancel_token) = self.session.task_registry.register(
+            candidates_key.clone(),
+            crate::session::task_registry::TaskKind::Metadata,
+        );
+
+        self.session.runtime.spawn(async move {
+            use tokio::time::{Duration, timeout};
+
+            let timeout_secs = constants::database::CONNECTION_TIMEOUT_SECS;
+            let result = timeout(
+                Duration::from_secs(timeout_secs),
+                crate::data::execute_typed(&config, &sql),
+            )
+            .await
+            .map_err(|_| format!("加载候选行超时 ({}秒)", timeout_secs))
+            .and_then(|r| r.map_err(|e| e.to_string()))
+            .and_then(|outcome| {
+                outcome
+                    .statements
+                    .into_iter()
+                    .find_map(|statement| match statement {
+                        crate::domain::execution::StatementOutcome::ResultSet(result) => {
+                            Some(result)
+                        }
+                        _ => None,
+                    })
+                    .ok_or_else(|| "查询没有返回结果集".to_string())
+            })
+            .map(|result| candidates_from_result(&result, label_column, label_options));
+
+            use crate::session::runtime_event::{RuntimeEvent, RuntimeOutcome};
+            let _ = tx.send(Message::RuntimeEvent(RuntimeEvent {
+                task_id,
+                key: candidates_key,
+                outcome: RuntimeOutcome::ReferenceCandidatesLoaded {
+                    table: lookup.table,
+                    key_column: lookup.key_column,
+                    search: lookup.search,
+                    result,
+                },
+            }));
+        });
+    }
+
+    pub(in crate::app) fn handle_reference_candidates_loaded(
+        &mut self,
+        lookup: ui::ReferenceLookup,
+        result: Result<ui::ReferenceCandidates, String>,
+    ) {
+        if let Err(error) = &result {
+            tracing::warn!(table = %lookup.table, error = %error, "加载外键候选行失败");
+        }
+        self.state
+            .grid_state
+            .reference_picker
+            .set_result(&lookup, result);
+    }
+
+    /// 保存被引用表的显示列并重新加载候选行
+    pub(in crate::app) fn set_reference_label_column(
+        &mut self,
+        table: String,
+        column: Option<String>,
+    ) {
+        let Some((connection, database)) = self.reference_label_scope() else {
+            return;
+        };
+        let settings = &mut self.app_config.reference_label_columns;
+        match settings
+            .iter_mut()
+            .find(|setting| setting.applies_to(&connection, database.as_deref(), &table))
+        {
+            Some(setting) => setting.column = column,
+            None => settings.push(ReferenceLabelColumn {
+                connection,
+                database,
+                table,
+                column,
+            }),
+        }
+        self.save_config_debounced();
+
+        if let Some(lookup) = self
+            .state
+            .grid_state
+            .reference_picker
+            .current_lookup()
+            .cloned()
+        {
+            self.load_reference_candidates(lookup);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::metadata::ColumnMetadata;
+
+    fn type_info(family: DbTypeFamily) -> DbTypeInfo {
+        DbTypeInfo {
+            family,
+            native_name: String::new(),
+            nullable: None,
+        }
+    }
+
+    fn column(name: &str, family: DbTypeFamily) -> ColumnMetadata {
+        ColumnMetadata {
+            name: name.to_string(),
+            position: 0,
+            type_info: type_info(family),
+            is_nullable: true,
+            is_primary_key: false,
+            default_value: None,
+        }
+    }
+
+    fn customers() -> TableMetadata {
+        TableMetadata {
+            name: "customers".to_string(),
+            schema: None,
+            columns: vec![
+                column("id", DbTypeFamily::Integer),
+                column("note", DbTypeFamily::Text),
+                column("Name", DbTypeFamily::Text),
+            ],
+            primary_key: None,
+            unique_keys: Vec::new(),
+            foreign_keys: Vec::new(),
+            indexes: Vec::new(),
+            check_constraints: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn label_column_prefers_name_like_columns() {
+        let mut table = customers();
+        assert_eq!(default_label_column(&table, "id").as_deref(), Some("Name"));
+        table.columns.retain(|column| column.name != "Name");
+        assert_eq!(default_label_column(&table, "id").as_deref(), Some("note"));
+        assert_eq!(label_options(&table, "id"), vec!["note".to_string()]);
+    }
+
+    #[test]
+    fn candidate_query_is_bounded_and_searches_key_and_label() {
+        let key = ResultColumn {
+            name: "id".to_string(),
+            type_info: type_info(DbTypeFamily::Integer),
+        };
+        let label = ResultColumn {
+            name: "name".to_string(),
+            type_info: type_info(DbTypeFamily::Text),
+        };
+        let mut lookup = ui::ReferenceLookup {
+            table: "customers".to_string(),
+            key_column: "id".to_string(),
+            search: String::new(),
+        };
+        assert_eq!(
+            reference_candidates_sql(&lookup, &key, None, IdentifierDialect::PostgreSql).unwrap(),
+            "SELECT \"id\" FROM \"customers\" ORDER BY \"id\" LIMIT 51;"
+        );
+
+        lookup.search = "o'k".to_string();
+        let sql = reference_candidates_sql(&lookup, &key, Some(&label), IdentifierDialect::MySql)
+            .unwrap();
+        assert!(sql.starts_with("SELECT `id`, `name` FROM `customers` WHERE ("));
+        assert!(sql.contains("CAST(`id` AS CHAR)"));
+        assert!(sql.contains(" OR "));
+        assert!(sql.ends_with(" ORDER BY `id` LIMIT 51;"));
+    }
+}
@@ -83,6 +83,7 @@ impl DbManagerApp {
         self.state.selected_cell = None;
         self.restore_grid_surface_from_active_tab();
         self.state.grid_state.result_set = active_result_set;
+        self.sync_grid_page_info();
         if let Some(tab) = query_bottom_panel_tab {
             self.reveal_bottom_panel_for_query(tab);
         }
@@ -149,7 +150,13 @@ impl DbManagerApp {
             .session
             .task_registry
             .active_keys()
-            .filter(|(k, _)| matches!(k, crate::session::task_registry::OperationKey::Query { .. }))
+            .filter(|(k, _)| {
+                matches!(
+                    k,
+                    crate::session::task_registry::OperationKey::Query { .. }
+                        | crate::session::task_registry::OperationKey::Explain { .. }
+                )
+            })
             .map(|(k, _)| k.clone())
             .collect();
         for key in query_keys {
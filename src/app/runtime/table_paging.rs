@@ -0,0 +1,217 @@
+//! 表格分页浏览
+//!
+//! 打开表时为当前 Tab 建立 `TablePager`；翻页查询复用普通查询执行路径，
+//! 结果回到 `handle_query_execution_finished` 后由游标裁剪为一页。
+//! 服务端筛选模式下，筛选条件编译为 WHERE 谓词交给游标，翻页在筛选结果上进行。
+
+use crate::core::{PageRequest, TablePager, constants};
+use crate::domain::identifier::IdentifierDialect;
+use crate::ui;
+
+use super::{DbManagerApp, Message};
+
+impl DbManagerApp {
+    /// 为当前 Tab 建立分页游标，返回首页 SQL
+    ///
+    /// 有主键时按主键 keyset 分页，否则回退到 LIMIT/OFFSET。刷新同一张表时保留已应用的服务端筛选。
+    pub(in crate::app) fn begin_table_paging(&mut self, table: &str) -> Result<String, String> {
+        let dialect = self
+            .session
+            .manager
+            .get_active()
+            .map(|conn| IdentifierDialect::from_db_type(conn.config.db_type))
+            .unwrap_or(IdentifierDialect::SQLite);
+        let table_ref = ui::quote_table_identifier(table, dialect)?;
+        let key_columns = self
+            .state
+            .grid_state
+            .table_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.primary_key.as_ref())
+            .map(|key| key.columns.clone())
+            .unwrap_or_default();
+
+        let mut pager = TablePager::new(
+            table_ref,
+            dialect,
+            key_columns,
+            constants::database::DEFAULT_QUERY_LIMIT,
+        );
+        let tab = self.session.ensure_active_tab();
+        if let Some(previous) = &tab.table_pager
+            && previous.table_ref() == pager.table_ref()
+        {
+            pager.set_filter(previous.filter().map(str::to_string));
+        }
+        let sql = pager.restart();
+        let tab_id = tab.id.clone();
+        tab.table_pager = Some(pager);
+        self.load_table_row_estimate(tab_id, table.to_string());
+        Ok(sql)
+    }
+
+    /// 当前 Tab 处于分页浏览时翻页
+    pub(in crate::app) fn request_table_page(&mut self, request: PageRequest) {
+        if self.state.grid_state.has_changes() {
+            self.session
+                .notifications
+                .warning("当前页有未保存的修改，请先保存或放弃后再翻页");
+            return;
+        }
+        let Some(pager) = self
+            .session
+            .tab_manager
+            .get_active_mut()
+            .and_then(|tab| tab.table_pager.as_mut())
+        else {
+            return;
+        };
+        if pager.is_loading() {
+            return;
+        }
+        if let Some(sql) = pager.begin(request) {
+            let _ = self.execute(sql);
+        }
+    }
+
+    /// 按当前筛选条件重新查询分页浏览的表
+    ///
+    /// 服务端筛选开启时把筛选条件编译为 WHERE 谓词；关闭时撤销已应用的谓词。
+    pub(in crate::app) fn apply_server_side_filters(&mut self) {
+        if self.state.grid_state.has_changes() {
+            self.session
+                .notifications
+                .warning("当前页有未保存的修改，请先保存或放弃后再筛选");
+            return;
+        }
+        let columns = self
+            .state
+            .grid_state
+            .result_set
+            .as_ref()
+            .map(|result| result.columns.clone());
+        let Some(pager) = self
+            .session
+            .tab_manager
+            .get_active_mut()
+            .and_then(|tab| tab.table_pager.as_mut())
+        else {
+            self.session
+                .notifications
+                .warning("服务端筛选仅适用于分页浏览的表");
+            return;
+        };
+        if pager.is_loading() {
+            return;
+        }
+
+        let filter = if self.state.grid_state.server_side_filter {
+            let compiled = ui::compile_filter_clause(
+                &self.state.grid_state.filters,
+                columns.as_deref().unwrap_or_default(),
+                pager.dialect(),
+            )
+            .and_then(|clause| clause.map(|clause| clause.inline_sql()).transpose());
+            match compiled {
+                Ok(filter) => filter,
+                Err(error) => {
+                    self.session
+                        .notifications
+                        .error(format!("无法生成服务端筛选条件: {}", error));
+                    return;
+                }
+            }
+        } else {
+            None
+        };
+        pager.set_filter(filter);
+        let sql = pager.restart();
+        self.sync_grid_page_info();
+        let _ = self.execute(sql);
+    }
+
+    /// 将当前 Tab 的分页游标投影到表格模式栏
+    pub(in crate::app) fn sync_grid_page_info(&mut self) {
+        self.state.grid_state.page_info = self
+            .session
+            .tab_manager
+            .get_active()
+            .and_then(|tab| tab.table_pager.as_ref())
+            .map(|pager| ui::GridPageInfo {
+                label: pager.status_label(),
+                has_previous: pager.has_previous(),
+                has_next: pager.has_next(),
+                filtered: pager.filter().is_some(),
+            });
+    }
+
+    /// 异步估算表行数，结果以 `TableRowsEstimated` 事件送回
+    fn load_table_row_estimate(&mut self, tab_id: String, table: String) {
+        let Some(conn) = self.session.manager.get_active() else {
+            return;
+        };
+        let config = conn.config.clone();
+        let connection_id = conn.id;
+        let tx = self.session.tx.clone();
+
+        let estimate_key = crate::session::task_registry::OperationKey::Metadata {
+            connection: connection_id,
+            scope: crate::session::task_registry::MetadataScope::RowEstimate,
+        };
+        let (task_id, _cancel_token) = self.session.task_registry.register(
+            estimate_key.clone(),
+            crate::session::task_registry::TaskKind::Metadata,
+        );
+
+        self.session.runtime.spawn(async move {
+            use tokio::time::{Duration, timeout};
+
+            let timeout_secs = constants::database::CONNECTION_TIMEOUT_SECS;
+            let result = timeout(
+                Duration::from_secs(timeout_secs),
+                crate::data::estimate_table_rows(&config, &table),
+            )
+            .await
+            .map_err(|_| format!("估算表行数超时 ({}秒)", timeout_secs))
+            .and_then(|r| r.map_err(|e| e.to_string()));
+
+            use crate::session::runtime_event::{RuntimeEvent, RuntimeOutcome};
+            let _ = tx.send(Message::RuntimeEvent(RuntimeEvent {
+                task_id,
+                key: estimate_key,
+                outcome: RuntimeOutcome::TableRowsEstimated {
+                    tab_id,
+                    table,
+                    result,
+                },
+            }));
+        });
+    }
+
+    pub(in crate::app) fn handle_table_rows_estimated(
+        &mut self,
+        tab_id: String,
+        table: String,
+        result: Result<Option<u64>, String>,
+    ) {
+        let estimate = match result {
+            Ok(estimate) => estimate,
+            Err(error) => {
+                tracing::warn!(table = %table, error = %error, "估算表行数失败");
+                return;
+            }
+        };
+        let Some(pager) = self
+            .session
+            .tab_manager
+            .tabs
+            .iter_mut()
+            .find(|tab| tab.id == tab_id)
+            .and_then(|tab| tab.table_pager.as_mut())
+        else {
+            return;
+        };
+        pager.set_estimated_rows(estimate);
+        self.sync_grid_page_info();
+    }
+}
@@ -0,0 +1,334 @@
+//! 手动提交模式与交互式事务
+//!
+//! 手动提交的查询 Tab 独占一条连接（`PinnedSession`），语句在同一事务中执行，
+//! 直到显式提交或回滚。关闭 Tab、断开连接会丢弃未提交的改动，需先经用户确认。
+
+use crate::app::dialogs::host::DialogId;
+use crate::data::{ConnectionConfig, PinnedSession};
+use crate::session::tab::PinnedTabSession;
+use crate::state::TransactionDiscard;
+
+use super::DbManagerApp;
+use super::message::Message;
+
+impl DbManagerApp {
+    /// 手动提交模式下取得（必要时建立）当前 Tab 的独占会话
+    ///
+    /// 自动提交模式返回 `Ok(None)`。会话属于其它连接且仍有未提交的事务时拒绝执行，
+    /// 避免切换连接后事务被悄悄丢弃。
+    pub(in crate::app) fn pinned_session_for_active_tab(
+        &mut self,
+        connection_name: &str,
+        config: &ConnectionConfig,
+    ) -> Result<Option<PinnedSession>, String> {
+        let runtime = self.session.runtime.handle().clone();
+        let Some(tab) = self.session.tab_manager.get_active_mut() else {
+            return Ok(None);
+        };
+        if !tab.manual_commit {
+            return Ok(None);
+        }
+        if let Some(pinned) = &tab.pinned_session {
+            if pinned.connection_name == connection_name && !pinned.session.is_closed() {
+                return Ok(Some(pinned.session.clone()));
+            }
+            if pinned.session.in_transaction() {
+                return Err(format!(
+                    "当前标签页在连接 '{}' 上有未提交的事务，请先提交或回滚",
+                    pinned.connection_name
+                ));
+            }
+        }
+
+        let (session, driver) = PinnedSession::new(config.clone());
+        runtime.spawn(driver);
+        tab.pinned_session = Some(PinnedTabSession {
+            connection_name: connection_name.to_string(),
+            session: session.clone(),
+        });
+        Ok(Some(session))
+    }
+
+    /// 切换当前 Tab 的手动提交模式
+    pub(in crate::app) fn toggle_manual_commit(&mut self) {
+        let Some(tab) = self.session.tab_manager.get_active_mut() else {
+            return;
+        };
+        if tab.open_transaction().is_some() {
+            self.session.notifications.warning("请先提交或回滚当前事务");
+            return;
+        }
+        tab.manual_commit = !tab.manual_commit;
+        if tab.manual_commit {
+            self.session
+                .notifications
+                .info("已切换为手动提交：语句在事务中执行，需显式提交");
+        } else {
+            // 丢弃会话即归还独占连接
+            tab.pinned_session = None;
+            self.session.notifications.info("已切换为自动提交");
+        }
+    }
+
+    /// 提交（`commit = true`）或回滚当前 Tab 的事务
+    pub(in crate::app) fn end_active_transaction(&mut self, commit: bool) {
+        let Some(pinned) = self
+            .session
+            .tab_manager
+            .get_active()
+            .and_then(|tab| tab.open_transaction())
+            .cloned()
+        else {
+            self.session
+                .notifications
+                .info("当前标签页没有未提交的事务");
+            return;
+        };
+        let tx = self.session.tx.clone();
+        self.session.runtime.spawn(async move {
+            let result = if commit {
+                pinned.session.commit().await
+            } else {
+                pinned.session.rollback().await
+            };
+            let _ = tx.send(Message::TransactionEnded(
+                pinned.connection_name,
+                commit,
+                result.map_err(|e| e.to_string()),
+            ));
+        });
+    }
+
+    /// 处理事务提交/回滚完成消息
+    pub(in crate::app) fn handle_transaction_ended(
+        &mut self,
+        conn_name: String,
+        committed: bool,
+        result: Result<(), String>,
+    ) {
+        match result {
+            Ok(()) if committed => {
+                self.session
+                    .notifications
+                    .success(format!("连接 '{}' 上的事务已提交", conn_name));
+            }
+            Ok(()) => {
+                self.session
+                    .notifications
+                    .info(format!("连接 '{}' 上的事务已回滚", conn_name));
+            }
+            Err(error) => {
+                let verb = if committed { "提交" } else { "回滚" };
+                self.session
+                    .notifications
+                    .error(format!("{}事务失败: {}", verb, error));
+            }
+        }
+        self.session.needs_repaint = true;
+    }
+
+    /// 操作会丢弃未提交的事务时先弹出确认
+    ///
+    /// 返回 `true` 表示操作已挂起，确认后由 `confirm_transaction_discard` 继续执行。
+    pub(in crate::app) fn defer_for_open_transactions(
+        &mut self,
+        discard: TransactionDiscard,
+    ) -> bool {
+        if self.transaction_discard_tabs(&discard).is_empty() {
+            return false;
+        }
+        self.open_dialog(DialogId::TransactionDiscardConfirm);
+        self.state.pending_transaction_discard = Some(discard);
+        true
+    }
+
+    /// 用户确认后丢弃未提交的事务并继续挂起的操作
+    pub(in crate::app) fn confirm_transaction_discard(&mut self) {
+        let discard = self.state.pending_transaction_discard.take();
+        self.close_dialog(DialogId::TransactionDiscardConfirm);
+        if let Some(discard) = discard {
+            self.discard_open_transactions(discard);
+        }
+    }
+
+    /// 确认对话框的标题、说明与确认按钮文字
+    pub(in crate::app) fn transaction_discard_prompt(
+        &self,
+        discard: &TransactionDiscard,
+    ) -> (&'static str, String, &'static str) {
+        let tabs = self.transaction_discard_tabs(discard);
+        let statements: usize = tabs.iter().map(|(_, count)| count).sum();
+        let affected = match tabs.as_slice() {
+            [(title, _)] => format!("标签页 '{}'", title),
+            _ => format!("{} 个标签页", tabs.len()),
+        };
+        match discard {
+            TransactionDiscard::CloseTabs(_) => (
+                "未提交的事务",
+                format!(
+                    "{}有未提交的事务（{} 条语句）。关闭将回滚这些改动。",
+                    affected, statements
+                ),
+                "回滚并关闭",
+            ),
+            TransactionDiscard::Disconnect(connection) => (
+                "未提交的事务",
+                format!(
+                    "连接 '{}' 上{}有未提交的事务（{} 条语句）。断开将回滚这些改动。",
+                    connection, affected, statements
+                ),
+                "回滚并断开",
+            ),
+        }
+    }
+
+    /// 确认后回滚受影响 Tab 的事务并继续被挂起的操作
+    fn discard_open_transactions(&mut self, discard: TransactionDiscard) {
+        match discard {
+            TransactionDiscard::CloseTabs(tab_ids) => {
+                for tab in &mut self.session.tab_manager.tabs {
+                    if tab_ids.contains(&tab.id) {
+                        tab.pinned_session = None;
+                    }
+                }
+                for tab_id in tab_ids {
+                    if let Some(index) = self
+                        .session
+                        .tab_manager
+                        .tabs
+                        .iter()
+                        .position(|tab| tab.id == tab_id)
+                    {
+                        self.on_dock_tab_close(index);
+                    }
+                }
+            }
+            TransactionDiscard::Disconnect(connection) => self.disconnect(connection),
+        }
+    }
+
+    /// 丢弃属于该连接的独占会话，未提交的事务随之回滚
+    pub(in crate::app) fn drop_pinned_sessions_for_connection(&mut self, connection_name: &str) {
+        for tab in &mut self.session.tab_manager.tabs {
+            if tab
+                .pinned_session
+                .as_ref()
+                .is_some_and(|pinned| pinned.connection_name == connection_name)
+            {
+                tab.pinned_session = None;
+            }
+        }
+    }
+
+    /// 受影响且有未提交事务的 Tab：(标题, 语句数)
+    fn transaction_discard_tabs(&self, discard: &TransactionDiscard) -> Vec<(String, usize)> {
+        self.session
+            .tab_manager
+            .tabs
+            .iter()
+            .filter_map(|tab| {
+                let pinned = tab.open_transaction()?;
+                let affected = match discard {
+                    TransactionDiscard::CloseTabs(tab_ids) => tab_ids.contains(&tab.id),
+                    TransactionDiscard::Disconnect(connection) => {
+                        &pinned.connection_name == connection
+                    }
+                };
+                affected.then(|| (tab.title.clone(), pinned.session.statement_count()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::DatabaseType;
+    use tempfile::NamedTempFile;
+
+    /// 在第一个 Tab 上打开一个 SQLite 手动提交事务
+    fn open_transaction_on_first_tab(app: &mut DbManagerApp, file: &NamedTempFile) {
+        let config = ConnectionConfig {
+            db_type: DatabaseType::SQLite,
+            database: file.path().to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+        let (session, driver) = PinnedSession::new(config);
+        app.session.runtime.spawn(driver);
+        app.session
+            .runtime
+            .block_on(session.execute(
+                "CREATE TABLE t (id INTEGER)",
+                &tokio_util::sync::CancellationToken::new(),
+            ))
+            .unwrap();
+        let tab = &mut app.session.tab_manager.tabs[0];
+        tab.manual_commit = true;
+        tab.pinned_session = Some(PinnedTabSession {
+            connection_name: "demo".to_string(),
+            session,
+        });
+    }
+
+    #[test]
+    fn closing_tab_with_open_transaction_waits_for_confirmation() {
+        let mut app = DbManagerApp::new_for_test();
+        let file = NamedTempFile::new().unwrap();
+        app.session.tab_manager.new_tab();
+        open_transaction_on_first_tab(&mut app, &file);
+        let tab_id = app.session.tab_manager.tabs[0].id.clone();
+
+        assert!(!app.on_dock_tab_close(0));
+        assert_eq!(app.session.tab_manager.tabs.len(), 2);
+        assert_eq!(
+            app.active_dialog_id(),
+            Some(DialogId::TransactionDiscardConfirm)
+        );
+        assert_eq!(
+            app.state.pending_transaction_discard,
+            Some(TransactionDiscard::CloseTabs(vec![tab_id.clone()]))
+        );
+
+        app.confirm_transaction_discard();
+
+        assert_eq!(app.session.tab_manager.tabs.len(), 1);
+        assert!(
+            app.session
+                .tab_manager
+                .tabs
+                .iter()
+                .all(|tab| tab.id != tab_id)
+        );
+        assert!(app.state.pending_transaction_discard.is_none());
+    }
+
+    #[test]
+    fn cancelling_transaction_prompt_keeps_tab_and_clears_pending_discard() {
+        let mut app = DbManagerApp::new_for_test();
+        let file = NamedTempFile::new().unwrap();
+        open_transaction_on_first_tab(&mut app, &file);
+
+        assert!(
+            app.defer_for_open_transactions(TransactionDiscard::Disconnect("demo".to_string()))
+        );
+        app.close_dialog(DialogId::TransactionDiscardConfirm);
+
+        assert!(app.state.pending_transaction_discard.is_none());
+        assert!(app.session.tab_manager.tabs[0].open_transaction().is_some());
+    }
+
+    #[test]
+    fn toggle_manual_commit_refuses_while_transaction_is_open() {
+        let mut app = DbManagerApp::new_for_test();
+        let file = NamedTempFile::new().unwrap();
+        open_transaction_on_first_tab(&mut app, &file);
+
+        app.toggle_manual_commit();
+
+        assert!(app.session.tab_manager.tabs[0].manual_commit);
+        assert_eq!(
+            app.session.notifications.latest_message(),
+            Some("请先提交或回滚当前事务")
+        );
+    }
+}
@@ -156,6 +156,30 @@ impl DbManagerApp {
             }
         }
 
+        // 未提交事务确认
+        if active_dialog == Some(DialogId::TransactionDiscardConfirm) {
+            let Some(discard) = self.state.pending_transaction_discard.clone() else {
+                self.close_dialog(DialogId::TransactionDiscardConfirm);
+                return results;
+            };
+            let mut confirmed = false;
+            let (title, message, confirm_text) = self.transaction_discard_prompt(&discard);
+            ui::ConfirmDialog::show(
+                ctx,
+                &mut self.state.show_transaction_discard_confirm,
+                title,
+                &message,
+                confirm_text,
+                &mut confirmed,
+            );
+
+            if confirmed {
+                self.dispatch_app_action(ctx, AppAction::ConfirmTransactionDiscard);
+            } else if !self.state.show_transaction_discard_confirm {
+                self.state.pending_transaction_discard = None;
+            }
+        }
+
         // 导出对话框
         if active_dialog == Some(DialogId::Export) {
             let table_name = self.state.selected_table.clone().unwrap_or_default();
@@ -183,12 +207,19 @@ impl DbManagerApp {
             );
         }
         if active_dialog == Some(DialogId::Import) {
-            let is_mysql = self.is_mysql();
+            let import_db_type = self.active_db_type();
+            // 冲突键候选来自目标表的主键与唯一键
+            let target_table = self
+                .state
+                .import_state
+                .target_table_name()
+                .and_then(|name| self.active_schema_catalog()?.table(name.trim()).cloned());
             results.import_action = ui::ImportDialog::show(
                 ctx,
                 &mut self.state.show_import_dialog,
                 &mut self.state.import_state,
-                is_mysql,
+                import_db_type,
+                target_table.as_ref(),
             );
         }
 
@@ -391,12 +422,14 @@ impl DbManagerApp {
                 self.execute_import();
             }
             ui::ImportAction::CopyToEditor(sql) => {
-                self.set_active_sql(sql);
-                self.state.show_sql_editor = true;
-                self.set_focus_area(ui::FocusArea::SqlEditor);
-                self.close_dialog(DialogId::Import);
-                self.state.import_state.clear();
-                self.session.notifications.success("SQL 已复制到编辑器");
+                if let Some(sql) = self.import_sql_for_editor(sql) {
+                    self.set_active_sql(sql);
+                    self.state.show_sql_editor = true;
+                    self.set_focus_area(ui::FocusArea::SqlEditor);
+                    self.close_dialog(DialogId::Import);
+                    self.state.import_state.clear();
+                    self.session.notifications.success("SQL 已复制到编辑器");
+                }
             }
             ui::ImportAction::Close => {
                 self.state.import_state.clear();
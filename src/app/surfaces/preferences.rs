@@ -20,13 +20,13 @@ impl DbManagerApp {
         }
     }
 
-    /// 检查当前连接是否是 MySQL（用于选择 SQL 引号类型）
-    pub(in crate::app) fn is_mysql(&self) -> bool {
+    /// 当前连接的数据库类型（用于选择 SQL 引号与冲突语法），无连接时按 SQLite 处理
+    pub(in crate::app) fn active_db_type(&self) -> crate::data::DatabaseType {
         self.session
             .manager
             .get_active()
-            .map(|c| matches!(c.config.db_type, crate::data::DatabaseType::MySQL))
-            .unwrap_or(false)
+            .map(|c| c.config.db_type)
+            .unwrap_or(crate::data::DatabaseType::SQLite)
     }
 
     pub(in crate::app) fn set_theme(&mut self, ctx: &egui::Context, preset: ThemePreset) {
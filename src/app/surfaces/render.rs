@@ -7,7 +7,7 @@ use eframe::egui;
 use crate::app::dialogs::host::DialogId;
 use crate::core::{BottomPanelTab, constants, format_sql};
 use crate::data::ConnectionConfig;
-use crate::state::WorkbenchSurfaceKind;
+use crate::state::{TransactionDiscard, WorkbenchSurfaceKind};
 use crate::ui::{self, SqlEditorActions, TabBarActions, ToolbarActions};
 
 use super::DbManagerApp;
@@ -522,6 +522,23 @@ impl DbManagerApp {
         if grid_actions.refresh_requested {
             self.dispatch_app_action(ui.ctx(), AppAction::RefreshSelectedTable);
         }
+        if let Some(request) = grid_actions.page_request {
+            self.request_table_page(request);
+        }
+        if grid_actions.apply_server_filters {
+            self.apply_server_side_filters();
+        }
+        match grid_actions.reference_navigation {
+            Some(ui::ReferenceNavigation::Referenced) => self.follow_foreign_key(),
+            Some(ui::ReferenceNavigation::Referencing) => self.show_referencing_rows(),
+            None => {}
+        }
+        if let Some(change) = grid_actions.reference_label {
+            self.set_reference_label_column(change.table, change.column);
+        }
+        if let Some(lookup) = grid_actions.reference_lookup {
+            self.load_reference_candidates(lookup);
+        }
         if let Some(message) = grid_actions.message {
             self.session.notifications.info(message);
         }
@@ -610,6 +627,18 @@ impl DbManagerApp {
             .get(self.session.tab_manager.active_index)
             .and_then(|tab| tab.last_message.as_deref())
             .map(|s| s.to_owned());
+        let transaction = self
+            .session
+            .tab_manager
+            .tabs
+            .get(self.session.tab_manager.active_index)
+            .map(|tab| ui::SqlEditorTransaction {
+                manual_commit: tab.manual_commit,
+                open_statements: tab
+                    .open_transaction()
+                    .map(|pinned| pinned.session.statement_count()),
+            })
+            .unwrap_or_default();
         let tab_sql = &mut self.session.tab_manager.tabs[self.session.tab_manager.active_index].sql;
 
         ui.allocate_ui_with_layout(
@@ -637,6 +666,7 @@ impl DbManagerApp {
                     &mut request_editor_widget_focus,
                     is_editor_focused,
                     &mut self.state.editor_mode,
+                    transaction,
                 );
             },
         );
@@ -680,23 +710,23 @@ impl DbManagerApp {
             self.cancel_active_query();
         }
 
+        // 手动提交模式与事务
+        if actions.toggle_manual_commit {
+            self.toggle_manual_commit();
+        }
+        if actions.commit {
+            self.end_active_transaction(true);
+        }
+        if actions.rollback {
+            self.end_active_transaction(false);
+        }
+
         // EXPLAIN 分析
         if actions.explain && !active_sql.is_empty() {
-            let sql = active_sql.trim();
-            let explain_sql = if self.is_mysql() {
-                format!("EXPLAIN FORMAT=TRADITIONAL {}", sql)
-            } else if self
-                .session
-                .manager
-                .get_active()
-                .map(|c| c.config.db_type == crate::data::DatabaseType::PostgreSQL)
-                .unwrap_or(false)
-            {
-                format!("EXPLAIN (ANALYZE, BUFFERS, FORMAT TEXT) {}", sql)
-            } else {
-                format!("EXPLAIN QUERY PLAN {}", sql)
-            };
-            let _ = self.execute(explain_sql);
+            self.explain_active_sql(
+                active_sql.clone(),
+                crate::domain::plan::ExplainMode::Estimate,
+            );
             self.session.notifications.info("正在分析执行计划...");
         }
 
@@ -903,7 +933,9 @@ impl DbManagerApp {
             self.state.sidebar_section = ui::SidebarSection::Databases;
         }
 
-        if let Some(name) = actions.disconnect {
+        if let Some(name) = actions.disconnect
+            && !self.defer_for_open_transactions(TransactionDiscard::Disconnect(name.clone()))
+        {
             self.disconnect(name);
         }
 
@@ -978,6 +1010,10 @@ impl DbManagerApp {
             self.focus_sidebar_filter_input(index);
         }
 
+        if let Some(name) = actions.save_filter_preset {
+            self.save_filter_preset(name);
+        }
+
         // 触发器定义
         if let Some(definition) = actions.show_trigger_definition {
             self.set_active_sql(definition);
@@ -1087,9 +1123,20 @@ impl DbManagerApp {
             ui::SidebarFilterInsertMode::AppendEnd => self.state.grid_state.filters.len(),
         };
 
+        // 在选中条件后插入时沿用它所在的分组
+        let groups = match mode {
+            ui::SidebarFilterInsertMode::BelowSelection => self
+                .state
+                .grid_state
+                .filters
+                .get(self.state.sidebar_panel_state.selection.filters)
+                .map(|filter| filter.groups.clone())
+                .unwrap_or_default(),
+            ui::SidebarFilterInsertMode::AppendEnd => Vec::new(),
+        };
         self.state.grid_state.filters.insert(
             insert_index,
-            ui::ColumnFilter::new(default_col.name.clone()),
+            ui::ColumnFilter::new(default_col.name.clone()).with_groups(groups),
         );
         self.state.sidebar_panel_state.selection.filters = insert_index;
         self.state.grid_state.filter_cache.invalidate();
@@ -1190,7 +1237,8 @@ impl DbManagerApp {
 
         let db_type = conn.config.db_type;
         let use_backticks = matches!(db_type, crate::data::DatabaseType::MySQL);
-        let quoted_old = match ui::quote_identifier(table, use_backticks) {
+        let dialect = crate::domain::identifier::IdentifierDialect::from_db_type(db_type);
+        let quoted_old = match ui::quote_table_identifier(table, dialect) {
             Ok(name) => name,
             Err(e) => {
                 self.session.notifications.error(format!("表名无效: {}", e));
@@ -1247,20 +1295,25 @@ impl DbManagerApp {
                 .tabs
                 .get(idx)
                 .map(|tab| tab.id.clone());
-            if self.session.tab_manager.tabs.len() > 1
-                && let Some(request_id) = self
-                    .session
-                    .tab_manager
-                    .tabs
-                    .get(idx)
-                    .and_then(|tab| tab.pending_request_id)
-            {
-                self.cancel_query_request_silently(request_id);
-            }
-            self.session.tab_manager.close_tab(idx);
-            if let Some(tab_id) = closing_tab_id {
-                self.warn_if_tab_has_unsaved_grid_edits(&tab_id);
-                self.remove_grid_workspaces_for_tab(&tab_id);
+            let deferred = closing_tab_id.clone().is_some_and(|tab_id| {
+                self.defer_for_open_transactions(TransactionDiscard::CloseTabs(vec![tab_id]))
+            });
+            if !deferred {
+                if self.session.tab_manager.tabs.len() > 1
+                    && let Some(request_id) = self
+                        .session
+                        .tab_manager
+                        .tabs
+                        .get(idx)
+                        .and_then(|tab| tab.pending_request_id)
+                {
+                    self.cancel_query_request_silently(request_id);
+                }
+                self.session.tab_manager.close_tab(idx);
+                if let Some(tab_id) = closing_tab_id {
+                    self.warn_if_tab_has_unsaved_grid_edits(&tab_id);
+                    self.remove_grid_workspaces_for_tab(&tab_id);
+                }
             }
         }
 
@@ -1294,12 +1347,16 @@ impl DbManagerApp {
                     }
                 })
                 .collect();
-            for request_id in request_ids {
-                self.cancel_query_request_silently(request_id);
-            }
-            self.session.tab_manager.close_other_tabs();
-            for tab_id in closing_tab_ids {
-                self.remove_grid_workspaces_for_tab(&tab_id);
+            if !self
+                .defer_for_open_transactions(TransactionDiscard::CloseTabs(closing_tab_ids.clone()))
+            {
+                for request_id in request_ids {
+                    self.cancel_query_request_silently(request_id);
+                }
+                self.session.tab_manager.close_other_tabs();
+                for tab_id in closing_tab_ids {
+                    self.remove_grid_workspaces_for_tab(&tab_id);
+                }
             }
         }
 
@@ -1333,12 +1390,16 @@ impl DbManagerApp {
                     }
                 })
                 .collect();
-            for request_id in request_ids {
-                self.cancel_query_request_silently(request_id);
-            }
-            self.session.tab_manager.close_tabs_to_right();
-            for tab_id in closing_tab_ids {
-                self.remove_grid_workspaces_for_tab(&tab_id);
+            if !self
+                .defer_for_open_transactions(TransactionDiscard::CloseTabs(closing_tab_ids.clone()))
+            {
+                for request_id in request_ids {
+                    self.cancel_query_request_silently(request_id);
+                }
+                self.session.tab_manager.close_tabs_to_right();
+                for tab_id in closing_tab_ids {
+                    self.remove_grid_workspaces_for_tab(&tab_id);
+                }
             }
         }
 
@@ -6,8 +6,11 @@
 
 use eframe::egui;
 
+use crate::app::workflow::references::referenced_targets;
 use crate::core::{BottomPanelTab, RightInspectorTab, WorkbenchActivity, constants};
 use crate::data::Connection;
+use crate::domain::result::ResultSet;
+use crate::domain::value::{DbTypeFamily, DbTypeInfo};
 use crate::state::{WorkbenchFocus, WorkbenchSurfaceKind};
 use crate::ui::{self, ToolbarActions, WorkbenchStatusBarContent};
 
@@ -454,7 +457,7 @@ impl DbManagerApp {
             .inner;
 
         if let Some(id) = cancel_task_id {
-            self.session.progress.cancel(id);
+            self.cancel_progress_task(id);
         }
         if self.state.focus_area == ui::FocusArea::Toolbar {
             ui::Toolbar::handle_keyboard(ui, &mut self.state.toolbar_index, &mut toolbar_actions);
@@ -629,7 +632,7 @@ impl DbManagerApp {
     }
 
     fn render_right_inspector_surface_body(
-        &self,
+        &mut self,
         ui: &mut egui::Ui,
         active_tab: RightInspectorTab,
     ) {
@@ -638,6 +641,7 @@ impl DbManagerApp {
             RightInspectorTab::Schema => self.render_right_inspector_schema(ui),
             RightInspectorTab::Row => self.render_right_inspector_row(ui),
             RightInspectorTab::Cell => self.render_right_inspector_cell(ui),
+            RightInspectorTab::Profile => self.render_right_inspector_profile(ui),
             RightInspectorTab::ErSelection => self.render_right_inspector_er_selection(ui),
             RightInspectorTab::Connection => self.render_right_inspector_connection(ui),
         }
@@ -768,13 +772,14 @@ impl DbManagerApp {
             ui.heading(table_name);
             ui.add_space(8.0);
 
-            if let Some(er_table) = self
+            let er_table = self
                 .state
                 .er_diagram_state
                 .tables
                 .iter()
-                .find(|table| table.name == table_name)
-            {
+                .find(|table| table.name == table_name);
+            let er_table_loaded = er_table.is_some();
+            if let Some(er_table) = er_table {
                 ui.label(egui::RichText::new("ER 元数据").strong());
                 for column in &er_table.columns {
                     ui.horizontal_wrapped(|ui| {
@@ -794,6 +799,13 @@ impl DbManagerApp {
                 ui.add_space(8.0);
             }
 
+            let catalog = self.active_schema_catalog();
+            if let Some(table) = catalog.and_then(|catalog| catalog.table(table_name)) {
+                render_catalog_table(ui, table, er_table_loaded);
+            } else if let Some(view) = catalog.and_then(|catalog| catalog.view(table_name)) {
+                render_catalog_view(ui, view);
+            }
+
             if let Some(result) = self
                 .state
                 .grid_state
@@ -807,7 +819,7 @@ impl DbManagerApp {
             {
                 ui.label(egui::RichText::new("最近结构查询结果").strong());
                 render_compact_result_rows(ui, result, 24);
-            } else if self.state.er_diagram_state.tables.is_empty() {
+            } else if self.state.er_diagram_state.tables.is_empty() && catalog.is_none() {
                 ui.label(
                     "结构查询结果会显示在 BottomPanel::Results；加载 ER 图后这里会显示列元数据。",
                 );
@@ -815,15 +827,10 @@ impl DbManagerApp {
         });
     }
 
-    fn render_right_inspector_row(&self, ui: &mut egui::Ui) {
-        let result_set = self
-            .state
-            .grid_state
-            .result_set
-            .as_ref()
-            .map(|a| a.as_ref());
+    fn render_right_inspector_row(&mut self, ui: &mut egui::Ui) {
+        let result_set = self.state.grid_state.result_set.clone();
         let Some((result, row_index, row)) =
-            selected_result_row(result_set, self.state.selected_row)
+            selected_result_row(result_set.as_deref(), self.state.selected_row)
         else {
             ui::WorkbenchRightInspector::show_empty_state(
                 ui,
@@ -833,6 +840,18 @@ impl DbManagerApp {
             return;
         };
 
+        let referenced = self
+            .state
+            .grid_state
+            .table_metadata
+            .as_deref()
+            .map(|table| referenced_targets(table, result, row_index))
+            .unwrap_or_default();
+        let referencing = self
+            .referencing_targets_for_row(row_index)
+            .unwrap_or_default();
+        let mut open_target = None;
+
         egui::ScrollArea::vertical().show(ui, |ui| {
             ui.heading(format!("Row {}", row_index + 1));
             ui.add_space(8.0);
@@ -840,19 +859,40 @@ impl DbManagerApp {
                 let value = row[col_idx].display();
                 property_row(ui, &column.name, &value);
             }
+
+            for (title, targets) in [("引用的行", &referenced), ("引用此行的表", &referencing)]
+            {
+                if targets.is_empty() {
+                    continue;
+                }
+                ui.separator();
+                ui.label(egui::RichText::new(title).strong());
+                for target in targets {
+                    let response = ui.link(target.label());
+                    let response = match &target.constraint {
+                        Some(constraint) => response
+                            .on_hover_text(format!("在新 Tab 中打开（外键 {}）", constraint)),
+                        None => response.on_hover_text("在新 Tab 中打开"),
+                    };
+                    if response.clicked() {
+                        open_target = Some(target.clone());
+                    }
+                }
+            }
         });
+
+        if let Some(target) = open_target {
+            self.open_reference_target(target);
+        }
     }
 
-    fn render_right_inspector_cell(&self, ui: &mut egui::Ui) {
-        let result_set = self
-            .state
-            .grid_state
-            .result_set
-            .as_ref()
-            .map(|a| a.as_ref());
-        let Some((result, row_index, col_index, value)) =
-            selected_result_cell(result_set, self.state.selected_cell)
-        else {
+    fn render_right_inspector_cell(&mut self, ui: &mut egui::Ui) {
+        let result = self.state.grid_state.result_set.clone();
+        let Some((result, cell, current)) = result.and_then(|result| {
+            let cell = self.state.selected_cell?;
+            let current = self.state.grid_state.cell_edit(&result, cell)?;
+            Some((result, cell, current))
+        }) else {
             ui::WorkbenchRightInspector::show_empty_state(
                 ui,
                 "未选择单元格",
@@ -861,27 +901,337 @@ impl DbManagerApp {
             return;
         };
 
-        let column_name = result
-            .columns
-            .get(col_index)
-            .map(|c| c.name.as_str())
-            .unwrap_or("<unknown>");
+        let (row_index, col_index) = cell;
+        let is_new_row = row_index >= result.row_count;
+        let column_name = result.columns[col_index].name.clone();
+        let type_info =
+            ui::column_edit_types(&result, self.state.grid_state.table_metadata.as_deref())
+                .swap_remove(col_index);
+        let read_only_reason = self.cell_inspector_read_only_reason(row_index);
+
         egui::ScrollArea::vertical().show(ui, |ui| {
             ui.heading("Cell");
             ui.add_space(8.0);
-            property_row(ui, "行", &(row_index + 1).to_string());
-            property_row(ui, "列", column_name);
+            let row_label = if is_new_row {
+                format!("{}（新增）", row_index + 1)
+            } else {
+                (row_index + 1).to_string()
+            };
+            property_row(ui, "行", &row_label);
+            property_row(ui, "列", &column_name);
+            if !type_info.native_name.is_empty() {
+                property_row(ui, "类型", &type_info.native_name);
+            }
+            ui.separator();
+
+            let shows_bytes = match &current {
+                ui::CellEdit::Bytes(_) => true,
+                ui::CellEdit::Text(_) => false,
+                ui::CellEdit::Null | ui::CellEdit::Default => {
+                    type_info.family == DbTypeFamily::Bytes
+                }
+            };
+            if shows_bytes {
+                self.render_right_inspector_cell_bytes(
+                    ui,
+                    &result,
+                    cell,
+                    &current,
+                    &type_info,
+                    read_only_reason,
+                );
+                return;
+            }
+
+            let grid_state = &mut self.state.grid_state;
+            let is_modified = grid_state.modified_cells.contains_key(&cell);
+            if let Some(reason) = read_only_reason {
+                let value = if is_new_row || is_modified {
+                    current.display_text().to_string()
+                } else {
+                    result.cell(row_index, col_index).display()
+                };
+                ui.label(egui::RichText::new("完整值").strong());
+                ui.add_space(4.0);
+                ui.add(
+                    egui::Label::new(egui::RichText::new(value).monospace())
+                        .wrap()
+                        .selectable(true),
+                );
+                ui.add_space(6.0);
+                ui.label(
+                    egui::RichText::new(reason)
+                        .small()
+                        .color(crate::ui::styles::theme_muted_text(ui.visuals())),
+                );
+                return;
+            }
+
+            if !grid_state
+                .inspector_draft
+                .as_ref()
+                .is_some_and(|draft| draft.matches(cell, &current))
+            {
+                grid_state.inspector_draft = Some(ui::CellDraft::new(cell, current.clone()));
+            }
+            let Some(draft) = grid_state.inspector_draft.as_mut() else {
+                return;
+            };
+            let validation = ui::show_cell_editor(
+                ui,
+                ("inspector_cell_editor", cell),
+                &type_info,
+                &mut draft.kind,
+                &mut draft.text,
+                is_new_row,
+            );
+            let edit = draft.edit();
+            let changed = edit != current;
+
+            ui.add_space(6.0);
+            let mut apply = false;
+            let mut reset = false;
+            ui.horizontal(|ui| {
+                apply = ui
+                    .add_enabled(
+                        changed && validation.is_ok(),
+                        egui::Button::new("应用到表格"),
+                    )
+                    .on_hover_text("写入表格的未保存修改，保存表格时一并提交")
+                    .clicked();
+                reset = ui
+                    .add_enabled(changed, egui::Button::new("还原"))
+                    .on_hover_text("放弃检查器中的改动")
+                    .clicked();
+            });
+            if is_modified {
+                ui.add_space(4.0);
+                ui.label(
+                    egui::RichText::new(format!(
+                        "原值: {}",
+                        result.cell(row_index, col_index).display()
+                    ))
+                    .small()
+                    .color(crate::ui::styles::theme_muted_text(ui.visuals())),
+                );
+            }
+
+            if apply {
+                grid_state.set_cell_edit(&result, cell, edit);
+                grid_state.inspector_draft = None;
+            } else if reset {
+                grid_state.inspector_draft = None;
+            }
+        });
+    }
+
+    /// Cell 检查器中的二进制值：十六进制 / 文本 / 图片查看，保存到文件或从文件替换
+    fn render_right_inspector_cell_bytes(
+        &mut self,
+        ui: &mut egui::Ui,
+        result: &ResultSet,
+        cell: (usize, usize),
+        current: &ui::CellEdit,
+        type_info: &DbTypeInfo,
+        read_only_reason: Option<&str>,
+    ) {
+        let muted = crate::ui::styles::theme_muted_text(ui.visuals());
+        let bytes = match current {
+            ui::CellEdit::Bytes(bytes) => Some(bytes),
+            _ => None,
+        };
+        if bytes.is_none() {
+            ui.label(
+                egui::RichText::new(current.display_text())
+                    .italics()
+                    .color(muted),
+            );
+        }
+        let action = ui::show_bytes_viewer(
+            ui,
+            &mut self.state.bytes_viewer,
+            ("inspector_bytes_viewer", cell),
+            bytes,
+            read_only_reason.is_none(),
+        );
+        if let Some(reason) = read_only_reason {
+            ui.add_space(6.0);
+            ui.label(egui::RichText::new(reason).small().color(muted));
+        } else if self.state.grid_state.modified_cells.contains_key(&cell) {
+            ui.add_space(4.0);
+            ui.label(
+                egui::RichText::new(format!("原值: {}", result.cell(cell.0, cell.1).display()))
+                    .small()
+                    .color(muted),
+            );
+        }
+
+        let column_name = &result.columns[cell.1].name;
+        match action {
+            Some(ui::BytesViewerAction::SaveToFile) => {
+                let Some(bytes) = bytes else {
+                    return;
+                };
+                let extension = match ui::image_format(bytes) {
+                    Some("PNG") => "png",
+                    Some("JPEG") => "jpg",
+                    _ => "bin",
+                };
+                let Some(path) = rfd::FileDialog::new()
+                    .set_file_name(format!("{}.{}", column_name, extension))
+                    .save_file()
+                else {
+                    return;
+                };
+                match std::fs::write(&path, bytes) {
+                    Ok(()) => self.session.notifications.success(format!(
+                        "已保存 {} 到 {}",
+                        ui::format_byte_size(bytes.len()),
+                        path.display()
+                    )),
+                    Err(error) => self
+                        .session
+                        .notifications
+                        .error(format!("保存文件失败: {}", error)),
+                };
+            }
+            Some(ui::BytesViewerAction::ReplaceFromFile) => {
+                let Some(path) = rfd::FileDialog::new().pick_file() else {
+                    return;
+                };
+                match std::fs::read(&path) {
+                    Ok(content) => {
+                        let message = format!(
+                            "已载入 {}（{}），保存表格时提交",
+                            path.display(),
+                            ui::format_byte_size(content.len())
+                        );
+                        self.state.grid_state.set_cell_edit(
+                            result,
+                            cell,
+                            ui::CellEdit::Bytes(content.into()),
+                        );
+                        self.state.grid_state.inspector_draft = None;
+                        self.session.notifications.info(message);
+                    }
+                    Err(error) => {
+                        self.session
+                            .notifications
+                            .error(format!("读取文件失败: {}", error));
+                    }
+                }
+            }
+            Some(ui::BytesViewerAction::SetNull) => {
+                if type_info.nullable == Some(false) {
+                    self.session
+                        .notifications
+                        .warning(format!("列 '{}' 不允许 NULL", column_name));
+                } else {
+                    self.state
+                        .grid_state
+                        .set_cell_edit(result, cell, ui::CellEdit::Null);
+                    self.state.grid_state.inspector_draft = None;
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Cell 检查器不能编辑当前单元格的原因
+    fn render_right_inspector_profile(&mut self, ui: &mut egui::Ui) {
+        let result = self.state.grid_state.result_set.clone();
+        let selection = self.state.grid_state.get_selection();
+        let Some((result, scope)) = result.and_then(|result| {
+            let scope = match selection {
+                Some((start, end)) => ui::StatsScope::Selection(start, end),
+                None => ui::StatsScope::Column(self.state.selected_cell?.1),
+            };
+            let column_in_range = match scope {
+                ui::StatsScope::Selection(start, _) => start.1,
+                ui::StatsScope::Column(col) => col,
+            } < result.column_count();
+            column_in_range.then_some((result, scope))
+        }) else {
+            ui::WorkbenchRightInspector::show_empty_state(
+                ui,
+                "未选择列",
+                "在 Results 表格中选择单元格或选区后，这里会显示所在列或选区的统计。",
+            );
+            return;
+        };
+
+        let stats = ui::grid_stats(&mut self.state.grid_state, &result, scope);
+        let muted = crate::ui::styles::theme_muted_text(ui.visuals());
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            match scope {
+                ui::StatsScope::Selection(start, end) => {
+                    ui.heading(format!(
+                        "选区 {}x{}",
+                        end.0 - start.0 + 1,
+                        end.1 - start.1 + 1
+                    ));
+                }
+                ui::StatsScope::Column(col) => {
+                    let column = &result.columns[col];
+                    ui.heading(&column.name);
+                    ui.add_space(8.0);
+                    if !column.type_info.native_name.is_empty() {
+                        property_row(ui, "类型", &column.type_info.native_name);
+                    }
+                }
+            }
+            ui.add_space(8.0);
+            let share = |count: usize| count as f64 * 100.0 / stats.count.max(1) as f64;
+            property_row(ui, "行数", &stats.rows.to_string());
+            property_row(ui, "值", &stats.count.to_string());
+            property_row(
+                ui,
+                "NULL",
+                &format!("{} ({:.1}%)", stats.nulls, share(stats.nulls)),
+            );
+            property_row(ui, "去重", &stats.distinct.to_string());
+            if let (Some(sum), Some(average)) = (stats.sum, stats.average()) {
+                property_row(ui, "合计", &ui::format_number(sum));
+                property_row(ui, "平均", &ui::format_number(average));
+            }
+            if let Some(min) = &stats.min {
+                property_row(ui, "最小", &min.display());
+            }
+            if let Some(max) = &stats.max {
+                property_row(ui, "最大", &max.display());
+            }
+
             ui.separator();
-            ui.label(egui::RichText::new("完整值").strong());
+            ui.label(egui::RichText::new("高频值").strong());
             ui.add_space(4.0);
-            ui.add(
-                egui::Label::new(egui::RichText::new(value.display()).monospace())
-                    .wrap()
-                    .selectable(true),
+            ui::show_value_histogram(ui, &stats);
+            ui.add_space(6.0);
+            ui.label(
+                egui::RichText::new("统计当前显示的行（筛选后），含未保存的修改与新增行。")
+                    .small()
+                    .color(muted),
             );
         });
     }
 
+    fn cell_inspector_read_only_reason(&self, row_index: usize) -> Option<&'static str> {
+        let grid_state = &self.state.grid_state;
+        if self.state.selected_table.is_none() {
+            Some("查询结果只读；从表列表打开表后可在这里编辑")
+        } else if grid_state
+            .table_metadata
+            .as_ref()
+            .and_then(|table| table.primary_key.as_ref())
+            .is_some_and(|key| key.columns.len() > 1)
+        {
+            Some("复合主键表暂不支持编辑")
+        } else if grid_state.rows_to_delete.contains(&row_index) {
+            Some("该行已标记删除")
+        } else {
+            None
+        }
+    }
+
     fn render_right_inspector_er_selection(&self, ui: &mut egui::Ui) {
         let Some(table) = self
             .state
@@ -1021,12 +1371,75 @@ impl DbManagerApp {
         );
     }
 
-    fn render_bottom_panel_explain(&self, ui: &mut egui::Ui) {
-        ui::WorkbenchBottomPanel::show_empty_state(
-            ui,
-            "Explain 暂未独立接入",
-            "当前 EXPLAIN 查询仍作为普通结果显示在 Results。后续阶段会加入结构化执行计划视图。",
-        );
+    fn render_bottom_panel_explain(&mut self, ui: &mut egui::Ui) {
+        use crate::domain::plan::ExplainMode;
+        use crate::session::tab::QueryPlanState;
+
+        let Some(state) = self
+            .session
+            .tab_manager
+            .get_active()
+            .and_then(|tab| tab.query_plan.as_ref())
+        else {
+            ui::WorkbenchBottomPanel::show_empty_state(
+                ui,
+                "暂无执行计划",
+                "在 SQL 编辑器中触发 Explain，执行计划会以树形结构显示在这里。",
+            );
+            return;
+        };
+
+        let mut rerun = None;
+        let mut cancel = None;
+        ui.horizontal(|ui| {
+            match state {
+                QueryPlanState::Loading { progress_id, .. } => {
+                    if ui.button("取消").clicked() {
+                        cancel = Some(*progress_id);
+                    }
+                }
+                QueryPlanState::Ready { .. } | QueryPlanState::Failed { .. } => {
+                    if ui.button("重新分析").clicked() {
+                        rerun = Some(ExplainMode::Estimate);
+                    }
+                    if ui
+                        .button("EXPLAIN ANALYZE")
+                        .on_hover_text("实际执行语句并采集耗时；语句在事务中执行，结束后始终回滚")
+                        .clicked()
+                    {
+                        rerun = Some(ExplainMode::Analyze);
+                    }
+                }
+            }
+            ui.label(
+                egui::RichText::new(state.sql().lines().next().unwrap_or(""))
+                    .monospace()
+                    .color(ui.visuals().weak_text_color()),
+            );
+        });
+        match state {
+            QueryPlanState::Loading { .. } => {
+                ui::WorkbenchBottomPanel::show_loading_state(
+                    ui,
+                    "正在分析执行计划",
+                    "计划生成后会在这里显示节点树。",
+                );
+            }
+            QueryPlanState::Ready {
+                plan, elapsed_ms, ..
+            } => ui::WorkbenchPlanView::show(ui, plan, *elapsed_ms),
+            QueryPlanState::Failed { error, .. } => {
+                ui::WorkbenchBottomPanel::show_empty_state(ui, "生成执行计划失败", error);
+            }
+        }
+
+        let sql = state.sql().to_string();
+        if let Some(mode) = rerun {
+            self.explain_active_sql(sql, mode);
+        }
+        if let Some(progress_id) = cancel {
+            self.cancel_progress_task(progress_id);
+        }
     }
 
     fn render_bottom_panel_history(&self, ui: &mut egui::Ui) {
@@ -1052,7 +1465,7 @@ impl DbManagerApp {
         });
     }
 
-    fn render_bottom_panel_tasks(&self, ui: &mut egui::Ui) {
+    fn render_bottom_panel_tasks(&mut self, ui: &mut egui::Ui) {
         let tasks = self.session.progress.active_tasks();
         if tasks.is_empty() {
             ui::WorkbenchBottomPanel::show_empty_state(
@@ -1063,6 +1476,7 @@ impl DbManagerApp {
             return;
         }
 
+        let mut cancel_task_id = None;
         egui::ScrollArea::vertical().show(ui, |ui| {
             for task in tasks {
                 ui.horizontal(|ui| {
@@ -1078,9 +1492,15 @@ impl DbManagerApp {
                         ui.label("进行中");
                     }
                     ui.label(format!("{}ms", task.elapsed_ms()));
+                    if task.cancellable && ui.small_button("取消").clicked() {
+                        cancel_task_id = Some(task.id);
+                    }
                 });
             }
         });
+        if let Some(id) = cancel_task_id {
+            self.cancel_progress_task(id);
+        }
     }
 
     fn active_query_error_message(&self) -> Option<String> {
@@ -1126,6 +1546,12 @@ impl DbManagerApp {
                 .as_ref()
                 .map(|result| result.row_count);
         }
+        content.transaction = self
+            .session
+            .tab_manager
+            .get_active()
+            .and_then(|tab| tab.open_transaction())
+            .map(|pinned| format!("● 事务未提交 · {} 条语句", pinned.session.statement_count()));
         content
     }
 
@@ -1242,19 +1668,104 @@ fn selected_result_row(
     Some((result, row_index, row))
 }
 
-fn selected_result_cell(
-    result: Option<&crate::domain::result::ResultSet>,
-    selected_cell: Option<(usize, usize)>,
-) -> Option<(
-    &crate::domain::result::ResultSet,
-    usize,
-    usize,
-    &crate::domain::value::DbValue,
-)> {
-    let result = result?;
-    let (row_index, col_index) = selected_cell?;
-    let cell = result.cell(row_index, col_index);
-    Some((result, row_index, col_index, cell))
+/// Schema 页签中的目录元数据：列（ER 图未加载时）、索引与 CHECK 约束
+fn render_catalog_table(
+    ui: &mut egui::Ui,
+    table: &crate::domain::metadata::TableMetadata,
+    er_table_loaded: bool,
+) {
+    let muted = crate::ui::styles::theme_muted_text(ui.visuals());
+
+    if !er_table_loaded {
+        ui.label(egui::RichText::new("列").strong());
+        for column in &table.columns {
+            ui.horizontal_wrapped(|ui| {
+                ui.monospace(&column.name);
+                ui.label(&column.type_info.native_name);
+                if column.is_primary_key {
+                    ui.label("PK");
+                }
+                if !column.is_nullable {
+                    ui.label("NOT NULL");
+                }
+                if let Some(default) = &column.default_value {
+                    ui.label(egui::RichText::new(format!("DEFAULT {}", default)).color(muted));
+                }
+            });
+        }
+        ui.add_space(8.0);
+    }
+
+    ui.label(egui::RichText::new(format!("索引 ({})", table.indexes.len())).strong());
+    if table.indexes.is_empty() {
+        ui.label(egui::RichText::new("没有索引").small().color(muted));
+    }
+    for index in &table.indexes {
+        ui.horizontal_wrapped(|ui| {
+            ui.monospace(&index.name);
+            ui.label(format!("({})", index.columns.join(", ")));
+            if index.is_primary {
+                ui.label("PK");
+            } else if index.is_unique {
+                ui.label("UNIQUE");
+            }
+            if let Some(method) = &index.method {
+                ui.label(egui::RichText::new(method).color(muted));
+            }
+        });
+        if let Some(predicate) = &index.predicate {
+            ui.indent(("schema_index_predicate", &index.name), |ui| {
+                ui.label(
+                    egui::RichText::new(format!("WHERE {}", predicate))
+                        .small()
+                        .monospace()
+                        .color(muted),
+                );
+            });
+        }
+    }
+    ui.add_space(8.0);
+
+    if !table.check_constraints.is_empty() {
+        ui.label(
+            egui::RichText::new(format!("CHECK 约束 ({})", table.check_constraints.len())).strong(),
+        );
+        for check in &table.check_constraints {
+            ui.horizontal_wrapped(|ui| {
+                if let Some(name) = &check.name {
+                    ui.monospace(name);
+                }
+                ui.label(
+                    egui::RichText::new(&check.expression)
+                        .monospace()
+                        .color(muted),
+                );
+            });
+        }
+        ui.add_space(8.0);
+    }
+}
+
+fn render_catalog_view(ui: &mut egui::Ui, view: &crate::domain::metadata::ViewMetadata) {
+    let title = if view.materialized {
+        "物化视图定义"
+    } else {
+        "视图定义"
+    };
+    ui.label(egui::RichText::new(title).strong());
+    match &view.definition {
+        Some(definition) => {
+            ui.label(egui::RichText::new(definition.trim()).monospace());
+        }
+        None => {
+            ui.label(
+                egui::RichText::new("无权限查看定义")
+                    .small()
+                    .color(crate::ui::styles::theme_muted_text(ui.visuals())),
+            );
+        }
+    }
+    ui.add_space(8.0);
 }
 
 fn render_compact_result_rows(
@@ -0,0 +1,186 @@
+//! 筛选预设
+//!
+//! 把当前表的筛选条件（含分组）以名称保存到配置，之后可从命令面板重新应用。
+//! 预设按连接、数据库与表区分，只在同一张表上列出。
+
+use crate::core::FilterPreset;
+use crate::ui;
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 当前表对应的预设范围：(连接, 数据库, 表)
+    fn filter_preset_scope(&self) -> Option<(String, Option<String>, String)> {
+        let connection = self.session.manager.active.clone()?;
+        let database = self
+            .session
+            .manager
+            .get_active()
+            .and_then(|connection| connection.selected_database.clone());
+        let table = self.state.selected_table.clone()?;
+        Some((connection, database, table))
+    }
+
+    /// 适用于当前表的预设及其在配置中的下标
+    pub(in crate::app) fn filter_presets_for_active_table(&self) -> Vec<(usize, &FilterPreset)> {
+        let Some((connection, database, table)) = self.filter_preset_scope() else {
+            return Vec::new();
+        };
+        self.app_config
+            .filter_presets
+            .iter()
+            .enumerate()
+            .filter(|(_, preset)| preset.applies_to(&connection, database.as_deref(), &table))
+            .collect()
+    }
+
+    /// 以名称保存当前筛选条件；同一张表上的同名预设会被覆盖
+    pub(in crate::app) fn save_filter_preset(&mut self, name: String) {
+        let Some((connection, database, table)) = self.filter_preset_scope() else {
+            self.session
+                .notifications
+                .warning("请先打开一张表再保存筛选预设");
+            return;
+        };
+        if self.state.grid_state.filters.is_empty() {
+            self.session
+                .notifications
+                .warning("当前没有可保存的筛选条件");
+            return;
+        }
+
+        let preset = FilterPreset {
+            name,
+            connection,
+            database,
+            table,
+            conditions: self
+                .state
+                .grid_state
+                .filters
+                .iter()
+                .map(ui::ColumnFilter::to_preset_condition)
+                .collect(),
+        };
+        let message = format!("已保存筛选预设 '{}'", preset.name);
+        match self.app_config.filter_presets.iter_mut().find(|existing| {
+            existing.name == preset.name
+                && existing.applies_to(
+                    &preset.connection,
+                    preset.database.as_deref(),
+                    &preset.table,
+                )
+        }) {
+            Some(existing) => *existing = preset,
+            None => self.app_config.filter_presets.push(preset),
+        }
+        self.save_config_debounced();
+        self.session.notifications.success(message);
+    }
+
+    /// 用配置中第 `index` 个预设替换当前表的筛选条件
+    pub(in crate::app) fn apply_filter_preset(&mut self, index: usize) {
+        let Some(preset) = self.app_config.filter_presets.get(index).cloned() else {
+            return;
+        };
+        let applicable = self
+            .filter_preset_scope()
+            .is_some_and(|(connection, database, table)| {
+                preset.applies_to(&connection, database.as_deref(), &table)
+            });
+        if !applicable {
+            self.session
+                .notifications
+                .warning(format!("筛选预设 '{}' 不属于当前表", preset.name));
+            return;
+        }
+
+        let filters: Vec<ui::ColumnFilter> = preset
+            .conditions
+            .iter()
+            .filter_map(ui::ColumnFilter::from_preset_condition)
+            .collect();
+        let skipped = preset.conditions.len() - filters.len();
+        self.state.grid_state.filters = filters;
+        self.state.grid_state.filter_cache.invalidate();
+        self.state.sidebar_panel_state.selection.filters = 0;
+        self.state.sidebar_panel_state.exit_filter_input();
+        self.state.pending_filter_input_focus = None;
+        self.state.show_sidebar = true;
+        self.state.sidebar_panel_state.show_filters = true;
+
+        if skipped > 0 {
+            self.session.notifications.warning(format!(
+                "筛选预设 '{}' 中有 {} 个条件无法识别，已跳过",
+                preset.name, skipped
+            ));
+        } else {
+            self.session
+                .notifications
+                .info(format!("已应用筛选预设 '{}'", preset.name));
+        }
+
+        if self.state.grid_state.filters_pushed_down() {
+            self.apply_server_side_filters();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{Connection, ConnectionConfig, DatabaseType};
+
+    fn open_table(app: &mut DbManagerApp, table: &str) {
+        let mut connection = Connection::new(ConnectionConfig::new("demo", DatabaseType::SQLite));
+        connection.connected = true;
+        connection.selected_database = Some("main".to_string());
+        app.session
+            .manager
+            .connections
+            .insert("demo".to_string(), connection);
+        app.session.manager.active = Some("demo".to_string());
+        app.state.selected_table = Some(table.to_string());
+    }
+
+    #[test]
+    fn saved_preset_restores_grouped_filters_on_the_same_table() {
+        let mut app = DbManagerApp::new_for_test();
+        open_table(&mut app, "orders");
+        app.state.grid_state.filters = vec![
+            ui::ColumnFilter::new("status".to_string()).with_value("paid".to_string()),
+            ui::ColumnFilter::new("total".to_string())
+                .with_operator(ui::FilterOperator::GreaterThan)
+                .with_value("100".to_string())
+                .with_logic(ui::FilterLogic::Or)
+                .with_groups(vec![1]),
+            ui::ColumnFilter::new("total".to_string())
+                .with_operator(ui::FilterOperator::IsNull)
+                .with_groups(vec![1]),
+        ];
+
+        app.save_filter_preset("大额订单".to_string());
+        app.save_filter_preset("大额订单".to_string());
+        assert_eq!(app.app_config.filter_presets.len(), 1);
+
+        app.state.grid_state.filters.clear();
+        let presets = app.filter_presets_for_active_table();
+        assert_eq!(presets.len(), 1);
+        let index = presets[0].0;
+        app.apply_filter_preset(index);
+
+        let filters = &app.state.grid_state.filters;
+        assert_eq!(filters.len(), 3);
+        assert_eq!(filters[1].operator, ui::FilterOperator::GreaterThan);
+        assert_eq!(filters[1].logic, ui::FilterLogic::Or);
+        assert_eq!(filters[2].groups, vec![1]);
+
+        app.state.selected_table = Some("customers".to_string());
+        assert!(app.filter_presets_for_active_table().is_empty());
+        app.apply_filter_preset(index);
+        assert_eq!(
+            app.session.notifications.latest_message(),
+            Some("筛选预设 '大额订单' 不属于当前表")
+        );
+    }
+}
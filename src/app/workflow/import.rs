@@ -1,9 +1,10 @@
 //! 数据导入处理模块
 //!
-//! 处理 CSV、TSV、JSON、SQL 文件的统一传输逻辑。
+//! 处理 CSV、TSV、JSON、NDJSON、SQL、Excel 文件的统一传输逻辑。
 
+use crate::core::constants::database::IMPORT_BATCH_STATEMENTS;
 use crate::core::{plan_import_transfer, preview_import_transfer};
-use crate::data::execute_import_batch;
+use crate::data::execute_import_stream;
 use crate::ui;
 
 use super::{DbManagerApp, message::Message};
@@ -23,6 +24,8 @@ impl DbManagerApp {
             .add_filter("CSV 文件", &["csv"])
             .add_filter("TSV 文件", &["tsv", "tab"])
             .add_filter("JSON 文件", &["json"])
+            .add_filter("NDJSON 文件", &["ndjson", "jsonl"])
+            .add_filter("Excel 文件", &["xlsx", "xlsm", "xlsb", "xls", "ods"])
             .add_filter("所有文件", &["*"]);
 
         if let Some(path) = file_dialog.pick_file() {
@@ -35,14 +38,20 @@ impl DbManagerApp {
         let Some(ref path) = self.state.import_state.file_path else {
             return;
         };
-        let session = self.state.import_state.to_transfer_session(self.is_mysql());
+        let session = self
+            .state
+            .import_state
+            .to_transfer_session(self.active_db_type());
 
         self.state.import_state.loading = true;
         self.state.import_state.error = None;
         self.state.import_state.preview = None;
 
         match preview_import_transfer(path, &session) {
-            Ok(preview) => {
+            Ok(mut preview) => {
+                if let Some(layout) = preview.sheet_layout.take() {
+                    self.state.import_state.xlsx_config.layout = Some(layout);
+                }
                 self.state.import_state.preview =
                     Some(ui::ImportPreview::from_transfer_preview(preview));
             }
@@ -54,12 +63,45 @@ impl DbManagerApp {
         self.state.import_state.loading = false;
     }
 
+    /// 复制到编辑器的 SQL
+    ///
+    /// NDJSON 预览只生成样本行的语句，此时按当前配置重新生成完整语句。
+    pub(in crate::app) fn import_sql_for_editor(&mut self, preview_sql: String) -> Option<String> {
+        let partial = self
+            .state
+            .import_state
+            .preview
+            .as_ref()
+            .is_some_and(|preview| preview.sql_statements.len() < preview.statement_count);
+        if !partial {
+            return Some(preview_sql);
+        }
+
+        let path = self.state.import_state.file_path.clone()?;
+        let session = self
+            .state
+            .import_state
+            .to_transfer_session(self.active_db_type());
+        match plan_import_transfer(&path, &session).and_then(|plan| plan.into_sql_statements()) {
+            Ok(statements) => Some(statements.join("\n\n")),
+            Err(error) => {
+                self.session
+                    .notifications
+                    .error(format!("生成导入 SQL 失败: {}", error));
+                None
+            }
+        }
+    }
+
     /// 执行导入（直接执行 SQL）
     pub(in crate::app) fn execute_import(&mut self) {
         let Some(ref path) = self.state.import_state.file_path else {
             return;
         };
-        let session = self.state.import_state.to_transfer_session(self.is_mysql());
+        let session = self
+            .state
+            .import_state
+            .to_transfer_session(self.active_db_type());
 
         let plan = match plan_import_transfer(path, &session) {
             Ok(plan) => plan,
@@ -71,17 +113,7 @@ impl DbManagerApp {
             }
         };
 
-        let valid_statements: Vec<String> = match plan.into_sql_statements() {
-            Ok(statements) => statements
-                .into_iter()
-                .filter(|statement| !statement.trim().is_empty())
-                .collect(),
-            Err(error) => {
-                self.session.notifications.error(error);
-                return;
-            }
-        };
-        let valid_count = valid_statements.len();
+        let valid_count = plan.statement_count;
 
         if valid_count == 0 {
             self.session.notifications.warning("没有有效的 SQL 语句");
@@ -110,10 +142,15 @@ impl DbManagerApp {
 
         self.session.runtime.spawn(async move {
             let start = std::time::Instant::now();
-            let result =
-                execute_import_batch(&config, valid_statements, use_transaction, stop_on_error)
-                    .await
-                    .map_err(|e| e.to_string());
+            // 语句按批生成并执行，NDJSON 等大文件不会整体展开
+            let result = execute_import_stream(
+                &config,
+                move |sink| plan.stream_sql_statements(IMPORT_BATCH_STATEMENTS, sink),
+                use_transaction,
+                stop_on_error,
+            )
+            .await
+            .map_err(|e| e.to_string());
             let elapsed_ms = start.elapsed().as_millis() as u64;
 
             if tx.send(Message::ImportDone(result, elapsed_ms)).is_err() {
@@ -1,10 +1,12 @@
 //! 应用工作流层。
 //!
-//! 负责导入导出、帮助和欢迎页等跨 UI/DB 的用户流程。
+//! 负责导入导出、筛选预设、外键导航、帮助和欢迎页等跨 UI/DB 的用户流程。
 
 pub(in crate::app) mod export;
+pub(in crate::app) mod filter_presets;
 pub(in crate::app) mod help;
 pub(in crate::app) mod import;
+pub(in crate::app) mod references;
 pub(in crate::app) mod welcome;
 
 pub(in crate::app) use super::DbManagerApp;
@@ -0,0 +1,399 @@
+//! 外键导航
+//!
+//! `gd` 在新 Tab 中打开当前外键单元格引用的行，`gr` 在 Row 检查器中列出引用当前行的表。
+//! 外键来自 `SchemaCatalog`；打开目标表时外键值以等值条件写入筛选列表并下推为 WHERE 子句，
+//! 用户可以像普通服务端筛选一样继续修改或清除。
+
+use crate::core::RightInspectorTab;
+use crate::domain::metadata::{ForeignKeyMetadata, SchemaCatalog, TableMetadata};
+use crate::domain::result::{ResultColumn, ResultSet};
+use crate::domain::value::{DbTypeInfo, DbValue};
+use crate::ui;
+
+use super::DbManagerApp;
+
+/// 外键导航的目标：某张表中满足全部等值条件的行
+#[derive(Debug, Clone, PartialEq)]
+pub(in crate::app) struct ReferenceTarget {
+    pub table: String,
+    /// 外键约束名
+    pub constraint: Option<String>,
+    pub conditions: Vec<ReferenceCondition>,
+}
+
+/// 目标表中的一列与它应等于的值
+#[derive(Debug, Clone, PartialEq)]
+pub(in crate::app) struct ReferenceCondition {
+    pub column: String,
+    /// 编辑文本形式的值，与筛选面板中手工输入的值一致
+    pub value: String,
+    /// 当前结果中对应列的类型，外键两端的列类型一致
+    pub type_info: DbTypeInfo,
+}
+
+impl ReferenceTarget {
+    /// 列表与通知中显示的描述，如 `orders (customer_id = 7)`
+    pub fn label(&self) -> String {
+        let conditions: Vec<String> = self
+            .conditions
+            .iter()
+            .map(|condition| format!("{} = {}", condition.column, condition.value))
+            .collect();
+        format!("{} ({})", self.table, conditions.join(", "))
+    }
+}
+
+/// 当前行中 `source_columns` 的值，依次对应目标表的 `target_columns`
+fn row_conditions(
+    result: &ResultSet,
+    row: usize,
+    source_columns: &[String],
+    target_columns: &[String],
+) -> Result<Vec<ReferenceCondition>, String> {
+    if source_columns.len() != target_columns.len() || row >= result.row_count {
+        return Err("外键列与被引用列不匹配".to_string());
+    }
+    source_columns
+        .iter()
+        .zip(target_columns)
+        .map(|(source, target)| {
+            let index = result
+                .columns
+                .iter()
+                .position(|column| column.name == *source)
+                .ok_or_else(|| format!("当前结果中没有列 '{}'", source))?;
+            let value = result.cell(row, index);
+            if matches!(value, DbValue::Null) {
+                return Err(format!("列 '{}' 为 NULL，没有可跳转的行", source));
+            }
+            Ok(ReferenceCondition {
+                column: target.clone(),
+                value: ui::CellEdit::from_value(value).text().to_string(),
+                type_info: result.columns[index].type_info.clone(),
+            })
+        })
+        .collect()
+}
+
+fn referenced_target_for(
+    foreign_key: &ForeignKeyMetadata,
+    result: &ResultSet,
+    row: usize,
+) -> Result<ReferenceTarget, String> {
+    Ok(ReferenceTarget {
+        table: foreign_key.ref_table.clone(),
+        constraint: foreign_key.name.clone(),
+        conditions: row_conditions(
+            result,
+            row,
+            &foreign_key.from_columns,
+            &foreign_key.ref_columns,
+        )?,
+    })
+}
+
+/// 当前单元格所在外键引用的行；列参与多个外键时取第一个
+pub(in crate::app) fn referenced_target(
+    table: &TableMetadata,
+    result: &ResultSet,
+    row: usize,
+    column: &str,
+) -> Result<ReferenceTarget, String> {
+    let foreign_key = table
+        .foreign_keys
+        .iter()
+        .find(|foreign_key| foreign_key.from_columns.iter().any(|from| from == column))
+        .ok_or_else(|| format!("列 '{}' 不是外键", column))?;
+    referenced_target_for(foreign_key, result, row)
+}
+
+/// 当前行的所有外键引用（跳过值为 NULL 的外键）
+pub(in crate::app) fn referenced_targets(
+    table: &TableMetadata,
+    result: &ResultSet,
+    row: usize,
+) -> Vec<ReferenceTarget> {
+    table
+        .foreign_keys
+        .iter()
+        .filter_map(|foreign_key| referenced_target_for(foreign_key, result, row).ok())
+        .collect()
+}
+
+/// 引用当前行的子表：目录中 `ref_table` 指向 `table` 的外键
+pub(in crate::app) fn referencing_targets(
+    catalog: &SchemaCatalog,
+    table: &str,
+    result: &ResultSet,
+    row: usize,
+) -> Vec<ReferenceTarget> {
+    catalog
+        .tables
+        .iter()
+        .flat_map(|child| {
+            child
+                .foreign_keys
+                .iter()
+                .filter(|foreign_key| foreign_key.ref_table.eq_ignore_ascii_case(table))
+                .filter_map(|foreign_key| {
+                    let conditions = row_conditions(
+                        result,
+                        row,
+                        &foreign_key.ref_columns,
+                        &foreign_key.from_columns,
+                    )
+                    .ok()?;
+                    Some(ReferenceTarget {
+                        table: child.name.clone(),
+                        constraint: foreign_key.name.clone(),
+                        conditions,
+                    })
+                })
+        })
+        .collect()
+}
+
+impl DbManagerApp {
+    /// `gd`：打开光标所在外键单元格引用的行
+    pub(in crate::app) fn follow_foreign_key(&mut self) {
+        let (row, col) = self.state.grid_state.cursor;
+        let resolved = match (
+            self.state.grid_state.result_set.as_deref(),
+            self.state.grid_state.table_metadata.as_deref(),
+        ) {
+            (Some(result), Some(table)) if col < result.column_count() => {
+                referenced_target(table, result, row, &result.columns[col].name)
+            }
+            (Some(_), None) => Err("当前结果不是已知表，无法识别外键".to_string()),
+            _ => return,
+        };
+        match resolved {
+            Ok(target) => self.open_reference_target(target),
+            Err(error) => {
+                self.session.notifications.warning(error);
+            }
+        }
+    }
+
+    /// `gr`：在 Row 检查器中列出引用当前行的表
+    pub(in crate::app) fn show_referencing_rows(&mut self) {
+        let row = self.state.grid_state.cursor.0;
+        let count = self
+            .referencing_targets_for_row(row)
+            .map(|targets| targets.len());
+        match count {
+            Some(0) => {
+                self.session.notifications.info("没有表引用当前行");
+            }
+            Some(_) => {
+                self.state.selected_row = Some(row);
+                self.reveal_right_inspector_for_inspect(RightInspectorTab::Row);
+            }
+            None => {
+                self.session
+                    .notifications
+                    .warning("表结构尚未加载，无法查找引用当前行的表");
+            }
+        }
+    }
+
+    /// 引用当前表第 `row` 行的子表；没有选中表或目录尚未加载时返回 `None`
+    pub(in crate::app) fn referencing_targets_for_row(
+        &self,
+        row: usize,
+    ) -> Option<Vec<ReferenceTarget>> {
+        let table = self.state.selected_table.as_deref()?;
+        let result = self.state.grid_state.result_set.as_deref()?;
+        let catalog = self.active_schema_catalog()?;
+        Some(referencing_targets(catalog, table, result, row))
+    }
+
+    /// 在新 Tab 中打开目标表，并把外键条件作为服务端筛选
+    pub(in crate::app) fn open_reference_target(&mut self, target: ReferenceTarget) {
+        let tab_manager = &self.session.tab_manager;
+        if tab_manager.tabs.len() >= tab_manager.max_tabs {
+            self.session
+                .notifications
+                .warning("查询 Tab 已达上限，请先关闭一些 Tab");
+            return;
+        }
+
+        self.persist_active_tab_state_for_navigation();
+        self.session.tab_manager.new_tab();
+        if let Some(tab) = self.session.tab_manager.get_active_mut() {
+            tab.title = target.table.clone();
+            tab.table_name = Some(target.table.clone());
+            tab.selected_table = Some(target.table.clone());
+            tab.uses_grid_workspace = true;
+        }
+        self.sync_from_active_tab();
+
+        let grid_state = &mut self.state.grid_state;
+        grid_state.filters = target
+            .conditions
+            .iter()
+            .map(|condition| {
+                ui::ColumnFilter::new(condition.column.clone())
+                    .with_operator(ui::FilterOperator::Equals)
+                    .with_value(condition.value.clone())
+                    .with_case_sensitive(true)
+            })
+            .collect();
+        grid_state.server_side_filter = true;
+        grid_state.filter_cache.invalidate();
+
+        if let Err(error) = self.begin_table_paging(&target.table) {
+            self.session
+                .notifications
+                .error(format!("表名无效: {}", error));
+            return;
+        }
+        let columns: Vec<ResultColumn> = target
+            .conditions
+            .iter()
+            .map(|condition| ResultColumn {
+                name: condition.column.clone(),
+                type_info: condition.type_info.clone(),
+            })
+            .collect();
+        let Some(pager) = self
+            .session
+            .tab_manager
+            .get_active_mut()
+            .and_then(|tab| tab.table_pager.as_mut())
+        else {
+            return;
+        };
+        let filter =
+            ui::compile_filter_clause(&self.state.grid_state.filters, &columns, pager.dialect())
+                .and_then(|clause| clause.map(|clause| clause.inline_sql()).transpose());
+        match filter {
+            Ok(filter) => pager.set_filter(filter),
+            Err(error) => {
+                self.session
+                    .notifications
+                    .error(format!("无法生成外键筛选条件: {}", error));
+                return;
+            }
+        }
+        let sql = pager.restart();
+        self.sync_grid_page_info();
+        self.set_active_sql(String::new());
+        let _ = self.execute(sql);
+        self.session
+            .notifications
+            .info(format!("已打开 {}", target.label()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ids::SchemaRevision;
+    use crate::domain::result::ResultCompleteness;
+    use crate::domain::value::DbTypeFamily;
+    use std::sync::Arc;
+
+    fn integer() -> DbTypeInfo {
+        DbTypeInfo {
+            family: DbTypeFamily::Integer,
+            native_name: "INTEGER".to_string(),
+            nullable: None,
+        }
+    }
+
+    fn table(name: &str, foreign_keys: Vec<ForeignKeyMetadata>) -> TableMetadata {
+        TableMetadata {
+            name: name.to_string(),
+            schema: None,
+            columns: Vec::new(),
+            primary_key: None,
+            unique_keys: Vec::new(),
+            foreign_keys,
+            indexes: Vec::new(),
+            check_constraints: Vec::new(),
+        }
+    }
+
+    fn foreign_key(from: &[&str], ref_table: &str, to: &[&str]) -> ForeignKeyMetadata {
+        ForeignKeyMetadata {
+            name: Some(format!("fk_{}", from.join("_"))),
+            from_columns: from.iter().map(|column| column.to_string()).collect(),
+            ref_table: ref_table.to_string(),
+            ref_columns: to.iter().map(|column| column.to_string()).collect(),
+        }
+    }
+
+    /// orders(id, customer_id)，两行：(1, 7) 与 (2, NULL)
+    fn orders_result() -> ResultSet {
+        ResultSet {
+            columns: Arc::new([
+                ResultColumn {
+                    name: "id".to_string(),
+                    type_info: integer(),
+                },
+                ResultColumn {
+                    name: "customer_id".to_string(),
+                    type_info: integer(),
+                },
+            ]),
+            cells: vec![
+                DbValue::Int(1),
+                DbValue::Int(7),
+                DbValue::Int(2),
+                DbValue::Null,
+            ],
+            row_count: 2,
+            completeness: ResultCompleteness::Complete,
+        }
+    }
+
+    #[test]
+    fn foreign_key_cell_resolves_to_the_referenced_row() {
+        let orders = table(
+            "orders",
+            vec![foreign_key(&["customer_id"], "customers", &["id"])],
+        );
+        let result = orders_result();
+
+        let target = referenced_target(&orders, &result, 0, "customer_id").unwrap();
+        assert_eq!(target.table, "customers");
+        assert_eq!(target.label(), "customers (id = 7)");
+        assert_eq!(target.conditions[0].type_info.family, DbTypeFamily::Integer);
+
+        assert!(referenced_target(&orders, &result, 0, "id").is_err());
+        let error = referenced_target(&orders, &result, 1, "customer_id").unwrap_err();
+        assert!(error.contains("NULL"), "{error}");
+        assert_eq!(referenced_targets(&orders, &result, 0).len(), 1);
+        assert!(referenced_targets(&orders, &result, 1).is_empty());
+    }
+
+    #[test]
+    fn referencing_tables_are_found_through_the_catalog() {
+        let catalog = SchemaCatalog {
+            revision: SchemaRevision(1),
+            default_schema: None,
+            tables: vec![
+                table(
+                    "orders",
+                    vec![foreign_key(&["customer_id"], "customers", &["id"])],
+                ),
+                table(
+                    "shipments",
+                    vec![foreign_key(&["order_id"], "Orders", &["id"])],
+                ),
+                table("audit", Vec::new()),
+            ],
+            views: Vec::new(),
+            sequences: Vec::new(),
+        };
+        let result = orders_result();
+
+        let targets = referencing_targets(&catalog, "orders", &result, 1);
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].table, "shipments");
+        assert_eq!(targets[0].conditions[0].column, "order_id");
+        assert_eq!(targets[0].conditions[0].value, "2");
+        assert_eq!(targets[0].constraint.as_deref(), Some("fk_order_id"));
+    }
+}
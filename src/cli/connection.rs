@@ -0,0 +1,633 @@
+//! `gridix connection` — 在命令行中管理已保存的连接档案
+//!
+//! 读写与 GUI 相同的 `config.toml`，密码经 `AppConfig::save` 写入系统密钥链，
+//! 便于用脚本批量初始化开发机的连接配置。
+
+use super::output::{text_table, write_stdout};
+use crate::core::AppConfig;
+use crate::data::{
+    ConnectResult, ConnectionConfig, DatabaseType, DbError, MySqlSslMode, PostgresSslMode,
+    SshAuthMethod, SshTunnelConfig, connect_database, delete_password_secret,
+};
+use std::io::BufRead;
+
+/// `gridix connection` 子命令
+#[derive(Debug, clap::Subcommand)]
+pub enum ConnectionCommand {
+    /// 列出已保存的连接
+    List {
+        /// 以 JSON 输出
+        #[arg(long)]
+        json: bool,
+    },
+    /// 新增连接（配合 --replace 可覆盖同名连接）
+    Add(Box<ConnectionAddArgs>),
+    /// 删除连接及其保存在系统密钥链中的密码
+    Remove {
+        /// 连接名称
+        name: String,
+    },
+    /// 测试连接是否可用
+    Test {
+        /// 连接名称
+        name: String,
+        /// 覆盖连接的默认数据库
+        #[arg(short, long)]
+        database: Option<String>,
+    },
+    /// 显示连接详情（不含密码）
+    Show {
+        /// 连接名称
+        name: String,
+        /// 以 JSON 输出
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// 数据库类型参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DbTypeArg {
+    Sqlite,
+    #[value(alias = "postgresql", alias = "pg")]
+    Postgres,
+    Mysql,
+}
+
+impl From<DbTypeArg> for DatabaseType {
+    fn from(value: DbTypeArg) -> Self {
+        match value {
+            DbTypeArg::Sqlite => Self::SQLite,
+            DbTypeArg::Postgres => Self::PostgreSQL,
+            DbTypeArg::Mysql => Self::MySQL,
+        }
+    }
+}
+
+/// MySQL SSL 模式参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum MySqlSslModeArg {
+    Disabled,
+    Preferred,
+    Required,
+    VerifyCa,
+    VerifyIdentity,
+}
+
+impl From<MySqlSslModeArg> for MySqlSslMode {
+    fn from(value: MySqlSslModeArg) -> Self {
+        match value {
+            MySqlSslModeArg::Disabled => Self::Disabled,
+            MySqlSslModeArg::Preferred => Self::Preferred,
+            MySqlSslModeArg::Required => Self::Required,
+            MySqlSslModeArg::VerifyCa => Self::VerifyCa,
+            MySqlSslModeArg::VerifyIdentity => Self::VerifyIdentity,
+        }
+    }
+}
+
+/// PostgreSQL SSL 模式参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PostgresSslModeArg {
+    Disable,
+    Prefer,
+    Require,
+    VerifyCa,
+    VerifyFull,
+}
+
+impl From<PostgresSslModeArg> for PostgresSslMode {
+    fn from(value: PostgresSslModeArg) -> Self {
+        match value {
+            PostgresSslModeArg::Disable => Self::Disable,
+            PostgresSslModeArg::Prefer => Self::Prefer,
+            PostgresSslModeArg::Require => Self::Require,
+            PostgresSslModeArg::VerifyCa => Self::VerifyCa,
+            PostgresSslModeArg::VerifyFull => Self::VerifyFull,
+        }
+    }
+}
+
+/// `gridix connection add` 参数
+#[derive(Debug, clap::Args)]
+pub struct ConnectionAddArgs {
+    /// 连接名称
+    pub name: String,
+
+    /// 数据库类型
+    #[arg(long = "type", value_enum)]
+    pub db_type: DbTypeArg,
+
+    /// 主机地址（MySQL/PostgreSQL，默认 localhost）
+    #[arg(long)]
+    pub host: Option<String>,
+
+    /// 端口（默认使用数据库类型的标准端口）
+    #[arg(long)]
+    pub port: Option<u16>,
+
+    /// 用户名
+    #[arg(short, long)]
+    pub user: Option<String>,
+
+    /// 默认数据库；SQLite 为数据库文件路径
+    #[arg(short, long)]
+    pub database: Option<String>,
+
+    /// 从指定环境变量读取数据库密码
+    #[arg(long, value_name = "VAR", conflicts_with = "password_stdin")]
+    pub password_env: Option<String>,
+
+    /// 从标准输入第一行读取数据库密码
+    #[arg(long)]
+    pub password_stdin: bool,
+
+    /// MySQL SSL 模式
+    #[arg(long, value_enum)]
+    pub mysql_ssl_mode: Option<MySqlSslModeArg>,
+
+    /// PostgreSQL SSL 模式
+    #[arg(long, value_enum)]
+    pub postgres_ssl_mode: Option<PostgresSslModeArg>,
+
+    /// CA 证书路径（VerifyCa / 完全验证模式使用）
+    #[arg(long, value_name = "PATH")]
+    pub ssl_ca_cert: Option<String>,
+
+    /// PostgreSQL search_path（逗号分隔，如 "app,public"）
+    #[arg(long, value_name = "SCHEMAS")]
+    pub search_path: Option<String>,
+
+    /// SSH 跳板机地址（指定后启用 SSH 隧道）
+    #[arg(long)]
+    pub ssh_host: Option<String>,
+
+    /// SSH 端口
+    #[arg(long, default_value_t = 22)]
+    pub ssh_port: u16,
+
+    /// SSH 用户名
+    #[arg(long)]
+    pub ssh_user: Option<String>,
+
+    /// 从指定环境变量读取 SSH 密码（密码认证）
+    #[arg(long, value_name = "VAR", conflicts_with = "ssh_key")]
+    pub ssh_password_env: Option<String>,
+
+    /// SSH 私钥路径（私钥认证）
+    #[arg(long, value_name = "PATH")]
+    pub ssh_key: Option<String>,
+
+    /// 从 SSH 服务器视角访问数据库的主机（默认与 --host 相同）
+    #[arg(long)]
+    pub ssh_remote_host: Option<String>,
+
+    /// 从 SSH 服务器视角访问数据库的端口（默认与 --port 相同）
+    #[arg(long)]
+    pub ssh_remote_port: Option<u16>,
+
+    /// 本地隧道端口（0 表示自动分配）
+    #[arg(long, default_value_t = 0)]
+    pub ssh_local_port: u16,
+
+    /// 覆盖同名连接；未提供新密码时沿用原有密码
+    #[arg(long)]
+    pub replace: bool,
+}
+
+pub(super) fn run(command: ConnectionCommand) -> Result<(), String> {
+    match command {
+        ConnectionCommand::List { json } => list(json),
+        ConnectionCommand::Add(args) => add(*args),
+        ConnectionCommand::Remove { name } => remove(&name),
+        ConnectionCommand::Test { name, database } => test(&name, database.as_deref()),
+        ConnectionCommand::Show { name, json } => show(&name, json),
+    }
+}
+
+fn list(json: bool) -> Result<(), String> {
+    let config = AppConfig::load();
+
+    if json {
+        return print_json(&config.connections);
+    }
+
+    let rows = config
+        .connections
+        .iter()
+        .map(|connection| {
+            vec![
+                connection.name.clone(),
+                connection.db_type.display_name().to_string(),
+                connection_target(connection),
+                if connection.ssh_config.enabled {
+                    connection.ssh_config.ssh_addr()
+                } else {
+                    String::new()
+                },
+            ]
+        })
+        .collect();
+    write_stdout(&text_table(&["名称", "类型", "目标", "SSH"], rows))
+}
+
+fn add(args: ConnectionAddArgs) -> Result<(), String> {
+    let password = read_secret(args.password_env.as_deref(), args.password_stdin)?;
+    let ssh_password = read_secret(args.ssh_password_env.as_deref(), false)?;
+
+    let mut config = AppConfig::load();
+    let existing = config
+        .connections
+        .iter()
+        .position(|connection| connection.name == args.name);
+    if existing.is_some() && !args.replace {
+        return Err(format!(
+            "连接 '{}' 已存在，如需覆盖请加 --replace",
+            args.name
+        ));
+    }
+
+    let connection = build_connection(
+        &args,
+        password,
+        ssh_password,
+        existing.map(|idx| &config.connections[idx]),
+    )?;
+
+    match existing {
+        Some(idx) => config.connections[idx] = connection,
+        None => config.connections.push(connection),
+    }
+    config.save()?;
+
+    eprintln!(
+        "连接 '{}' 已{}",
+        args.name,
+        if existing.is_some() {
+            "更新"
+        } else {
+            "保存"
+        }
+    );
+    Ok(())
+}
+
+fn remove(name: &str) -> Result<(), String> {
+    let mut config = AppConfig::load();
+    let idx = config
+        .connections
+        .iter()
+        .position(|connection| connection.name == name)
+        .ok_or_else(|| format!("连接 '{}' 不存在", name))?;
+    let connection = config.connections.remove(idx);
+
+    // 凭据清理失败不阻止删除，仅提示用户手动处理
+    if let Some(password_ref) = &connection.password_ref
+        && let Err(e) = delete_password_secret(password_ref)
+    {
+        eprintln!("警告: 删除密钥链中的密码失败: {}", e);
+    }
+    if let Some(password_ref) = &connection.ssh_config.password_ref
+        && let Err(e) = delete_password_secret(password_ref)
+    {
+        eprintln!("警告: 删除密钥链中的 SSH 密码失败: {}", e);
+    }
+
+    config.command_history.remove(name);
+    config.save()?;
+
+    eprintln!("连接 '{}' 已删除", name);
+    Ok(())
+}
+
+fn test(name: &str, database: Option<&str>) -> Result<(), String> {
+    let app_config = AppConfig::load();
+    let connection = super::resolve_connection(&app_config, name, database)?;
+
+    let runtime = super::build_runtime()?;
+    match runtime.block_on(connect_database(&connection)) {
+        Ok(ConnectResult::Tables(tables)) => {
+            write_stdout(&format!("连接成功：{}（{} 张表）\n", name, tables.len()))
+        }
+        Ok(ConnectResult::Databases(databases)) => write_stdout(&format!(
+            "连接成功：{}（{} 个数据库）\n",
+            name,
+            databases.len()
+        )),
+        Err(error) => {
+            let error = classify_error(error);
+            Err(format!("连接失败 [{}]: {}", error.kind(), error))
+        }
+    }
+}
+
+fn show(name: &str, json: bool) -> Result<(), String> {
+    let app_config = AppConfig::load();
+    let connection = super::resolve_connection(&app_config, name, None)?;
+
+    if json {
+        return print_json(&connection);
+    }
+
+    let text: String = describe_connection(&connection)
+        .into_iter()
+        .map(|(label, value)| format!("{}: {}\n", label, value))
+        .collect();
+    write_stdout(&text)
+}
+
+/// 由命令行参数组装连接配置；`existing` 为 `--replace` 时的原连接，用于沿用密码引用。
+fn build_connection(
+    args: &ConnectionAddArgs,
+    password: Option<String>,
+    ssh_password: Option<String>,
+    existing: Option<&ConnectionConfig>,
+) -> Result<ConnectionConfig, String> {
+    let db_type = DatabaseType::from(args.db_type);
+    let mut connection = ConnectionConfig::new(args.name.clone(), db_type);
+
+    if let Some(host) = &args.host {
+        connection.host = host.clone();
+    }
+    if let Some(port) = args.port {
+        connection.port = port;
+    }
+    connection.username = args.user.clone().unwrap_or_default();
+    connection.database = args.database.clone().unwrap_or_default();
+    if let Some(mode) = args.mysql_ssl_mode {
+        connection.mysql_ssl_mode = mode.into();
+    }
+    if let Some(mode) = args.postgres_ssl_mode {
+        connection.postgres_ssl_mode = mode.into();
+    }
+    connection.ssl_ca_cert = args.ssl_ca_cert.clone().unwrap_or_default();
+    connection.search_path = args.search_path.clone().unwrap_or_default();
+
+    match password {
+        Some(password) => {
+            connection.password = password;
+            connection.password_ref = existing.and_then(|old| old.password_ref.clone());
+        }
+        None => {
+            if let Some(old) = existing {
+                connection.password = old.password.clone();
+                connection.password_ref = old.password_ref.clone();
+            }
+        }
+    }
+
+    if let Some(ssh_host) = &args.ssh_host {
+        let mut ssh = SshTunnelConfig {
+            enabled: true,
+            ssh_host: ssh_host.clone(),
+            ssh_port: args.ssh_port,
+            ssh_username: args.ssh_user.clone().unwrap_or_default(),
+            remote_host: args
+                .ssh_remote_host
+                .clone()
+                .unwrap_or_else(|| connection.host.clone()),
+            remote_port: args.ssh_remote_port.unwrap_or(connection.port),
+            local_port: args.ssh_local_port,
+            ..SshTunnelConfig::new()
+        };
+
+        match &args.ssh_key {
+            Some(path) => {
+                ssh.auth_method = SshAuthMethod::PrivateKey;
+                ssh.private_key_path = path.clone();
+            }
+            None => {
+                ssh.auth_method = SshAuthMethod::Password;
+                let old_ssh = existing.map(|old| &old.ssh_config);
+                match ssh_password {
+                    Some(password) => {
+                        ssh.ssh_password = password;
+                        if let Some(old_ssh) = old_ssh {
+                            ssh.credential_revision = old_ssh.credential_revision;
+                            ssh.mark_password_edited();
+                        }
+                    }
+                    None => {
+                        if let Some(old_ssh) = old_ssh {
+                            ssh.ssh_password = old_ssh.ssh_password.clone();
+                            ssh.password_ref = old_ssh.password_ref.clone();
+                            ssh.credential_revision = old_ssh.credential_revision;
+                        }
+                    }
+                }
+            }
+        }
+        connection.ssh_config = ssh;
+    }
+
+    let mut errors = connection.validation_errors();
+    if let Err(error) = connection.ssh_config.validate() {
+        errors.push(error);
+    }
+    if errors.is_empty() {
+        Ok(connection)
+    } else {
+        Err(format!("连接配置无效: {}", errors.join("；")))
+    }
+}
+
+/// 从环境变量或标准输入读取秘密值，均未指定时返回 `None`
+fn read_secret(env: Option<&str>, stdin: bool) -> Result<Option<String>, String> {
+    if let Some(var) = env {
+        return std::env::var(var)
+            .map(Some)
+            .map_err(|_| format!("环境变量 {} 未设置或不是有效的 UTF-8", var));
+    }
+
+    if stdin {
+        let mut line = String::new();
+        std::io::stdin()
+            .lock()
+            .read_line(&mut line)
+            .map_err(|e| format!("读取标准输入失败: {}", e))?;
+        return Ok(Some(line.trim_end_matches(['\r', '\n']).to_string()));
+    }
+
+    Ok(None)
+}
+
+/// 驱动层的通用连接错误再按消息内容细分类别
+fn classify_error(error: DbError) -> DbError {
+    match error {
+        DbError::Connection(message) => DbError::classify_connection(message),
+        other => other,
+    }
+}
+
+fn connection_target(connection: &ConnectionConfig) -> String {
+    match connection.db_type {
+        DatabaseType::SQLite => connection.database.clone(),
+        DatabaseType::PostgreSQL | DatabaseType::MySQL => {
+            let mut target = format!("{}:{}", connection.host, connection.port);
+            if !connection.database.is_empty() {
+                target.push('/');
+                target.push_str(&connection.database);
+            }
+            target
+        }
+    }
+}
+
+fn describe_connection(connection: &ConnectionConfig) -> Vec<(&'static str, String)> {
+    let mut lines = vec![
+        ("名称", connection.name.clone()),
+        ("类型", connection.db_type.display_name().to_string()),
+    ];
+
+    match connection.db_type {
+        DatabaseType::SQLite => lines.push(("文件", connection.database.clone())),
+        DatabaseType::PostgreSQL | DatabaseType::MySQL => {
+            lines.push(("主机", connection.host.clone()));
+            lines.push(("端口", connection.port.to_string()));
+            lines.push(("用户名", connection.username.clone()));
+            lines.push(("数据库", connection.database.clone()));
+            lines.push((
+                "密码",
+                if connection.password_ref.is_some() {
+                    "已保存在系统密钥链".to_string()
+                } else {
+                    "未保存".to_string()
+                },
+            ));
+            lines.push((
+                "SSL",
+                match connection.db_type {
+                    DatabaseType::MySQL => connection.mysql_ssl_mode.display_name().to_string(),
+                    _ => connection.postgres_ssl_mode.display_name().to_string(),
+                },
+            ));
+            if !connection.ssl_ca_cert.is_empty() {
+                lines.push(("CA 证书", connection.ssl_ca_cert.clone()));
+            }
+            if !connection.search_path.is_empty() {
+                lines.push(("search_path", connection.search_path.clone()));
+            }
+        }
+    }
+
+    let ssh = &connection.ssh_config;
+    if ssh.enabled {
+        lines.push((
+            "SSH",
+            format!(
+                "{}@{}（{}） -> {}:{}",
+                ssh.ssh_username,
+                ssh.ssh_addr(),
+                ssh.auth_method.display_name(),
+                ssh.remote_host,
+                ssh.remote_port
+            ),
+        ));
+    }
+
+    lines
+}
+
+fn print_json<T: serde::Serialize + ?Sized>(value: &T) -> Result<(), String> {
+    let mut text = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+    text.push('\n');
+    write_stdout(&text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, clap::Parser)]
+    struct TestCli {
+        #[command(subcommand)]
+        command: ConnectionCommand,
+    }
+
+    fn parse_add(args: &[&str]) -> ConnectionAddArgs {
+        let argv = ["gridix", "add"].iter().chain(args.iter());
+        match TestCli::parse_from(argv).command {
+            ConnectionCommand::Add(args) => *args,
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn build_connection_applies_network_defaults_and_ssl_mode() {
+        let args = parse_add(&[
+            "primary",
+            "--type",
+            "pg",
+            "--user",
+            "app",
+            "--postgres-ssl-mode",
+            "verify-full",
+            "--search-path",
+            "app, public",
+        ]);
+        let connection = build_connection(&args, Some("secret".to_string()), None, None).unwrap();
+
+        assert_eq!(connection.db_type, DatabaseType::PostgreSQL);
+        assert_eq!(connection.host, "localhost");
+        assert_eq!(connection.port, 5432);
+        assert_eq!(connection.password, "secret");
+        assert_eq!(connection.postgres_ssl_mode, PostgresSslMode::VerifyFull);
+        assert_eq!(
+            connection.postgres_startup_options().as_deref(),
+            Some("-c search_path=app,public")
+        );
+        assert!(!connection.ssh_config.enabled);
+    }
+
+    #[test]
+    fn build_connection_configures_ssh_tunnel_towards_database_host() {
+        let key = tempfile::NamedTempFile::new().unwrap();
+        let key_path = key.path().to_string_lossy().to_string();
+        let args = parse_add(&[
+            "prod",
+            "--type",
+            "mysql",
+            "--host",
+            "db.internal",
+            "--ssh-host",
+            "bastion",
+            "--ssh-user",
+            "ops",
+            "--ssh-key",
+            &key_path,
+        ]);
+        let connection = build_connection(&args, None, None, None).unwrap();
+
+        let ssh = &connection.ssh_config;
+        assert!(ssh.enabled);
+        assert_eq!(ssh.auth_method, SshAuthMethod::PrivateKey);
+        assert_eq!(ssh.ssh_port, 22);
+        assert_eq!(ssh.remote_host, "db.internal");
+        assert_eq!(ssh.remote_port, 3306);
+    }
+
+    #[test]
+    fn build_connection_replace_keeps_existing_password_reference() {
+        let mut existing = ConnectionConfig::new("primary", DatabaseType::PostgreSQL);
+        existing.password = "old".to_string();
+        existing.password_ref = Some("connection:abc".to_string());
+
+        let args = parse_add(&["primary", "--type", "postgres", "--replace"]);
+        let connection = build_connection(&args, None, None, Some(&existing)).unwrap();
+
+        assert_eq!(connection.password, "old");
+        assert_eq!(connection.password_ref.as_deref(), Some("connection:abc"));
+    }
+
+    #[test]
+    fn build_connection_reports_validation_errors() {
+        let args = parse_add(&["local", "--type", "sqlite"]);
+        let error = build_connection(&args, None, None, None).unwrap_err();
+        assert!(error.contains("数据库文件路径不能为空"));
+
+        let args = parse_add(&["remote", "--type", "postgres", "--ssh-host", "bastion"]);
+        let error = build_connection(&args, None, None, None).unwrap_err();
+        assert!(error.contains("SSH 用户名不能为空"));
+    }
+}
@@ -0,0 +1,151 @@
+//! 无界面命令行子命令
+//!
+//! 复用 GUI 维护的连接配置（`AppConfig.connections`，含系统密钥链中的密码与 SSH 隧道），
+//! 让脚本和 CI 直接使用同一套连接档案，而不必在 shell 中重复保存凭据。
+
+mod connection;
+mod output;
+mod query;
+mod transfer;
+
+pub use connection::{
+    ConnectionAddArgs, ConnectionCommand, DbTypeArg, MySqlSslModeArg, PostgresSslModeArg,
+};
+pub use output::OutputFormat;
+pub use query::QueryArgs;
+pub use transfer::{ExportArgs, ImportArgs, TransferFormatArg};
+
+use crate::core::AppConfig;
+use crate::data::ConnectionConfig;
+use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
+
+/// 命令行子命令
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// 使用已保存的连接执行 SQL 并打印结果
+    Query(QueryArgs),
+    /// 将表或查询结果导出为 CSV/TSV/JSON/SQL 文件
+    Export(ExportArgs),
+    /// 将 CSV/TSV/JSON/SQL 文件导入到数据库
+    Import(ImportArgs),
+    /// 管理已保存的连接档案
+    #[command(subcommand)]
+    Connection(ConnectionCommand),
+}
+
+/// 执行子命令
+///
+/// 错误以用户可读文本返回，由 `main` 打印到 stderr 并设置退出码。
+pub fn run(command: Command) -> Result<(), String> {
+    init_tracing();
+
+    match command {
+        Command::Query(args) => query::run(args),
+        Command::Export(args) => transfer::run_export(args),
+        Command::Import(args) => transfer::run_import(args),
+        Command::Connection(command) => connection::run(command),
+    }
+}
+
+/// 初始化日志系统。
+///
+/// 命令行模式的 stdout 用于输出结果，日志统一写到 stderr，默认只显示警告。
+fn init_tracing() {
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("gridix=warn,warn"));
+
+    let _ = tracing_subscriber::registry()
+        .with(filter)
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_target(false)
+                .with_writer(std::io::stderr),
+        )
+        .try_init();
+}
+
+/// 按名称查找已保存的连接，并可选地覆盖默认数据库。
+pub(crate) fn resolve_connection(
+    config: &AppConfig,
+    name: &str,
+    database: Option<&str>,
+) -> Result<ConnectionConfig, String> {
+    let mut connection = config
+        .connections
+        .iter()
+        .find(|connection| connection.name == name)
+        .cloned()
+        .ok_or_else(|| {
+            let known: Vec<&str> = config
+                .connections
+                .iter()
+                .map(|connection| connection.name.as_str())
+                .collect();
+            if known.is_empty() {
+                format!("未找到连接 '{}'：配置中还没有保存任何连接", name)
+            } else {
+                format!("未找到连接 '{}'，可用连接: {}", name, known.join(", "))
+            }
+        })?;
+
+    if let Some(database) = database.filter(|database| !database.trim().is_empty()) {
+        connection.database = database.to_string();
+    }
+
+    Ok(connection)
+}
+
+/// 创建命令行使用的 tokio 运行时。
+pub(crate) fn build_runtime() -> Result<tokio::runtime::Runtime, String> {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| format!("无法创建 tokio 运行时: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_connection;
+    use crate::core::AppConfig;
+    use crate::data::{ConnectionConfig, DatabaseType};
+
+    fn config_with(names: &[&str]) -> AppConfig {
+        AppConfig {
+            connections: names
+                .iter()
+                .map(|name| {
+                    let mut connection = ConnectionConfig::new(*name, DatabaseType::PostgreSQL);
+                    connection.database = "appdb".to_string();
+                    connection
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn resolve_connection_overrides_database() {
+        let config = config_with(&["primary"]);
+        let connection = resolve_connection(&config, "primary", Some("reporting")).unwrap();
+
+        assert_eq!(connection.database, "reporting");
+        assert_eq!(config.connections[0].database, "appdb");
+    }
+
+    #[test]
+    fn resolve_connection_keeps_default_database_without_override() {
+        let config = config_with(&["primary"]);
+        let connection = resolve_connection(&config, "primary", None).unwrap();
+
+        assert_eq!(connection.database, "appdb");
+    }
+
+    #[test]
+    fn resolve_connection_lists_known_names_on_miss() {
+        let config = config_with(&["primary", "replica"]);
+        let error = resolve_connection(&config, "missing", None).unwrap_err();
+
+        assert!(error.contains("missing"));
+        assert!(error.contains("primary, replica"));
+    }
+}
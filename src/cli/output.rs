@@ -0,0 +1,338 @@
+//! 命令行结果输出格式
+//!
+//! 将 `StatementOutcome` 渲染为对齐表格、CSV 或 JSON 文本。
+
+use crate::core::{ExportFormat, ExportOptions};
+use crate::domain::execution::StatementOutcome;
+use crate::domain::result::{ResultColumn, ResultCompleteness, ResultSet};
+use crate::domain::value::{DbTypeFamily, DbTypeInfo, DbValue};
+use std::sync::Arc;
+
+/// 结果输出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// 对齐的文本表格
+    #[default]
+    Table,
+    /// RFC 4180 CSV（带表头）
+    Csv,
+    /// 单个 JSON 数组，每条语句一个对象
+    Json,
+}
+
+/// 渲染全部语句结果
+pub(crate) fn render_outcomes(outcomes: &[StatementOutcome], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Table => outcomes
+            .iter()
+            .map(render_table_outcome)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Csv => outcomes
+            .iter()
+            .filter_map(|outcome| match outcome {
+                StatementOutcome::ResultSet(result) => Some(render_csv(result)),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => {
+            let values: Vec<serde_json::Value> = outcomes.iter().map(outcome_to_json).collect();
+            let mut text = serde_json::to_string_pretty(&values).unwrap_or_else(|_| "[]".into());
+            text.push('\n');
+            text
+        }
+    }
+}
+
+/// 写入标准输出；下游管道提前关闭（如 `| head`）时返回错误而不是 panic
+pub(crate) fn write_stdout(text: &str) -> Result<(), String> {
+    use std::io::Write;
+
+    let mut stdout = std::io::stdout().lock();
+    stdout
+        .write_all(text.as_bytes())
+        .and_then(|()| stdout.flush())
+        .map_err(|e| format!("写入输出失败: {}", e))
+}
+
+/// 非结果集语句的摘要（CSV 模式下写到 stderr，避免污染数据）
+pub(crate) fn outcome_summary(outcome: &StatementOutcome) -> Option<String> {
+    match outcome {
+        StatementOutcome::ResultSet(_) => None,
+        StatementOutcome::AffectedRows { rows } => Some(format!("影响 {} 行", rows)),
+        StatementOutcome::Command { tag, affected_rows } => Some(match affected_rows {
+            Some(rows) => format!("{} ({} 行)", tag, rows),
+            None => tag.clone(),
+        }),
+        StatementOutcome::Notice(message) => Some(format!("提示: {}", message)),
+    }
+}
+
+/// 将纯文本行渲染为对齐表格（用于连接列表等非查询输出）
+pub(crate) fn text_table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    let columns: Arc<[ResultColumn]> = headers
+        .iter()
+        .map(|name| ResultColumn {
+            name: name.to_string(),
+            type_info: DbTypeInfo {
+                family: DbTypeFamily::Text,
+                native_name: String::new(),
+                nullable: None,
+            },
+        })
+        .collect();
+    let row_count = rows.len();
+    render_table(&ResultSet {
+        columns,
+        cells: rows.into_iter().flatten().map(DbValue::Text).collect(),
+        row_count,
+        completeness: ResultCompleteness::Complete,
+    })
+}
+
+fn render_table_outcome(outcome: &StatementOutcome) -> String {
+    match outcome {
+        StatementOutcome::ResultSet(result) => render_table(result),
+        other => {
+            let mut line = outcome_summary(other).unwrap_or_default();
+            line.push('\n');
+            line
+        }
+    }
+}
+
+fn render_table(result: &ResultSet) -> String {
+    let headers = result.column_names();
+    let rows: Vec<Vec<String>> = (0..result.row_count)
+        .map(|row| result.row(row).iter().map(table_cell_text).collect())
+        .collect();
+
+    let mut widths: Vec<usize> = headers.iter().map(|h| display_width(h)).collect();
+    for row in &rows {
+        for (idx, cell) in row.iter().enumerate() {
+            widths[idx] = widths[idx].max(display_width(cell));
+        }
+    }
+
+    let numeric: Vec<bool> = result
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(col, _)| {
+            (0..result.row_count).any(|row| is_numeric(result.cell(row, col)))
+                && (0..result.row_count).all(|row| {
+                    let value = result.cell(row, col);
+                    matches!(value, DbValue::Null) || is_numeric(value)
+                })
+        })
+        .collect();
+
+    let mut out = String::new();
+    if !headers.is_empty() {
+        let header_cells: Vec<String> = headers
+            .iter()
+            .zip(&widths)
+            .map(|(h, w)| pad(h, *w, false))
+            .collect();
+        out.push_str(header_cells.join(" | ").trim_end());
+        out.push('\n');
+        let separator: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+        out.push_str(&separator.join("-+-"));
+        out.push('\n');
+        for row in &rows {
+            let cells: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(idx, cell)| pad(cell, widths[idx], numeric[idx]))
+                .collect();
+            out.push_str(cells.join(" | ").trim_end());
+            out.push('\n');
+        }
+    }
+
+    match result.completeness {
+        ResultCompleteness::Complete => {
+            out.push_str(&format!("({} 行)\n", result.row_count));
+        }
+        ResultCompleteness::Truncated { displayed } => {
+            out.push_str(&format!("({} 行，结果已截断)\n", displayed));
+        }
+    }
+    out
+}
+
+fn render_csv(result: &ResultSet) -> String {
+    let options = ExportOptions {
+        format: ExportFormat::Csv,
+        ..Default::default()
+    };
+    crate::core::render_export_content_for_transfer(result, "query_result", &options)
+        .unwrap_or_default()
+}
+
+fn outcome_to_json(outcome: &StatementOutcome) -> serde_json::Value {
+    match outcome {
+        StatementOutcome::ResultSet(result) => {
+            let columns = result.column_names();
+            let rows: Vec<serde_json::Value> = (0..result.row_count)
+                .map(|row| {
+                    let object: serde_json::Map<String, serde_json::Value> = columns
+                        .iter()
+                        .zip(result.row(row))
+                        .map(|(name, value)| {
+                            (
+                                name.clone(),
+                                serde_json::to_value(value).unwrap_or(serde_json::Value::Null),
+                            )
+                        })
+                        .collect();
+                    serde_json::Value::Object(object)
+                })
+                .collect();
+            serde_json::json!({
+                "type": "result_set",
+                "columns": columns,
+                "rows": rows,
+                "truncated": matches!(result.completeness, ResultCompleteness::Truncated { .. }),
+            })
+        }
+        StatementOutcome::AffectedRows { rows } => serde_json::json!({
+            "type": "affected_rows",
+            "rows": rows,
+        }),
+        StatementOutcome::Command { tag, affected_rows } => serde_json::json!({
+            "type": "command",
+            "tag": tag,
+            "affected_rows": affected_rows,
+        }),
+        StatementOutcome::Notice(message) => serde_json::json!({
+            "type": "notice",
+            "message": message,
+        }),
+    }
+}
+
+fn table_cell_text(value: &DbValue) -> String {
+    value.display().replace(['\n', '\r', '\t'], " ")
+}
+
+fn is_numeric(value: &DbValue) -> bool {
+    matches!(
+        value,
+        DbValue::Int(_) | DbValue::UInt(_) | DbValue::Float(_) | DbValue::Decimal(_)
+    )
+}
+
+fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|ch| if is_wide_char(ch) { 2 } else { 1 })
+        .sum()
+}
+
+/// 粗略判断东亚宽字符，保证中文列名与数据在终端中对齐。
+fn is_wide_char(ch: char) -> bool {
+    matches!(
+        ch as u32,
+        0x1100..=0x115F
+            | 0x2E80..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFE30..=0xFE4F
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6
+    )
+}
+
+fn pad(text: &str, width: usize, align_right: bool) -> String {
+    let fill = " ".repeat(width.saturating_sub(display_width(text)));
+    if align_right {
+        format!("{}{}", fill, text)
+    } else {
+        format!("{}{}", text, fill)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result_set(names: &[&str], rows: Vec<Vec<DbValue>>) -> ResultSet {
+        let columns: Arc<[ResultColumn]> = names
+            .iter()
+            .map(|name| ResultColumn {
+                name: name.to_string(),
+                type_info: DbTypeInfo {
+                    family: DbTypeFamily::Other,
+                    native_name: String::new(),
+                    nullable: None,
+                },
+            })
+            .collect();
+        let row_count = rows.len();
+        ResultSet {
+            columns,
+            cells: rows.into_iter().flatten().collect(),
+            row_count,
+            completeness: ResultCompleteness::Complete,
+        }
+    }
+
+    #[test]
+    fn table_output_aligns_columns_and_right_aligns_numbers() {
+        let result = result_set(
+            &["id", "name"],
+            vec![
+                vec![DbValue::Int(7), DbValue::Text("Alice".into())],
+                vec![DbValue::Int(1024), DbValue::Null],
+            ],
+        );
+
+        let text = render_outcomes(&[StatementOutcome::ResultSet(result)], OutputFormat::Table);
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], "id   | name");
+        assert_eq!(lines[1], "-----+------");
+        assert_eq!(lines[2], "   7 | Alice");
+        assert_eq!(lines[3], "1024 | NULL");
+        assert_eq!(lines[4], "(2 行)");
+    }
+
+    #[test]
+    fn csv_output_leaves_null_empty_and_skips_non_result_statements() {
+        let result = result_set(&["id", "note"], vec![vec![DbValue::Int(1), DbValue::Null]]);
+
+        let text = render_outcomes(
+            &[
+                StatementOutcome::AffectedRows { rows: 3 },
+                StatementOutcome::ResultSet(result),
+            ],
+            OutputFormat::Csv,
+        );
+
+        assert_eq!(text.trim_end(), "id,note\n1,");
+    }
+
+    #[test]
+    fn json_output_keeps_value_types() {
+        let result = result_set(
+            &["id", "active", "note"],
+            vec![vec![DbValue::Int(1), DbValue::Bool(true), DbValue::Null]],
+        );
+
+        let text = render_outcomes(
+            &[
+                StatementOutcome::ResultSet(result),
+                StatementOutcome::AffectedRows { rows: 2 },
+            ],
+            OutputFormat::Json,
+        );
+        let parsed: serde_json::Value = serde_json::from_str(&text).unwrap();
+
+        assert_eq!(parsed[0]["type"], "result_set");
+        assert_eq!(parsed[0]["rows"][0]["id"], 1);
+        assert_eq!(parsed[0]["rows"][0]["active"], true);
+        assert!(parsed[0]["rows"][0]["note"].is_null());
+        assert_eq!(parsed[1]["rows"], 2);
+    }
+}
@@ -0,0 +1,243 @@
+//! `gridix query` — 使用已保存的连接执行 SQL
+
+use super::output::{OutputFormat, outcome_summary, render_outcomes, write_stdout};
+use crate::core::{AppConfig, split_sql_script};
+use crate::data::{
+    ConnectionConfig, PinnedSession, TransactionControl, execute_typed, transaction_control,
+};
+use crate::domain::execution::StatementOutcome;
+use std::io::Read;
+use std::path::PathBuf;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+/// `gridix query` 参数
+#[derive(Debug, clap::Args)]
+#[command(group(
+    clap::ArgGroup::new("source")
+        .required(true)
+        .args(["execute", "file"]),
+))]
+pub struct QueryArgs {
+    /// 已保存的连接名称（与 GUI 中的连接名一致）
+    #[arg(short, long)]
+    pub connection: String,
+
+    /// 覆盖连接的默认数据库
+    #[arg(short, long)]
+    pub database: Option<String>,
+
+    /// 直接执行的 SQL 文本，可包含多条以 `;` 分隔的语句
+    #[arg(short = 'e', long = "execute", value_name = "SQL")]
+    pub execute: Option<String>,
+
+    /// 读取 SQL 的文件路径，`-` 表示标准输入
+    #[arg(short, long, value_name = "FILE")]
+    pub file: Option<PathBuf>,
+
+    /// 结果输出格式
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+}
+
+pub(super) fn run(args: QueryArgs) -> Result<(), String> {
+    let script = read_script(&args)?;
+    let statements = split_sql_script(&script);
+    if statements.is_empty() {
+        return Err("没有可执行的 SQL 语句".to_string());
+    }
+
+    let app_config = AppConfig::load();
+    let connection =
+        super::resolve_connection(&app_config, &args.connection, args.database.as_deref())?;
+
+    let runtime = super::build_runtime()?;
+    let (outcomes, failure) = runtime.block_on(execute_script(&connection, &statements));
+
+    print_outcomes(&outcomes, args.format)?;
+
+    match failure {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// 依次执行脚本语句
+///
+/// 语句默认走自动提交的 `execute_typed`，与逐条执行一致；遇到 `BEGIN` 时改用独占会话，
+/// 之后的语句在同一连接上执行，直到 `COMMIT` / `ROLLBACK` 后释放。语句失败或脚本结束时
+/// 仍未结束的事务会被回滚。
+async fn execute_script(
+    connection: &ConnectionConfig,
+    statements: &[String],
+) -> (Vec<StatementOutcome>, Option<String>) {
+    let cancellation = CancellationToken::new();
+    let mut outcomes = Vec::new();
+    let mut transaction: Option<(PinnedSession, JoinHandle<()>)> = None;
+    let mut failure = None;
+
+    for (index, statement) in statements.iter().enumerate() {
+        let control = transaction_control(statement);
+        if transaction.is_none() && control == Some(TransactionControl::Begin) {
+            let (session, driver) = PinnedSession::new(connection.clone());
+            transaction = Some((session, tokio::spawn(driver)));
+        }
+
+        let result = match &transaction {
+            Some((session, _)) => session.execute(statement, &cancellation).await,
+            None => execute_typed(connection, statement).await,
+        };
+        match result {
+            Ok(outcome) => outcomes.extend(outcome.statements),
+            Err(error) => {
+                failure = Some(if statements.len() > 1 {
+                    format!("第 {} 条语句执行失败: {}", index + 1, error)
+                } else {
+                    error.to_string()
+                });
+                break;
+            }
+        }
+
+        if matches!(
+            control,
+            Some(TransactionControl::Commit | TransactionControl::Rollback)
+        ) && let Some(pinned) = transaction.take()
+        {
+            close_session(pinned).await;
+        }
+    }
+
+    if let Some(pinned) = transaction.take() {
+        if failure.is_none() {
+            failure = Some("脚本结束时事务未提交，已回滚".to_string());
+        }
+        close_session(pinned).await;
+    }
+    (outcomes, failure)
+}
+
+/// 释放独占会话
+///
+/// 丢弃会话会回滚未提交的事务；等待后台任务退出，确保回滚在进程结束前完成。
+async fn close_session((session, driver): (PinnedSession, JoinHandle<()>)) {
+    drop(session);
+    let _ = driver.await;
+}
+
+fn read_script(args: &QueryArgs) -> Result<String, String> {
+    if let Some(sql) = &args.execute {
+        return Ok(sql.clone());
+    }
+
+    match args.file.as_deref() {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut content = String::new();
+            std::io::stdin()
+                .read_to_string(&mut content)
+                .map_err(|e| format!("读取标准输入失败: {}", e))?;
+            Ok(content)
+        }
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|e| format!("读取 SQL 文件 {} 失败: {}", path.display(), e)),
+        None => Err("请通过 -e 或 -f 指定要执行的 SQL".to_string()),
+    }
+}
+
+fn print_outcomes(outcomes: &[StatementOutcome], format: OutputFormat) -> Result<(), String> {
+    if outcomes.is_empty() {
+        return Ok(());
+    }
+
+    // CSV 只承载结果集数据，其余语句的摘要写到 stderr，保持 stdout 可被直接解析。
+    if format == OutputFormat::Csv {
+        for summary in outcomes.iter().filter_map(outcome_summary) {
+            eprintln!("{}", summary);
+        }
+    }
+
+    write_stdout(&render_outcomes(outcomes, format))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::DatabaseType;
+    use tempfile::NamedTempFile;
+
+    fn count_rows(path: &std::path::Path) -> i64 {
+        rusqlite::Connection::open(path)
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM t", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    async fn run_script(path: &std::path::Path, script: &str) -> Option<String> {
+        let config = ConnectionConfig {
+            db_type: DatabaseType::SQLite,
+            database: path.to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+        let (_, failure) = execute_script(&config, &split_sql_script(script)).await;
+        failure
+    }
+
+    #[tokio::test]
+    async fn execute_script_keeps_explicit_transaction_on_one_session() {
+        let file = NamedTempFile::new().unwrap();
+        rusqlite::Connection::open(file.path())
+            .unwrap()
+            .execute_batch("CREATE TABLE t (id INTEGER)")
+            .unwrap();
+
+        let failure = run_script(
+            file.path(),
+            "BEGIN; INSERT INTO t VALUES (1); ROLLBACK; INSERT INTO t VALUES (2);",
+        )
+        .await;
+        assert_eq!(failure, None);
+        assert_eq!(count_rows(file.path()), 1);
+
+        let failure = run_script(
+            file.path(),
+            "BEGIN; INSERT INTO t VALUES (3); INSERT INTO missing VALUES (1); COMMIT;",
+        )
+        .await;
+        assert!(failure.unwrap().starts_with("第 3 条语句执行失败"));
+        assert_eq!(count_rows(file.path()), 1);
+    }
+
+    #[tokio::test]
+    async fn execute_script_rolls_back_transaction_left_open() {
+        let file = NamedTempFile::new().unwrap();
+        rusqlite::Connection::open(file.path())
+            .unwrap()
+            .execute_batch("CREATE TABLE t (id INTEGER)")
+            .unwrap();
+
+        let failure = run_script(
+            file.path(),
+            "INSERT INTO t VALUES (1); BEGIN; INSERT INTO t VALUES (2);",
+        )
+        .await;
+        assert!(failure.is_some());
+        assert_eq!(count_rows(file.path()), 1);
+    }
+
+    #[tokio::test]
+    async fn execute_script_runs_statements_outside_transactions_in_autocommit() {
+        let file = NamedTempFile::new().unwrap();
+        rusqlite::Connection::open(file.path())
+            .unwrap()
+            .execute_batch("CREATE TABLE t (id INTEGER)")
+            .unwrap();
+
+        let failure = run_script(
+            file.path(),
+            "INSERT INTO t VALUES (1); DELETE FROM t; VACUUM; INSERT INTO t VALUES (2);",
+        )
+        .await;
+        assert_eq!(failure, None);
+        assert_eq!(count_rows(file.path()), 1);
+    }
+}
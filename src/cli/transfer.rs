@@ -0,0 +1,715 @@
+//! `gridix export` / `gridix import` — 基于统一传输管线的无界面导入导出
+//!
+//! 与 `ExportDialog` / `ImportDialog` 共用 `TransferSession` → plan → 执行的同一条路径，
+//! 命令行参数只负责组装会话。
+
+use crate::core::constants;
+use crate::core::{
+    AppConfig, ImportWriteMode, ImportWriteOptions, SheetHeader, SqlDialect,
+    TransferColumnarOptions, TransferDelimitedOptions, TransferDirection, TransferFormat,
+    TransferFormatOptions, TransferJsonOptions, TransferMapping, TransferRowWindow, TransferSchema,
+    TransferSession, TransferSpreadsheetOptions, TransferSqlOptions, import_source_columns,
+    open_streaming_export, plan_import_transfer,
+};
+use crate::data::{execute_import_stream, execute_streaming};
+use crate::domain::identifier::IdentifierDialect;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use tokio_util::sync::CancellationToken;
+
+/// 流式导出时在途批次上限（与写入速度形成背压）
+const STREAM_CHANNEL_CAPACITY: usize = 4;
+
+/// 传输文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TransferFormatArg {
+    Csv,
+    Tsv,
+    Json,
+    Ndjson,
+    Sql,
+    Xlsx,
+    Parquet,
+    Arrow,
+    Markdown,
+    Html,
+    Latex,
+}
+
+impl From<TransferFormatArg> for TransferFormat {
+    fn from(value: TransferFormatArg) -> Self {
+        match value {
+            TransferFormatArg::Csv => Self::Csv,
+            TransferFormatArg::Tsv => Self::Tsv,
+            TransferFormatArg::Json => Self::Json,
+            TransferFormatArg::Ndjson => Self::Ndjson,
+            TransferFormatArg::Sql => Self::Sql,
+            TransferFormatArg::Xlsx => Self::Xlsx,
+            TransferFormatArg::Parquet => Self::Parquet,
+            TransferFormatArg::Arrow => Self::Arrow,
+            TransferFormatArg::Markdown => Self::Markdown,
+            TransferFormatArg::Html => Self::Html,
+            TransferFormatArg::Latex => Self::Latex,
+        }
+    }
+}
+
+/// 导入写入方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ImportWriteModeArg {
+    /// 普通 INSERT
+    #[default]
+    Insert,
+    /// 跳过冲突行
+    Ignore,
+    /// 冲突时更新已有行
+    Upsert,
+    /// 冲突时替换已有行
+    Replace,
+    /// 先清空目标表
+    Truncate,
+}
+
+impl From<ImportWriteModeArg> for ImportWriteMode {
+    fn from(value: ImportWriteModeArg) -> Self {
+        match value {
+            ImportWriteModeArg::Insert => Self::Insert,
+            ImportWriteModeArg::Ignore => Self::InsertIgnore,
+            ImportWriteModeArg::Upsert => Self::Upsert,
+            ImportWriteModeArg::Replace => Self::Replace,
+            ImportWriteModeArg::Truncate => Self::TruncateInsert,
+        }
+    }
+}
+
+/// `gridix export` 参数
+#[derive(Debug, clap::Args)]
+#[command(group(
+    clap::ArgGroup::new("source")
+        .required(true)
+        .args(["table", "query"]),
+))]
+pub struct ExportArgs {
+    /// 已保存的连接名称
+    #[arg(short, long)]
+    pub connection: String,
+
+    /// 覆盖连接的默认数据库
+    #[arg(short, long)]
+    pub database: Option<String>,
+
+    /// 导出整张表（支持 schema.table）
+    #[arg(short, long)]
+    pub table: Option<String>,
+
+    /// 导出任意查询的结果
+    #[arg(short, long, value_name = "SQL")]
+    pub query: Option<String>,
+
+    /// 输出文件，`-` 表示标准输出
+    #[arg(short, long, value_name = "FILE")]
+    pub out: PathBuf,
+
+    /// 输出格式，省略时按输出文件扩展名推断
+    #[arg(long, value_enum)]
+    pub format: Option<TransferFormatArg>,
+
+    /// 只导出这些列（逗号分隔，按结果列名匹配）
+    #[arg(long, value_delimiter = ',', value_name = "COLUMNS")]
+    pub columns: Vec<String>,
+
+    /// 从第 N 行开始导出（0 起）
+    #[arg(long, default_value_t = 0)]
+    pub start_row: usize,
+
+    /// 最多导出的行数（0 = 不限制）
+    #[arg(long, default_value_t = 0)]
+    pub limit: usize,
+
+    /// CSV 分隔符
+    #[arg(long, default_value_t = ',')]
+    pub delimiter: char,
+
+    /// CSV/TSV 引用字符
+    #[arg(long, default_value_t = '"')]
+    pub quote: char,
+
+    /// CSV/TSV/Excel 不输出表头
+    #[arg(long)]
+    pub no_header: bool,
+
+    /// JSON 输出为紧凑格式
+    #[arg(long)]
+    pub compact: bool,
+
+    /// SQL 导出时生成的 INSERT 目标表名（默认取 --table，查询导出为 query_result）
+    #[arg(long, value_name = "NAME")]
+    pub target_table: Option<String>,
+
+    /// SQL 导出每条 INSERT 的行数（0 = 每行一条）
+    #[arg(long, default_value_t = 100)]
+    pub sql_batch_size: usize,
+
+    /// SQL 导出不包裹 BEGIN/COMMIT
+    #[arg(long)]
+    pub no_transaction: bool,
+
+    /// Parquet 每个行组的行数
+    #[arg(long, default_value_t = TransferColumnarOptions::default().row_group_rows)]
+    pub row_group_rows: usize,
+}
+
+/// `gridix import` 参数
+#[derive(Debug, clap::Args)]
+pub struct ImportArgs {
+    /// 已保存的连接名称
+    #[arg(short, long)]
+    pub connection: String,
+
+    /// 覆盖连接的默认数据库
+    #[arg(short, long)]
+    pub database: Option<String>,
+
+    /// 目标表（CSV/TSV/JSON/NDJSON/Excel 必填）
+    #[arg(short, long)]
+    pub table: Option<String>,
+
+    /// 要导入的文件
+    #[arg(short, long, value_name = "FILE")]
+    pub file: PathBuf,
+
+    /// 文件格式，省略时按扩展名推断
+    #[arg(long, value_enum)]
+    pub format: Option<TransferFormatArg>,
+
+    /// 列映射 `源列=目标列`，`源列=-` 表示跳过该列；可重复或以逗号分隔
+    #[arg(long, value_delimiter = ',', value_name = "SRC=DST")]
+    pub mapping: Vec<String>,
+
+    /// CSV 分隔符
+    #[arg(long, default_value_t = ',')]
+    pub delimiter: char,
+
+    /// CSV/TSV 引用字符
+    #[arg(long, default_value_t = '"')]
+    pub quote: char,
+
+    /// 文件没有表头行（源列名为 column_1、column_2 ...）
+    #[arg(long)]
+    pub no_header: bool,
+
+    /// Excel 工作表名（默认第一个）
+    #[arg(long, value_name = "NAME")]
+    pub sheet: Option<String>,
+
+    /// Excel 表头所在行（1 起，默认自动检测）
+    #[arg(long, value_name = "ROW", conflicts_with = "no_header", value_parser = clap::value_parser!(u32).range(1..))]
+    pub header_row: Option<u32>,
+
+    /// 跳过文件开头的 N 行
+    #[arg(long, default_value_t = 0)]
+    pub skip_rows: usize,
+
+    /// 最多导入的行数（0 = 不限制）
+    #[arg(long, default_value_t = 0)]
+    pub max_rows: usize,
+
+    /// JSON 数组所在路径（如 data.items）
+    #[arg(long, value_name = "PATH")]
+    pub json_path: Option<String>,
+
+    /// 展平嵌套 JSON 对象（a.b.c）
+    #[arg(long)]
+    pub flatten: bool,
+
+    /// 写入方式（结构化格式）
+    #[arg(long, value_enum, default_value_t = ImportWriteModeArg::Insert)]
+    pub mode: ImportWriteModeArg,
+
+    /// 冲突键列（目标列名，逗号分隔），upsert 在 PostgreSQL/SQLite 上必填
+    #[arg(long, value_delimiter = ',', value_name = "COLUMNS")]
+    pub conflict_key: Vec<String>,
+
+    /// 不使用事务逐条执行
+    #[arg(long)]
+    pub no_transaction: bool,
+
+    /// 遇到第一条错误即停止
+    #[arg(long)]
+    pub stop_on_error: bool,
+
+    /// 只打印将要执行的 SQL，不连接数据库
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+pub(super) fn run_export(args: ExportArgs) -> Result<(), String> {
+    let format = resolve_format(args.format, &args.out)?;
+    let app_config = AppConfig::load();
+    let connection =
+        super::resolve_connection(&app_config, &args.connection, args.database.as_deref())?;
+
+    let sql = match (&args.query, &args.table) {
+        (Some(query), _) => query.clone(),
+        (None, Some(table)) => format!(
+            "SELECT * FROM {}",
+            quote_qualified_name(IdentifierDialect::from_db_type(connection.db_type), table)
+        ),
+        (None, None) => return Err("请通过 --table 或 --query 指定导出来源".to_string()),
+    };
+
+    let target_name = args
+        .target_table
+        .clone()
+        .or_else(|| args.table.clone())
+        .unwrap_or_else(|| "query_result".to_string());
+
+    // 结果按批流入导出写入器，不在内存中保留完整结果集
+    let runtime = super::build_runtime()?;
+    let cancellation = CancellationToken::new();
+    let (sink, batches) = tokio::sync::mpsc::channel(STREAM_CHANNEL_CAPACITY);
+    let (streamed, exported) = runtime.block_on(async {
+        let producer = execute_streaming(
+            &connection,
+            &sql,
+            constants::database::STREAM_BATCH_ROWS,
+            sink,
+            &cancellation,
+        );
+        let consumer = async {
+            // 接收端随消费者一起释放，出错提前返回时后端也会停止拉取
+            let mut batches = batches;
+            let first = batches
+                .recv()
+                .await
+                .ok_or_else(|| "查询没有返回结果集".to_string())?;
+            let column_names: Vec<String> = first
+                .columns
+                .iter()
+                .map(|column| column.name.clone())
+                .collect();
+            let session = export_session(
+                &args,
+                format,
+                &column_names,
+                &target_name,
+                connection.db_type,
+            )?;
+            let mut exporter = open_streaming_export(open_export_writer(&args.out)?, &session)?;
+            let mut next = Some(first);
+            while let Some(batch) = next {
+                if !exporter.write_batch(batch)? {
+                    break;
+                }
+                next = batches.recv().await;
+            }
+            // 关闭接收端，让仍在拉取的后端尽快停止
+            batches.close();
+            for warning in exporter.warnings() {
+                eprintln!("警告: {}", warning);
+            }
+            exporter.finish()
+        };
+        tokio::join!(producer, consumer)
+    });
+
+    streamed.map_err(|e| format!("查询执行失败: {}", e))?;
+    let exported_rows = exported?;
+    if args.out.as_os_str() != "-" {
+        eprintln!("已导出 {} 行到 {}", exported_rows, args.out.display());
+    }
+
+    Ok(())
+}
+
+pub(super) fn run_import(args: ImportArgs) -> Result<(), String> {
+    let format = resolve_format(args.format, &args.file)?;
+    let app_config = AppConfig::load();
+    let connection =
+        super::resolve_connection(&app_config, &args.connection, args.database.as_deref())?;
+
+    let mut session = import_session(&args, format, connection.db_type);
+    if !args.mapping.is_empty() {
+        let columns = import_source_columns(&args.file, &session)?;
+        session.mapping = build_import_mapping(&columns, &args.mapping)?;
+    }
+
+    let plan = plan_import_transfer(&args.file, &session)?;
+    for warning in &plan.warnings {
+        eprintln!("警告: {}", warning);
+    }
+    if plan.statement_count == 0 {
+        return Err("没有有效的 SQL 语句".to_string());
+    }
+
+    if args.dry_run {
+        // 逐批写出，不展开完整语句列表
+        let mut stdout = BufWriter::new(std::io::stdout().lock());
+        let mut write_error = None;
+        plan.stream_sql_statements(constants::database::IMPORT_BATCH_STATEMENTS, |batch| {
+            let written = batch
+                .iter()
+                .try_for_each(|statement| writeln!(stdout, "{}", statement));
+            match written {
+                Ok(()) => true,
+                Err(e) => {
+                    write_error = Some(e);
+                    false
+                }
+            }
+        })?;
+        return match write_error {
+            Some(e) => Err(e),
+            None => stdout.flush(),
+        }
+        .map_err(|e| format!("写入输出失败: {}", e));
+    }
+
+    let runtime = super::build_runtime()?;
+    let report = runtime
+        .block_on(execute_import_stream(
+            &connection,
+            move |sink| {
+                plan.stream_sql_statements(constants::database::IMPORT_BATCH_STATEMENTS, sink)
+            },
+            !args.no_transaction,
+            args.stop_on_error,
+        ))
+        .map_err(|e| format!("导入执行失败: {}", e))?;
+
+    eprintln!(
+        "导入完成：共 {} 条语句，成功 {} 条，失败 {} 条",
+        report.total, report.succeeded, report.failed
+    );
+    match report.first_error {
+        Some(error) if report.failed > 0 => Err(format!("导入存在失败语句: {}", error)),
+        _ => Ok(()),
+    }
+}
+
+/// 打开导出目标，`-` 表示标准输出
+fn open_export_writer(out: &Path) -> Result<Box<dyn Write>, String> {
+    if out.as_os_str() == "-" {
+        return Ok(Box::new(BufWriter::new(std::io::stdout().lock())));
+    }
+    let file =
+        std::fs::File::create(out).map_err(|e| format!("写入 {} 失败: {}", out.display(), e))?;
+    Ok(Box::new(BufWriter::new(file)))
+}
+
+/// 显式格式优先，否则按文件扩展名推断
+fn resolve_format(
+    format: Option<TransferFormatArg>,
+    path: &Path,
+) -> Result<TransferFormat, String> {
+    if let Some(format) = format {
+        return Ok(format.into());
+    }
+
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("csv") => Ok(TransferFormat::Csv),
+        Some("tsv" | "tab") => Ok(TransferFormat::Tsv),
+        Some("json") => Ok(TransferFormat::Json),
+        Some("ndjson" | "jsonl") => Ok(TransferFormat::Ndjson),
+        Some("sql") => Ok(TransferFormat::Sql),
+        Some("xlsx" | "xlsm" | "xlsb" | "xls" | "ods") => Ok(TransferFormat::Xlsx),
+        Some("parquet") => Ok(TransferFormat::Parquet),
+        Some("arrow" | "arrows" | "feather" | "ipc") => Ok(TransferFormat::Arrow),
+        Some("md" | "markdown") => Ok(TransferFormat::Markdown),
+        Some("html" | "htm") => Ok(TransferFormat::Html),
+        Some("tex" | "latex") => Ok(TransferFormat::Latex),
+        _ => Err(format!(
+            "无法从 {} 推断格式，请使用 --format 指定",
+            path.display()
+        )),
+    }
+}
+
+/// 逐段引用 `schema.table` 形式的表名
+fn quote_qualified_name(dialect: IdentifierDialect, name: &str) -> String {
+    name.split('.')
+        .map(|part| dialect.quote(part))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// 与 `ExportConfig::to_transfer_session` 对应的命令行会话组装
+fn export_session(
+    args: &ExportArgs,
+    format: TransferFormat,
+    column_names: &[String],
+    target_name: &str,
+    db_type: crate::data::DatabaseType,
+) -> Result<TransferSession, String> {
+    let selected_columns = args
+        .columns
+        .iter()
+        .map(|name| {
+            column_names
+                .iter()
+                .position(|column| column == name)
+                .ok_or_else(|| {
+                    format!(
+                        "结果中不存在列 '{}'，可用列: {}",
+                        name,
+                        column_names.join(", ")
+                    )
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(TransferSession {
+        direction: TransferDirection::Export,
+        format,
+        schema: TransferSchema::from_columns(
+            args.table.clone(),
+            Some(target_name.to_string()),
+            column_names,
+            None,
+        ),
+        mapping: TransferMapping::from_selection(column_names, &selected_columns),
+        row_window: TransferRowWindow {
+            start_row: args.start_row,
+            row_limit: args.limit,
+            preview_rows: 10,
+        },
+        options: match format {
+            TransferFormat::Csv | TransferFormat::Tsv => {
+                TransferFormatOptions::Delimited(TransferDelimitedOptions {
+                    delimiter: args.delimiter,
+                    quote_char: args.quote,
+                    include_header: !args.no_header,
+                    ..Default::default()
+                })
+            }
+            TransferFormat::Sql => TransferFormatOptions::Sql(TransferSqlOptions {
+                use_transaction: !args.no_transaction,
+                batch_size: args.sql_batch_size,
+                dialect: SqlDialect::from(db_type),
+                ..Default::default()
+            }),
+            TransferFormat::Json | TransferFormat::Ndjson => {
+                TransferFormatOptions::Json(TransferJsonOptions {
+                    pretty: !args.compact,
+                    ..Default::default()
+                })
+            }
+            TransferFormat::Xlsx => {
+                TransferFormatOptions::Spreadsheet(TransferSpreadsheetOptions {
+                    include_header: !args.no_header,
+                    ..Default::default()
+                })
+            }
+            TransferFormat::Parquet | TransferFormat::Arrow => {
+                TransferFormatOptions::Columnar(TransferColumnarOptions {
+                    row_group_rows: args.row_group_rows.max(1),
+                })
+            }
+            // 文档表格始终带表头，没有额外选项
+            TransferFormat::Markdown | TransferFormat::Html | TransferFormat::Latex => {
+                TransferFormatOptions::Delimited(TransferDelimitedOptions::default())
+            }
+        },
+        write: Default::default(),
+    })
+}
+
+/// 与 `ImportState::to_transfer_session` 对应的命令行会话组装
+fn import_session(
+    args: &ImportArgs,
+    format: TransferFormat,
+    db_type: crate::data::DatabaseType,
+) -> TransferSession {
+    TransferSession {
+        direction: TransferDirection::Import,
+        format,
+        schema: TransferSchema {
+            source_name: Some(args.file.display().to_string()),
+            target_name: args.table.clone(),
+            fields: Vec::new(),
+            total_rows: None,
+        },
+        mapping: TransferMapping::default(),
+        row_window: TransferRowWindow {
+            preview_rows: 10,
+            ..Default::default()
+        },
+        options: match format {
+            TransferFormat::Sql => TransferFormatOptions::Sql(TransferSqlOptions {
+                use_transaction: !args.no_transaction,
+                batch_size: 0,
+                stop_on_error: args.stop_on_error,
+                dialect: SqlDialect::from(db_type),
+                ..Default::default()
+            }),
+            TransferFormat::Csv | TransferFormat::Tsv => {
+                TransferFormatOptions::Delimited(TransferDelimitedOptions {
+                    delimiter: args.delimiter,
+                    quote_char: args.quote,
+                    has_header: !args.no_header,
+                    skip_rows: args.skip_rows,
+                    max_rows: args.max_rows,
+                    include_header: false,
+                })
+            }
+            TransferFormat::Json | TransferFormat::Ndjson => {
+                TransferFormatOptions::Json(TransferJsonOptions {
+                    pretty: true,
+                    json_path: args
+                        .json_path
+                        .clone()
+                        .filter(|path| !path.trim().is_empty()),
+                    flatten_nested: args.flatten,
+                    max_rows: args.max_rows,
+                })
+            }
+            TransferFormat::Xlsx => {
+                TransferFormatOptions::Spreadsheet(TransferSpreadsheetOptions {
+                    include_header: false,
+                    sheet: args.sheet.clone(),
+                    header: if args.no_header {
+                        SheetHeader::None
+                    } else {
+                        args.header_row
+                            .map_or(SheetHeader::Detect, |row| SheetHeader::Row(row - 1))
+                    },
+                    max_rows: args.max_rows,
+                })
+            }
+            // 列式与文档表格格式仅支持导出，计划阶段会给出明确错误
+            TransferFormat::Parquet
+            | TransferFormat::Arrow
+            | TransferFormat::Markdown
+            | TransferFormat::Html
+            | TransferFormat::Latex => {
+                TransferFormatOptions::Columnar(TransferColumnarOptions::default())
+            }
+        },
+        write: ImportWriteOptions {
+            mode: args.mode.into(),
+            conflict_columns: args
+                .conflict_key
+                .iter()
+                .map(|column| column.trim().to_string())
+                .filter(|column| !column.is_empty())
+                .collect(),
+            db_type: Some(db_type),
+        },
+    }
+}
+
+/// 将 `源列=目标列` 规则应用到源列上；未提及的列按原名导入。
+fn build_import_mapping(columns: &[String], specs: &[String]) -> Result<TransferMapping, String> {
+    let mut mapping = TransferMapping::from_columns(columns);
+
+    for spec in specs
+        .iter()
+        .map(|spec| spec.trim())
+        .filter(|spec| !spec.is_empty())
+    {
+        let (source, target) = spec
+            .split_once('=')
+            .map(|(source, target)| (source.trim(), target.trim()))
+            .ok_or_else(|| format!("无效的列映射 '{}'，应为 源列=目标列", spec))?;
+        let field = mapping
+            .fields
+            .iter_mut()
+            .find(|field| columns[field.source_index] == source)
+            .ok_or_else(|| {
+                format!(
+                    "映射中的源列 '{}' 不存在，可用列: {}",
+                    source,
+                    columns.join(", ")
+                )
+            })?;
+
+        if target.is_empty() || target == "-" {
+            field.included = false;
+        } else {
+            field.target_name = target.to_string();
+            field.included = true;
+        }
+    }
+
+    if mapping.selected_count() == 0 {
+        return Err("列映射跳过了所有列".to_string());
+    }
+    Ok(mapping)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn resolve_format_prefers_explicit_flag_then_extension() {
+        assert_eq!(
+            resolve_format(Some(TransferFormatArg::Json), Path::new("out.csv")).unwrap(),
+            TransferFormat::Json
+        );
+        assert_eq!(
+            resolve_format(None, Path::new("dump.TSV")).unwrap(),
+            TransferFormat::Tsv
+        );
+        assert_eq!(
+            resolve_format(None, Path::new("report.xlsx")).unwrap(),
+            TransferFormat::Xlsx
+        );
+        assert_eq!(
+            resolve_format(None, Path::new("events.feather")).unwrap(),
+            TransferFormat::Arrow
+        );
+        assert_eq!(
+            resolve_format(None, Path::new("events.jsonl")).unwrap(),
+            TransferFormat::Ndjson
+        );
+        assert_eq!(
+            resolve_format(None, Path::new("report.htm")).unwrap(),
+            TransferFormat::Html
+        );
+        assert!(resolve_format(None, Path::new("-")).is_err());
+    }
+
+    #[test]
+    fn import_mapping_renames_and_skips_columns() {
+        let mapping = build_import_mapping(
+            &columns(&["id", "name", "age"]),
+            &["name=full_name".to_string(), "age=-".to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(mapping.selected_indices(), vec![0, 1]);
+        assert_eq!(mapping.target_columns(), vec!["id", "full_name"]);
+    }
+
+    #[test]
+    fn import_mapping_rejects_unknown_or_malformed_rules() {
+        let source = columns(&["id", "name"]);
+
+        let unknown = build_import_mapping(&source, &["email=mail".to_string()]).unwrap_err();
+        assert!(unknown.contains("id, name"));
+        assert!(build_import_mapping(&source, &["name".to_string()]).is_err());
+        assert!(
+            build_import_mapping(&source, &["id=-".to_string(), "name=-".to_string()]).is_err()
+        );
+    }
+
+    #[test]
+    fn quote_qualified_name_quotes_each_segment() {
+        assert_eq!(
+            quote_qualified_name(
+                IdentifierDialect::from_db_type(crate::data::DatabaseType::PostgreSQL),
+                "public.users"
+            ),
+            "\"public\".\"users\""
+        );
+    }
+}
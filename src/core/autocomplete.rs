@@ -221,6 +221,8 @@ pub struct AutoComplete {
     tables: Vec<String>,
     /// 表的列信息 (table_name -> columns)
     columns: std::collections::HashMap<String, Vec<String>>,
+    /// 默认 schema（PostgreSQL）；该 schema 下的表名不带限定前缀
+    default_schema: Option<String>,
 }
 
 impl Default for AutoComplete {
@@ -234,6 +236,7 @@ impl AutoComplete {
         Self {
             tables: Vec::new(),
             columns: std::collections::HashMap::new(),
+            default_schema: None,
         }
     }
 
@@ -259,10 +262,16 @@ impl AutoComplete {
         self.columns.insert(table, limited_columns);
     }
 
+    /// 设置默认 schema，输入 `默认schema.` 时补全未限定的表名
+    pub fn set_default_schema(&mut self, schema: Option<String>) {
+        self.default_schema = schema;
+    }
+
     /// 清空所有信息
     pub fn clear(&mut self) {
         self.tables.clear();
         self.columns.clear();
+        self.default_schema = None;
     }
 
     /// 从 SchemaCatalog 批量填充表名和列名（Phase 6）
@@ -273,6 +282,7 @@ impl AutoComplete {
             .take(consts::MAX_CACHED_TABLES)
             .map(String::from)
             .collect();
+        self.default_schema = catalog.default_schema.clone();
         self.columns.clear();
         for table in &self.tables {
             let cols: Vec<String> = catalog
@@ -300,7 +310,10 @@ impl AutoComplete {
         let mut completions = Vec::new();
 
         let upper_text = text_before_cursor.to_uppercase();
-        if has_prefix {
+        let before_word = &text_before_cursor[..text_before_cursor.len() - current_word.len()];
+        if let Some(schema) = self.schema_qualifier(before_word) {
+            self.extend_schema_table_completions(&schema, &prefix, &mut completions);
+        } else if has_prefix {
             self.extend_keyword_completions(&prefix, &mut completions);
             self.extend_function_completions(&prefix, &mut completions);
             self.extend_table_completions(&prefix, &mut completions);
@@ -380,7 +393,11 @@ impl AutoComplete {
 
     fn extend_table_completions(&self, prefix: &str, completions: &mut Vec<CompletionItem>) {
         for table in &self.tables {
-            if table.to_uppercase().starts_with(prefix) {
+            // `schema.table` 既可按完整名称匹配，也可只按表名部分匹配
+            let bare = table
+                .rsplit_once('.')
+                .map_or(table.as_str(), |(_, name)| name);
+            if table.to_uppercase().starts_with(prefix) || bare.to_uppercase().starts_with(prefix) {
                 completions.push(CompletionItem {
                     label: table.clone(),
                     insert_text: table.clone(),
@@ -391,6 +408,56 @@ impl AutoComplete {
         }
     }
 
+    /// 光标前紧邻 `schema.` 且该 schema 已知时返回 schema 名
+    ///
+    /// 未知限定符（如表别名 `t.`）返回 `None`，沿用普通补全。
+    fn schema_qualifier(&self, before_word: &str) -> Option<String> {
+        let qualifier_text = before_word.strip_suffix('.')?;
+        let qualifier = self.get_current_word(qualifier_text);
+        if qualifier.is_empty() {
+            return None;
+        }
+        let is_default = self
+            .default_schema
+            .as_deref()
+            .is_some_and(|schema| schema.eq_ignore_ascii_case(&qualifier));
+        let is_known = is_default
+            || self.tables.iter().any(|table| {
+                table
+                    .split_once('.')
+                    .is_some_and(|(schema, _)| schema.eq_ignore_ascii_case(&qualifier))
+            });
+        is_known.then_some(qualifier)
+    }
+
+    /// `schema.` 之后补全该 schema 下的表，只插入表名部分
+    fn extend_schema_table_completions(
+        &self,
+        schema: &str,
+        prefix: &str,
+        completions: &mut Vec<CompletionItem>,
+    ) {
+        let is_default = self
+            .default_schema
+            .as_deref()
+            .is_some_and(|default| default.eq_ignore_ascii_case(schema));
+        for table in &self.tables {
+            let bare = match table.split_once('.') {
+                Some((table_schema, name)) if table_schema.eq_ignore_ascii_case(schema) => name,
+                None if is_default => table.as_str(),
+                _ => continue,
+            };
+            if bare.to_uppercase().starts_with(prefix) {
+                completions.push(CompletionItem {
+                    label: bare.to_string(),
+                    insert_text: bare.to_string(),
+                    kind: CompletionKind::Table,
+                    detail: Some(format!("数据表 ({})", schema)),
+                });
+            }
+        }
+    }
+
     fn extend_column_completions(&self, prefix: &str, completions: &mut Vec<CompletionItem>) {
         for (table, cols) in &self.columns {
             for col in cols {
@@ -0,0 +1,644 @@
+//! Parquet / Arrow IPC 列式导出
+//!
+//! 列类型由 `DbTypeInfo.family` 决定，供 DuckDB、pandas 等分析工具直接读取，
+//! 不再经过 CSV 丢失类型与精度：整数写为 Int64（MySQL UNSIGNED 为 UInt64），
+//! 声明了精度的 DECIMAL 写为 Decimal128（未声明精度的写为文本），日期时间写为 Date32 / Timestamp，
+//! JSON 写为带 `arrow.json` 扩展标记的 Utf8。
+//!
+//! 列结构在收到第一批行时确定，之后每批转换为一个 `RecordBatch` 写出，
+//! 已编码的字节随即交给底层写入器，内存中只保留当前的 Parquet 行组。
+
+use super::export::ExportFormat;
+use crate::domain::result::ResultSet;
+use crate::domain::value::{DbDate, DbTime, DbTypeFamily, DbTypeInfo, DbValue};
+use arrow_array::builder::{
+    BinaryBuilder, BooleanBuilder, Date32Builder, Decimal128Builder, Float64Builder, Int64Builder,
+    StringBuilder, Time64MicrosecondBuilder, TimestampMicrosecondBuilder, UInt64Builder,
+};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_ipc::writer::FileWriter;
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::{Compression, ZstdLevel};
+use parquet::file::properties::WriterProperties;
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::Arc;
+
+/// Parquet 默认行组大小
+pub(crate) const DEFAULT_ROW_GROUP_ROWS: usize = 100_000;
+/// Decimal128 可表示的最大精度
+const MAX_DECIMAL128_PRECISION: u8 = 38;
+
+/// 列在 Arrow 中的存储方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColumnKind {
+    Boolean,
+    Int64,
+    UInt64,
+    Float64,
+    Decimal { precision: u8, scale: i8 },
+    Date32,
+    Time64,
+    Timestamp,
+    Utf8,
+    Json,
+    Binary,
+}
+
+impl ColumnKind {
+    fn data_type(self) -> DataType {
+        match self {
+            Self::Boolean => DataType::Boolean,
+            Self::Int64 => DataType::Int64,
+            Self::UInt64 => DataType::UInt64,
+            Self::Float64 => DataType::Float64,
+            Self::Decimal { precision, scale } => DataType::Decimal128(precision, scale),
+            Self::Date32 => DataType::Date32,
+            Self::Time64 => DataType::Time64(TimeUnit::Microsecond),
+            Self::Timestamp => DataType::Timestamp(TimeUnit::Microsecond, None),
+            Self::Utf8 | Self::Json => DataType::Utf8,
+            Self::Binary => DataType::Binary,
+        }
+    }
+
+    fn field(self, name: &str) -> Field {
+        let field = Field::new(name, self.data_type(), true);
+        if self == Self::Json {
+            // Arrow 规范扩展类型，DuckDB / pyarrow 据此识别 JSON 列
+            field.with_metadata(HashMap::from([(
+                "ARROW:extension:name".to_string(),
+                "arrow.json".to_string(),
+            )]))
+        } else {
+            field
+        }
+    }
+}
+
+/// 从原生类型名中解析 `(precision, scale)`，如 `numeric(12,2)`
+fn declared_precision(native_name: &str) -> Option<(u32, i8)> {
+    let inner = native_name.split_once('(')?.1.split_once(')')?.0;
+    let mut parts = inner.split(',').map(str::trim);
+    let precision = parts.next()?.parse().ok()?;
+    let scale = parts.next().map_or(Some(0), |scale| scale.parse().ok())?;
+    Some((precision, scale))
+}
+
+/// 按列类型族选择 Arrow 类型
+///
+/// 列结构在第一批行时就固定，未声明精度的 DECIMAL（如 PostgreSQL 的 `numeric`）
+/// 无法预知后续行的小数位，也可能出现 `NaN`，因此写为文本。
+fn column_kind(name: &str, type_info: &DbTypeInfo, warnings: &mut Vec<String>) -> ColumnKind {
+    match type_info.family {
+        DbTypeFamily::Bool => ColumnKind::Boolean,
+        DbTypeFamily::Integer => {
+            if type_info
+                .native_name
+                .to_ascii_lowercase()
+                .contains("unsigned")
+            {
+                ColumnKind::UInt64
+            } else {
+                ColumnKind::Int64
+            }
+        }
+        DbTypeFamily::Float => ColumnKind::Float64,
+        DbTypeFamily::Decimal => match declared_precision(&type_info.native_name) {
+            Some((precision, scale))
+                if precision <= u32::from(MAX_DECIMAL128_PRECISION)
+                    && (0..=precision as i8).contains(&scale) =>
+            {
+                ColumnKind::Decimal {
+                    precision: precision.max(1) as u8,
+                    scale,
+                }
+            }
+            Some((precision, _)) => {
+                warnings.push(format!(
+                    "列 {} 的精度 {} 超过 Decimal128 上限 ({})，已写为文本",
+                    name, precision, MAX_DECIMAL128_PRECISION
+                ));
+                ColumnKind::Utf8
+            }
+            None => {
+                warnings.push(format!(
+                    "列 {} 未声明精度，已写为文本以保留全部小数位",
+                    name
+                ));
+                ColumnKind::Utf8
+            }
+        },
+        DbTypeFamily::Date => ColumnKind::Date32,
+        DbTypeFamily::Time => ColumnKind::Time64,
+        DbTypeFamily::DateTime => ColumnKind::Timestamp,
+        DbTypeFamily::Json => ColumnKind::Json,
+        DbTypeFamily::Bytes => ColumnKind::Binary,
+        DbTypeFamily::Text
+        | DbTypeFamily::Uuid
+        | DbTypeFamily::Array
+        | DbTypeFamily::Null
+        | DbTypeFamily::Other => ColumnKind::Utf8,
+    }
+}
+
+/// 把小数文本按 `scale` 转为 Decimal128 的整数表示；超出精度或需要舍入时返回 None
+fn parse_decimal(text: &str, precision: u8, scale: i8) -> Option<i128> {
+    let text = text.trim();
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+    if int_part.is_empty() && frac_part.is_empty()
+        || !int_part
+            .chars()
+            .chain(frac_part.chars())
+            .all(|ch| ch.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let scale = scale.max(0) as usize;
+    let (kept, dropped) = frac_part.split_at(frac_part.len().min(scale));
+    if dropped.chars().any(|ch| ch != '0') {
+        return None;
+    }
+    let unscaled = format!(
+        "{}{}{:0<width$}",
+        int_part,
+        kept,
+        "",
+        width = scale - kept.len()
+    );
+    let unscaled = unscaled.trim_start_matches('0');
+    if unscaled.len() > usize::from(precision) {
+        return None;
+    }
+    let value: i128 = if unscaled.is_empty() {
+        0
+    } else {
+        unscaled.parse().ok()?
+    };
+    Some(if negative { -value } else { value })
+}
+
+fn days_since_epoch(date: &DbDate) -> Option<i32> {
+    let date = chrono::NaiveDate::from_ymd_opt(date.year, date.month.into(), date.day.into())?;
+    let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1)?;
+    i32::try_from(date.signed_duration_since(epoch).num_days()).ok()
+}
+
+fn time_micros(time: &DbTime) -> i64 {
+    (i64::from(time.hour) * 3600 + i64::from(time.minute) * 60 + i64::from(time.second)) * 1_000_000
+        + i64::from(time.nanos / 1_000)
+}
+
+fn timestamp_micros(date: &DbDate, time: &DbTime) -> Option<i64> {
+    i64::from(days_since_epoch(date)?)
+        .checked_mul(86_400_000_000)?
+        .checked_add(time_micros(time))
+}
+
+/// 类型不符的值先按显示文本解析（如 SQLite 动态类型列中的数字文本）
+fn parsed<T: std::str::FromStr>(value: &DbValue) -> Option<T> {
+    value.display().trim().parse().ok()
+}
+
+fn unconvertible(name: &str, row_idx: usize, value: &DbValue, kind: ColumnKind) -> String {
+    format!(
+        "第 {} 行列 {} 的值 '{}' 无法写为 {}",
+        row_idx + 1,
+        name,
+        value.display(),
+        kind.data_type()
+    )
+}
+
+/// 按列类型把一列值写入 Arrow 数组
+fn build_column(
+    name: &str,
+    kind: ColumnKind,
+    result: &ResultSet,
+    col_idx: usize,
+) -> Result<ArrayRef, String> {
+    let rows = result.row_count;
+    let values = (0..rows).map(|row_idx| (row_idx, result.cell(row_idx, col_idx)));
+
+    // 每种类型：NULL 追加空值，其余值转换失败时报告行号与原值
+    macro_rules! build {
+        ($builder:expr, |$value:ident| $convert:expr) => {{
+            let mut builder = $builder;
+            for (row_idx, $value) in values {
+                if matches!($value, DbValue::Null) {
+                    builder.append_null();
+                } else {
+                    let converted =
+                        $convert.ok_or_else(|| unconvertible(name, row_idx, $value, kind))?;
+                    builder.append_value(converted);
+                }
+            }
+            Arc::new(builder.finish()) as ArrayRef
+        }};
+    }
+
+    Ok(match kind {
+        ColumnKind::Boolean => build!(BooleanBuilder::with_capacity(rows), |value| match value {
+            DbValue::Bool(value) => Some(*value),
+            DbValue::Int(value) if matches!(value, 0 | 1) => Some(*value == 1),
+            _ => parsed(value),
+        }),
+        ColumnKind::Int64 => build!(Int64Builder::with_capacity(rows), |value| match value {
+            DbValue::Int(value) => Some(*value),
+            DbValue::UInt(value) => i64::try_from(*value).ok(),
+            DbValue::Bool(value) => Some(i64::from(*value)),
+            _ => parsed(value),
+        }),
+        ColumnKind::UInt64 => build!(UInt64Builder::with_capacity(rows), |value| match value {
+            DbValue::UInt(value) => Some(*value),
+            DbValue::Int(value) => u64::try_from(*value).ok(),
+            _ => parsed(value),
+        }),
+        ColumnKind::Float64 => build!(Float64Builder::with_capacity(rows), |value| match value {
+            DbValue::Float(value) => Some(*value),
+            DbValue::Int(value) => Some(*value as f64),
+            DbValue::UInt(value) => Some(*value as f64),
+            _ => parsed(value),
+        }),
+        ColumnKind::Decimal { precision, scale } => {
+            let builder = Decimal128Builder::with_capacity(rows)
+                .with_precision_and_scale(precision, scale)
+                .map_err(|e| e.to_string())?;
+            build!(builder, |value| parse_decimal(
+                &value.display(),
+                precision,
+                scale
+            ))
+        }
+        ColumnKind::Date32 => build!(Date32Builder::with_capacity(rows), |value| match value {
+            DbValue::Date(date) => days_since_epoch(date),
+            _ => None,
+        }),
+        ColumnKind::Time64 => {
+            build!(
+                Time64MicrosecondBuilder::with_capacity(rows),
+                |value| match value {
+                    DbValue::Time(time) => Some(time_micros(time)),
+                    _ => None,
+                }
+            )
+        }
+        ColumnKind::Timestamp => build!(
+            TimestampMicrosecondBuilder::with_capacity(rows),
+            |value| match value {
+                DbValue::DateTime(datetime) => timestamp_micros(&datetime.date, &datetime.time),
+                DbValue::Date(date) => timestamp_micros(
+                    date,
+                    &DbTime {
+                        hour: 0,
+                        minute: 0,
+                        second: 0,
+                        nanos: 0,
+                    }
+                ),
+                _ => None,
+            }
+        ),
+        ColumnKind::Utf8 => build!(StringBuilder::new(), |value| Some(value.display())),
+        ColumnKind::Json => build!(StringBuilder::new(), |value| match value {
+            DbValue::Json(json) => Some(json.to_string()),
+            _ => Some(value.display()),
+        }),
+        ColumnKind::Binary => build!(BinaryBuilder::new(), |value| match value {
+            DbValue::Bytes(bytes) => Some(bytes.to_vec()),
+            _ => Some(value.display().into_bytes()),
+        }),
+    })
+}
+
+enum ColumnarSink {
+    Parquet(ArrowWriter<Vec<u8>>),
+    Ipc(FileWriter<Vec<u8>>),
+}
+
+/// 逐批写出 Parquet / Arrow IPC 文件
+///
+/// 供一次性导出与流式导出共用。编码后的字节先写入内部缓冲，每批之后转交给调用方的写入器。
+pub(crate) struct ColumnarWriter {
+    schema: SchemaRef,
+    kinds: Vec<ColumnKind>,
+    sink: ColumnarSink,
+    warnings: Vec<String>,
+}
+
+impl ColumnarWriter {
+    /// 按（已投影的）第一批行确定列结构
+    pub(crate) fn new(
+        format: ExportFormat,
+        first_batch: &ResultSet,
+        row_group_rows: usize,
+    ) -> Result<Self, String> {
+        let mut warnings = Vec::new();
+        let kinds: Vec<ColumnKind> = first_batch
+            .columns
+            .iter()
+            .map(|column| column_kind(&column.name, &column.type_info, &mut warnings))
+            .collect();
+        let schema = Arc::new(Schema::new(
+            first_batch
+                .columns
+                .iter()
+                .zip(&kinds)
+                .map(|(column, kind)| kind.field(&column.name))
+                .collect::<Vec<_>>(),
+        ));
+
+        let sink = match format {
+            ExportFormat::Parquet => {
+                let properties = WriterProperties::builder()
+                    .set_compression(Compression::ZSTD(ZstdLevel::default()))
+                    .set_max_row_group_size(row_group_rows.max(1))
+                    .build();
+                ColumnarSink::Parquet(
+                    ArrowWriter::try_new(Vec::new(), Arc::clone(&schema), Some(properties))
+                        .map_err(|e| format!("创建 Parquet 写入器失败: {}", e))?,
+                )
+            }
+            ExportFormat::ArrowIpc => ColumnarSink::Ipc(
+                FileWriter::try_new(Vec::new(), &schema)
+                    .map_err(|e| format!("创建 Arrow IPC 写入器失败: {}", e))?,
+            ),
+            other => return Err(format!("{} 不是列式格式", other.display_name())),
+        };
+
+        Ok(Self {
+            schema,
+            kinds,
+            sink,
+            warnings,
+        })
+    }
+
+    /// 列结构推断时产生的提示
+    pub(crate) fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// 把一批行转换为 `RecordBatch` 写出，并把已编码的字节交给 `out`
+    pub(crate) fn write_batch(
+        &mut self,
+        result: &ResultSet,
+        out: &mut impl Write,
+    ) -> Result<(), String> {
+        if result.row_count == 0 {
+            return Ok(());
+        }
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .zip(&self.kinds)
+            .enumerate()
+            .map(|(col_idx, (field, kind))| build_column(field.name(), *kind, result, col_idx))
+            .collect::<Result<Vec<_>, _>>()?;
+        let batch =
+            RecordBatch::try_new(Arc::clone(&self.schema), columns).map_err(|e| e.to_string())?;
+
+        let buffer = match &mut self.sink {
+            ColumnarSink::Parquet(writer) => {
+                writer.write(&batch).map_err(|e| e.to_string())?;
+                writer.inner_mut()
+            }
+            ColumnarSink::Ipc(writer) => {
+                writer.write(&batch).map_err(|e| e.to_string())?;
+                writer.get_mut()
+            }
+        };
+        out.write_all(buffer).map_err(|e| e.to_string())?;
+        buffer.clear();
+        Ok(())
+    }
+
+    /// 写出剩余的行组与文件尾
+    pub(crate) fn finish(self, out: &mut impl Write) -> Result<(), String> {
+        let tail = match self.sink {
+            ColumnarSink::Parquet(writer) => writer.into_inner().map_err(|e| e.to_string())?,
+            ColumnarSink::Ipc(mut writer) => {
+                writer.finish().map_err(|e| e.to_string())?;
+                writer.into_inner().map_err(|e| e.to_string())?
+            }
+        };
+        out.write_all(&tail).map_err(|e| e.to_string())
+    }
+}
+
+/// 把（已按导出选项筛选的）结果集写成列式文件内容，同时返回列结构提示
+pub(crate) fn render_columnar(
+    result: &ResultSet,
+    format: ExportFormat,
+    row_group_rows: usize,
+) -> Result<(Vec<u8>, Vec<String>), String> {
+    if result.columns.is_empty() {
+        return Err("未选择任何列".to_string());
+    }
+
+    let mut writer = ColumnarWriter::new(format, result, row_group_rows)?;
+    let warnings = writer.warnings().to_vec();
+    let mut bytes = Vec::new();
+    writer.write_batch(result, &mut bytes)?;
+    writer.finish(&mut bytes)?;
+    Ok((bytes, warnings))
+}
+
+/// 导出预览：列式文件不可读，改为列出每列写出的 Arrow 类型
+pub(crate) fn preview_columnar_schema(result: &ResultSet, format: ExportFormat) -> String {
+    let mut warnings = Vec::new();
+    let mut lines = vec![format!("{} 列结构:", format.display_name())];
+    for column in result.columns.iter() {
+        let kind = column_kind(&column.name, &column.type_info, &mut warnings);
+        let json_note = if kind == ColumnKind::Json {
+            " (JSON)"
+        } else {
+            ""
+        };
+        lines.push(format!(
+            "  {}: {}{}",
+            column.name,
+            kind.data_type(),
+            json_note
+        ));
+    }
+    lines.extend(
+        warnings
+            .into_iter()
+            .map(|warning| format!("提示: {}", warning)),
+    );
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::result::{ResultColumn, ResultCompleteness};
+    use crate::domain::value::DbDateTime;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Date32Type, Decimal128Type, Int64Type, TimestampMicrosecondType};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    fn column(name: &str, family: DbTypeFamily, native_name: &str) -> ResultColumn {
+        ResultColumn {
+            name: name.to_string(),
+            type_info: DbTypeInfo {
+                family,
+                native_name: native_name.to_string(),
+                nullable: None,
+            },
+        }
+    }
+
+    fn sample_result() -> ResultSet {
+        let date = DbDate {
+            year: 2024,
+            month: 2,
+            day: 29,
+        };
+        ResultSet {
+            columns: Arc::from(vec![
+                column("id", DbTypeFamily::Integer, "bigint"),
+                column("price", DbTypeFamily::Decimal, "numeric(20,4)"),
+                column("day", DbTypeFamily::Date, "date"),
+                column("at", DbTypeFamily::DateTime, "timestamp"),
+                column("meta", DbTypeFamily::Json, "jsonb"),
+            ]),
+            cells: vec![
+                DbValue::Int(1),
+                DbValue::Decimal("12345678901234.5678".into()),
+                DbValue::Date(date),
+                DbValue::DateTime(DbDateTime {
+                    date,
+                    time: DbTime {
+                        hour: 13,
+                        minute: 5,
+                        second: 9,
+                        nanos: 250_000_000,
+                    },
+                }),
+                DbValue::Json(serde_json::json!({"tags": ["a"]})),
+                DbValue::Null,
+                DbValue::Decimal("-0.5".into()),
+                DbValue::Null,
+                DbValue::Null,
+                DbValue::Null,
+            ],
+            row_count: 2,
+            completeness: ResultCompleteness::Complete,
+        }
+    }
+
+    #[test]
+    fn parquet_export_keeps_column_types_and_decimal_precision() {
+        let (bytes, warnings) =
+            render_columnar(&sample_result(), ExportFormat::Parquet, 1).expect("render");
+        assert!(warnings.is_empty());
+
+        let mut file = tempfile::tempfile().expect("temp file");
+        file.write_all(&bytes).expect("write parquet");
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file).expect("reader");
+        // 行组大小为 1，两行分属两个行组
+        assert_eq!(builder.metadata().num_row_groups(), 2);
+        let batches: Vec<RecordBatch> = builder
+            .with_batch_size(1)
+            .build()
+            .expect("build")
+            .map(|batch| batch.expect("batch"))
+            .collect();
+        assert_eq!(batches.len(), 2);
+        let first = &batches[0];
+        let schema = first.schema();
+        assert_eq!(schema.field(1).data_type(), &DataType::Decimal128(20, 4));
+        assert_eq!(
+            schema.field(3).data_type(),
+            &DataType::Timestamp(TimeUnit::Microsecond, None)
+        );
+        assert_eq!(
+            schema.field(4).metadata().get("ARROW:extension:name"),
+            Some(&"arrow.json".to_string())
+        );
+
+        assert_eq!(first.column(0).as_primitive::<Int64Type>().value(0), 1);
+        assert_eq!(
+            first.column(1).as_primitive::<Decimal128Type>().value(0),
+            123_456_789_012_345_678
+        );
+        assert_eq!(
+            first.column(2).as_primitive::<Date32Type>().value(0),
+            19_782
+        );
+        assert_eq!(
+            first
+                .column(3)
+                .as_primitive::<TimestampMicrosecondType>()
+                .value(0),
+            1_709_211_909_250_000
+        );
+        assert_eq!(
+            first.column(4).as_string::<i32>().value(0),
+            r#"{"tags":["a"]}"#
+        );
+
+        let second = &batches[1];
+        assert!(second.column(0).is_null(0));
+        assert_eq!(
+            second.column(1).as_primitive::<Decimal128Type>().value(0),
+            -5_000
+        );
+    }
+
+    #[test]
+    fn ipc_export_streams_batches_and_rejects_lossy_decimals() {
+        let result = sample_result();
+        let mut writer = ColumnarWriter::new(ExportFormat::ArrowIpc, &result, 0).expect("writer");
+        let mut bytes = Vec::new();
+        writer.write_batch(&result, &mut bytes).expect("first");
+        writer.write_batch(&result, &mut bytes).expect("second");
+        writer.finish(&mut bytes).expect("finish");
+
+        let reader =
+            arrow_ipc::reader::FileReader::try_new(std::io::Cursor::new(bytes), None).expect("ipc");
+        let rows: usize = reader.map(|batch| batch.expect("batch").num_rows()).sum();
+        assert_eq!(rows, 4);
+
+        assert_eq!(parse_decimal("1.2300", 5, 2), Some(123));
+        assert_eq!(parse_decimal("1.235", 5, 2), None);
+        assert_eq!(parse_decimal("123456", 5, 0), None);
+        let mut lossy = sample_result();
+        lossy.cells[1] = DbValue::Decimal("1.23456".into());
+        let error = render_columnar(&lossy, ExportFormat::ArrowIpc, 0).unwrap_err();
+        assert!(error.contains("第 1 行列 price"));
+    }
+
+    #[test]
+    fn undeclared_decimal_is_written_as_text_across_batches() {
+        let batch = |value: &str| ResultSet {
+            columns: Arc::from(vec![column("amount", DbTypeFamily::Decimal, "numeric")]),
+            cells: vec![DbValue::Decimal(value.into())],
+            row_count: 1,
+            completeness: ResultCompleteness::Complete,
+        };
+        let first = batch("1.5");
+        let mut writer = ColumnarWriter::new(ExportFormat::ArrowIpc, &first, 0).expect("writer");
+        assert!(writer.warnings().iter().any(|w| w.contains("amount")));
+        let mut bytes = Vec::new();
+        writer.write_batch(&first, &mut bytes).expect("first");
+        // 后续批次的小数位更多，或是 PostgreSQL 的 NaN，都不应中断导出
+        writer
+            .write_batch(&batch("0.000123"), &mut bytes)
+            .expect("more digits");
+        writer.write_batch(&batch("NaN"), &mut bytes).expect("nan");
+        writer.finish(&mut bytes).expect("finish");
+
+        let reader =
+            arrow_ipc::reader::FileReader::try_new(std::io::Cursor::new(bytes), None).expect("ipc");
+        assert_eq!(reader.schema().field(0).data_type(), &DataType::Utf8);
+    }
+}
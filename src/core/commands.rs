@@ -725,6 +725,30 @@ pub(crate) const SCOPED_COMMANDS: &[ScopedCommand] = &[
         category: "筛选",
         default_bindings: &[bind(KeyCode::S, KeyModifiers::NONE)],
     },
+    ScopedCommand {
+        id: "sidebar.filters.group_indent",
+        description: "筛选条件缩进到分组",
+        category: "筛选",
+        default_bindings: &[bind(KeyCode::Period, KeyModifiers::SHIFT)],
+    },
+    ScopedCommand {
+        id: "sidebar.filters.group_outdent",
+        description: "筛选条件移出分组",
+        category: "筛选",
+        default_bindings: &[bind(KeyCode::Comma, KeyModifiers::SHIFT)],
+    },
+    ScopedCommand {
+        id: "sidebar.filters.group_begin",
+        description: "以筛选条件新建分组",
+        category: "筛选",
+        default_bindings: &[bind(KeyCode::Num9, KeyModifiers::SHIFT)],
+    },
+    ScopedCommand {
+        id: "sidebar.filters.save_preset",
+        description: "保存筛选预设",
+        category: "筛选",
+        default_bindings: &[bind(KeyCode::S, KeyModifiers::SHIFT)],
+    },
     ScopedCommand {
         id: "sidebar.filters.input.dismiss",
         description: "筛选输入返回列表",
@@ -755,6 +779,48 @@ pub(crate) const SCOPED_COMMANDS: &[ScopedCommand] = &[
         category: "导出",
         default_bindings: &[bind(KeyCode::Num4, KeyModifiers::NONE)],
     },
+    ScopedCommand {
+        id: "dialog.export.format_xlsx",
+        description: "导出切到 Excel",
+        category: "导出",
+        default_bindings: &[bind(KeyCode::Num5, KeyModifiers::NONE)],
+    },
+    ScopedCommand {
+        id: "dialog.export.format_parquet",
+        description: "导出切到 Parquet",
+        category: "导出",
+        default_bindings: &[bind(KeyCode::Num6, KeyModifiers::NONE)],
+    },
+    ScopedCommand {
+        id: "dialog.export.format_arrow",
+        description: "导出切到 Arrow IPC",
+        category: "导出",
+        default_bindings: &[bind(KeyCode::Num7, KeyModifiers::NONE)],
+    },
+    ScopedCommand {
+        id: "dialog.export.format_ndjson",
+        description: "导出切到 NDJSON",
+        category: "导出",
+        default_bindings: &[bind(KeyCode::Num8, KeyModifiers::NONE)],
+    },
+    ScopedCommand {
+        id: "dialog.export.format_markdown",
+        description: "导出切到 Markdown",
+        category: "导出",
+        default_bindings: &[bind(KeyCode::Num9, KeyModifiers::NONE)],
+    },
+    ScopedCommand {
+        id: "dialog.export.format_html",
+        description: "导出切到 HTML",
+        category: "导出",
+        default_bindings: &[bind(KeyCode::Num0, KeyModifiers::NONE)],
+    },
+    ScopedCommand {
+        id: "dialog.export.format_latex",
+        description: "导出切到 LaTeX",
+        category: "导出",
+        default_bindings: &[bind(KeyCode::Num0, KeyModifiers::SHIFT)],
+    },
     ScopedCommand {
         id: "dialog.export.cycle_prev",
         description: "导出格式向前切换",
@@ -895,6 +961,18 @@ pub(crate) const SCOPED_COMMANDS: &[ScopedCommand] = &[
             bind(KeyCode::Escape, KeyModifiers::NONE),
         ],
     },
+    ScopedCommand {
+        id: "grid.cell_editor.apply",
+        description: "类型编辑器中应用修改",
+        category: "表格编辑",
+        default_bindings: &[bind(KeyCode::Enter, KeyModifiers::CTRL)],
+    },
+    ScopedCommand {
+        id: "grid.cell_editor.cancel",
+        description: "取消类型编辑",
+        category: "表格编辑",
+        default_bindings: &[bind(KeyCode::Escape, KeyModifiers::NONE)],
+    },
     ScopedCommand {
         id: "dialog.import.refresh",
         description: "刷新导入预览",
@@ -925,6 +1003,18 @@ pub(crate) const SCOPED_COMMANDS: &[ScopedCommand] = &[
         category: "导入",
         default_bindings: &[bind(KeyCode::Num4, KeyModifiers::NONE)],
     },
+    ScopedCommand {
+        id: "dialog.import.format_xlsx",
+        description: "导入切到 Excel",
+        category: "导入",
+        default_bindings: &[bind(KeyCode::Num5, KeyModifiers::NONE)],
+    },
+    ScopedCommand {
+        id: "dialog.import.format_ndjson",
+        description: "导入切到 NDJSON",
+        category: "导入",
+        default_bindings: &[bind(KeyCode::Num6, KeyModifiers::NONE)],
+    },
     ScopedCommand {
         id: "dialog.import.cycle_prev",
         description: "导入格式向前切换",
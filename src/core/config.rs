@@ -50,6 +50,67 @@ impl Default for SidebarConfig {
     }
 }
 
+/// 保存的筛选预设，按连接、数据库与表区分
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FilterPreset {
+    pub name: String,
+    pub connection: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database: Option<String>,
+    pub table: String,
+    #[serde(default)]
+    pub conditions: Vec<FilterPresetCondition>,
+}
+
+impl FilterPreset {
+    /// 预设是否属于该连接下的表
+    pub fn applies_to(&self, connection: &str, database: Option<&str>, table: &str) -> bool {
+        self.connection == connection && self.database.as_deref() == database && self.table == table
+    }
+}
+
+/// 筛选预设中的单个条件
+///
+/// 操作符与逻辑关系以字符串保存，避免配置格式依赖界面层的类型。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FilterPresetCondition {
+    pub column: String,
+    pub operator: String,
+    #[serde(default)]
+    pub value: String,
+    #[serde(default)]
+    pub value2: String,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub case_sensitive: bool,
+    /// 与下一个条件的逻辑关系：`and` / `or`
+    #[serde(default)]
+    pub logic: String,
+    /// 所在分组的编号，由外到内
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<u32>,
+}
+
+/// 外键候选值旁显示的列，按连接、数据库与被引用表区分
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReferenceLabelColumn {
+    pub connection: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database: Option<String>,
+    pub table: String,
+    /// 显示列；`None` 表示只显示键值
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+}
+
+impl ReferenceLabelColumn {
+    /// 设置是否属于该连接下的表
+    pub fn applies_to(&self, connection: &str, database: Option<&str>, table: &str) -> bool {
+        self.connection == connection && self.database.as_deref() == database && self.table == table
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum WorkbenchActivity {
@@ -90,6 +151,7 @@ pub enum RightInspectorTab {
     Schema,
     Row,
     Cell,
+    Profile,
     ErSelection,
     Connection,
 }
@@ -420,6 +482,12 @@ pub struct AppConfig {
     /// 编辑器式 workbench 布局配置
     #[serde(default)]
     pub workbench: WorkbenchConfig,
+    /// 保存的筛选预设
+    #[serde(default)]
+    pub filter_presets: Vec<FilterPreset>,
+    /// 外键候选值的显示列
+    #[serde(default)]
+    pub reference_label_columns: Vec<ReferenceLabelColumn>,
 }
 
 fn default_ui_scale() -> f32 {
@@ -653,6 +721,10 @@ struct AppConfigWire {
     sidebar: SidebarConfig,
     #[serde(default)]
     workbench: WorkbenchConfigWire,
+    #[serde(default)]
+    filter_presets: Vec<FilterPreset>,
+    #[serde(default)]
+    reference_label_columns: Vec<ReferenceLabelColumn>,
 }
 
 impl AppConfigWire {
@@ -678,6 +750,8 @@ impl AppConfigWire {
             connection_dialog_show_advanced: self.connection_dialog_show_advanced,
             sidebar: self.sidebar,
             workbench,
+            filter_presets: self.filter_presets,
+            reference_label_columns: self.reference_label_columns,
         };
         config.normalize();
         config
@@ -839,6 +913,8 @@ impl Default for AppConfig {
             connection_dialog_show_advanced: default_connection_dialog_show_advanced(),
             sidebar: SidebarConfig::default(),
             workbench: WorkbenchConfig::default(),
+            filter_presets: Vec::new(),
+            reference_label_columns: Vec::new(),
         }
     }
 }
@@ -947,8 +1023,8 @@ impl AppConfig {
 #[cfg(test)]
 mod tests {
     use super::{
-        AppConfig, BottomPanelTab, CONFIG_VERSION_WORKBENCH, ResultPlacement, TableOpenMode,
-        WorkbenchActivity, WorkbenchDensity,
+        AppConfig, BottomPanelTab, CONFIG_VERSION_WORKBENCH, FilterPreset, FilterPresetCondition,
+        ResultPlacement, TableOpenMode, WorkbenchActivity, WorkbenchDensity,
     };
 
     #[test]
@@ -1096,4 +1172,31 @@ mod tests {
         assert!(toml.contains("table_open_mode = \"reuse_active_table_view\""));
         assert!(toml.contains("result_placement = \"bottom_panel\""));
     }
+
+    #[test]
+    fn filter_presets_round_trip_through_toml() {
+        let mut config = AppConfig::default();
+        config.filter_presets.push(FilterPreset {
+            name: "活跃客户".to_string(),
+            connection: "primary".to_string(),
+            database: Some("appdb".to_string()),
+            table: "customers".to_string(),
+            conditions: vec![FilterPresetCondition {
+                column: "status".to_string(),
+                operator: "equals".to_string(),
+                value: "active".to_string(),
+                enabled: true,
+                logic: "or".to_string(),
+                groups: vec![1],
+                ..Default::default()
+            }],
+        });
+
+        let toml = toml::to_string_pretty(&config).expect("serialize config");
+        let parsed: AppConfig = toml::from_str(&toml).expect("parse config");
+
+        assert_eq!(parsed.filter_presets, config.filter_presets);
+        assert!(parsed.filter_presets[0].applies_to("primary", Some("appdb"), "customers"));
+        assert!(!parsed.filter_presets[0].applies_to("primary", None, "customers"));
+    }
 }
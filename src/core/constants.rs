@@ -28,6 +28,10 @@ pub mod database {
     pub const LARGE_RESULT_SET_WARNING_THRESHOLD: usize = 10000;
     /// 最大结果集行数限制（防止内存溢出）
     pub const MAX_RESULT_SET_ROWS: usize = 500000;
+    /// 流式执行每批行数
+    pub const STREAM_BATCH_ROWS: usize = 1000;
+    /// 流式导入每批语句数
+    pub const IMPORT_BATCH_STATEMENTS: usize = 1000;
     /// 启用并行筛选的行数阈值
     pub const PARALLEL_FILTER_THRESHOLD: usize = 10000;
 
@@ -1,14 +1,23 @@
 //! 数据导入导出模块
 //!
-//! 支持 CSV、TSV、SQL、JSON 格式的数据导入导出。
-
-use crate::domain::result::{ResultColumn, ResultCompleteness, ResultSet};
+//! 支持 CSV、TSV、SQL、JSON 格式的数据导入导出，以及 Markdown / HTML / LaTeX
+//! 表格导出；NDJSON 见 `ndjson` 模块，Excel 工作簿见 `spreadsheet` 模块，
+//! Parquet / Arrow IPC 见 `columnar` 模块。
+
+use super::columnar::{
+    ColumnarWriter, DEFAULT_ROW_GROUP_ROWS, preview_columnar_schema, render_columnar,
+};
+use super::import_write::{ImportWriteOptions, InsertWriter};
+use super::ndjson::{ndjson_row_line, render_ndjson};
+use super::spreadsheet::{XlsxSheetWriter, preview_xlsx_text, render_xlsx};
+use crate::domain::result::{ResultBatch, ResultColumn, ResultCompleteness, ResultSet};
 use crate::types::DatabaseType;
 use std::borrow::Cow;
 use std::collections::HashSet;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
+use std::sync::Arc;
 
 /// JSON 导入文件大小上限（防止超大文件导致内存峰值过高）
 const MAX_JSON_IMPORT_FILE_BYTES: u64 = 128 * 1024 * 1024;
@@ -23,6 +32,13 @@ pub enum ExportFormat {
     Tsv,
     Sql,
     Json,
+    Ndjson,
+    Xlsx,
+    Parquet,
+    ArrowIpc,
+    Markdown,
+    Html,
+    Latex,
 }
 
 impl ExportFormat {
@@ -32,6 +48,13 @@ impl ExportFormat {
             ExportFormat::Tsv => "tsv",
             ExportFormat::Sql => "sql",
             ExportFormat::Json => "json",
+            ExportFormat::Ndjson => "ndjson",
+            ExportFormat::Xlsx => "xlsx",
+            ExportFormat::Parquet => "parquet",
+            ExportFormat::ArrowIpc => "arrow",
+            ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+            ExportFormat::Latex => "tex",
         }
     }
 
@@ -41,10 +64,161 @@ impl ExportFormat {
             ExportFormat::Tsv => "TSV",
             ExportFormat::Sql => "SQL",
             ExportFormat::Json => "JSON",
+            ExportFormat::Ndjson => "NDJSON",
+            ExportFormat::Xlsx => "Excel",
+            ExportFormat::Parquet => "Parquet",
+            ExportFormat::ArrowIpc => "Arrow IPC",
+            ExportFormat::Markdown => "Markdown",
+            ExportFormat::Html => "HTML",
+            ExportFormat::Latex => "LaTeX",
+        }
+    }
+
+    /// Parquet / Arrow IPC 列式格式
+    pub fn is_columnar(&self) -> bool {
+        matches!(self, ExportFormat::Parquet | ExportFormat::ArrowIpc)
+    }
+
+    /// Markdown / HTML / LaTeX 表格格式对应的标记
+    pub fn table_markup(&self) -> Option<TableMarkup> {
+        match self {
+            ExportFormat::Markdown => Some(TableMarkup::Markdown),
+            ExportFormat::Html => Some(TableMarkup::Html),
+            ExportFormat::Latex => Some(TableMarkup::Latex),
+            _ => None,
         }
     }
 }
 
+// ============================================================================
+// 表格标记（Markdown / HTML / LaTeX）
+// ============================================================================
+
+/// 文档表格标记，用于导出文件和“复制选区为…”
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableMarkup {
+    /// GFM 管道表格
+    Markdown,
+    /// `<table>` 元素
+    Html,
+    /// `tabular` 环境
+    Latex,
+}
+
+impl TableMarkup {
+    pub const ALL: [TableMarkup; 3] =
+        [TableMarkup::Markdown, TableMarkup::Html, TableMarkup::Latex];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            TableMarkup::Markdown => "Markdown",
+            TableMarkup::Html => "HTML",
+            TableMarkup::Latex => "LaTeX",
+        }
+    }
+
+    fn escape(self, text: &str) -> String {
+        match self {
+            TableMarkup::Markdown => text
+                .replace('\\', "\\\\")
+                .replace('|', "\\|")
+                .replace("\r\n", "<br>")
+                .replace(['\r', '\n'], "<br>"),
+            TableMarkup::Html => {
+                let mut escaped = String::with_capacity(text.len());
+                for ch in text.replace("\r\n", "\n").chars() {
+                    match ch {
+                        '&' => escaped.push_str("&amp;"),
+                        '<' => escaped.push_str("&lt;"),
+                        '>' => escaped.push_str("&gt;"),
+                        '"' => escaped.push_str("&quot;"),
+                        '\'' => escaped.push_str("&#39;"),
+                        '\n' | '\r' => escaped.push_str("<br>"),
+                        _ => escaped.push(ch),
+                    }
+                }
+                escaped
+            }
+            TableMarkup::Latex => {
+                let mut escaped = String::with_capacity(text.len());
+                for ch in text.chars() {
+                    match ch {
+                        '\\' => escaped.push_str("\\textbackslash{}"),
+                        '~' => escaped.push_str("\\textasciitilde{}"),
+                        '^' => escaped.push_str("\\textasciicircum{}"),
+                        '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                            escaped.push('\\');
+                            escaped.push(ch);
+                        }
+                        '\n' | '\r' => escaped.push(' '),
+                        _ => escaped.push(ch),
+                    }
+                }
+                escaped
+            }
+        }
+    }
+
+    /// 表头部分（含开始标记），以换行结尾
+    pub fn header(self, headers: &[String]) -> String {
+        let cells: Vec<String> = headers.iter().map(|h| self.escape(h)).collect();
+        match self {
+            TableMarkup::Markdown => format!(
+                "| {} |\n|{}|\n",
+                cells.join(" | "),
+                vec![" --- "; cells.len().max(1)].join("|")
+            ),
+            TableMarkup::Html => format!(
+                "<table>\n  <thead>\n    <tr>{}</tr>\n  </thead>\n  <tbody>\n",
+                cells
+                    .iter()
+                    .map(|cell| format!("<th>{}</th>", cell))
+                    .collect::<String>()
+            ),
+            TableMarkup::Latex => format!(
+                "\\begin{{tabular}}{{|{}|}}\n\\hline\n{} \\\\\n\\hline\n",
+                vec!["l"; cells.len().max(1)].join("|"),
+                cells.join(" & ")
+            ),
+        }
+    }
+
+    /// 一行数据，以换行结尾
+    pub fn row(self, values: &[String]) -> String {
+        let cells: Vec<String> = values.iter().map(|v| self.escape(v)).collect();
+        match self {
+            TableMarkup::Markdown => format!("| {} |\n", cells.join(" | ")),
+            TableMarkup::Html => format!(
+                "    <tr>{}</tr>\n",
+                cells
+                    .iter()
+                    .map(|cell| format!("<td>{}</td>", cell))
+                    .collect::<String>()
+            ),
+            TableMarkup::Latex => format!("{} \\\\\n", cells.join(" & ")),
+        }
+    }
+
+    /// 结束标记；Markdown 表格没有结尾
+    pub fn footer(self) -> &'static str {
+        match self {
+            TableMarkup::Markdown => "",
+            TableMarkup::Html => "  </tbody>\n</table>\n",
+            TableMarkup::Latex => "\\hline\n\\end{tabular}\n",
+        }
+    }
+
+    /// 渲染完整表格
+    pub fn render(self, headers: &[String], rows: &[Vec<String>]) -> String {
+        let mut output = self.header(headers);
+        for row in rows {
+            output.push_str(&self.row(row));
+        }
+        output.push_str(self.footer());
+        output
+    }
+}
+
 // ============================================================================
 // 导入配置（简化版，供 core 导入函数使用）
 // ============================================================================
@@ -83,7 +257,7 @@ impl Default for CsvImportConfig {
 }
 
 /// JSON 导入配置
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct JsonImportConfig {
     /// 目标表名
     pub table_name: String,
@@ -165,6 +339,8 @@ pub struct ExportOptions {
     pub sql_batch_size: usize,
     pub json_pretty: bool,
     pub sql_dialect: SqlDialect,
+    /// Parquet 每个行组的行数
+    pub parquet_row_group_rows: usize,
 }
 
 impl Default for ExportOptions {
@@ -181,6 +357,7 @@ impl Default for ExportOptions {
             sql_batch_size: 100,
             json_pretty: true,
             sql_dialect: SqlDialect::Standard,
+            parquet_row_group_rows: DEFAULT_ROW_GROUP_ROWS,
         }
     }
 }
@@ -250,7 +427,11 @@ fn export_sql_literal(result: &ResultSet, row_idx: usize, col_idx: usize) -> Str
     }
 }
 
-fn export_json_value(result: &ResultSet, row_idx: usize, col_idx: usize) -> serde_json::Value {
+pub(super) fn export_json_value(
+    result: &ResultSet,
+    row_idx: usize,
+    col_idx: usize,
+) -> serde_json::Value {
     if result.is_null(row_idx, col_idx) {
         serde_json::Value::Null
     } else {
@@ -279,41 +460,75 @@ fn finish_line_output(lines: Vec<String>) -> String {
     }
 }
 
-fn render_delimited(result: &ResultSet, options: &ExportOptions, delimiter: char) -> String {
+fn delimited_header_line(result: &ResultSet, options: &ExportOptions, delimiter: char) -> String {
+    let delimiter_text = delimiter.to_string();
+    result
+        .columns
+        .iter()
+        .map(|column| escape_delimited_field(&column.name, &delimiter_text, options.csv_quote_char))
+        .collect::<Vec<_>>()
+        .join(&delimiter_text)
+}
+
+fn delimited_row_line(
+    result: &ResultSet,
+    options: &ExportOptions,
+    delimiter: char,
+    row_idx: usize,
+) -> String {
     let delimiter_text = delimiter.to_string();
+    (0..result.column_count())
+        .map(|col_idx| {
+            escape_delimited_field(
+                &export_cell_text(result, row_idx, col_idx),
+                &delimiter_text,
+                options.csv_quote_char,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(&delimiter_text)
+}
+
+fn render_delimited(result: &ResultSet, options: &ExportOptions, delimiter: char) -> String {
     let mut lines = Vec::new();
 
     if options.csv_include_header {
-        lines.push(
-            result
-                .columns
-                .iter()
-                .map(|column| {
-                    escape_delimited_field(&column.name, &delimiter_text, options.csv_quote_char)
-                })
-                .collect::<Vec<_>>()
-                .join(&delimiter_text),
-        );
+        lines.push(delimited_header_line(result, options, delimiter));
     }
 
     for row_idx in 0..result.row_count {
-        lines.push(
-            (0..result.column_count())
-                .map(|col_idx| {
-                    escape_delimited_field(
-                        &export_cell_text(result, row_idx, col_idx),
-                        &delimiter_text,
-                        options.csv_quote_char,
-                    )
-                })
-                .collect::<Vec<_>>()
-                .join(&delimiter_text),
-        );
+        lines.push(delimited_row_line(result, options, delimiter, row_idx));
     }
 
     finish_line_output(lines)
 }
 
+fn sql_column_list(result: &ResultSet, options: &ExportOptions) -> String {
+    result
+        .columns
+        .iter()
+        .map(|column| options.sql_dialect.quote_identifier(&column.name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn sql_row_values(result: &ResultSet, row_idx: usize) -> String {
+    let values = (0..result.column_count())
+        .map(|col_idx| export_sql_literal(result, row_idx, col_idx))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("({})", values)
+}
+
+fn sql_multi_row_insert(quoted_table: &str, columns_str: &str, values_list: &[String]) -> String {
+    format!(
+        "INSERT INTO {} ({}) VALUES\n  {};\n\n",
+        quoted_table,
+        columns_str,
+        values_list.join(",\n  ")
+    )
+}
+
 fn render_sql(
     result: &ResultSet,
     table_name: &str,
@@ -338,42 +553,28 @@ fn render_sql(
     }
 
     let quoted_table = options.sql_dialect.quote_identifier(table_name);
-    let columns_str = result
-        .columns
-        .iter()
-        .map(|column| options.sql_dialect.quote_identifier(&column.name))
-        .collect::<Vec<_>>()
-        .join(", ");
+    let columns_str = sql_column_list(result, options);
 
     if options.sql_batch_size > 0 {
         for start in (0..result.row_count).step_by(options.sql_batch_size) {
             let end = (start + options.sql_batch_size).min(result.row_count);
             let values_list = (start..end)
-                .map(|row_idx| {
-                    let values = (0..result.column_count())
-                        .map(|col_idx| export_sql_literal(result, row_idx, col_idx))
-                        .collect::<Vec<_>>()
-                        .join(", ");
-                    format!("({})", values)
-                })
+                .map(|row_idx| sql_row_values(result, row_idx))
                 .collect::<Vec<_>>();
 
-            output.push_str(&format!(
-                "INSERT INTO {} ({}) VALUES\n  {};\n\n",
-                quoted_table,
-                columns_str,
-                values_list.join(",\n  ")
+            output.push_str(&sql_multi_row_insert(
+                &quoted_table,
+                &columns_str,
+                &values_list,
             ));
         }
     } else {
         for row_idx in 0..result.row_count {
-            let values = (0..result.column_count())
-                .map(|col_idx| export_sql_literal(result, row_idx, col_idx))
-                .collect::<Vec<_>>()
-                .join(", ");
             output.push_str(&format!(
-                "INSERT INTO {} ({}) VALUES ({});\n",
-                quoted_table, columns_str, values
+                "INSERT INTO {} ({}) VALUES {};\n",
+                quoted_table,
+                columns_str,
+                sql_row_values(result, row_idx)
             ));
         }
     }
@@ -386,21 +587,26 @@ fn render_sql(
     Ok(output)
 }
 
+fn json_row_object(
+    result: &ResultSet,
+    row_idx: usize,
+) -> serde_json::Map<String, serde_json::Value> {
+    result
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(col_idx, col)| {
+            (
+                col.name.clone(),
+                export_json_value(result, row_idx, col_idx),
+            )
+        })
+        .collect()
+}
+
 fn render_json(result: &ResultSet, options: &ExportOptions) -> Result<String, String> {
     let json_rows: Vec<serde_json::Map<String, serde_json::Value>> = (0..result.row_count)
-        .map(|row_idx| {
-            result
-                .columns
-                .iter()
-                .enumerate()
-                .map(|(col_idx, col)| {
-                    (
-                        col.name.clone(),
-                        export_json_value(result, row_idx, col_idx),
-                    )
-                })
-                .collect()
-        })
+        .map(|row_idx| json_row_object(result, row_idx))
         .collect();
 
     if options.json_pretty {
@@ -411,6 +617,27 @@ fn render_json(result: &ResultSet, options: &ExportOptions) -> Result<String, St
     .map_err(|e| e.to_string())
 }
 
+fn markup_header(markup: TableMarkup, result: &ResultSet) -> String {
+    let headers: Vec<String> = result.columns.iter().map(|c| c.name.clone()).collect();
+    markup.header(&headers)
+}
+
+fn markup_row(markup: TableMarkup, result: &ResultSet, row_idx: usize) -> String {
+    let values: Vec<String> = (0..result.column_count())
+        .map(|col_idx| export_cell_text(result, row_idx, col_idx))
+        .collect();
+    markup.row(&values)
+}
+
+fn render_table_markup(result: &ResultSet, markup: TableMarkup) -> String {
+    let mut output = markup_header(markup, result);
+    for row_idx in 0..result.row_count {
+        output.push_str(&markup_row(markup, result, row_idx));
+    }
+    output.push_str(markup.footer());
+    output
+}
+
 fn render_export_content(
     result: &ResultSet,
     table_name: &str,
@@ -421,6 +648,14 @@ fn render_export_content(
         ExportFormat::Tsv => Ok(render_delimited(result, options, '\t')),
         ExportFormat::Sql => render_sql(result, table_name, options),
         ExportFormat::Json => render_json(result, options),
+        ExportFormat::Ndjson => render_ndjson(result),
+        ExportFormat::Markdown => Ok(render_table_markup(result, TableMarkup::Markdown)),
+        ExportFormat::Html => Ok(render_table_markup(result, TableMarkup::Html)),
+        ExportFormat::Latex => Ok(render_table_markup(result, TableMarkup::Latex)),
+        ExportFormat::Xlsx | ExportFormat::Parquet | ExportFormat::ArrowIpc => Err(format!(
+            "{} 为二进制格式，无法生成文本内容",
+            options.format.display_name()
+        )),
     }
 }
 
@@ -472,6 +707,15 @@ pub fn export_to_path(
         return Err("未选择任何列".to_string());
     }
 
+    if options.format == ExportFormat::Xlsx {
+        let (bytes, _) = render_xlsx(&filtered, table_name, options)?;
+        return std::fs::write(path, bytes).map_err(|e| e.to_string());
+    }
+    if options.format.is_columnar() {
+        let (bytes, _) =
+            render_columnar(&filtered, options.format, options.parquet_row_group_rows)?;
+        return std::fs::write(path, bytes).map_err(|e| e.to_string());
+    }
     let content = render_export_content(&filtered, table_name, options)?;
     std::fs::write(path, content).map_err(|e| e.to_string())
 }
@@ -488,8 +732,15 @@ pub fn preview_export(
     }
 
     let preview = preview_subset(&filtered, preview_rows);
-    let mut content = render_export_content(&preview, table_name, options)
-        .unwrap_or_else(|err| format!("（预览失败: {}）", err));
+    let mut content = match options.format {
+        ExportFormat::Xlsx => preview_xlsx_text(&preview, table_name, options.csv_include_header),
+        ExportFormat::Parquet | ExportFormat::ArrowIpc => {
+            // 列结构即文件内容的全部可读部分，不再追加剩余行数
+            return preview_columnar_schema(&preview, options.format);
+        }
+        _ => render_export_content(&preview, table_name, options)
+            .unwrap_or_else(|err| format!("（预览失败: {}）", err)),
+    };
     content = trim_trailing_newlines(content);
 
     let remaining_rows = filtered.row_count.saturating_sub(preview.row_count);
@@ -498,7 +749,15 @@ pub fn preview_export(
     }
 
     let suffix_label = match options.format {
-        ExportFormat::Csv | ExportFormat::Tsv => "行",
+        ExportFormat::Csv
+        | ExportFormat::Tsv
+        | ExportFormat::Ndjson
+        | ExportFormat::Xlsx
+        | ExportFormat::Parquet
+        | ExportFormat::ArrowIpc
+        | ExportFormat::Markdown
+        | ExportFormat::Html
+        | ExportFormat::Latex => "行",
         ExportFormat::Sql | ExportFormat::Json => "条",
     };
 
@@ -509,6 +768,314 @@ pub fn preview_export(
     content
 }
 
+// ============================================================================
+// 流式导出
+// ============================================================================
+
+/// 流式导出写入器
+///
+/// 逐批接收 `ResultBatch` 并立即写出，不保留已写出的行，适合任意大小的结果。
+/// 列选择与行窗口语义与 `export_to_path` 一致；SQL 格式的总行数改为写在末尾。
+/// Excel 工作簿只能整体写出，单元格先保存在工作表中，`finish` 时一次写入；
+/// Parquet / Arrow IPC 按批转换为列式数据写出。
+pub struct StreamingExporter<W: Write> {
+    writer: W,
+    table_name: String,
+    options: ExportOptions,
+    /// 已投影的列描述，收到第一批后确定
+    columns: Option<Arc<[ResultColumn]>>,
+    /// 已经过的源行数（用于 `start_row` 窗口）
+    source_rows: usize,
+    /// 已写出的行数
+    written_rows: usize,
+    /// SQL 多行 INSERT 中尚未写出的 VALUES 元组
+    pending_sql_values: Vec<String>,
+    /// Excel 格式的工作表，收到第一批后创建
+    xlsx_sheet: Option<XlsxSheetWriter>,
+    /// 列式格式的写入器，按第一批的列类型创建
+    columnar: Option<ColumnarWriter>,
+}
+
+impl<W: Write> StreamingExporter<W> {
+    pub fn new(writer: W, table_name: impl Into<String>, options: ExportOptions) -> Self {
+        Self {
+            writer,
+            table_name: table_name.into(),
+            options,
+            columns: None,
+            source_rows: 0,
+            written_rows: 0,
+            pending_sql_values: Vec::new(),
+            xlsx_sheet: None,
+            columnar: None,
+        }
+    }
+
+    /// 已写出的行数
+    pub fn written_rows(&self) -> usize {
+        self.written_rows
+    }
+
+    /// 写出过程中产生的提示（如被截断的单元格、推断的列类型）
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if let Some(sheet) = &self.xlsx_sheet {
+            warnings.extend(sheet.warnings());
+        }
+        if let Some(columnar) = &self.columnar {
+            warnings.extend_from_slice(columnar.warnings());
+        }
+        warnings
+    }
+
+    /// 行窗口已写满，后续批次不会再产生输出
+    pub fn is_saturated(&self) -> bool {
+        self.options.row_limit > 0 && self.written_rows >= self.options.row_limit
+    }
+
+    /// 写出一批行；返回 `false` 表示行窗口已写满，调用方可以停止拉取
+    pub fn write_batch(&mut self, batch: ResultBatch) -> Result<bool, String> {
+        let batch_rows = batch.row_count;
+        let batch_start = self.source_rows;
+        self.source_rows += batch_rows;
+
+        let local_start = self.options.start_row.saturating_sub(batch_start);
+        let remaining = if self.options.row_limit > 0 {
+            self.options.row_limit - self.written_rows.min(self.options.row_limit)
+        } else {
+            usize::MAX
+        };
+        let local_rows = batch_rows.saturating_sub(local_start).min(remaining);
+
+        // row_limit 为 0 表示不限制，因此空窗口通过把起点移到批末尾来表达
+        let window = ExportOptions {
+            start_row: if local_rows == 0 {
+                batch_rows
+            } else {
+                local_start
+            },
+            row_limit: local_rows,
+            ..self.options.clone()
+        };
+        let filtered = filter_result_for_export(&batch.into_result_set(), &window);
+
+        if self.columns.is_none() {
+            if filtered.columns.is_empty() {
+                return Err("未选择任何列".to_string());
+            }
+            self.write_prologue(&filtered)?;
+            self.columns = Some(Arc::clone(&filtered.columns));
+        }
+
+        if let Some(columnar) = self.columnar.as_mut() {
+            columnar.write_batch(&filtered, &mut self.writer)?;
+            self.written_rows += filtered.row_count;
+        } else {
+            for row_idx in 0..filtered.row_count {
+                self.write_row(&filtered, row_idx)?;
+                self.written_rows += 1;
+            }
+        }
+
+        Ok(!self.is_saturated())
+    }
+
+    /// 写出结尾并刷新，返回导出的行数
+    pub fn finish(mut self) -> Result<usize, String> {
+        if self.columns.is_none() {
+            return Err("查询没有返回结果集".to_string());
+        }
+
+        match self.options.format {
+            ExportFormat::Csv | ExportFormat::Tsv | ExportFormat::Ndjson => {}
+            ExportFormat::Markdown | ExportFormat::Html | ExportFormat::Latex => {
+                if let Some(markup) = self.options.format.table_markup() {
+                    self.write_str(markup.footer())?;
+                }
+            }
+            ExportFormat::Xlsx => {
+                if let Some(sheet) = self.xlsx_sheet.take() {
+                    let bytes = sheet.finish()?;
+                    self.writer.write_all(&bytes).map_err(|e| e.to_string())?;
+                }
+            }
+            ExportFormat::Parquet | ExportFormat::ArrowIpc => {
+                if let Some(columnar) = self.columnar.take() {
+                    columnar.finish(&mut self.writer)?;
+                }
+            }
+            ExportFormat::Json => {
+                let tail = match (self.written_rows, self.options.json_pretty) {
+                    (0, _) => "]",
+                    (_, true) => "\n]",
+                    (_, false) => "]",
+                };
+                self.write_str(tail)?;
+            }
+            ExportFormat::Sql => {
+                if self.written_rows == 0 {
+                    self.write_str("-- No data to export\n")?;
+                } else {
+                    self.flush_sql_values()?;
+                    if self.options.sql_use_transaction {
+                        self.write_str("\nCOMMIT;\n")?;
+                    }
+                }
+                let footer = format!("-- Rows: {}\n", self.written_rows);
+                self.write_str(&footer)?;
+            }
+        }
+
+        self.writer.flush().map_err(|e| e.to_string())?;
+        Ok(self.written_rows)
+    }
+
+    fn write_str(&mut self, text: &str) -> Result<(), String> {
+        self.writer
+            .write_all(text.as_bytes())
+            .map_err(|e| e.to_string())
+    }
+
+    fn delimiter(&self) -> char {
+        match self.options.format {
+            ExportFormat::Tsv => '\t',
+            _ => self.options.csv_delimiter,
+        }
+    }
+
+    fn write_prologue(&mut self, columns: &ResultSet) -> Result<(), String> {
+        match self.options.format {
+            ExportFormat::Csv | ExportFormat::Tsv => {
+                if self.options.csv_include_header {
+                    let mut line = delimited_header_line(columns, &self.options, self.delimiter());
+                    line.push('\n');
+                    self.write_str(&line)?;
+                }
+                Ok(())
+            }
+            ExportFormat::Json => self.write_str("["),
+            ExportFormat::Ndjson => Ok(()),
+            ExportFormat::Markdown | ExportFormat::Html | ExportFormat::Latex => {
+                match self.options.format.table_markup() {
+                    Some(markup) => self.write_str(&markup_header(markup, columns)),
+                    None => Ok(()),
+                }
+            }
+            ExportFormat::Xlsx => {
+                self.xlsx_sheet = Some(XlsxSheetWriter::new(
+                    &self.table_name,
+                    columns,
+                    self.options.csv_include_header,
+                )?);
+                Ok(())
+            }
+            ExportFormat::Parquet | ExportFormat::ArrowIpc => {
+                self.columnar = Some(ColumnarWriter::new(
+                    self.options.format,
+                    columns,
+                    self.options.parquet_row_group_rows,
+                )?);
+                Ok(())
+            }
+            ExportFormat::Sql => {
+                let header = format!(
+                    "-- Exported from Rust DB Manager\n-- Table: {}\n\n",
+                    self.table_name
+                );
+                self.write_str(&header)
+            }
+        }
+    }
+
+    fn write_row(&mut self, result: &ResultSet, row_idx: usize) -> Result<(), String> {
+        match self.options.format {
+            ExportFormat::Csv | ExportFormat::Tsv => {
+                let mut line = delimited_row_line(result, &self.options, self.delimiter(), row_idx);
+                line.push('\n');
+                self.write_str(&line)
+            }
+            ExportFormat::Xlsx => match self.xlsx_sheet.as_mut() {
+                Some(sheet) => sheet.write_row(result, row_idx),
+                None => Ok(()),
+            },
+            ExportFormat::Parquet | ExportFormat::ArrowIpc => {
+                Err("列式格式按批写出，不支持逐行写入".to_string())
+            }
+            ExportFormat::Markdown | ExportFormat::Html | ExportFormat::Latex => {
+                match self.options.format.table_markup() {
+                    Some(markup) => self.write_str(&markup_row(markup, result, row_idx)),
+                    None => Ok(()),
+                }
+            }
+            ExportFormat::Ndjson => {
+                let mut line = ndjson_row_line(result, row_idx)?;
+                line.push('\n');
+                self.write_str(&line)
+            }
+            ExportFormat::Json => {
+                let object = json_row_object(result, row_idx);
+                let separator = if self.written_rows == 0 { "" } else { "," };
+                let text = if self.options.json_pretty {
+                    let pretty =
+                        serde_json::to_string_pretty(&object).map_err(|e| e.to_string())?;
+                    let indented = pretty
+                        .lines()
+                        .map(|line| format!("  {}", line))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    format!("{}\n{}", separator, indented)
+                } else {
+                    let compact = serde_json::to_string(&object).map_err(|e| e.to_string())?;
+                    format!("{}{}", separator, compact)
+                };
+                self.write_str(&text)
+            }
+            ExportFormat::Sql => {
+                if self.written_rows == 0 && self.options.sql_use_transaction {
+                    self.write_str("BEGIN;\n\n")?;
+                }
+                let values = sql_row_values(result, row_idx);
+                if self.options.sql_batch_size > 0 {
+                    self.pending_sql_values.push(values);
+                    if self.pending_sql_values.len() >= self.options.sql_batch_size {
+                        self.flush_sql_values()?;
+                    }
+                    Ok(())
+                } else {
+                    let statement = format!(
+                        "INSERT INTO {} ({}) VALUES {};\n",
+                        self.options.sql_dialect.quote_identifier(&self.table_name),
+                        sql_column_list(result, &self.options),
+                        values
+                    );
+                    self.write_str(&statement)
+                }
+            }
+        }
+    }
+
+    fn flush_sql_values(&mut self) -> Result<(), String> {
+        if self.pending_sql_values.is_empty() {
+            return Ok(());
+        }
+        let Some(columns) = self.columns.as_ref() else {
+            return Ok(());
+        };
+        let columns_str = columns
+            .iter()
+            .map(|column| self.options.sql_dialect.quote_identifier(&column.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let statement = sql_multi_row_insert(
+            &self.options.sql_dialect.quote_identifier(&self.table_name),
+            &columns_str,
+            &self.pending_sql_values,
+        );
+        self.pending_sql_values.clear();
+        self.write_str(&statement)
+    }
+}
+
 // ============================================================================
 // CSV 导入
 // ============================================================================
@@ -659,10 +1226,23 @@ pub fn import_csv_to_sql(
     path: &Path,
     config: &CsvImportConfig,
     use_mysql_syntax: bool,
+) -> Result<ImportResult, String> {
+    import_csv_to_sql_mapped(
+        path,
+        config,
+        &ImportWriteOptions::insert(use_mysql_syntax),
+        None,
+    )
+}
+
+/// 从 CSV 文件生成写入语句，可按 `(源列索引, 目标列名)` 投影列
+pub(crate) fn import_csv_to_sql_mapped(
+    path: &Path,
+    config: &CsvImportConfig,
+    write: &ImportWriteOptions,
+    projection: Option<&[(usize, String)]>,
 ) -> Result<ImportResult, String> {
     let mut reader = open_csv_reader(path, config)?;
-    let mut sql_statements = Vec::new();
-    let mut rows_imported = 0;
     let mut first_data_fields: Option<Vec<String>> = None;
 
     // 读取列名
@@ -697,26 +1277,16 @@ pub fn import_csv_to_sql(
     if config.table_name.is_empty() {
         return Err("未指定目标表名".to_string());
     }
-
-    // 生成列名部分
-    let quote_char = if use_mysql_syntax { '`' } else { '"' };
-    let columns_str = columns
-        .iter()
-        .map(|c| format!("{}{}{}", quote_char, escape_sql_identifier(c), quote_char))
-        .collect::<Vec<_>>()
-        .join(", ");
-
-    let table_name = format!(
-        "{}{}{}",
-        quote_char,
-        escape_sql_identifier(&config.table_name),
-        quote_char
-    );
+    let source_indices = projected_source_indices(&columns, projection)?;
+    let mut writer = InsertWriter::new(
+        &config.table_name,
+        &projected_target_columns(&columns, projection),
+        write,
+    )?;
 
     let append_insert_statement = |fields: Vec<String>,
                                    data_row_idx: usize,
-                                   rows_imported: &mut usize,
-                                   sql_statements: &mut Vec<String>|
+                                   writer: &mut InsertWriter|
      -> Result<(), String> {
         if fields.len() != columns.len() {
             return Err(format!(
@@ -727,37 +1297,26 @@ pub fn import_csv_to_sql(
             ));
         }
 
-        let values = fields
+        let values = source_indices
             .iter()
-            .map(|field| sql_value_from_string(field))
-            .collect::<Vec<_>>()
-            .join(", ");
-
-        sql_statements.push(format!(
-            "INSERT INTO {} ({}) VALUES ({});",
-            table_name, columns_str, values
-        ));
-        *rows_imported += 1;
+            .map(|&idx| sql_value_from_string(&fields[idx]))
+            .collect::<Vec<_>>();
+        writer.push_row(&values);
         Ok(())
     };
 
     let mut data_row_idx = 0usize;
 
     if let Some(fields) = first_data_fields
-        && (config.max_rows == 0 || rows_imported < config.max_rows)
+        && (config.max_rows == 0 || writer.rows() < config.max_rows)
     {
         data_row_idx += 1;
-        append_insert_statement(
-            fields,
-            data_row_idx,
-            &mut rows_imported,
-            &mut sql_statements,
-        )?;
+        append_insert_statement(fields, data_row_idx, &mut writer)?;
     }
 
     // 处理数据记录
     for record_result in records {
-        if config.max_rows > 0 && rows_imported >= config.max_rows {
+        if config.max_rows > 0 && writer.rows() >= config.max_rows {
             break;
         }
 
@@ -768,15 +1327,10 @@ pub fn import_csv_to_sql(
 
         data_row_idx += 1;
         let fields = record.iter().map(|v| v.to_string()).collect::<Vec<_>>();
-        append_insert_statement(
-            fields,
-            data_row_idx,
-            &mut rows_imported,
-            &mut sql_statements,
-        )?;
+        append_insert_statement(fields, data_row_idx, &mut writer)?;
     }
 
-    Ok(ImportResult { sql_statements })
+    Ok(writer.finish())
 }
 
 /// 解析 CSV 行
@@ -944,6 +1498,21 @@ pub fn import_json_to_sql(
     path: &Path,
     config: &JsonImportConfig,
     use_mysql_syntax: bool,
+) -> Result<ImportResult, String> {
+    import_json_to_sql_mapped(
+        path,
+        config,
+        &ImportWriteOptions::insert(use_mysql_syntax),
+        None,
+    )
+}
+
+/// 从 JSON 文件生成写入语句，可按 `(源列索引, 目标列名)` 投影列
+pub(crate) fn import_json_to_sql_mapped(
+    path: &Path,
+    config: &JsonImportConfig,
+    write: &ImportWriteOptions,
+    projection: Option<&[(usize, String)]>,
 ) -> Result<ImportResult, String> {
     let content = read_json_content(path)?;
 
@@ -963,9 +1532,6 @@ pub fn import_json_to_sql(
         return Err("未指定目标表名".to_string());
     }
 
-    let mut sql_statements = Vec::new();
-    let mut rows_imported = 0;
-
     let scan_rows = if config.max_rows > 0 {
         config.max_rows
     } else {
@@ -973,66 +1539,92 @@ pub fn import_json_to_sql(
     };
     let (columns, _scan_truncated, _has_non_object) =
         collect_json_columns(array, config.flatten_nested, scan_rows);
-
-    let quote_char = if use_mysql_syntax { '`' } else { '"' };
-    let columns_str = columns
-        .iter()
-        .map(|c| format!("{}{}{}", quote_char, escape_sql_identifier(c), quote_char))
-        .collect::<Vec<_>>()
-        .join(", ");
-
-    let table_name = format!(
-        "{}{}{}",
-        quote_char,
-        escape_sql_identifier(&config.table_name),
-        quote_char
-    );
+    let source_indices = projected_source_indices(&columns, projection)?;
+    let mut writer = InsertWriter::new(
+        &config.table_name,
+        &projected_target_columns(&columns, projection),
+        write,
+    )?;
 
     for item in array {
-        if config.max_rows > 0 && rows_imported >= config.max_rows {
+        if config.max_rows > 0 && writer.rows() >= config.max_rows {
             break;
         }
 
         let normalized_item = normalize_json_item(item, config.flatten_nested);
         let values = match normalized_item.as_ref() {
-            serde_json::Value::Object(obj) => columns
+            serde_json::Value::Object(obj) => source_indices
                 .iter()
-                .map(|col| {
-                    obj.get(col)
+                .map(|&idx| {
+                    obj.get(&columns[idx])
                         .map(json_value_to_sql)
                         .unwrap_or_else(|| "NULL".to_string())
                 })
-                .collect::<Vec<_>>()
-                .join(", "),
-            other => {
-                if columns.len() == 1 {
-                    json_value_to_sql(other)
-                } else {
-                    let mut values = Vec::with_capacity(columns.len());
-                    values.push(json_value_to_sql(other));
-                    values.extend((1..columns.len()).map(|_| "NULL".to_string()));
-                    values.join(", ")
-                }
-            }
+                .collect::<Vec<_>>(),
+            // 非对象元素写入第一个源列，其余列填充 NULL
+            other => source_indices
+                .iter()
+                .map(|&idx| {
+                    if idx == 0 {
+                        json_value_to_sql(other)
+                    } else {
+                        "NULL".to_string()
+                    }
+                })
+                .collect::<Vec<_>>(),
         };
 
         if values.is_empty() {
             continue;
         }
 
-        sql_statements.push(format!(
-            "INSERT INTO {} ({}) VALUES ({});",
-            table_name, columns_str, values
-        ));
+        writer.push_row(&values);
+    }
+
+    Ok(writer.finish())
+}
+
+/// 解析列投影对应的源列索引；未指定投影时按原顺序保留全部列
+pub(super) fn projected_source_indices(
+    columns: &[String],
+    projection: Option<&[(usize, String)]>,
+) -> Result<Vec<usize>, String> {
+    let Some(projection) = projection else {
+        return Ok((0..columns.len()).collect());
+    };
 
-        rows_imported += 1;
+    if projection.is_empty() {
+        return Err("未选择任何列".to_string());
     }
+    projection
+        .iter()
+        .map(|(idx, _)| {
+            if *idx < columns.len() {
+                Ok(*idx)
+            } else {
+                Err(format!(
+                    "列映射引用了不存在的源列 #{}（共 {} 列）",
+                    idx + 1,
+                    columns.len()
+                ))
+            }
+        })
+        .collect()
+}
 
-    Ok(ImportResult { sql_statements })
+/// 列投影对应的目标列名
+pub(super) fn projected_target_columns<'a>(
+    columns: &'a [String],
+    projection: Option<&'a [(usize, String)]>,
+) -> Vec<&'a String> {
+    match projection {
+        Some(projection) => projection.iter().map(|(_, target)| target).collect(),
+        None => columns.iter().collect(),
+    }
 }
 
 /// 在需要时展平 JSON 对象；未启用时返回借用
-fn normalize_json_item<'a>(
+pub(super) fn normalize_json_item<'a>(
     item: &'a serde_json::Value,
     flatten_nested: bool,
 ) -> Cow<'a, serde_json::Value> {
@@ -1155,7 +1747,7 @@ pub fn sql_value_from_string(s: &str) -> String {
 // ============================================================================
 
 /// 转义 SQL 标识符（表名、列名）中的特殊字符
-fn escape_sql_identifier(name: &str) -> String {
+pub(super) fn escape_sql_identifier(name: &str) -> String {
     name.replace('`', "``").replace('"', "\"\"")
 }
 
@@ -1225,6 +1817,92 @@ mod tests {
         assert!(!filtered.is_null(0, 1));
     }
 
+    fn split_into_batches(result: &ResultSet, batch_rows: usize) -> Vec<ResultBatch> {
+        let col_count = result.column_count();
+        (0..result.row_count)
+            .step_by(batch_rows)
+            .map(|start| {
+                let end = (start + batch_rows).min(result.row_count);
+                ResultBatch {
+                    columns: Arc::clone(&result.columns),
+                    cells: result.cells[start * col_count..end * col_count].to_vec(),
+                    row_count: end - start,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn streaming_export_matches_materialized_export_across_batches() {
+        let rows = (1..=7)
+            .map(|i| {
+                vec![
+                    DbValue::Text(i.to_string()),
+                    if i % 3 == 0 {
+                        DbValue::Null
+                    } else {
+                        DbValue::Text(format!("v{}", i))
+                    },
+                ]
+            })
+            .collect();
+        let result = make_result_set(&["id", "value"], rows);
+
+        for format in [
+            ExportFormat::Csv,
+            ExportFormat::Tsv,
+            ExportFormat::Json,
+            ExportFormat::Ndjson,
+            ExportFormat::Markdown,
+            ExportFormat::Html,
+            ExportFormat::Latex,
+        ] {
+            for json_pretty in [true, false] {
+                let options = ExportOptions {
+                    format,
+                    json_pretty,
+                    selected_columns: vec![1, 0],
+                    start_row: 2,
+                    row_limit: 4,
+                    ..Default::default()
+                };
+                let file = NamedTempFile::new().expect("create temp file");
+                export_to_path(&result, "t", file.path(), &options).expect("export");
+                let expected = fs::read_to_string(file.path()).expect("read export");
+
+                let mut output = Vec::new();
+                let mut exporter = StreamingExporter::new(&mut output, "t", options);
+                for batch in split_into_batches(&result, 3) {
+                    if !exporter.write_batch(batch).expect("write batch") {
+                        break;
+                    }
+                }
+                assert_eq!(exporter.written_rows(), 4);
+                exporter.finish().expect("finish");
+                assert_eq!(String::from_utf8(output).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn table_markup_escapes_cells_for_each_format() {
+        let headers = vec!["a|b".to_string(), "<n>".to_string()];
+        let rows = vec![vec!["x & y".to_string(), "50%_\nok".to_string()]];
+
+        assert_eq!(
+            TableMarkup::Markdown.render(&headers, &rows),
+            "| a\\|b | <n> |\n| --- | --- |\n| x & y | 50%_<br>ok |\n"
+        );
+        assert_eq!(
+            TableMarkup::Html.render(&headers, &rows),
+            "<table>\n  <thead>\n    <tr><th>a|b</th><th>&lt;n&gt;</th></tr>\n  </thead>\n  <tbody>\n    <tr><td>x &amp; y</td><td>50%_<br>ok</td></tr>\n  </tbody>\n</table>\n"
+        );
+        assert_eq!(
+            TableMarkup::Latex.render(&headers, &rows),
+            "\\begin{tabular}{|l|l|}\n\\hline\na|b & <n> \\\\\n\\hline\nx \\& y & 50\\%\\_ ok \\\\\n\\hline\n\\end{tabular}\n"
+        );
+    }
+
     #[test]
     fn preview_export_matches_csv_file_content() {
         let result = make_result_set(
@@ -0,0 +1,414 @@
+//! 结构化导入的写入方式
+//!
+//! CSV / JSON / NDJSON / Excel 导入逐行生成写入语句。除普通 INSERT 外，
+//! 还支持忽略冲突、UPSERT、REPLACE 与清空后插入，便于重复导入同一份文件。
+//! 冲突子句按目标库生成：PostgreSQL / SQLite 使用 `ON CONFLICT`，
+//! MySQL 使用 `INSERT IGNORE`、`ON DUPLICATE KEY UPDATE` 与 `REPLACE INTO`。
+
+use super::export::{ImportResult, escape_sql_identifier};
+use crate::types::DatabaseType;
+
+/// 导入写入方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImportWriteMode {
+    /// 普通 INSERT，遇到重复键报错
+    #[default]
+    Insert,
+    /// 跳过与已有行冲突的记录
+    InsertIgnore,
+    /// 冲突时用导入的值更新已有行
+    Upsert,
+    /// 冲突时删除已有行后重新插入
+    Replace,
+    /// 先清空目标表再插入
+    TruncateInsert,
+}
+
+impl ImportWriteMode {
+    pub const ALL: [ImportWriteMode; 5] = [
+        ImportWriteMode::Insert,
+        ImportWriteMode::InsertIgnore,
+        ImportWriteMode::Upsert,
+        ImportWriteMode::Replace,
+        ImportWriteMode::TruncateInsert,
+    ];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Insert => "插入",
+            Self::InsertIgnore => "忽略冲突",
+            Self::Upsert => "更新冲突 (UPSERT)",
+            Self::Replace => "替换",
+            Self::TruncateInsert => "清空后插入",
+        }
+    }
+
+    /// 该方式在目标库上是否需要显式指定冲突键
+    pub const fn requires_conflict_key(self, db_type: DatabaseType) -> bool {
+        match self {
+            Self::Upsert => !matches!(db_type, DatabaseType::MySQL),
+            Self::Replace => matches!(db_type, DatabaseType::PostgreSQL),
+            Self::Insert | Self::InsertIgnore | Self::TruncateInsert => false,
+        }
+    }
+
+    /// 冲突键是否参与生成的语句
+    pub const fn uses_conflict_key(self) -> bool {
+        matches!(self, Self::InsertIgnore | Self::Upsert | Self::Replace)
+    }
+}
+
+/// 导入写入配置
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ImportWriteOptions {
+    pub mode: ImportWriteMode,
+    /// 冲突判定列（目标列名），取自主键或唯一键
+    pub conflict_columns: Vec<String>,
+    /// 目标库类型；`None` 时使用通用语法，只支持插入与清空后插入
+    pub db_type: Option<DatabaseType>,
+}
+
+impl ImportWriteOptions {
+    /// 普通 INSERT，仅区分标识符引号
+    pub fn insert(use_mysql_syntax: bool) -> Self {
+        Self {
+            db_type: use_mysql_syntax.then_some(DatabaseType::MySQL),
+            ..Default::default()
+        }
+    }
+
+    fn uses_mysql_syntax(&self) -> bool {
+        self.db_type == Some(DatabaseType::MySQL)
+    }
+}
+
+/// 逐行生成写入语句
+///
+/// 语句的固定部分在创建时拼好，每行只拼接值列表。
+pub(crate) struct InsertWriter {
+    /// 值列表之前的部分，如 `INSERT INTO "t" ("a", "b") VALUES (`
+    prefix: String,
+    /// 值列表之后的部分，如 `) ON CONFLICT ("a") DO NOTHING;`
+    suffix: String,
+    /// PostgreSQL 的 REPLACE：先按冲突键删除，元素为 (引用后的列名, 值位置)
+    delete_keys: Vec<(String, usize)>,
+    table: String,
+    rows: usize,
+    statements: Vec<String>,
+}
+
+impl InsertWriter {
+    pub(crate) fn new(
+        table_name: &str,
+        target_columns: &[impl AsRef<str>],
+        write: &ImportWriteOptions,
+    ) -> Result<Self, String> {
+        let quote_char = if write.uses_mysql_syntax() { '`' } else { '"' };
+        let quote = |name: &str| format!("{0}{1}{0}", quote_char, escape_sql_identifier(name));
+        let table = quote(table_name);
+        let columns_str = target_columns
+            .iter()
+            .map(|column| quote(column.as_ref()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mode = write.mode;
+        let db_type = match (write.db_type, mode) {
+            (Some(db_type), _) => db_type,
+            (None, ImportWriteMode::Insert | ImportWriteMode::TruncateInsert) => {
+                DatabaseType::SQLite
+            }
+            (None, _) => return Err(format!("{}需要确定目标数据库类型", mode.label())),
+        };
+
+        let mut key_positions = Vec::new();
+        if mode.uses_conflict_key() {
+            for key in &write.conflict_columns {
+                let position = target_columns
+                    .iter()
+                    .position(|column| column.as_ref() == key)
+                    .ok_or_else(|| format!("冲突键列 {} 不在导入的列中", key))?;
+                key_positions.push(position);
+            }
+        }
+        if mode.requires_conflict_key(db_type) && key_positions.is_empty() {
+            return Err(format!("{}需要选择冲突键（主键或唯一键）", mode.label()));
+        }
+
+        let conflict_target = if key_positions.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " ({})",
+                key_positions
+                    .iter()
+                    .map(|&idx| quote(target_columns[idx].as_ref()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        };
+        // UPSERT 只更新非冲突键列
+        let update_columns: Vec<String> = target_columns
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !key_positions.contains(idx))
+            .map(|(_, column)| quote(column.as_ref()))
+            .collect();
+
+        let insert = format!("INSERT INTO {} ({}) VALUES (", table, columns_str);
+        let (prefix, suffix) = match (mode, db_type) {
+            (ImportWriteMode::Insert | ImportWriteMode::TruncateInsert, _) => {
+                (insert, ");".to_string())
+            }
+            (ImportWriteMode::InsertIgnore, DatabaseType::MySQL) => (
+                format!("INSERT IGNORE INTO {} ({}) VALUES (", table, columns_str),
+                ");".to_string(),
+            ),
+            (ImportWriteMode::InsertIgnore, _) => (
+                insert,
+                format!(") ON CONFLICT{} DO NOTHING;", conflict_target),
+            ),
+            (ImportWriteMode::Upsert, DatabaseType::MySQL) if update_columns.is_empty() => (
+                format!("INSERT IGNORE INTO {} ({}) VALUES (", table, columns_str),
+                ");".to_string(),
+            ),
+            (ImportWriteMode::Upsert, DatabaseType::MySQL) => (
+                insert,
+                format!(
+                    ") ON DUPLICATE KEY UPDATE {};",
+                    update_columns
+                        .iter()
+                        .map(|column| format!("{0} = VALUES({0})", column))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            ),
+            (ImportWriteMode::Upsert, _) if update_columns.is_empty() => (
+                insert,
+                format!(") ON CONFLICT{} DO NOTHING;", conflict_target),
+            ),
+            (ImportWriteMode::Upsert, _) => (
+                insert,
+                format!(
+                    ") ON CONFLICT{} DO UPDATE SET {};",
+                    conflict_target,
+                    update_columns
+                        .iter()
+                        .map(|column| format!("{0} = excluded.{0}", column))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            ),
+            // PostgreSQL 没有 REPLACE，改为按冲突键删除后插入
+            (ImportWriteMode::Replace, DatabaseType::PostgreSQL) => (insert, ");".to_string()),
+            (ImportWriteMode::Replace, _) => (
+                format!("REPLACE INTO {} ({}) VALUES (", table, columns_str),
+                ");".to_string(),
+            ),
+        };
+
+        let delete_keys = if mode == ImportWriteMode::Replace && db_type == DatabaseType::PostgreSQL
+        {
+            key_positions
+                .iter()
+                .map(|&idx| (quote(target_columns[idx].as_ref()), idx))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        // SQLite 没有 TRUNCATE；MySQL 的 TRUNCATE 会隐式提交事务，后续插入失败时无法回滚清空，
+        // 因此只有 PostgreSQL 使用 TRUNCATE
+        let mut statements = Vec::new();
+        if mode == ImportWriteMode::TruncateInsert {
+            statements.push(match db_type {
+                DatabaseType::PostgreSQL => format!("TRUNCATE TABLE {};", table),
+                DatabaseType::MySQL | DatabaseType::SQLite => format!("DELETE FROM {};", table),
+            });
+        }
+
+        Ok(Self {
+            prefix,
+            suffix,
+            delete_keys,
+            table,
+            rows: 0,
+            statements,
+        })
+    }
+
+    /// 写入一行；`values` 为按目标列顺序排列的 SQL 字面量
+    pub(crate) fn push_row(&mut self, values: &[String]) {
+        if !self.delete_keys.is_empty() {
+            let condition = self
+                .delete_keys
+                .iter()
+                .map(|(column, idx)| {
+                    // `= NULL` 不匹配任何行，NULL 键需用 IS NULL 才能删掉旧行
+                    if values[*idx].eq_ignore_ascii_case("NULL") {
+                        format!("{} IS NULL", column)
+                    } else {
+                        format!("{} = {}", column, values[*idx])
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" AND ");
+            self.statements
+                .push(format!("DELETE FROM {} WHERE {};", self.table, condition));
+        }
+        self.statements.push(format!(
+            "{}{}{}",
+            self.prefix,
+            values.join(", "),
+            self.suffix
+        ));
+        self.rows += 1;
+    }
+
+    /// 已写入的行数
+    pub(crate) fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// 再写入 `rows` 行后累计的语句数，不实际生成语句
+    pub(crate) fn statement_count_after(&self, rows: usize) -> usize {
+        let per_row = if self.delete_keys.is_empty() { 1 } else { 2 };
+        self.statements.len() + rows * per_row
+    }
+
+    /// 尚未取走的语句数
+    pub(crate) fn pending(&self) -> usize {
+        self.statements.len()
+    }
+
+    /// 取走已生成的语句，供按批交给执行器
+    pub(crate) fn take_statements(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.statements)
+    }
+
+    pub(crate) fn finish(self) -> ImportResult {
+        ImportResult {
+            sql_statements: self.statements,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn write(mode: ImportWriteMode, db_type: DatabaseType, keys: &[&str]) -> ImportWriteOptions {
+        ImportWriteOptions {
+            mode,
+            conflict_columns: columns(keys),
+            db_type: Some(db_type),
+        }
+    }
+
+    fn statements(write: &ImportWriteOptions) -> Vec<String> {
+        let mut writer =
+            InsertWriter::new("codes", &columns(&["id", "label"]), write).expect("writer");
+        writer.push_row(&["1".to_string(), "'a'".to_string()]);
+        writer.finish().sql_statements
+    }
+
+    #[test]
+    fn upsert_and_ignore_follow_the_target_dialect() {
+        assert_eq!(
+            statements(&write(
+                ImportWriteMode::Upsert,
+                DatabaseType::PostgreSQL,
+                &["id"]
+            )),
+            vec![
+                r#"INSERT INTO "codes" ("id", "label") VALUES (1, 'a') ON CONFLICT ("id") DO UPDATE SET "label" = excluded."label";"#
+            ]
+        );
+        assert_eq!(
+            statements(&write(
+                ImportWriteMode::Upsert,
+                DatabaseType::MySQL,
+                &["id"]
+            )),
+            vec![
+                "INSERT INTO `codes` (`id`, `label`) VALUES (1, 'a') ON DUPLICATE KEY UPDATE `label` = VALUES(`label`);"
+            ]
+        );
+        assert_eq!(
+            statements(&write(
+                ImportWriteMode::InsertIgnore,
+                DatabaseType::SQLite,
+                &[]
+            )),
+            vec![r#"INSERT INTO "codes" ("id", "label") VALUES (1, 'a') ON CONFLICT DO NOTHING;"#]
+        );
+        assert_eq!(
+            statements(&write(
+                ImportWriteMode::InsertIgnore,
+                DatabaseType::MySQL,
+                &[]
+            )),
+            vec!["INSERT IGNORE INTO `codes` (`id`, `label`) VALUES (1, 'a');"]
+        );
+    }
+
+    #[test]
+    fn replace_and_truncate_emit_extra_statements_and_keys_are_validated() {
+        assert_eq!(
+            statements(&write(
+                ImportWriteMode::Replace,
+                DatabaseType::PostgreSQL,
+                &["id"]
+            )),
+            vec![
+                r#"DELETE FROM "codes" WHERE "id" = 1;"#,
+                r#"INSERT INTO "codes" ("id", "label") VALUES (1, 'a');"#,
+            ]
+        );
+        assert_eq!(
+            statements(&write(ImportWriteMode::Replace, DatabaseType::SQLite, &[])),
+            vec![r#"REPLACE INTO "codes" ("id", "label") VALUES (1, 'a');"#]
+        );
+        assert_eq!(
+            statements(&write(
+                ImportWriteMode::TruncateInsert,
+                DatabaseType::SQLite,
+                &[]
+            )),
+            vec![
+                r#"DELETE FROM "codes";"#,
+                r#"INSERT INTO "codes" ("id", "label") VALUES (1, 'a');"#,
+            ]
+        );
+
+        assert_eq!(
+            statements(&write(
+                ImportWriteMode::TruncateInsert,
+                DatabaseType::MySQL,
+                &[]
+            ))[0],
+            "DELETE FROM `codes`;"
+        );
+
+        let target = columns(&["id", "label"]);
+        let write_options = write(ImportWriteMode::Replace, DatabaseType::PostgreSQL, &["id"]);
+        let mut writer = InsertWriter::new("codes", &target, &write_options).expect("writer");
+        writer.push_row(&["NULL".to_string(), "'a'".to_string()]);
+        assert_eq!(
+            writer.finish().sql_statements[0],
+            r#"DELETE FROM "codes" WHERE "id" IS NULL;"#
+        );
+
+        let missing_key = write(ImportWriteMode::Upsert, DatabaseType::PostgreSQL, &[]);
+        assert!(InsertWriter::new("codes", &target, &missing_key).is_err());
+        let unknown_key = write(ImportWriteMode::Upsert, DatabaseType::SQLite, &["code"]);
+        let error = InsertWriter::new("codes", &target, &unknown_key)
+            .err()
+            .expect("unknown key");
+        assert!(error.contains("code"));
+    }
+}
@@ -1,6 +1,7 @@
 //! 核心模块 - 包含配置、主题、语法高亮、历史记录、导出等核心功能
 
 mod autocomplete;
+mod columnar;
 mod commands;
 mod config;
 pub mod constants;
@@ -8,9 +9,13 @@ mod export;
 mod formatter;
 pub mod hash;
 mod history;
+mod import_write;
 mod keybindings;
+mod ndjson;
 mod notification;
+mod paging;
 mod progress;
+mod spreadsheet;
 mod syntax;
 mod theme;
 mod transfer;
@@ -21,35 +26,45 @@ pub use commands::{
     MISSING_SCOPED_COMMAND, ScopedCommand, ScopedCommandBinding, scoped_command, scoped_commands,
 };
 pub use config::{
-    AppConfig, BottomPanelConfig, BottomPanelTab, EditorAreaConfig, PrimarySidebarConfig,
-    ResultPlacement, RightInspectorConfig, RightInspectorTab, StatusBarConfig, TableOpenMode,
-    WorkbenchActivity, WorkbenchBehaviorConfig, WorkbenchConfig, WorkbenchDensity,
+    AppConfig, BottomPanelConfig, BottomPanelTab, EditorAreaConfig, FilterPreset,
+    FilterPresetCondition, PrimarySidebarConfig, ReferenceLabelColumn, ResultPlacement,
+    RightInspectorConfig, RightInspectorTab, StatusBarConfig, TableOpenMode, WorkbenchActivity,
+    WorkbenchBehaviorConfig, WorkbenchConfig, WorkbenchDensity,
 };
+pub(crate) use export::render_export_content_for_transfer;
 #[allow(unused_imports)] // parse_csv_line 等供测试使用
 pub use export::{
-    CsvImportConfig, ExportFormat, ExportOptions, JsonImportConfig, SqlDialect, export_to_path,
-    filter_result_for_export, import_csv_to_sql, import_json_to_sql, json_value_to_sql,
-    parse_csv_line, preview_csv, preview_export, preview_json, sql_value_from_string,
+    CsvImportConfig, ExportFormat, ExportOptions, JsonImportConfig, SqlDialect, StreamingExporter,
+    TableMarkup, export_to_path, filter_result_for_export, import_csv_to_sql, import_json_to_sql,
+    json_value_to_sql, parse_csv_line, preview_csv, preview_export, preview_json,
+    sql_value_from_string,
 };
 pub use formatter::format_sql;
 pub use history::QueryHistory;
+pub use import_write::{ImportWriteMode, ImportWriteOptions};
 #[allow(unused_imports)] // 公开 API，供未来使用
 pub use keybindings::{
     Action, KeyBinding, KeyBindings, KeyCode, KeyModifiers, KeymapDiagnostic, KeymapDiagnosticCode,
     KeymapDiagnosticSeverity,
 };
+#[allow(unused_imports)] // 公开 API：预览与 preview_json 对应，语句来源出现在导入计划中
+pub use ndjson::{NdjsonStatementSource, preview_ndjson};
 pub use notification::{Notification, NotificationLevel, NotificationManager};
+pub use paging::{PageRequest, TablePager, sql_literal};
 #[allow(unused_imports)] // 公开 API，供外部使用
 pub use progress::{ProgressManager, ProgressTask};
+#[allow(unused_imports)] // 公开 API，供传输会话与导入对话框使用
+pub use spreadsheet::{SheetHeader, SheetLayout, XlsxImportConfig, preview_xlsx};
 #[allow(unused_imports)] // 公开 API
 pub use syntax::{HighlightColors, SqlHighlighter, clear_highlight_cache, highlight_sql};
 pub use theme::{ThemeManager, ThemePreset};
 #[allow(unused_imports)] // 公开 API，供应用层与 UI 的传输工作流使用
 pub use transfer::{
-    TransferDelimitedOptions, TransferDirection, TransferExecutionPayload, TransferExecutionPlan,
-    TransferField, TransferFieldMapping, TransferFormat, TransferFormatOptions,
-    TransferJsonOptions, TransferMapping, TransferPreview, TransferRowWindow, TransferSchema,
-    TransferSession, TransferSqlOptions, plan_export_transfer, plan_import_transfer,
-    plan_sql_transfer_content, preview_export_transfer, preview_import_transfer,
-    preview_sql_transfer_content, write_transfer_plan,
+    TransferColumnarOptions, TransferDelimitedOptions, TransferDirection, TransferExecutionPayload,
+    TransferExecutionPlan, TransferField, TransferFieldMapping, TransferFormat,
+    TransferFormatOptions, TransferJsonOptions, TransferMapping, TransferPreview,
+    TransferRowWindow, TransferSchema, TransferSession, TransferSpreadsheetOptions,
+    TransferSqlOptions, import_source_columns, open_streaming_export, plan_export_transfer,
+    plan_import_transfer, plan_sql_transfer_content, preview_export_transfer,
+    preview_import_transfer, preview_sql_transfer_content, split_sql_script, write_transfer_plan,
 };
@@ -0,0 +1,548 @@
+//! NDJSON（JSON Lines）导入导出
+//!
+//! 每行一个 JSON 值。与 JSON 数组不同，文件逐行读取，不整体载入内存，因此没有文件大小上限。
+//! 嵌套对象与数组作为 `DbValue::Json` 单元格保留（启用展平时改为 a.b 列）；
+//! 无法解析的行不会中止读取，预览中逐行列出，导入时跳过。
+//! 导入先扫描一遍确定列与行数，执行时再读一遍、按批生成语句交给执行器，不持有完整语句列表。
+
+use super::export::{
+    ImportPreview, JsonImportConfig, export_json_value, normalize_json_item,
+    projected_source_indices, projected_target_columns,
+};
+use super::import_write::{ImportWriteOptions, InsertWriter};
+use crate::domain::result::ResultSet;
+use crate::domain::value::DbValue;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+/// 导入预览最多展示的数据行数
+const PREVIEW_ROWS: usize = 100;
+/// 预览中逐条列出的解析错误数
+const MAX_LISTED_ERRORS: usize = 20;
+
+// ============================================================================
+// 导出
+// ============================================================================
+
+/// 一行结果写为一个 JSON 对象；JSON 列按原结构嵌套，其余值与 JSON 导出一致写为字符串
+pub(crate) fn ndjson_row_line(result: &ResultSet, row_idx: usize) -> Result<String, String> {
+    let object: serde_json::Map<String, serde_json::Value> = result
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(col_idx, column)| {
+            let value = match result.cell(row_idx, col_idx) {
+                DbValue::Json(json) => json.clone(),
+                _ => export_json_value(result, row_idx, col_idx),
+            };
+            (column.name.clone(), value)
+        })
+        .collect();
+    serde_json::to_string(&object).map_err(|e| e.to_string())
+}
+
+/// 整个结果集写为 NDJSON，每行以换行结尾
+pub(crate) fn render_ndjson(result: &ResultSet) -> Result<String, String> {
+    let mut output = String::new();
+    for row_idx in 0..result.row_count {
+        output.push_str(&ndjson_row_line(result, row_idx)?);
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+// ============================================================================
+// 导入
+// ============================================================================
+
+/// 一个非空行的读取结果
+enum NdjsonRecord {
+    Value(serde_json::Value),
+    Invalid { line: usize, error: String },
+}
+
+/// 逐行读取 NDJSON 文件，跳过空行
+struct NdjsonReader {
+    reader: BufReader<File>,
+    buffer: Vec<u8>,
+    line: usize,
+}
+
+impl NdjsonReader {
+    fn open(path: &Path) -> Result<Self, String> {
+        let file = File::open(path).map_err(|e| format!("无法打开文件: {}", e))?;
+        Ok(Self {
+            reader: BufReader::new(file),
+            buffer: Vec::new(),
+            line: 0,
+        })
+    }
+}
+
+impl Iterator for NdjsonReader {
+    type Item = Result<NdjsonRecord, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.buffer.clear();
+            match self.reader.read_until(b'\n', &mut self.buffer) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(e) => return Some(Err(format!("读取文件失败: {}", e))),
+            }
+            self.line += 1;
+
+            let mut bytes = self.buffer.as_slice();
+            if self.line == 1 {
+                bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+            }
+            if bytes.trim_ascii().is_empty() {
+                continue;
+            }
+            return Some(Ok(match serde_json::from_slice(bytes) {
+                Ok(value) => NdjsonRecord::Value(value),
+                Err(e) => NdjsonRecord::Invalid {
+                    line: self.line,
+                    error: e.to_string(),
+                },
+            }));
+        }
+    }
+}
+
+/// 按首次出现的顺序累积对象字段
+#[derive(Default)]
+struct ColumnCollector {
+    columns: Vec<String>,
+    seen: HashSet<String>,
+    has_non_object: bool,
+}
+
+impl ColumnCollector {
+    fn add(&mut self, value: &serde_json::Value, flatten_nested: bool) {
+        match normalize_json_item(value, flatten_nested).as_ref() {
+            serde_json::Value::Object(object) => {
+                for key in object.keys() {
+                    if self.seen.insert(key.clone()) {
+                        self.columns.push(key.clone());
+                    }
+                }
+            }
+            _ => self.has_non_object = true,
+        }
+    }
+
+    /// 没有任何对象字段时整行写入 value 列
+    fn finish(self) -> (Vec<String>, bool) {
+        if self.columns.is_empty() {
+            (vec!["value".to_string()], true)
+        } else {
+            (self.columns, self.has_non_object)
+        }
+    }
+}
+
+/// JSON 值对应的单元格：嵌套对象与数组保留为 JSON
+fn ndjson_cell(value: &serde_json::Value) -> DbValue {
+    match value {
+        serde_json::Value::Null => DbValue::Null,
+        serde_json::Value::Bool(value) => DbValue::Bool(*value),
+        serde_json::Value::Number(number) => {
+            if let Some(value) = number.as_i64() {
+                DbValue::Int(value)
+            } else if let Some(value) = number.as_u64() {
+                DbValue::UInt(value)
+            } else {
+                DbValue::Float(number.as_f64().unwrap_or(f64::NAN))
+            }
+        }
+        serde_json::Value::String(text) => DbValue::Text(text.clone()),
+        serde_json::Value::Array(_) | serde_json::Value::Object(_) => DbValue::Json(value.clone()),
+    }
+}
+
+/// 一条记录按列名取出单元格；非对象记录写入第一列，其余列为 NULL
+fn record_cells(
+    value: &serde_json::Value,
+    columns: &[String],
+    flatten_nested: bool,
+) -> Vec<DbValue> {
+    match normalize_json_item(value, flatten_nested).as_ref() {
+        serde_json::Value::Object(object) => columns
+            .iter()
+            .map(|column| object.get(column).map_or(DbValue::Null, ndjson_cell))
+            .collect(),
+        other => {
+            let mut cells = vec![DbValue::Null; columns.len()];
+            if let Some(first) = cells.first_mut() {
+                *first = ndjson_cell(other);
+            }
+            cells
+        }
+    }
+}
+
+/// 单元格对应的 SQL 字面量，与 JSON 导入的取值规则一致
+fn ndjson_sql_literal(value: &DbValue) -> String {
+    match value {
+        DbValue::Null => "NULL".to_string(),
+        DbValue::Bool(value) => if *value { "1" } else { "0" }.to_string(),
+        DbValue::Int(value) => value.to_string(),
+        DbValue::UInt(value) => value.to_string(),
+        DbValue::Float(value) if value.is_finite() => value.to_string(),
+        DbValue::Json(json) => format!("'{}'", json.to_string().replace('\'', "''")),
+        other => format!("'{}'", other.display().replace('\'', "''")),
+    }
+}
+
+/// 一遍扫描 NDJSON 文件得到的列、样本与统计
+struct NdjsonScan {
+    columns: Vec<String>,
+    has_non_object: bool,
+    /// 前 `PREVIEW_ROWS` 条记录
+    sample: Vec<serde_json::Value>,
+    /// 可解析的记录数
+    total_rows: usize,
+    error_count: usize,
+    /// 前 `MAX_LISTED_ERRORS` 条解析错误
+    errors: Vec<String>,
+}
+
+/// 扫描整个文件：列取自将要导入的记录（`max_rows` 以内），同时统计行数与解析错误
+fn scan_ndjson(path: &Path, config: &JsonImportConfig) -> Result<NdjsonScan, String> {
+    let column_rows = import_row_limit(config);
+    let mut collector = ColumnCollector::default();
+    let mut sample = Vec::new();
+    let mut total_rows = 0;
+    let mut errors = Vec::new();
+    let mut error_count = 0;
+
+    for record in NdjsonReader::open(path)? {
+        match record? {
+            NdjsonRecord::Value(value) => {
+                if total_rows < column_rows {
+                    collector.add(&value, config.flatten_nested);
+                }
+                if sample.len() < PREVIEW_ROWS {
+                    sample.push(value);
+                }
+                total_rows += 1;
+            }
+            NdjsonRecord::Invalid { line, error } => {
+                error_count += 1;
+                if errors.len() < MAX_LISTED_ERRORS {
+                    errors.push(format!("第 {} 行解析失败: {}", line, error));
+                }
+            }
+        }
+    }
+
+    if total_rows == 0 {
+        return Err(match errors.first() {
+            Some(error) => format!("没有可解析的行，{}", error),
+            None => "NDJSON 文件为空".to_string(),
+        });
+    }
+
+    let (columns, has_non_object) = collector.finish();
+    Ok(NdjsonScan {
+        columns,
+        has_non_object,
+        sample,
+        total_rows,
+        error_count,
+        errors,
+    })
+}
+
+/// `max_rows` 为 0 表示不限制
+fn import_row_limit(config: &JsonImportConfig) -> usize {
+    if config.max_rows > 0 {
+        config.max_rows
+    } else {
+        usize::MAX
+    }
+}
+
+impl NdjsonScan {
+    fn into_preview(self, config: &JsonImportConfig) -> ImportPreview {
+        let mut warnings = Vec::new();
+        if self.columns.len() == 1 && self.columns[0] == "value" {
+            warnings.push("未检测到可用对象字段，使用 value 列导入".to_string());
+        } else if self.has_non_object {
+            warnings.push("检测到非对象行，将其写入第一个列，其余列填充 NULL".to_string());
+        }
+        if self.error_count > 0 {
+            warnings.push(format!("{} 行无法解析，导入时将跳过", self.error_count));
+            warnings.extend(self.errors);
+            if self.error_count > MAX_LISTED_ERRORS {
+                warnings.push(format!(
+                    "另有 {} 行解析失败未列出",
+                    self.error_count - MAX_LISTED_ERRORS
+                ));
+            }
+        }
+
+        let preview_rows = self
+            .sample
+            .iter()
+            .map(|value| {
+                record_cells(value, &self.columns, config.flatten_nested)
+                    .iter()
+                    .map(DbValue::display)
+                    .collect()
+            })
+            .collect();
+
+        ImportPreview {
+            columns: self.columns,
+            preview_rows,
+            total_rows: self.total_rows,
+            warnings,
+        }
+    }
+}
+
+/// 预览 NDJSON 文件：统计全部行，列出解析失败的行
+pub fn preview_ndjson(path: &Path, config: &JsonImportConfig) -> Result<ImportPreview, String> {
+    Ok(scan_ndjson(path, config)?.into_preview(config))
+}
+
+/// 扫描后的 NDJSON 导入：预览、样本语句与执行时使用的语句来源
+pub(crate) struct NdjsonImport {
+    pub(crate) preview: ImportPreview,
+    /// 预览样本行生成的语句
+    pub(crate) sample_statements: Vec<String>,
+    /// 完整导入将生成的语句数
+    pub(crate) statement_count: usize,
+    pub(crate) source: NdjsonStatementSource,
+}
+
+/// 扫描一遍 NDJSON 文件，准备导入；可按 `(源列索引, 目标列名)` 投影列
+///
+/// 只生成样本行的语句，完整语句由 `NdjsonStatementSource` 在执行时按批生成。
+pub(crate) fn prepare_ndjson_import(
+    path: &Path,
+    config: &JsonImportConfig,
+    write: &ImportWriteOptions,
+    projection: Option<&[(usize, String)]>,
+) -> Result<NdjsonImport, String> {
+    if config.table_name.is_empty() {
+        return Err("未指定目标表名".to_string());
+    }
+
+    let scan = scan_ndjson(path, config)?;
+    let source = NdjsonStatementSource {
+        path: path.to_path_buf(),
+        config: config.clone(),
+        write: write.clone(),
+        columns: scan.columns.clone(),
+        projection: projection.map(<[_]>::to_vec),
+    };
+    let (mut writer, source_indices) = source.writer()?;
+    let statement_count =
+        writer.statement_count_after(scan.total_rows.min(import_row_limit(config)));
+    for value in scan.sample.iter().take(import_row_limit(config)) {
+        source.push_record(&mut writer, &source_indices, value);
+    }
+
+    Ok(NdjsonImport {
+        sample_statements: writer.take_statements(),
+        statement_count,
+        preview: scan.into_preview(config),
+        source,
+    })
+}
+
+/// NDJSON 导入的写入语句来源：执行时再读一遍文件，按批生成语句
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NdjsonStatementSource {
+    path: PathBuf,
+    config: JsonImportConfig,
+    write: ImportWriteOptions,
+    /// 扫描时确定的源列
+    columns: Vec<String>,
+    projection: Option<Vec<(usize, String)>>,
+}
+
+impl NdjsonStatementSource {
+    fn writer(&self) -> Result<(InsertWriter, Vec<usize>), String> {
+        let projection = self.projection.as_deref();
+        let source_indices = projected_source_indices(&self.columns, projection)?;
+        let writer = InsertWriter::new(
+            &self.config.table_name,
+            &projected_target_columns(&self.columns, projection),
+            &self.write,
+        )?;
+        Ok((writer, source_indices))
+    }
+
+    fn push_record(
+        &self,
+        writer: &mut InsertWriter,
+        source_indices: &[usize],
+        value: &serde_json::Value,
+    ) {
+        let cells = record_cells(value, &self.columns, self.config.flatten_nested);
+        let values = source_indices
+            .iter()
+            .map(|&idx| ndjson_sql_literal(&cells[idx]))
+            .collect::<Vec<_>>();
+        writer.push_row(&values);
+    }
+
+    /// 逐批交出写入语句，每批约 `batch_len` 条；`sink` 返回 `false` 时停止读取
+    pub(crate) fn for_each_batch(
+        &self,
+        batch_len: usize,
+        mut sink: impl FnMut(Vec<String>) -> bool,
+    ) -> Result<(), String> {
+        let (mut writer, source_indices) = self.writer()?;
+        let row_limit = import_row_limit(&self.config);
+        for record in NdjsonReader::open(&self.path)? {
+            if writer.rows() >= row_limit {
+                break;
+            }
+            let NdjsonRecord::Value(value) = record? else {
+                continue;
+            };
+            self.push_record(&mut writer, &source_indices, &value);
+            if writer.pending() >= batch_len && !sink(writer.take_statements()) {
+                return Ok(());
+            }
+        }
+
+        let rest = writer.take_statements();
+        if !rest.is_empty() {
+            sink(rest);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::result::{ResultColumn, ResultCompleteness};
+    use crate::domain::value::{DbTypeFamily, DbTypeInfo};
+    use std::sync::Arc;
+    use tempfile::NamedTempFile;
+
+    fn write_lines(content: &str) -> NamedTempFile {
+        let file = NamedTempFile::new().expect("temp file");
+        std::fs::write(file.path(), content).expect("write ndjson");
+        file
+    }
+
+    fn config() -> JsonImportConfig {
+        JsonImportConfig {
+            table_name: "events".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn preview_reports_bad_lines_and_keeps_nested_objects_as_json() {
+        let file = write_lines(concat!(
+            "{\"id\":1,\"meta\":{\"ip\":\"10.0.0.1\"}}\n",
+            "\n",
+            "{\"id\":2,\"tags\":[\"a\"]\r\n",
+            "{\"id\":3,\"tags\":[\"b\"],\"note\":\"it's\"}\n",
+        ));
+
+        let preview = preview_ndjson(file.path(), &config()).expect("preview");
+        assert_eq!(preview.columns, ["id", "meta", "note", "tags"]);
+        assert_eq!(preview.total_rows, 2);
+        assert_eq!(preview.preview_rows[0][1], r#"{"ip":"10.0.0.1"}"#);
+        assert_eq!(preview.preview_rows[1][3], r#"["b"]"#);
+        assert_eq!(preview.warnings[0], "1 行无法解析，导入时将跳过");
+        assert!(preview.warnings[1].starts_with("第 3 行解析失败"));
+
+        let import =
+            prepare_ndjson_import(file.path(), &config(), &ImportWriteOptions::default(), None)
+                .expect("import");
+        assert_eq!(import.statement_count, 2);
+        let mut statements = Vec::new();
+        import
+            .source
+            .for_each_batch(100, |batch| {
+                statements.extend(batch);
+                true
+            })
+            .expect("statements");
+        assert_eq!(statements, import.sample_statements);
+        assert_eq!(
+            statements,
+            [
+                r#"INSERT INTO "events" ("id", "meta", "note", "tags") VALUES (1, '{"ip":"10.0.0.1"}', NULL, NULL);"#,
+                r#"INSERT INTO "events" ("id", "meta", "note", "tags") VALUES (3, NULL, 'it''s', '["b"]');"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn statement_source_yields_bounded_batches_and_stops_when_asked() {
+        let lines: String = (1..=250).map(|id| format!("{{\"id\":{}}}\n", id)).collect();
+        let file = write_lines(&lines);
+
+        let import =
+            prepare_ndjson_import(file.path(), &config(), &ImportWriteOptions::default(), None)
+                .expect("import");
+        // 预览只生成样本行的语句
+        assert_eq!(import.statement_count, 250);
+        assert_eq!(import.sample_statements.len(), PREVIEW_ROWS);
+
+        let mut sizes = Vec::new();
+        import
+            .source
+            .for_each_batch(100, |batch| {
+                sizes.push(batch.len());
+                true
+            })
+            .expect("batches");
+        assert_eq!(sizes, [100, 100, 50]);
+
+        let mut calls = 0;
+        import
+            .source
+            .for_each_batch(100, |_| {
+                calls += 1;
+                false
+            })
+            .expect("stopped");
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn exported_lines_nest_json_columns() {
+        let column = |name: &str, family| ResultColumn {
+            name: name.to_string(),
+            type_info: DbTypeInfo {
+                family,
+                native_name: String::new(),
+                nullable: None,
+            },
+        };
+        let result = ResultSet {
+            columns: Arc::from(vec![
+                column("id", DbTypeFamily::Integer),
+                column("payload", DbTypeFamily::Json),
+            ]),
+            cells: vec![
+                DbValue::Int(7),
+                DbValue::Json(serde_json::json!({"level": "warn"})),
+                DbValue::Int(8),
+                DbValue::Null,
+            ],
+            row_count: 2,
+            completeness: ResultCompleteness::Complete,
+        };
+
+        assert_eq!(
+            render_ndjson(&result).expect("render"),
+            "{\"id\":\"7\",\"payload\":{\"level\":\"warn\"}}\n{\"id\":\"8\",\"payload\":null}\n"
+        );
+    }
+}
@@ -0,0 +1,663 @@
+//! 表格分页浏览
+//!
+//! 打开表时不再一次性 `LIMIT` 截断，而是按页按需拉取：
+//! - 有主键时使用 keyset 分页（`WHERE (pk) > (last_pk) ORDER BY pk`），翻页代价与页码无关
+//! - 无主键或键值无法安全写成字面量时回退到 `LIMIT/OFFSET`
+//!
+//! 每页多取一行作为前瞻，用于判断是否还有下一页；上一页以倒序拉取后在客户端翻转。
+//! 设置筛选谓词后，各页查询都带上该谓词，分页在筛选结果上进行。
+
+use crate::domain::identifier::IdentifierDialect;
+use crate::domain::result::ResultSet;
+use crate::domain::value::DbValue;
+
+/// 翻页请求
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageRequest {
+    /// 第一页
+    First,
+    /// 下一页
+    Next,
+    /// 上一页
+    Previous,
+}
+
+/// 已发出、等待结果的翻页查询
+#[derive(Debug, Clone)]
+struct PendingPage {
+    request: PageRequest,
+    sql: String,
+    /// 是否按倒序拉取（keyset 上一页）
+    reversed: bool,
+    /// 是否带一行前瞻
+    lookahead: bool,
+}
+
+/// 单张表的分页游标
+#[derive(Debug, Clone)]
+pub struct TablePager {
+    /// 已引用的表名
+    table_ref: String,
+    dialect: IdentifierDialect,
+    /// 排序/keyset 列（主键）；为空时只能使用 OFFSET 分页
+    key_columns: Vec<String>,
+    /// 下推到数据库的筛选谓词
+    filter: Option<String>,
+    page_size: usize,
+    page_index: usize,
+    first_key: Option<Vec<DbValue>>,
+    last_key: Option<Vec<DbValue>>,
+    has_next: bool,
+    estimated_rows: Option<u64>,
+    pending: Option<PendingPage>,
+}
+
+impl TablePager {
+    /// 创建分页游标；`table_ref` 必须已按方言引用
+    pub fn new(
+        table_ref: String,
+        dialect: IdentifierDialect,
+        key_columns: Vec<String>,
+        page_size: usize,
+    ) -> Self {
+        Self {
+            table_ref,
+            dialect,
+            key_columns,
+            filter: None,
+            page_size: page_size.max(1),
+            page_index: 0,
+            first_key: None,
+            last_key: None,
+            has_next: false,
+            estimated_rows: None,
+            pending: None,
+        }
+    }
+
+    pub fn table_ref(&self) -> &str {
+        &self.table_ref
+    }
+
+    pub fn dialect(&self) -> IdentifierDialect {
+        self.dialect
+    }
+
+    pub fn filter(&self) -> Option<&str> {
+        self.filter.as_deref()
+    }
+
+    /// 设置筛选谓词（须为完整的布尔表达式），游标回到未加载状态，需随后 `restart`
+    pub fn set_filter(&mut self, filter: Option<String>) {
+        self.filter = filter;
+        self.page_index = 0;
+        self.first_key = None;
+        self.last_key = None;
+        self.has_next = false;
+        self.pending = None;
+    }
+
+    pub fn page_index(&self) -> usize {
+        self.page_index
+    }
+
+    pub fn has_previous(&self) -> bool {
+        self.page_index > 0
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.has_next
+    }
+
+    /// 是否使用 keyset 分页
+    pub fn uses_keyset(&self) -> bool {
+        !self.key_columns.is_empty()
+    }
+
+    pub fn set_estimated_rows(&mut self, estimate: Option<u64>) {
+        self.estimated_rows = estimate;
+    }
+
+    /// 是否有翻页查询在途
+    pub fn is_loading(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// 给定 SQL 是否为本游标发出、尚未完成的翻页查询
+    pub fn owns_sql(&self, sql: &str) -> bool {
+        self.pending
+            .as_ref()
+            .is_some_and(|pending| pending.sql == sql)
+    }
+
+    /// 回到第一页，返回首页 SQL 并记为在途
+    pub fn restart(&mut self) -> String {
+        let pending = self.first_page_query();
+        let sql = pending.sql.clone();
+        self.pending = Some(pending);
+        sql
+    }
+
+    /// 生成翻页 SQL 并记为在途；无法翻页（已在首/末页）时返回 `None`
+    pub fn begin(&mut self, request: PageRequest) -> Option<String> {
+        let pending = match request {
+            PageRequest::First => return Some(self.restart()),
+            PageRequest::Next => {
+                if !self.has_next {
+                    return None;
+                }
+                self.next_page_query()
+            }
+            PageRequest::Previous => {
+                if self.page_index == 0 {
+                    return None;
+                }
+                self.previous_page_query()
+            }
+        };
+        let sql = pending.sql.clone();
+        self.pending = Some(pending);
+        Some(sql)
+    }
+
+    /// 放弃在途翻页（查询失败或被取消）
+    pub fn abort(&mut self) {
+        self.pending = None;
+    }
+
+    /// 将在途翻页的查询结果裁剪为一页，并推进游标
+    ///
+    /// 返回完成的翻页请求；`sql` 不属于本游标时原样保留结果并返回 `None`。
+    pub fn complete(&mut self, sql: &str, result: &mut ResultSet) -> Option<PageRequest> {
+        if !self.owns_sql(sql) {
+            return None;
+        }
+        let pending = self.pending.take()?;
+
+        if pending.lookahead {
+            self.has_next = result.row_count > self.page_size;
+            truncate_rows(result, self.page_size);
+        }
+        if pending.reversed {
+            reverse_rows(result);
+            // 从后一页倒退回来，后面必然还有数据
+            self.has_next = true;
+        }
+
+        self.page_index = match pending.request {
+            PageRequest::First => 0,
+            PageRequest::Next => self.page_index + 1,
+            PageRequest::Previous => self.page_index.saturating_sub(1),
+        };
+        let key_indices = self.key_indices(result);
+        self.first_key = key_indices
+            .as_ref()
+            .filter(|_| result.row_count > 0)
+            .map(|indices| row_key(result, 0, indices));
+        self.last_key = key_indices
+            .as_ref()
+            .filter(|_| result.row_count > 0)
+            .map(|indices| row_key(result, result.row_count - 1, indices));
+        Some(pending.request)
+    }
+
+    /// 模式栏中显示的分页状态
+    pub fn status_label(&self) -> String {
+        let mut label = format!(
+            "第 {} 页（每页 {} 行）",
+            self.page_index + 1,
+            self.page_size
+        );
+        if self.filter.is_some() {
+            // 行数估算针对整张表，筛选后不再适用
+            label.push_str(" · 服务端筛选");
+        } else if let Some(estimate) = self.estimated_rows {
+            let pages = estimate.div_ceil(self.page_size as u64).max(1);
+            label.push_str(&format!(" · 约 {} 行 / {} 页", estimate, pages));
+        }
+        if !self.uses_keyset() {
+            label.push_str(" · OFFSET");
+        }
+        label
+    }
+
+    fn first_page_query(&self) -> PendingPage {
+        let sql = format!(
+            "SELECT * FROM {}{}{} LIMIT {};",
+            self.table_ref,
+            self.where_clause(None),
+            self.order_by_clause(false),
+            self.page_size + 1
+        );
+        PendingPage {
+            request: PageRequest::First,
+            sql,
+            reversed: false,
+            lookahead: true,
+        }
+    }
+
+    fn next_page_query(&self) -> PendingPage {
+        let sql = match self.keyset_predicate(self.last_key.as_deref(), ">") {
+            Some(predicate) => format!(
+                "SELECT * FROM {}{}{} LIMIT {};",
+                self.table_ref,
+                self.where_clause(Some(&predicate)),
+                self.order_by_clause(false),
+                self.page_size + 1
+            ),
+            None => self.offset_query((self.page_index + 1) * self.page_size),
+        };
+        PendingPage {
+            request: PageRequest::Next,
+            sql,
+            reversed: false,
+            lookahead: true,
+        }
+    }
+
+    fn previous_page_query(&self) -> PendingPage {
+        match self.keyset_predicate(self.first_key.as_deref(), "<") {
+            Some(predicate) => PendingPage {
+                request: PageRequest::Previous,
+                sql: format!(
+                    "SELECT * FROM {}{}{} LIMIT {};",
+                    self.table_ref,
+                    self.where_clause(Some(&predicate)),
+                    self.order_by_clause(true),
+                    self.page_size
+                ),
+                reversed: true,
+                lookahead: false,
+            },
+            None => PendingPage {
+                request: PageRequest::Previous,
+                sql: self.offset_query((self.page_index - 1) * self.page_size),
+                reversed: false,
+                lookahead: true,
+            },
+        }
+    }
+
+    fn offset_query(&self, offset: usize) -> String {
+        format!(
+            "SELECT * FROM {}{}{} LIMIT {} OFFSET {};",
+            self.table_ref,
+            self.where_clause(None),
+            self.order_by_clause(false),
+            self.page_size + 1,
+            offset
+        )
+    }
+
+    /// 合并筛选谓词与 keyset 谓词；两者都没有时返回空串
+    fn where_clause(&self, keyset: Option<&str>) -> String {
+        let predicates = self
+            .filter
+            .as_deref()
+            .into_iter()
+            .chain(keyset)
+            .collect::<Vec<_>>();
+        if predicates.is_empty() {
+            return String::new();
+        }
+        format!(" WHERE {}", predicates.join(" AND "))
+    }
+
+    fn order_by_clause(&self, descending: bool) -> String {
+        if self.key_columns.is_empty() {
+            return String::new();
+        }
+        let direction = if descending { " DESC" } else { "" };
+        let columns = self
+            .key_columns
+            .iter()
+            .map(|column| format!("{}{}", self.dialect.quote(column), direction))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(" ORDER BY {}", columns)
+    }
+
+    /// 构造行值比较谓词；任一键值无法写成字面量时返回 `None`（回退 OFFSET）
+    fn keyset_predicate(&self, key: Option<&[DbValue]>, operator: &str) -> Option<String> {
+        let key = key?;
+        if self.key_columns.is_empty() || key.len() != self.key_columns.len() {
+            return None;
+        }
+        let literals = key
+            .iter()
+            .map(|value| key_literal(value, self.dialect))
+            .collect::<Option<Vec<_>>>()?;
+        let columns = self
+            .key_columns
+            .iter()
+            .map(|column| self.dialect.quote(column))
+            .collect::<Vec<_>>();
+        if columns.len() == 1 {
+            Some(format!("{} {} {}", columns[0], operator, literals[0]))
+        } else {
+            Some(format!(
+                "({}) {} ({})",
+                columns.join(", "),
+                operator,
+                literals.join(", ")
+            ))
+        }
+    }
+
+    fn key_indices(&self, result: &ResultSet) -> Option<Vec<usize>> {
+        if self.key_columns.is_empty() {
+            return None;
+        }
+        self.key_columns
+            .iter()
+            .map(|key| result.columns.iter().position(|column| column.name == *key))
+            .collect()
+    }
+}
+
+fn row_key(result: &ResultSet, row: usize, indices: &[usize]) -> Vec<DbValue> {
+    indices
+        .iter()
+        .map(|index| result.cell(row, *index).clone())
+        .collect()
+}
+
+fn truncate_rows(result: &mut ResultSet, rows: usize) {
+    if result.row_count > rows {
+        result.cells.truncate(rows * result.column_count());
+        result.row_count = rows;
+    }
+}
+
+fn reverse_rows(result: &mut ResultSet) {
+    let width = result.column_count();
+    if width == 0 || result.row_count < 2 {
+        return;
+    }
+    let cells = std::mem::take(&mut result.cells);
+    result.cells = cells
+        .chunks(width)
+        .rev()
+        .flat_map(|row| row.iter().cloned())
+        .collect();
+}
+
+/// 将主键值写成 SQL 字面量；NULL 与浮点数无法可靠比较，返回 `None`
+fn key_literal(value: &DbValue, dialect: IdentifierDialect) -> Option<String> {
+    if matches!(value, DbValue::Null | DbValue::Float(_)) {
+        return None;
+    }
+    sql_literal(value, dialect)
+}
+
+/// 将值按方言写成 SQL 字面量；JSON、数组等没有通用字面量写法的值返回 `None`
+pub fn sql_literal(value: &DbValue, dialect: IdentifierDialect) -> Option<String> {
+    let literal = match value {
+        DbValue::Json(_) | DbValue::Array(_) | DbValue::Other { .. } => return None,
+        DbValue::Null => "NULL".to_string(),
+        DbValue::Float(value) => {
+            if !value.is_finite() {
+                return None;
+            }
+            value.to_string()
+        }
+        DbValue::Bool(value) => {
+            let (truthy, falsy) = match dialect {
+                IdentifierDialect::PostgreSql => ("TRUE", "FALSE"),
+                IdentifierDialect::MySql | IdentifierDialect::SQLite => ("1", "0"),
+            };
+            let literal = if *value { truthy } else { falsy };
+            literal.to_string()
+        }
+        DbValue::Int(value) => value.to_string(),
+        DbValue::UInt(value) => value.to_string(),
+        DbValue::Decimal(value) => {
+            if value.is_empty()
+                || !value
+                    .chars()
+                    .all(|ch| ch.is_ascii_digit() || matches!(ch, '-' | '+' | '.' | 'e' | 'E'))
+            {
+                return None;
+            }
+            value.clone()
+        }
+        DbValue::Bytes(bytes) => {
+            let hex = bytes
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<String>();
+            match dialect {
+                IdentifierDialect::PostgreSql => format!("'\\x{}'::bytea", hex),
+                IdentifierDialect::MySql | IdentifierDialect::SQLite => format!("X'{}'", hex),
+            }
+        }
+        DbValue::Time(time) => text_literal(&with_fraction(value.display(), time.nanos), dialect),
+        DbValue::DateTime(datetime) => text_literal(
+            &with_fraction(value.display(), datetime.time.nanos),
+            dialect,
+        ),
+        DbValue::Text(text) => text_literal(text, dialect),
+        DbValue::Date(_) | DbValue::Uuid(_) => text_literal(&value.display(), dialect),
+    };
+    Some(literal)
+}
+
+fn with_fraction(display: String, nanos: u32) -> String {
+    if nanos == 0 {
+        return display;
+    }
+    let fraction = format!("{:09}", nanos);
+    format!("{}.{}", display, fraction.trim_end_matches('0'))
+}
+
+fn text_literal(text: &str, dialect: IdentifierDialect) -> String {
+    let escaped = text.replace('\'', "''");
+    match dialect {
+        // MySQL 默认把反斜杠当作转义符
+        IdentifierDialect::MySql => format!("'{}'", escaped.replace('\\', "\\\\")),
+        IdentifierDialect::PostgreSql | IdentifierDialect::SQLite => format!("'{}'", escaped),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PageRequest, TablePager};
+    use crate::domain::identifier::IdentifierDialect;
+    use crate::domain::result::{ResultColumn, ResultCompleteness, ResultSet};
+    use crate::domain::value::{DbTypeFamily, DbTypeInfo, DbValue};
+    use std::sync::Arc;
+
+    fn page_result(ids: &[i64]) -> ResultSet {
+        let columns: Arc<[ResultColumn]> = Arc::new([
+            ResultColumn {
+                name: "id".into(),
+                type_info: DbTypeInfo {
+                    family: DbTypeFamily::Integer,
+                    native_name: "INTEGER".into(),
+                    nullable: Some(false),
+                },
+            },
+            ResultColumn {
+                name: "name".into(),
+                type_info: DbTypeInfo {
+                    family: DbTypeFamily::Text,
+                    native_name: "TEXT".into(),
+                    nullable: Some(true),
+                },
+            },
+        ]);
+        let cells = ids
+            .iter()
+            .flat_map(|id| [DbValue::Int(*id), DbValue::Text(format!("n{}", id))])
+            .collect();
+        ResultSet {
+            columns,
+            cells,
+            row_count: ids.len(),
+            completeness: ResultCompleteness::Complete,
+        }
+    }
+
+    fn ids(result: &ResultSet) -> Vec<i64> {
+        (0..result.row_count)
+            .map(|row| match result.cell(row, 0) {
+                DbValue::Int(id) => *id,
+                other => panic!("unexpected key {:?}", other),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn keyset_pager_walks_forward_and_back() {
+        let mut pager = TablePager::new(
+            "\"users\"".to_string(),
+            IdentifierDialect::SQLite,
+            vec!["id".to_string()],
+            2,
+        );
+
+        let sql = pager.begin(PageRequest::First).expect("first page");
+        assert_eq!(sql, "SELECT * FROM \"users\" ORDER BY \"id\" LIMIT 3;");
+        let mut first = page_result(&[1, 2, 3]);
+        assert_eq!(pager.complete(&sql, &mut first), Some(PageRequest::First));
+        assert_eq!(ids(&first), vec![1, 2]);
+        assert!(pager.has_next());
+        assert!(!pager.has_previous());
+
+        let sql = pager.begin(PageRequest::Next).expect("next page");
+        assert_eq!(
+            sql,
+            "SELECT * FROM \"users\" WHERE \"id\" > 2 ORDER BY \"id\" LIMIT 3;"
+        );
+        let mut second = page_result(&[3, 4]);
+        pager.complete(&sql, &mut second);
+        assert_eq!(pager.page_index(), 1);
+        assert!(!pager.has_next());
+        assert!(pager.begin(PageRequest::Next).is_none());
+
+        let sql = pager.begin(PageRequest::Previous).expect("previous page");
+        assert_eq!(
+            sql,
+            "SELECT * FROM \"users\" WHERE \"id\" < 3 ORDER BY \"id\" DESC LIMIT 2;"
+        );
+        let mut back = page_result(&[2, 1]);
+        pager.complete(&sql, &mut back);
+        assert_eq!(ids(&back), vec![1, 2]);
+        assert_eq!(pager.page_index(), 0);
+        assert!(pager.has_next());
+    }
+
+    #[test]
+    fn composite_key_uses_row_value_comparison() {
+        let mut pager = TablePager::new(
+            "`orders`".to_string(),
+            IdentifierDialect::MySql,
+            vec!["tenant".to_string(), "name".to_string()],
+            1,
+        );
+        let sql = pager.begin(PageRequest::First).expect("first page");
+        let mut first = page_result(&[7, 8]);
+        // 复用 id/name 两列模拟复合键 (tenant, name)
+        first.columns = Arc::new([
+            ResultColumn {
+                name: "tenant".into(),
+                type_info: first.columns[0].type_info.clone(),
+            },
+            ResultColumn {
+                name: "name".into(),
+                type_info: first.columns[1].type_info.clone(),
+            },
+        ]);
+        first.cells[1] = DbValue::Text("o'k\\".into());
+        pager.complete(&sql, &mut first);
+
+        let sql = pager.begin(PageRequest::Next).expect("next page");
+        assert_eq!(
+            sql,
+            "SELECT * FROM `orders` WHERE (`tenant`, `name`) > (7, 'o''k\\\\') \
+             ORDER BY `tenant`, `name` LIMIT 2;"
+        );
+    }
+
+    #[test]
+    fn pager_without_key_falls_back_to_offset() {
+        let mut pager = TablePager::new(
+            "\"logs\"".to_string(),
+            IdentifierDialect::PostgreSql,
+            Vec::new(),
+            2,
+        );
+        let sql = pager.begin(PageRequest::First).expect("first page");
+        assert_eq!(sql, "SELECT * FROM \"logs\" LIMIT 3;");
+        pager.complete(&sql, &mut page_result(&[1, 2, 3]));
+
+        let sql = pager.begin(PageRequest::Next).expect("next page");
+        assert_eq!(sql, "SELECT * FROM \"logs\" LIMIT 3 OFFSET 2;");
+        pager.complete(&sql, &mut page_result(&[3, 4, 5]));
+
+        let sql = pager.begin(PageRequest::Previous).expect("previous page");
+        assert_eq!(sql, "SELECT * FROM \"logs\" LIMIT 3 OFFSET 0;");
+        assert!(pager.status_label().contains("OFFSET"));
+    }
+
+    #[test]
+    fn null_key_falls_back_to_offset_and_foreign_sql_is_ignored() {
+        let mut pager = TablePager::new(
+            "\"users\"".to_string(),
+            IdentifierDialect::SQLite,
+            vec!["id".to_string()],
+            2,
+        );
+        let sql = pager.begin(PageRequest::First).expect("first page");
+        let mut untouched = page_result(&[1, 2, 3]);
+        assert_eq!(pager.complete("SELECT 1;", &mut untouched), None);
+        assert_eq!(untouched.row_count, 3);
+
+        let mut first = page_result(&[1, 2, 3]);
+        first.cells[2] = DbValue::Null;
+        pager.complete(&sql, &mut first);
+        let sql = pager.begin(PageRequest::Next).expect("next page");
+        assert_eq!(
+            sql,
+            "SELECT * FROM \"users\" ORDER BY \"id\" LIMIT 3 OFFSET 2;"
+        );
+    }
+
+    #[test]
+    fn filter_applies_to_every_page_query() {
+        let mut pager = TablePager::new(
+            "\"users\"".to_string(),
+            IdentifierDialect::PostgreSql,
+            vec!["id".to_string()],
+            2,
+        );
+        pager.set_estimated_rows(Some(100));
+        pager.set_filter(Some("(\"age\" > 30 OR \"name\" = 'x')".to_string()));
+
+        let sql = pager.begin(PageRequest::First).expect("first page");
+        assert_eq!(
+            sql,
+            "SELECT * FROM \"users\" WHERE (\"age\" > 30 OR \"name\" = 'x') \
+             ORDER BY \"id\" LIMIT 3;"
+        );
+        pager.complete(&sql, &mut page_result(&[4, 9, 12]));
+
+        let sql = pager.begin(PageRequest::Next).expect("next page");
+        assert_eq!(
+            sql,
+            "SELECT * FROM \"users\" WHERE (\"age\" > 30 OR \"name\" = 'x') AND \"id\" > 9 \
+             ORDER BY \"id\" LIMIT 3;"
+        );
+        assert!(pager.status_label().contains("服务端筛选"));
+        assert!(!pager.status_label().contains("100"));
+
+        pager.set_filter(None);
+        assert_eq!(pager.page_index(), 0);
+        assert!(!pager.is_loading());
+        assert_eq!(
+            pager.restart(),
+            "SELECT * FROM \"users\" ORDER BY \"id\" LIMIT 3;"
+        );
+    }
+}
@@ -0,0 +1,755 @@
+//! Excel 工作簿导入导出
+//!
+//! 导出按 `DbValue` 的类型写单元格：数字写为数值，日期时间写为带格式的 Excel 日期，
+//! NULL 留空，文本始终写为字符串（前导零不会丢失）。超过 Excel 15 位有效数字的
+//! 整数与小数改写为文本，避免静默丢失精度。
+//!
+//! 导入读取一个工作表，表头行可以指定，也可以自动检测；单元格按自身类型生成 SQL 字面量，
+//! 不再像 CSV 那样从文本推断。
+
+use super::export::{
+    ExportOptions, ImportPreview, ImportResult, projected_source_indices, projected_target_columns,
+};
+use super::import_write::{ImportWriteOptions, InsertWriter};
+use crate::domain::result::ResultSet;
+use crate::domain::value::{DbDate, DbTime, DbValue};
+use calamine::{Data, Range, Reader, open_workbook_auto};
+use rust_xlsxwriter::{ExcelDateTime, Format, Workbook, Worksheet, XlsxError};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// 工作表的行数上限
+const MAX_SHEET_ROWS: u32 = 1_048_576;
+/// 工作表的列数上限
+const MAX_SHEET_COLUMNS: usize = 16_384;
+/// 单元格文本的字符数上限
+const MAX_CELL_CHARS: usize = 32_767;
+/// Excel 数值只保留 15 位有效数字
+const MAX_NUMBER_DIGITS: usize = 15;
+/// 导入预览最多展示的数据行数
+const PREVIEW_ROWS: usize = 100;
+/// 自动检测表头时最多检查的非空行数
+const HEADER_SCAN_ROWS: usize = 10;
+
+fn xlsx_error(error: XlsxError) -> String {
+    format!("写入 Excel 失败: {}", error)
+}
+
+// ============================================================================
+// 导出
+// ============================================================================
+
+/// 单元格在工作表中的写法
+enum SheetCell {
+    Empty,
+    Bool(bool),
+    Number(f64),
+    Date(ExcelDateTime),
+    Time(ExcelDateTime),
+    DateTime(ExcelDateTime),
+    Text(String),
+}
+
+fn excel_date(date: &DbDate) -> Option<ExcelDateTime> {
+    let year = u16::try_from(date.year).ok()?;
+    ExcelDateTime::from_ymd(year, date.month, date.day).ok()
+}
+
+fn excel_seconds(time: &DbTime) -> f64 {
+    f64::from(time.second) + f64::from(time.nanos) / 1e9
+}
+
+/// 有效数字不超过 15 位的小数转为数值，其余保留为文本
+fn exact_decimal(text: &str) -> Option<f64> {
+    let digits = text
+        .trim_start_matches(['-', '+'])
+        .chars()
+        .filter(|ch| *ch != '.')
+        .skip_while(|ch| *ch == '0')
+        .count();
+    if digits > MAX_NUMBER_DIGITS
+        || !text
+            .chars()
+            .all(|ch| ch.is_ascii_digit() || "+-.".contains(ch))
+    {
+        return None;
+    }
+    text.parse::<f64>().ok()
+}
+
+fn fits_number(magnitude: u64) -> bool {
+    magnitude < 10u64.pow(MAX_NUMBER_DIGITS as u32)
+}
+
+fn sheet_cell(value: &DbValue) -> SheetCell {
+    let cell = match value {
+        DbValue::Null => Some(SheetCell::Empty),
+        DbValue::Bool(value) => Some(SheetCell::Bool(*value)),
+        DbValue::Int(value) => {
+            fits_number(value.unsigned_abs()).then_some(SheetCell::Number(*value as f64))
+        }
+        DbValue::UInt(value) => fits_number(*value).then_some(SheetCell::Number(*value as f64)),
+        DbValue::Float(value) => value.is_finite().then_some(SheetCell::Number(*value)),
+        DbValue::Decimal(text) => exact_decimal(text).map(SheetCell::Number),
+        DbValue::Date(date) => excel_date(date).map(SheetCell::Date),
+        DbValue::Time(time) => {
+            ExcelDateTime::from_hms(u16::from(time.hour), time.minute, excel_seconds(time))
+                .ok()
+                .map(SheetCell::Time)
+        }
+        DbValue::DateTime(datetime) => excel_date(&datetime.date)
+            .and_then(|date| {
+                date.and_hms(
+                    u16::from(datetime.time.hour),
+                    datetime.time.minute,
+                    excel_seconds(&datetime.time),
+                )
+                .ok()
+            })
+            .map(SheetCell::DateTime),
+        _ => None,
+    };
+    cell.unwrap_or_else(|| SheetCell::Text(value.display()))
+}
+
+/// Excel 工作表名：去掉不允许的字符并截断到 31 个字符
+fn sheet_name(table_name: &str) -> String {
+    let name: String = table_name
+        .chars()
+        .map(|ch| match ch {
+            '[' | ']' | ':' | '*' | '?' | '/' | '\\' => '_',
+            other => other,
+        })
+        .take(31)
+        .collect();
+    let name = name.trim_matches('\'').trim();
+    if name.is_empty() || name.eq_ignore_ascii_case("history") {
+        "Sheet1".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+struct SheetFormats {
+    header: Format,
+    date: Format,
+    time: Format,
+    datetime: Format,
+}
+
+impl Default for SheetFormats {
+    fn default() -> Self {
+        Self {
+            header: Format::new().set_bold(),
+            date: Format::new().set_num_format("yyyy-mm-dd"),
+            time: Format::new().set_num_format("hh:mm:ss"),
+            datetime: Format::new().set_num_format("yyyy-mm-dd hh:mm:ss"),
+        }
+    }
+}
+
+/// 逐行写入一个工作表，结束时生成 .xlsx 文件内容
+///
+/// 供一次性导出与流式导出共用。
+pub(crate) struct XlsxSheetWriter {
+    worksheet: Worksheet,
+    formats: SheetFormats,
+    next_row: u32,
+    /// 超过单元格长度上限而被截断的文本数
+    truncated_cells: usize,
+}
+
+impl XlsxSheetWriter {
+    /// 创建工作表，按需写出加粗并冻结的表头行
+    pub(crate) fn new(
+        table_name: &str,
+        columns: &ResultSet,
+        include_header: bool,
+    ) -> Result<Self, String> {
+        if columns.column_count() > MAX_SHEET_COLUMNS {
+            return Err(format!(
+                "列数 ({}) 超过 Excel 工作表上限 ({} 列)",
+                columns.column_count(),
+                MAX_SHEET_COLUMNS
+            ));
+        }
+
+        let mut worksheet = Worksheet::new();
+        worksheet
+            .set_name(sheet_name(table_name))
+            .map_err(xlsx_error)?;
+        let formats = SheetFormats::default();
+        let mut next_row = 0;
+        if include_header {
+            for (col, column) in columns.columns.iter().enumerate() {
+                worksheet
+                    .write_string_with_format(0, col as u16, column.name.as_str(), &formats.header)
+                    .map_err(xlsx_error)?;
+            }
+            worksheet.set_freeze_panes(1, 0).map_err(xlsx_error)?;
+            next_row = 1;
+        }
+
+        Ok(Self {
+            worksheet,
+            formats,
+            next_row,
+            truncated_cells: 0,
+        })
+    }
+
+    pub(crate) fn write_row(&mut self, result: &ResultSet, row_idx: usize) -> Result<(), String> {
+        if self.next_row >= MAX_SHEET_ROWS {
+            return Err(format!(
+                "超过 Excel 工作表的行数上限 ({} 行)，请缩小导出范围或改用 CSV",
+                MAX_SHEET_ROWS
+            ));
+        }
+
+        let row = self.next_row;
+        for col_idx in 0..result.column_count() {
+            let col = col_idx as u16;
+            let worksheet = &mut self.worksheet;
+            match sheet_cell(result.cell(row_idx, col_idx)) {
+                SheetCell::Empty => Ok(worksheet),
+                SheetCell::Bool(value) => worksheet.write_boolean(row, col, value),
+                SheetCell::Number(value) => worksheet.write_number(row, col, value),
+                SheetCell::Date(value) => {
+                    worksheet.write_datetime_with_format(row, col, &value, &self.formats.date)
+                }
+                SheetCell::Time(value) => {
+                    worksheet.write_datetime_with_format(row, col, &value, &self.formats.time)
+                }
+                SheetCell::DateTime(value) => {
+                    worksheet.write_datetime_with_format(row, col, &value, &self.formats.datetime)
+                }
+                SheetCell::Text(text) => {
+                    if text.chars().count() > MAX_CELL_CHARS {
+                        self.truncated_cells += 1;
+                        let truncated: String = text.chars().take(MAX_CELL_CHARS).collect();
+                        worksheet.write_string(row, col, truncated)
+                    } else {
+                        worksheet.write_string(row, col, text)
+                    }
+                }
+            }
+            .map_err(xlsx_error)?;
+        }
+        self.next_row += 1;
+        Ok(())
+    }
+
+    pub(crate) fn warnings(&self) -> Vec<String> {
+        if self.truncated_cells == 0 {
+            Vec::new()
+        } else {
+            vec![format!(
+                "{} 个单元格的文本超过 Excel 上限 ({} 字符)，已截断",
+                self.truncated_cells, MAX_CELL_CHARS
+            )]
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> Result<Vec<u8>, String> {
+        self.worksheet.autofit();
+        let mut workbook = Workbook::new();
+        workbook.push_worksheet(self.worksheet);
+        workbook.save_to_buffer().map_err(xlsx_error)
+    }
+}
+
+/// 把（已按导出选项筛选的）结果集写成 .xlsx 文件内容，同时返回写出时的警告
+pub(crate) fn render_xlsx(
+    result: &ResultSet,
+    table_name: &str,
+    options: &ExportOptions,
+) -> Result<(Vec<u8>, Vec<String>), String> {
+    if result.columns.is_empty() {
+        return Err("未选择任何列".to_string());
+    }
+
+    let mut writer = XlsxSheetWriter::new(table_name, result, options.csv_include_header)?;
+    for row_idx in 0..result.row_count {
+        writer.write_row(result, row_idx)?;
+    }
+    let warnings = writer.warnings();
+    Ok((writer.finish()?, warnings))
+}
+
+/// 导出预览：工作表的文本示意，数值与日期按单元格中的样子显示，NULL 显示为空
+pub(crate) fn preview_xlsx_text(
+    result: &ResultSet,
+    table_name: &str,
+    include_header: bool,
+) -> String {
+    let mut lines = vec![format!("工作表: {}", sheet_name(table_name))];
+    if include_header {
+        lines.push(result.column_names().join(" | "));
+    }
+    for row_idx in 0..result.row_count {
+        let cells: Vec<String> = (0..result.column_count())
+            .map(|col_idx| match result.cell(row_idx, col_idx) {
+                DbValue::Null => String::new(),
+                value => value.display(),
+            })
+            .collect();
+        lines.push(cells.join(" | "));
+    }
+    lines.join("\n")
+}
+
+// ============================================================================
+// 导入
+// ============================================================================
+
+/// 工作表表头行的识别方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SheetHeader {
+    /// 在前几行中找一行互不重复的文本，且不比下一行窄
+    #[default]
+    Detect,
+    /// 指定的工作表行（0 起），之前的行被忽略
+    Row(u32),
+    /// 没有表头，列名为 column_N
+    None,
+}
+
+/// Excel 导入配置
+#[derive(Debug, Clone, Default)]
+pub struct XlsxImportConfig {
+    /// 目标表名
+    pub table_name: String,
+    /// 工作表名，未指定时读取第一个
+    pub sheet: Option<String>,
+    /// 表头行
+    pub header: SheetHeader,
+    /// 最大导入行数 (0 = 无限制)
+    pub max_rows: usize,
+}
+
+/// 工作簿结构：可选的工作表与实际使用的表头行，供导入预览展示
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SheetLayout {
+    pub sheets: Vec<String>,
+    /// 读取的工作表
+    pub sheet: String,
+    /// 表头所在的工作表行（0 起），None 表示没有表头
+    pub header_row: Option<u32>,
+}
+
+/// 表头与非空数据行
+struct SheetTable<'a> {
+    columns: Vec<String>,
+    header_row: Option<u32>,
+    rows: Vec<&'a [Data]>,
+}
+
+fn open_sheet(
+    path: &Path,
+    sheet: Option<&str>,
+) -> Result<(Vec<String>, String, Range<Data>), String> {
+    let mut workbook = open_workbook_auto(path).map_err(|e| format!("无法打开工作簿: {}", e))?;
+    let sheets = workbook.sheet_names();
+    let name = match sheet {
+        Some(name) => sheets
+            .iter()
+            .find(|candidate| *candidate == name)
+            .cloned()
+            .ok_or_else(|| format!("工作簿中没有工作表 '{}'", name))?,
+        None => sheets
+            .first()
+            .cloned()
+            .ok_or_else(|| "工作簿中没有工作表".to_string())?,
+    };
+    let range = workbook
+        .worksheet_range(&name)
+        .map_err(|e| format!("读取工作表 '{}' 失败: {}", name, e))?;
+    Ok((sheets, name, range))
+}
+
+fn is_blank_row(cells: &[Data]) -> bool {
+    cells.iter().all(|cell| match cell {
+        Data::Empty => true,
+        Data::String(text) => text.trim().is_empty(),
+        _ => false,
+    })
+}
+
+fn filled_cells(cells: &[Data]) -> usize {
+    cells
+        .iter()
+        .filter(|cell| !is_blank_row(std::slice::from_ref(*cell)))
+        .count()
+}
+
+/// 表头候选：至少一个名称，非空单元格都是互不重复、不像数字的文本
+fn header_names_count(cells: &[Data]) -> Option<usize> {
+    let mut seen = HashSet::new();
+    for cell in cells {
+        match cell {
+            Data::Empty => {}
+            Data::String(text) => {
+                let text = text.trim();
+                if !text.is_empty() && (text.parse::<f64>().is_ok() || !seen.insert(text)) {
+                    return None;
+                }
+            }
+            _ => return None,
+        }
+    }
+    (!seen.is_empty()).then_some(seen.len())
+}
+
+/// 在前几行非空行中找表头：标题行通常比下面的数据窄，因此要求不窄于下一行
+fn detect_header(rows: &[(u32, &[Data])]) -> Option<u32> {
+    rows.windows(2)
+        .take(HEADER_SCAN_ROWS)
+        .find(|pair| {
+            header_names_count(pair[0].1).is_some_and(|names| names >= filled_cells(pair[1].1))
+        })
+        .map(|pair| pair[0].0)
+}
+
+fn column_names(header: Option<&[Data]>, width: usize) -> Vec<String> {
+    let mut seen = HashSet::new();
+    (0..width)
+        .map(|idx| {
+            let base = header
+                .and_then(|cells| cells.get(idx))
+                .map(|cell| cell_text(cell).trim().to_string())
+                .filter(|name| !name.is_empty() && name != "NULL")
+                .unwrap_or_else(|| format!("column_{}", idx + 1));
+            let mut name = base.clone();
+            let mut suffix = 2;
+            while !seen.insert(name.clone()) {
+                name = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+            name
+        })
+        .collect()
+}
+
+fn sheet_table(range: &Range<Data>, header: SheetHeader) -> Result<SheetTable<'_>, String> {
+    let first_row = range.start().map_or(0, |(row, _)| row);
+    let rows: Vec<(u32, &[Data])> = range
+        .rows()
+        .enumerate()
+        .map(|(offset, cells)| (first_row + offset as u32, cells))
+        .filter(|(_, cells)| !is_blank_row(cells))
+        .collect();
+    if rows.is_empty() {
+        return Err("工作表为空".to_string());
+    }
+
+    let header_row = match header {
+        SheetHeader::Detect => detect_header(&rows),
+        SheetHeader::Row(row) => {
+            let last_row = range.end().map_or(0, |(row, _)| row);
+            if row < first_row || row > last_row {
+                return Err(format!("第 {} 行不在工作表的数据范围内", row + 1));
+            }
+            Some(row)
+        }
+        SheetHeader::None => None,
+    };
+    let header_cells = header_row.and_then(|header_row| {
+        rows.iter()
+            .find(|(row, _)| *row == header_row)
+            .map(|(_, cells)| *cells)
+    });
+
+    Ok(SheetTable {
+        columns: column_names(header_cells, range.width()),
+        header_row,
+        rows: rows
+            .into_iter()
+            .filter(|(row, _)| header_row.is_none_or(|header_row| *row > header_row))
+            .map(|(_, cells)| cells)
+            .collect(),
+    })
+}
+
+/// 整数值的浮点数去掉小数部分（Excel 把所有数字存为浮点）
+fn number_text(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        value.to_string()
+    }
+}
+
+fn excel_datetime_text(value: &calamine::ExcelDateTime) -> Option<String> {
+    let serial = value.as_f64();
+    if value.is_duration() || (0.0..1.0).contains(&serial) {
+        // 时长与不含日期的时间按 时:分:秒 输出
+        let seconds = (serial * 86_400.0).round() as i64;
+        return Some(format!(
+            "{:02}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        ));
+    }
+    let datetime = value.as_datetime()?;
+    let format = if datetime.time() == chrono::NaiveTime::MIN {
+        "%Y-%m-%d"
+    } else {
+        "%Y-%m-%d %H:%M:%S"
+    };
+    Some(datetime.format(format).to_string())
+}
+
+/// 预览中显示的单元格文本
+fn cell_text(cell: &Data) -> String {
+    match cell {
+        Data::Empty => "NULL".to_string(),
+        Data::String(text) | Data::DateTimeIso(text) | Data::DurationIso(text) => text.clone(),
+        Data::Int(value) => value.to_string(),
+        Data::Float(value) => number_text(*value),
+        Data::Bool(value) => value.to_string(),
+        Data::DateTime(value) => {
+            excel_datetime_text(value).unwrap_or_else(|| number_text(value.as_f64()))
+        }
+        Data::Error(error) => error.to_string(),
+    }
+}
+
+fn quote_sql_text(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+/// 按单元格类型生成 SQL 字面量：文本始终加引号，空单元格与公式错误为 NULL
+fn cell_sql(cell: &Data) -> String {
+    match cell {
+        Data::Empty | Data::Error(_) => "NULL".to_string(),
+        Data::String(text) | Data::DateTimeIso(text) | Data::DurationIso(text) => {
+            quote_sql_text(text)
+        }
+        Data::Int(value) => value.to_string(),
+        Data::Float(value) if value.is_finite() => number_text(*value),
+        Data::Float(_) => "NULL".to_string(),
+        Data::Bool(value) => if *value { "1" } else { "0" }.to_string(),
+        Data::DateTime(value) => excel_datetime_text(value)
+            .map(|text| quote_sql_text(&text))
+            .unwrap_or_else(|| "NULL".to_string()),
+    }
+}
+
+/// 预览 Excel 工作表
+pub fn preview_xlsx(
+    path: &Path,
+    config: &XlsxImportConfig,
+) -> Result<(ImportPreview, SheetLayout), String> {
+    let (sheets, sheet, range) = open_sheet(path, config.sheet.as_deref())?;
+    let table = sheet_table(&range, config.header)?;
+
+    let mut warnings = Vec::new();
+    let error_cells = table
+        .rows
+        .iter()
+        .flat_map(|cells| cells.iter())
+        .filter(|cell| matches!(cell, Data::Error(_)))
+        .count();
+    if error_cells > 0 {
+        warnings.push(format!("{} 个公式错误单元格将导入为 NULL", error_cells));
+    }
+
+    let preview_rows = table
+        .rows
+        .iter()
+        .take(PREVIEW_ROWS)
+        .map(|cells| cells.iter().map(cell_text).collect())
+        .collect();
+
+    Ok((
+        ImportPreview {
+            columns: table.columns,
+            preview_rows,
+            total_rows: table.rows.len(),
+            warnings,
+        },
+        SheetLayout {
+            sheets,
+            sheet,
+            header_row: table.header_row,
+        },
+    ))
+}
+
+/// 从 Excel 工作表生成写入语句，可按 `(源列索引, 目标列名)` 投影列
+pub(crate) fn import_xlsx_to_sql_mapped(
+    path: &Path,
+    config: &XlsxImportConfig,
+    write: &ImportWriteOptions,
+    projection: Option<&[(usize, String)]>,
+) -> Result<ImportResult, String> {
+    if config.table_name.is_empty() {
+        return Err("未指定目标表名".to_string());
+    }
+
+    let (_, _, range) = open_sheet(path, config.sheet.as_deref())?;
+    let table = sheet_table(&range, config.header)?;
+    let source_indices = projected_source_indices(&table.columns, projection)?;
+    let mut writer = InsertWriter::new(
+        &config.table_name,
+        &projected_target_columns(&table.columns, projection),
+        write,
+    )?;
+
+    let row_limit = if config.max_rows > 0 {
+        config.max_rows
+    } else {
+        usize::MAX
+    };
+    for cells in table.rows.iter().take(row_limit) {
+        let values = source_indices
+            .iter()
+            .map(|&idx| cells.get(idx).map_or_else(|| "NULL".to_string(), cell_sql))
+            .collect::<Vec<_>>();
+        writer.push_row(&values);
+    }
+
+    Ok(writer.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::result::{ResultColumn, ResultCompleteness};
+    use crate::domain::value::{DbDateTime, DbTypeFamily, DbTypeInfo};
+    use std::sync::Arc;
+
+    fn xlsx_file(bytes: &[u8]) -> tempfile::NamedTempFile {
+        let file = tempfile::Builder::new()
+            .suffix(".xlsx")
+            .tempfile()
+            .expect("temp file");
+        std::fs::write(file.path(), bytes).expect("write xlsx");
+        file
+    }
+
+    fn import_config(header: SheetHeader) -> XlsxImportConfig {
+        XlsxImportConfig {
+            table_name: "items".to_string(),
+            header,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn exported_cells_keep_their_types_on_reimport() {
+        let column = |name: &str| ResultColumn {
+            name: name.to_string(),
+            type_info: DbTypeInfo {
+                family: DbTypeFamily::Other,
+                native_name: String::new(),
+                nullable: None,
+            },
+        };
+        let date = DbDate {
+            year: 2024,
+            month: 2,
+            day: 29,
+        };
+        let result = ResultSet {
+            columns: Arc::from(vec![
+                column("id"),
+                column("code"),
+                column("note"),
+                column("price"),
+                column("big"),
+                column("day"),
+                column("at"),
+            ]),
+            cells: vec![
+                DbValue::Int(7),
+                DbValue::Text("007".into()),
+                DbValue::Null,
+                DbValue::Decimal("12.50".into()),
+                DbValue::Decimal("12345678901234567890.5".into()),
+                DbValue::Date(date),
+                DbValue::DateTime(DbDateTime {
+                    date,
+                    time: DbTime {
+                        hour: 13,
+                        minute: 5,
+                        second: 9,
+                        nanos: 0,
+                    },
+                }),
+            ],
+            row_count: 1,
+            completeness: ResultCompleteness::Complete,
+        };
+
+        let (bytes, warnings) =
+            render_xlsx(&result, "items", &ExportOptions::default()).expect("render xlsx");
+        assert!(warnings.is_empty());
+        let file = xlsx_file(&bytes);
+
+        let (preview, layout) =
+            preview_xlsx(file.path(), &import_config(SheetHeader::Detect)).expect("preview");
+        assert_eq!(layout.sheets, vec!["items"]);
+        assert_eq!(layout.header_row, Some(0));
+        assert_eq!(preview.columns, result.column_names());
+        assert_eq!(preview.total_rows, 1);
+
+        let statements = import_xlsx_to_sql_mapped(
+            file.path(),
+            &import_config(SheetHeader::Detect),
+            &ImportWriteOptions::default(),
+            None,
+        )
+        .expect("import")
+        .sql_statements;
+        assert_eq!(
+            statements,
+            vec![
+                r#"INSERT INTO "items" ("id", "code", "note", "price", "big", "day", "at") VALUES (7, '007', NULL, 12.5, '12345678901234567890.5', '2024-02-29', '2024-02-29 13:05:09');"#
+            ]
+        );
+    }
+
+    #[test]
+    fn header_row_is_detected_below_title_rows_and_can_be_overridden() {
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet().set_name("Report").unwrap();
+        sheet.write_string(0, 0, "Quarterly sales").unwrap();
+        sheet.write_string(2, 0, "code").unwrap();
+        sheet.write_string(2, 1, "amount").unwrap();
+        sheet.write_string(3, 0, "001").unwrap();
+        sheet.write_number(3, 1, 12.5).unwrap();
+        sheet.write_string(4, 0, "002").unwrap();
+        workbook.add_worksheet().set_name("Notes").unwrap();
+        let file = xlsx_file(&workbook.save_to_buffer().unwrap());
+
+        let (preview, layout) =
+            preview_xlsx(file.path(), &import_config(SheetHeader::Detect)).expect("detect");
+        assert_eq!(layout.sheets, vec!["Report", "Notes"]);
+        assert_eq!(layout.sheet, "Report");
+        assert_eq!(layout.header_row, Some(2));
+        assert_eq!(preview.columns, vec!["code", "amount"]);
+        assert_eq!(
+            preview.preview_rows,
+            vec![vec!["001", "12.5"], vec!["002", "NULL"]]
+        );
+
+        let (preview, layout) =
+            preview_xlsx(file.path(), &import_config(SheetHeader::None)).expect("no header");
+        assert_eq!(layout.header_row, None);
+        assert_eq!(preview.columns, vec!["column_1", "column_2"]);
+        assert_eq!(preview.total_rows, 4);
+
+        let (preview, _) =
+            preview_xlsx(file.path(), &import_config(SheetHeader::Row(3))).expect("row 4");
+        assert_eq!(preview.columns, vec!["001", "12.5"]);
+        assert_eq!(preview.total_rows, 1);
+
+        let config = XlsxImportConfig {
+            sheet: Some("Notes".to_string()),
+            ..import_config(SheetHeader::Detect)
+        };
+        assert!(preview_xlsx(file.path(), &config).is_err());
+    }
+}
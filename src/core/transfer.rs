@@ -3,14 +3,23 @@
 //! 将导入和导出统一到同一套 session / preview / plan / execution 模型中，
 //! 让 UI 只负责收集配置与展示结果，核心逻辑留在 core 层。
 
+use super::columnar::{DEFAULT_ROW_GROUP_ROWS, render_columnar};
+use super::constants;
 use super::export::{
     CsvImportConfig as LegacyCsvImportConfig, ExportFormat as LegacyExportFormat, ExportOptions,
-    JsonImportConfig as LegacyJsonImportConfig, SqlDialect, filter_result_for_export,
-    import_csv_to_sql, import_json_to_sql, preview_csv, preview_export, preview_json,
-    render_export_content_for_transfer,
+    JsonImportConfig as LegacyJsonImportConfig, SqlDialect, StreamingExporter,
+    filter_result_for_export, import_csv_to_sql_mapped, import_json_to_sql_mapped, preview_csv,
+    preview_export, preview_json, render_export_content_for_transfer,
+};
+use super::import_write::ImportWriteOptions;
+use super::ndjson::{NdjsonStatementSource, prepare_ndjson_import, preview_ndjson};
+use super::spreadsheet::{
+    SheetHeader, SheetLayout, XlsxImportConfig, import_xlsx_to_sql_mapped, preview_xlsx,
+    render_xlsx,
 };
 use crate::domain::result::ResultSet;
 use std::collections::HashSet;
+use std::io::Write;
 use std::path::Path;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -27,6 +36,13 @@ pub enum TransferFormat {
     Tsv,
     Sql,
     Json,
+    Ndjson,
+    Xlsx,
+    Parquet,
+    Arrow,
+    Markdown,
+    Html,
+    Latex,
 }
 
 impl TransferFormat {
@@ -36,6 +52,13 @@ impl TransferFormat {
             Self::Tsv => "tsv",
             Self::Sql => "sql",
             Self::Json => "json",
+            Self::Ndjson => "ndjson",
+            Self::Xlsx => "xlsx",
+            Self::Parquet => "parquet",
+            Self::Arrow => "arrow",
+            Self::Markdown => "md",
+            Self::Html => "html",
+            Self::Latex => "tex",
         }
     }
 
@@ -45,8 +68,23 @@ impl TransferFormat {
             Self::Tsv => "TSV",
             Self::Sql => "SQL",
             Self::Json => "JSON",
+            Self::Ndjson => "NDJSON",
+            Self::Xlsx => "Excel",
+            Self::Parquet => "Parquet",
+            Self::Arrow => "Arrow IPC",
+            Self::Markdown => "Markdown",
+            Self::Html => "HTML",
+            Self::Latex => "LaTeX",
         }
     }
+
+    /// 仅支持导出的格式
+    pub const fn is_export_only(self) -> bool {
+        matches!(
+            self,
+            Self::Parquet | Self::Arrow | Self::Markdown | Self::Html | Self::Latex
+        )
+    }
 }
 
 impl From<LegacyExportFormat> for TransferFormat {
@@ -56,6 +94,13 @@ impl From<LegacyExportFormat> for TransferFormat {
             LegacyExportFormat::Tsv => Self::Tsv,
             LegacyExportFormat::Sql => Self::Sql,
             LegacyExportFormat::Json => Self::Json,
+            LegacyExportFormat::Ndjson => Self::Ndjson,
+            LegacyExportFormat::Xlsx => Self::Xlsx,
+            LegacyExportFormat::Parquet => Self::Parquet,
+            LegacyExportFormat::ArrowIpc => Self::Arrow,
+            LegacyExportFormat::Markdown => Self::Markdown,
+            LegacyExportFormat::Html => Self::Html,
+            LegacyExportFormat::Latex => Self::Latex,
         }
     }
 }
@@ -67,6 +112,13 @@ impl From<TransferFormat> for LegacyExportFormat {
             TransferFormat::Tsv => Self::Tsv,
             TransferFormat::Sql => Self::Sql,
             TransferFormat::Json => Self::Json,
+            TransferFormat::Ndjson => Self::Ndjson,
+            TransferFormat::Xlsx => Self::Xlsx,
+            TransferFormat::Parquet => Self::Parquet,
+            TransferFormat::Arrow => Self::ArrowIpc,
+            TransferFormat::Markdown => Self::Markdown,
+            TransferFormat::Html => Self::Html,
+            TransferFormat::Latex => Self::Latex,
         }
     }
 }
@@ -226,6 +278,40 @@ impl Default for TransferJsonOptions {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferSpreadsheetOptions {
+    pub include_header: bool,
+    /// 导入的工作表，未指定时读取第一个
+    pub sheet: Option<String>,
+    pub header: SheetHeader,
+    pub max_rows: usize,
+}
+
+impl Default for TransferSpreadsheetOptions {
+    fn default() -> Self {
+        Self {
+            include_header: true,
+            sheet: None,
+            header: SheetHeader::Detect,
+            max_rows: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferColumnarOptions {
+    /// Parquet 每个行组的行数，Arrow IPC 忽略
+    pub row_group_rows: usize,
+}
+
+impl Default for TransferColumnarOptions {
+    fn default() -> Self {
+        Self {
+            row_group_rows: DEFAULT_ROW_GROUP_ROWS,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TransferSqlOptions {
     pub use_transaction: bool,
@@ -254,6 +340,8 @@ pub enum TransferFormatOptions {
     Delimited(TransferDelimitedOptions),
     Json(TransferJsonOptions),
     Sql(TransferSqlOptions),
+    Spreadsheet(TransferSpreadsheetOptions),
+    Columnar(TransferColumnarOptions),
 }
 
 impl Default for TransferFormatOptions {
@@ -270,6 +358,8 @@ pub struct TransferSession {
     pub mapping: TransferMapping,
     pub row_window: TransferRowWindow,
     pub options: TransferFormatOptions,
+    /// 导入的写入方式与冲突键；导出忽略
+    pub write: ImportWriteOptions,
 }
 
 impl TransferSession {
@@ -293,6 +383,20 @@ impl TransferSession {
             _ => None,
         }
     }
+
+    pub fn spreadsheet_options(&self) -> Option<&TransferSpreadsheetOptions> {
+        match &self.options {
+            TransferFormatOptions::Spreadsheet(options) => Some(options),
+            _ => None,
+        }
+    }
+
+    pub fn columnar_options(&self) -> Option<&TransferColumnarOptions> {
+        match &self.options {
+            TransferFormatOptions::Columnar(options) => Some(options),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -305,12 +409,18 @@ pub struct TransferPreview {
     pub statement_count: usize,
     pub rendered_text: Option<String>,
     pub sql_statements: Vec<String>,
+    /// Excel 导入时的工作表列表与使用的表头行
+    pub sheet_layout: Option<SheetLayout>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransferExecutionPayload {
     FileContent(String),
+    /// 二进制文件内容（Excel 工作簿、Parquet、Arrow IPC）
+    FileBytes(Vec<u8>),
     SqlStatements(Vec<String>),
+    /// 执行时才按批生成的写入语句（NDJSON 导入），不在计划中展开
+    SqlStatementSource(NdjsonStatementSource),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -328,30 +438,63 @@ impl TransferExecutionPlan {
     pub fn rendered_text(&self) -> Option<&str> {
         match &self.payload {
             TransferExecutionPayload::FileContent(content) => Some(content.as_str()),
-            TransferExecutionPayload::SqlStatements(_) => None,
+            TransferExecutionPayload::FileBytes(_)
+            | TransferExecutionPayload::SqlStatements(_)
+            | TransferExecutionPayload::SqlStatementSource(_) => None,
         }
     }
 
     pub fn into_rendered_text(self) -> Result<String, String> {
         match self.payload {
             TransferExecutionPayload::FileContent(content) => Ok(content),
-            TransferExecutionPayload::SqlStatements(_) => {
+            TransferExecutionPayload::FileBytes(_)
+            | TransferExecutionPayload::SqlStatements(_)
+            | TransferExecutionPayload::SqlStatementSource(_) => {
                 Err("当前传输计划不是文件内容导出".to_string())
             }
         }
     }
 
+    /// 已展开的 SQL 语句；按批生成的计划返回 `None`
     pub fn sql_statements(&self) -> Option<&[String]> {
         match &self.payload {
             TransferExecutionPayload::SqlStatements(statements) => Some(statements.as_slice()),
-            TransferExecutionPayload::FileContent(_) => None,
+            TransferExecutionPayload::FileContent(_)
+            | TransferExecutionPayload::FileBytes(_)
+            | TransferExecutionPayload::SqlStatementSource(_) => None,
         }
     }
 
+    /// 取出全部 SQL 语句；按批生成的计划会一次性展开
     pub fn into_sql_statements(self) -> Result<Vec<String>, String> {
+        let mut all = Vec::new();
+        self.stream_sql_statements(constants::database::IMPORT_BATCH_STATEMENTS, |batch| {
+            all.extend(batch);
+            true
+        })?;
+        Ok(all)
+    }
+
+    /// 按批交出待执行的 SQL 语句，跳过空语句；`sink` 返回 `false` 时停止
+    ///
+    /// NDJSON 导入在此时才读取源文件，内存中只保留当前一批。
+    pub fn stream_sql_statements(
+        self,
+        batch_len: usize,
+        mut sink: impl FnMut(Vec<String>) -> bool,
+    ) -> Result<(), String> {
         match self.payload {
-            TransferExecutionPayload::SqlStatements(statements) => Ok(statements),
-            TransferExecutionPayload::FileContent(_) => {
+            TransferExecutionPayload::SqlStatements(mut statements) => {
+                statements.retain(|statement| !statement.trim().is_empty());
+                if !statements.is_empty() {
+                    sink(statements);
+                }
+                Ok(())
+            }
+            TransferExecutionPayload::SqlStatementSource(source) => {
+                source.for_each_batch(batch_len, sink)
+            }
+            TransferExecutionPayload::FileContent(_) | TransferExecutionPayload::FileBytes(_) => {
                 Err("当前传输计划不是 SQL 语句执行计划".to_string())
             }
         }
@@ -393,6 +536,7 @@ pub fn preview_export_transfer(
         statement_count: export_statement_count(&filtered, &options),
         rendered_text: Some(preview_export(result, &table_name, &options, preview_rows)),
         sql_statements: Vec::new(),
+        sheet_layout: None,
     })
 }
 
@@ -416,16 +560,34 @@ pub fn plan_export_transfer(
         &column_names,
         Some(filtered.row_count),
     );
-    let content = render_export_content_for_transfer(&filtered, &table_name, &options)?;
+    let (payload, warnings) = match session.format {
+        TransferFormat::Xlsx => {
+            let (bytes, warnings) = render_xlsx(&filtered, &table_name, &options)?;
+            (TransferExecutionPayload::FileBytes(bytes), warnings)
+        }
+        TransferFormat::Parquet | TransferFormat::Arrow => {
+            let (bytes, warnings) =
+                render_columnar(&filtered, options.format, options.parquet_row_group_rows)?;
+            (TransferExecutionPayload::FileBytes(bytes), warnings)
+        }
+        _ => (
+            TransferExecutionPayload::FileContent(render_export_content_for_transfer(
+                &filtered,
+                &table_name,
+                &options,
+            )?),
+            Vec::new(),
+        ),
+    };
 
     Ok(TransferExecutionPlan {
         session: session.clone(),
         schema: schema.clone(),
         mapping: TransferMapping::from_columns(&column_names),
-        warnings: Vec::new(),
+        warnings,
         total_rows: filtered.row_count,
         statement_count: export_statement_count(&filtered, &options),
-        payload: TransferExecutionPayload::FileContent(content),
+        payload,
     })
 }
 
@@ -437,6 +599,13 @@ pub fn preview_import_transfer(
     match session.format {
         TransferFormat::Csv | TransferFormat::Tsv => preview_delimited_import(path, session),
         TransferFormat::Json => preview_json_import(path, session),
+        TransferFormat::Ndjson => preview_ndjson_import(path, session),
+        TransferFormat::Xlsx => preview_spreadsheet_import(path, session),
+        TransferFormat::Parquet
+        | TransferFormat::Arrow
+        | TransferFormat::Markdown
+        | TransferFormat::Html
+        | TransferFormat::Latex => Err(export_only_error(session.format)),
         TransferFormat::Sql => {
             let content =
                 std::fs::read_to_string(path).map_err(|e| format!("读取文件失败: {}", e))?;
@@ -453,6 +622,13 @@ pub fn plan_import_transfer(
     match session.format {
         TransferFormat::Csv | TransferFormat::Tsv => plan_delimited_import(path, session),
         TransferFormat::Json => plan_json_import(path, session),
+        TransferFormat::Ndjson => plan_ndjson_import(path, session),
+        TransferFormat::Xlsx => plan_spreadsheet_import(path, session),
+        TransferFormat::Parquet
+        | TransferFormat::Arrow
+        | TransferFormat::Markdown
+        | TransferFormat::Html
+        | TransferFormat::Latex => Err(export_only_error(session.format)),
         TransferFormat::Sql => {
             let content =
                 std::fs::read_to_string(path).map_err(|e| format!("读取文件失败: {}", e))?;
@@ -503,6 +679,7 @@ pub fn preview_sql_transfer_content(
                 .join("\n\n"),
         ),
         sql_statements: statements,
+        sheet_layout: None,
     })
 }
 
@@ -523,13 +700,100 @@ pub fn plan_sql_transfer_content(
     })
 }
 
+/// 按导出会话创建流式写入器
+///
+/// 与 `plan_export_transfer` 使用同一套会话→导出选项转换，但不要求预先拿到完整结果集；
+/// 列映射按流中第一批的列序解析。
+pub fn open_streaming_export<W: std::io::Write>(
+    writer: W,
+    session: &TransferSession,
+) -> Result<StreamingExporter<W>, String> {
+    ensure_direction(session, TransferDirection::Export)?;
+    let options = export_options_from_session(session)?;
+    Ok(StreamingExporter::new(
+        writer,
+        export_target_name(session),
+        options,
+    ))
+}
+
 pub fn write_transfer_plan(path: &Path, plan: &TransferExecutionPlan) -> Result<(), String> {
-    let content = match &plan.payload {
-        TransferExecutionPayload::FileContent(content) => content.clone(),
-        TransferExecutionPayload::SqlStatements(statements) => statements.join("\n\n"),
-    };
+    match &plan.payload {
+        TransferExecutionPayload::FileContent(content) => std::fs::write(path, content),
+        TransferExecutionPayload::FileBytes(bytes) => std::fs::write(path, bytes),
+        TransferExecutionPayload::SqlStatements(statements) => {
+            std::fs::write(path, statements.join("\n\n"))
+        }
+        TransferExecutionPayload::SqlStatementSource(source) => {
+            return write_statement_source(path, source);
+        }
+    }
+    .map_err(|e| e.to_string())
+}
+
+/// 逐批写出语句，与 `SqlStatements` 的分隔方式一致
+fn write_statement_source(path: &Path, source: &NdjsonStatementSource) -> Result<(), String> {
+    let file = std::fs::File::create(path).map_err(|e| e.to_string())?;
+    let mut writer = std::io::BufWriter::new(file);
+    let mut separator = "";
+    let mut write_error = None;
+    source.for_each_batch(constants::database::IMPORT_BATCH_STATEMENTS, |batch| {
+        for statement in batch {
+            if let Err(e) = write!(writer, "{}{}", separator, statement) {
+                write_error = Some(e.to_string());
+                return false;
+            }
+            separator = "\n\n";
+        }
+        true
+    })?;
+    if let Some(error) = write_error {
+        return Err(error);
+    }
+    writer.flush().map_err(|e| e.to_string())
+}
 
-    std::fs::write(path, content).map_err(|e| e.to_string())
+/// 将 SQL 脚本拆分为逐条执行的语句。
+///
+/// 与 SQL 导入共享同一套解析规则（DELIMITER、dollar-quote、注释剥离），
+/// 供命令行等无界面入口逐条调用 `execute_typed`。
+pub fn split_sql_script(content: &str) -> Vec<String> {
+    let options = TransferSqlOptions::default();
+    parse_sql_statements(content, &options).0
+}
+
+/// 读取导入文件的源列名（不应用列映射）
+///
+/// 供命令行按列名构建 `TransferMapping`；无表头的分隔文本返回 `column_N`。
+pub fn import_source_columns(
+    path: &Path,
+    session: &TransferSession,
+) -> Result<Vec<String>, String> {
+    ensure_direction(session, TransferDirection::Import)?;
+    match session.format {
+        TransferFormat::Csv | TransferFormat::Tsv => {
+            Ok(preview_csv(path, &legacy_csv_config_from_session(session)?)?.columns)
+        }
+        TransferFormat::Json => {
+            Ok(preview_json(path, &legacy_json_config_from_session(session)?)?.columns)
+        }
+        TransferFormat::Ndjson => {
+            Ok(preview_ndjson(path, &legacy_json_config_from_session(session)?)?.columns)
+        }
+        TransferFormat::Xlsx => Ok(preview_xlsx(path, &xlsx_config_from_session(session)?)?
+            .0
+            .columns),
+        TransferFormat::Parquet
+        | TransferFormat::Arrow
+        | TransferFormat::Markdown
+        | TransferFormat::Html
+        | TransferFormat::Latex => Err(export_only_error(session.format)),
+        TransferFormat::Sql => Err("SQL 导入不支持列映射".to_string()),
+    }
+}
+
+fn export_only_error(format: TransferFormat) -> String {
+    format!("{} 仅支持导出", format.display_name())
 }
 
 fn preview_delimited_import(
@@ -538,8 +802,14 @@ fn preview_delimited_import(
 ) -> Result<TransferPreview, String> {
     let config = legacy_csv_config_from_session(session)?;
     let preview = preview_csv(path, &config)?;
-    let statements =
-        import_csv_to_sql(path, &config, import_uses_mysql_syntax(session))?.sql_statements;
+    let projection = import_projection(session);
+    let statements = import_csv_to_sql_mapped(
+        path,
+        &config,
+        &import_write_options(session),
+        projection.as_deref(),
+    )?
+    .sql_statements;
     Ok(build_import_preview(
         session,
         config.table_name,
@@ -557,8 +827,14 @@ fn plan_delimited_import(
 ) -> Result<TransferExecutionPlan, String> {
     let config = legacy_csv_config_from_session(session)?;
     let preview = preview_csv(path, &config)?;
-    let statements =
-        import_csv_to_sql(path, &config, import_uses_mysql_syntax(session))?.sql_statements;
+    let projection = import_projection(session);
+    let statements = import_csv_to_sql_mapped(
+        path,
+        &config,
+        &import_write_options(session),
+        projection.as_deref(),
+    )?
+    .sql_statements;
     Ok(build_import_plan(
         session,
         config.table_name,
@@ -572,8 +848,14 @@ fn plan_delimited_import(
 fn preview_json_import(path: &Path, session: &TransferSession) -> Result<TransferPreview, String> {
     let config = legacy_json_config_from_session(session)?;
     let preview = preview_json(path, &config)?;
-    let statements =
-        import_json_to_sql(path, &config, import_uses_mysql_syntax(session))?.sql_statements;
+    let projection = import_projection(session);
+    let statements = import_json_to_sql_mapped(
+        path,
+        &config,
+        &import_write_options(session),
+        projection.as_deref(),
+    )?
+    .sql_statements;
     Ok(build_import_preview(
         session,
         config.table_name,
@@ -591,8 +873,120 @@ fn plan_json_import(
 ) -> Result<TransferExecutionPlan, String> {
     let config = legacy_json_config_from_session(session)?;
     let preview = preview_json(path, &config)?;
-    let statements =
-        import_json_to_sql(path, &config, import_uses_mysql_syntax(session))?.sql_statements;
+    let projection = import_projection(session);
+    let statements = import_json_to_sql_mapped(
+        path,
+        &config,
+        &import_write_options(session),
+        projection.as_deref(),
+    )?
+    .sql_statements;
+    Ok(build_import_plan(
+        session,
+        config.table_name,
+        preview.columns,
+        preview.total_rows,
+        preview.warnings,
+        statements,
+    ))
+}
+
+/// NDJSON 预览只生成样本行的语句
+fn preview_ndjson_import(
+    path: &Path,
+    session: &TransferSession,
+) -> Result<TransferPreview, String> {
+    let config = legacy_json_config_from_session(session)?;
+    let projection = import_projection(session);
+    let import = prepare_ndjson_import(
+        path,
+        &config,
+        &import_write_options(session),
+        projection.as_deref(),
+    )?;
+    Ok(TransferPreview {
+        statement_count: import.statement_count,
+        ..build_import_preview(
+            session,
+            config.table_name,
+            import.preview.columns,
+            import.preview.preview_rows,
+            import.preview.total_rows,
+            import.preview.warnings,
+            import.sample_statements,
+        )
+    })
+}
+
+/// NDJSON 计划不展开语句，执行时由语句来源按批生成
+fn plan_ndjson_import(
+    path: &Path,
+    session: &TransferSession,
+) -> Result<TransferExecutionPlan, String> {
+    let config = legacy_json_config_from_session(session)?;
+    let projection = import_projection(session);
+    let import = prepare_ndjson_import(
+        path,
+        &config,
+        &import_write_options(session),
+        projection.as_deref(),
+    )?;
+    Ok(TransferExecutionPlan {
+        statement_count: import.statement_count,
+        payload: TransferExecutionPayload::SqlStatementSource(import.source),
+        ..build_import_plan(
+            session,
+            config.table_name,
+            import.preview.columns,
+            import.preview.total_rows,
+            import.preview.warnings,
+            Vec::new(),
+        )
+    })
+}
+
+fn preview_spreadsheet_import(
+    path: &Path,
+    session: &TransferSession,
+) -> Result<TransferPreview, String> {
+    let config = xlsx_config_from_session(session)?;
+    let (preview, layout) = preview_xlsx(path, &config)?;
+    let projection = import_projection(session);
+    let statements = import_xlsx_to_sql_mapped(
+        path,
+        &config,
+        &import_write_options(session),
+        projection.as_deref(),
+    )?
+    .sql_statements;
+    Ok(TransferPreview {
+        sheet_layout: Some(layout),
+        ..build_import_preview(
+            session,
+            config.table_name,
+            preview.columns,
+            preview.preview_rows,
+            preview.total_rows,
+            preview.warnings,
+            statements,
+        )
+    })
+}
+
+fn plan_spreadsheet_import(
+    path: &Path,
+    session: &TransferSession,
+) -> Result<TransferExecutionPlan, String> {
+    let config = xlsx_config_from_session(session)?;
+    let (preview, _) = preview_xlsx(path, &config)?;
+    let projection = import_projection(session);
+    let statements = import_xlsx_to_sql_mapped(
+        path,
+        &config,
+        &import_write_options(session),
+        projection.as_deref(),
+    )?
+    .sql_statements;
     Ok(build_import_plan(
         session,
         config.table_name,
@@ -618,7 +1012,7 @@ fn build_import_preview(
         &columns,
         Some(total_rows),
     );
-    let mapping = TransferMapping::from_columns(&columns);
+    let mapping = import_mapping(session, &columns);
     let statement_count = statements.len();
 
     TransferPreview {
@@ -630,6 +1024,7 @@ fn build_import_preview(
         statement_count,
         rendered_text: None,
         sql_statements: statements,
+        sheet_layout: None,
     }
 }
 
@@ -647,7 +1042,7 @@ fn build_import_plan(
         &columns,
         Some(total_rows),
     );
-    let mapping = TransferMapping::from_columns(&columns);
+    let mapping = import_mapping(session, &columns);
     let statement_count = statements.len();
 
     TransferExecutionPlan {
@@ -665,6 +1060,10 @@ fn export_options_from_session(session: &TransferSession) -> Result<ExportOption
     let sql_options = session.sql_options().cloned().unwrap_or_default();
     let delimited_options = session.delimited_options().cloned().unwrap_or_default();
     let json_options = session.json_options().cloned().unwrap_or_default();
+    let include_header = match session.spreadsheet_options() {
+        Some(options) => options.include_header,
+        None => delimited_options.include_header,
+    };
 
     Ok(ExportOptions {
         format: session.format.into(),
@@ -675,12 +1074,17 @@ fn export_options_from_session(session: &TransferSession) -> Result<ExportOption
             TransferFormat::Tsv => '\t',
             _ => delimited_options.delimiter,
         },
-        csv_include_header: delimited_options.include_header,
+        csv_include_header: include_header,
         csv_quote_char: delimited_options.quote_char,
         sql_use_transaction: sql_options.use_transaction,
         sql_batch_size: sql_options.batch_size,
         json_pretty: json_options.pretty,
         sql_dialect: sql_options.dialect,
+        parquet_row_group_rows: session
+            .columnar_options()
+            .cloned()
+            .unwrap_or_default()
+            .row_group_rows,
     })
 }
 
@@ -701,7 +1105,8 @@ fn legacy_csv_config_from_session(
         skip_rows: options.skip_rows,
         max_rows: options.max_rows,
         table_name: import_target_name(session)?,
-        column_names: session.mapping.target_columns(),
+        // 目标列名由映射投影决定，无表头时源列名为 column_N
+        column_names: Vec::new(),
     })
 }
 
@@ -720,6 +1125,44 @@ fn legacy_json_config_from_session(
     })
 }
 
+fn xlsx_config_from_session(session: &TransferSession) -> Result<XlsxImportConfig, String> {
+    let options = session
+        .spreadsheet_options()
+        .ok_or_else(|| "Excel 传输缺少工作表配置".to_string())?;
+
+    Ok(XlsxImportConfig {
+        table_name: import_target_name(session)?,
+        sheet: options.sheet.clone(),
+        header: options.header,
+        max_rows: options.max_rows,
+    })
+}
+
+/// 会话映射转换为 `(源列索引, 目标列名)` 投影；映射为空表示按源列原样导入。
+fn import_projection(session: &TransferSession) -> Option<Vec<(usize, String)>> {
+    if session.mapping.fields.is_empty() {
+        return None;
+    }
+
+    Some(
+        session
+            .mapping
+            .fields
+            .iter()
+            .filter(|field| field.included)
+            .map(|field| (field.source_index, field.target_name.clone()))
+            .collect(),
+    )
+}
+
+fn import_mapping(session: &TransferSession, columns: &[String]) -> TransferMapping {
+    if session.mapping.fields.is_empty() {
+        TransferMapping::from_columns(columns)
+    } else {
+        session.mapping.clone()
+    }
+}
+
 fn import_target_name(session: &TransferSession) -> Result<String, String> {
     session
         .schema
@@ -738,10 +1181,17 @@ fn export_target_name(session: &TransferSession) -> String {
         .unwrap_or_else(|| "query_result".to_string())
 }
 
-fn import_uses_mysql_syntax(session: &TransferSession) -> bool {
-    session
-        .sql_options()
-        .is_some_and(|options| options.dialect == SqlDialect::MySql)
+/// 会话未指定目标库时，沿用 SQL 方言推断 MySQL 语法
+fn import_write_options(session: &TransferSession) -> ImportWriteOptions {
+    let mut write = session.write.clone();
+    if write.db_type.is_none()
+        && session
+            .sql_options()
+            .is_some_and(|options| options.dialect == SqlDialect::MySql)
+    {
+        write.db_type = Some(crate::types::DatabaseType::MySQL);
+    }
+    write
 }
 
 fn ensure_direction(session: &TransferSession, expected: TransferDirection) -> Result<(), String> {
@@ -986,6 +1436,7 @@ fn matches_token(chars: &[char], idx: usize, token: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::ImportWriteMode;
     use crate::domain::result::{ResultColumn, ResultCompleteness, ResultSet};
     use crate::domain::value::{DbTypeFamily, DbTypeInfo, DbValue};
     use std::sync::Arc;
@@ -1056,6 +1507,7 @@ SELECT 1;
                 stop_on_error: false,
                 dialect: SqlDialect::Standard,
             }),
+            write: Default::default(),
         };
 
         let preview = preview_sql_transfer_content(content, &session).expect("preview");
@@ -1092,6 +1544,7 @@ SELECT 1;
                 stop_on_error: false,
                 dialect: SqlDialect::Standard,
             }),
+            write: Default::default(),
         };
 
         let plan = plan_export_transfer(&result, &session).expect("plan");
@@ -1117,6 +1570,7 @@ SELECT 1;
             mapping: TransferMapping::default(),
             row_window: TransferRowWindow::default(),
             options: TransferFormatOptions::Delimited(TransferDelimitedOptions::default()),
+            write: Default::default(),
         };
 
         let preview = preview_import_transfer(file.path(), &session).expect("preview");
@@ -1128,4 +1582,214 @@ SELECT 1;
         assert_eq!(preview.statement_count, plan.statement_count);
         assert!(preview.statement_count > 0);
     }
+
+    fn import_session(format: TransferFormat, mapping: TransferMapping) -> TransferSession {
+        TransferSession {
+            direction: TransferDirection::Import,
+            format,
+            schema: TransferSchema {
+                source_name: None,
+                target_name: Some("people".to_string()),
+                fields: Vec::new(),
+                total_rows: None,
+            },
+            mapping,
+            row_window: TransferRowWindow::default(),
+            options: match format {
+                TransferFormat::Json | TransferFormat::Ndjson => {
+                    TransferFormatOptions::Json(TransferJsonOptions::default())
+                }
+                TransferFormat::Xlsx => {
+                    TransferFormatOptions::Spreadsheet(TransferSpreadsheetOptions::default())
+                }
+                _ => TransferFormatOptions::Delimited(TransferDelimitedOptions::default()),
+            },
+            write: Default::default(),
+        }
+    }
+
+    #[test]
+    fn csv_import_plan_applies_mapping_projection() {
+        let file = NamedTempFile::new().expect("temp file");
+        std::fs::write(file.path(), "id,name,age\n1,Alice,30\n").expect("write csv");
+
+        let columns = vec!["id".to_string(), "name".to_string(), "age".to_string()];
+        let mut mapping = TransferMapping::from_columns(&columns);
+        mapping.fields[1].target_name = "full_name".to_string();
+        mapping.fields[2].included = false;
+        let session = import_session(TransferFormat::Csv, mapping);
+
+        let plan = plan_import_transfer(file.path(), &session).expect("plan");
+        assert_eq!(plan.mapping.target_columns(), vec!["id", "full_name"]);
+        let statements = plan.into_sql_statements().expect("statements");
+        assert_eq!(
+            statements,
+            vec![r#"INSERT INTO "people" ("id", "full_name") VALUES (1, 'Alice');"#]
+        );
+    }
+
+    #[test]
+    fn csv_import_plan_uses_session_write_mode_and_mapped_conflict_key() {
+        let file = NamedTempFile::new().expect("temp file");
+        std::fs::write(file.path(), "id,name\n1,Alice\n").expect("write csv");
+
+        let columns = vec!["id".to_string(), "name".to_string()];
+        let mut mapping = TransferMapping::from_columns(&columns);
+        mapping.fields[0].target_name = "person_id".to_string();
+        let mut session = import_session(TransferFormat::Csv, mapping);
+        session.write = ImportWriteOptions {
+            mode: ImportWriteMode::Upsert,
+            conflict_columns: vec!["person_id".to_string()],
+            db_type: Some(crate::types::DatabaseType::PostgreSQL),
+        };
+
+        let statements = plan_import_transfer(file.path(), &session)
+            .and_then(TransferExecutionPlan::into_sql_statements)
+            .expect("statements");
+        assert_eq!(
+            statements,
+            vec![
+                r#"INSERT INTO "people" ("person_id", "name") VALUES (1, 'Alice') ON CONFLICT ("person_id") DO UPDATE SET "name" = excluded."name";"#
+            ]
+        );
+
+        // 冲突键必须是映射后的目标列
+        session.write.conflict_columns = vec!["id".to_string()];
+        assert!(plan_import_transfer(file.path(), &session).is_err());
+    }
+
+    #[test]
+    fn json_import_plan_applies_mapping_projection() {
+        let file = NamedTempFile::new().expect("temp file");
+        std::fs::write(file.path(), r#"[{"id": 1, "name": "Alice"}]"#).expect("write json");
+
+        let session = import_session(
+            TransferFormat::Json,
+            TransferMapping {
+                fields: vec![TransferFieldMapping {
+                    source_index: 1,
+                    target_name: "display_name".to_string(),
+                    included: true,
+                }],
+            },
+        );
+
+        let statements = plan_import_transfer(file.path(), &session)
+            .and_then(TransferExecutionPlan::into_sql_statements)
+            .expect("statements");
+        assert_eq!(
+            statements,
+            vec![r#"INSERT INTO "people" ("display_name") VALUES ('Alice');"#]
+        );
+    }
+
+    #[test]
+    fn ndjson_import_plan_applies_mapping_and_skips_bad_lines() {
+        let file = NamedTempFile::new().expect("temp file");
+        std::fs::write(
+            file.path(),
+            "{\"id\": 1, \"name\": \"Alice\"}\nnot json\n{\"id\": 2, \"name\": \"Bob\"}\n",
+        )
+        .expect("write ndjson");
+
+        let session = import_session(
+            TransferFormat::Ndjson,
+            TransferMapping {
+                fields: vec![TransferFieldMapping {
+                    source_index: 1,
+                    target_name: "display_name".to_string(),
+                    included: true,
+                }],
+            },
+        );
+
+        let preview = preview_import_transfer(file.path(), &session).expect("preview");
+        assert_eq!(preview.total_rows, 2);
+        assert!(
+            preview
+                .warnings
+                .iter()
+                .any(|w| w.starts_with("第 2 行解析失败"))
+        );
+        let statements = plan_import_transfer(file.path(), &session)
+            .and_then(TransferExecutionPlan::into_sql_statements)
+            .expect("statements");
+        assert_eq!(
+            statements,
+            vec![
+                r#"INSERT INTO "people" ("display_name") VALUES ('Alice');"#,
+                r#"INSERT INTO "people" ("display_name") VALUES ('Bob');"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn xlsx_export_plan_writes_a_workbook_that_imports_with_mapping() {
+        let result = make_result_set(
+            &["id", "name"],
+            vec![vec![DbValue::Int(1), DbValue::Text("Alice".into())]],
+        );
+        let column_names = result.column_names();
+        let session = TransferSession {
+            direction: TransferDirection::Export,
+            format: TransferFormat::Xlsx,
+            schema: TransferSchema::from_columns(
+                None,
+                Some("people".to_string()),
+                &column_names,
+                None,
+            ),
+            mapping: TransferMapping::from_columns(&column_names),
+            row_window: TransferRowWindow::default(),
+            options: TransferFormatOptions::Spreadsheet(TransferSpreadsheetOptions::default()),
+            write: Default::default(),
+        };
+        let plan = plan_export_transfer(&result, &session).expect("plan");
+        assert!(matches!(
+            plan.payload,
+            TransferExecutionPayload::FileBytes(_)
+        ));
+        let file = tempfile::Builder::new()
+            .suffix(".xlsx")
+            .tempfile()
+            .expect("temp file");
+        write_transfer_plan(file.path(), &plan).expect("write");
+
+        let mut mapping = TransferMapping::from_columns(&column_names);
+        mapping.fields[0].included = false;
+        let session = import_session(TransferFormat::Xlsx, mapping);
+        let preview = preview_import_transfer(file.path(), &session).expect("preview");
+        assert_eq!(
+            preview.sheet_layout.map(|layout| layout.sheets),
+            Some(vec!["people".to_string()])
+        );
+        assert_eq!(
+            preview.sql_statements,
+            vec![r#"INSERT INTO "people" ("name") VALUES ('Alice');"#]
+        );
+    }
+
+    #[test]
+    fn import_mapping_rejects_unknown_source_column() {
+        let file = NamedTempFile::new().expect("temp file");
+        std::fs::write(file.path(), "id,name\n1,Alice\n").expect("write csv");
+
+        let session = import_session(
+            TransferFormat::Csv,
+            TransferMapping {
+                fields: vec![TransferFieldMapping {
+                    source_index: 5,
+                    target_name: "ghost".to_string(),
+                    included: true,
+                }],
+            },
+        );
+
+        let error = plan_import_transfer(file.path(), &session).unwrap_err();
+        assert!(error.contains("#6"));
+        assert_eq!(
+            import_source_columns(file.path(), &session).expect("columns"),
+            vec!["id", "name"]
+        );
+    }
 }
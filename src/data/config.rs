@@ -220,6 +220,9 @@ pub struct ConnectionConfig {
     /// CA 证书路径（可选，用于 VerifyCa/VerifyIdentity 模式）
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub ssl_ca_cert: String,
+    /// PostgreSQL search_path（逗号分隔的 schema 列表，留空使用服务器默认值）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub search_path: String,
 }
 
 impl std::fmt::Debug for ConnectionConfig {
@@ -240,6 +243,7 @@ impl std::fmt::Debug for ConnectionConfig {
             .field("mysql_ssl_mode", &self.mysql_ssl_mode)
             .field("postgres_ssl_mode", &self.postgres_ssl_mode)
             .field("ssl_ca_cert", &self.ssl_ca_cert)
+            .field("search_path", &self.search_path)
             .finish()
     }
 }
@@ -318,13 +322,14 @@ impl ConnectionConfig {
             }
             DatabaseType::PostgreSQL => {
                 let material = format!(
-                    "pg:{}:{}:{}:{}:{:?}:{}",
+                    "pg:{}:{}:{}:{}:{:?}:{}:{}",
                     self.pool_route_key_material(),
                     self.username,
                     self.database,
                     self.password,
                     self.postgres_ssl_mode,
-                    self.ssl_ca_cert
+                    self.ssl_ca_cert,
+                    self.search_path
                 );
                 format!("pg:{}", crate::core::hash::sha256_hex(&material))
             }
@@ -343,6 +348,80 @@ impl ConnectionConfig {
         }
     }
 
+    /// PostgreSQL 启动参数 `options`：配置了 search_path 时返回 `-c search_path=...`
+    ///
+    /// 参数在建立连接时生效，连接池中的每个客户端都使用同一 search_path。
+    pub fn postgres_startup_options(&self) -> Option<String> {
+        let search_path = self
+            .search_path
+            .split(',')
+            .map(str::trim)
+            .filter(|schema| !schema.is_empty())
+            .collect::<Vec<_>>()
+            .join(",");
+        if search_path.is_empty() {
+            return None;
+        }
+        // options 以空白分隔参数，值中的反斜杠与空格需要转义
+        Some(format!(
+            "-c search_path={}",
+            search_path.replace('\\', "\\\\").replace(' ', "\\ ")
+        ))
+    }
+
+    /// 校验连接配置，返回全部问题描述（为空表示可以保存）
+    ///
+    /// 连接对话框和命令行 `gridix connection add` 共用同一套规则。
+    pub fn validation_errors(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        // 验证连接名称
+        if self.name.is_empty() {
+            errors.push("连接名称不能为空".to_string());
+        } else if self.name.len() > 64 {
+            errors.push("连接名称不能超过 64 个字符".to_string());
+        }
+
+        match self.db_type {
+            DatabaseType::SQLite => {
+                if self.database.is_empty() {
+                    errors.push("数据库文件路径不能为空".to_string());
+                } else if let Some(parent) = std::path::Path::new(&self.database).parent()
+                    && !parent.as_os_str().is_empty()
+                    && !parent.exists()
+                {
+                    errors.push(format!("目录不存在: {}", parent.display()));
+                }
+            }
+            DatabaseType::PostgreSQL | DatabaseType::MySQL => {
+                // 主机验证
+                if self.host.is_empty() {
+                    errors.push("主机地址不能为空".to_string());
+                } else if self.host.contains(' ') {
+                    errors.push("主机地址不能包含空格".to_string());
+                } else if self.host.len() > 255 {
+                    errors.push("主机地址过长".to_string());
+                }
+
+                // 端口验证（u16 类型范围已确保 0-65535）
+                if self.port == 0 {
+                    errors.push("端口号不能为 0".to_string());
+                }
+
+                // 用户名验证（可选但推荐）
+                if self.username.len() > 128 {
+                    errors.push("用户名过长".to_string());
+                }
+
+                if self.search_path.contains(['\'', ';', '\n', '\r']) {
+                    errors.push("search_path 不能包含单引号、分号或换行".to_string());
+                }
+            }
+        }
+
+        errors
+    }
+
     /// 生成安全的连接字符串描述（密码遮蔽，用于日志）
     pub fn connection_string_masked(&self) -> String {
         match self.db_type {
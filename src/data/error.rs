@@ -100,6 +100,26 @@ impl DbError {
         }
     }
 
+    /// 稳定的错误类别标识，供命令行输出和脚本判断使用
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Cancelled => "cancelled",
+            Self::Timeout { .. } => "timeout",
+            Self::Connection(_) => "connection",
+            Self::Authentication { .. } => "authentication",
+            Self::DatabaseNotFound { .. } => "database_not_found",
+            Self::PermissionDenied { .. } => "permission_denied",
+            Self::ConstraintViolation { .. } => "constraint_violation",
+            Self::Query(_) => "query",
+            Self::Tls(_) => "tls",
+            Self::Ssh(_) => "ssh",
+            Self::Unsupported { .. } => "unsupported",
+            Self::ValueOutOfRange { .. } => "value_out_of_range",
+            Self::Keyring(_) => "keyring",
+            Self::Io(_) => "io",
+        }
+    }
+
     /// 从原始数据库驱动错误字符串推断错误类别。
     ///
     /// 优先返回结构化变体，无法识别时回退到通用 `Connection`/`Query`。
@@ -39,13 +39,15 @@ pub use connection::{Connection, ConnectionManager};
 pub use pool::{POOL_MANAGER, PoolManager};
 
 // 查询
+pub use query::session::PinnedSession;
 pub use query::{
     ConnectResult, ImportExecutionReport, RoutineInfo, RoutineType, TriggerInfo, apply_mutations,
-    connect_database, drop_database, execute_import_batch, execute_typed,
-    execute_typed_cancellable, get_routines, get_tables_for_database, get_triggers,
-    infer_type_family, infer_value, load_schema_catalog,
+    connect_database, drop_database, estimate_table_rows, execute_import_batch,
+    execute_import_stream, execute_streaming, execute_typed, execute_typed_cancellable,
+    execute_typed_cancellable_rolled_back, execute_typed_progressive, explain_query, get_routines,
+    get_tables_for_database, get_triggers, infer_type_family, infer_value, load_schema_catalog,
 };
-pub(crate) use query::{SqlUiHints, analyze_sql_for_ui};
+pub(crate) use query::{SqlUiHints, TransactionControl, analyze_sql_for_ui, transaction_control};
 
 // SSH 隧道
 #[allow(unused_imports)] // SshTunnelConfig 公开 API
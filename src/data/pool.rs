@@ -296,6 +296,17 @@ impl PoolManager {
         Ok(client)
     }
 
+    /// 建立不进入缓存的独占 PostgreSQL 连接（交互式事务会话使用）
+    ///
+    /// 缓存客户端被所有查询共享，无法跨多次执行保持事务；独占连接由调用方持有，
+    /// 丢弃客户端并中止返回的任务句柄即关闭连接。
+    pub async fn open_dedicated_pg_client(
+        &self,
+        config: &ConnectionConfig,
+    ) -> Result<(tokio_postgres::Client, tokio::task::JoinHandle<()>), DbError> {
+        Self::connect_pg_with_ssl(config).await
+    }
+
     /// 根据 SSL 模式连接 PostgreSQL。返回客户端及其后台连接任务句柄。
     async fn connect_pg_with_ssl(
         config: &ConnectionConfig,
@@ -419,6 +430,9 @@ fn build_pg_connection_config(
         .parse::<tokio_postgres::Config>()
         .map_err(|e| DbError::Connection(format!("PostgreSQL URL 解析失败: {}", e)))?;
     pg_config.ssl_mode(ssl_mode);
+    if let Some(options) = config.postgres_startup_options() {
+        pg_config.options(&options);
+    }
     Ok(pg_config)
 }
 
@@ -0,0 +1,680 @@
+//! 执行计划生成与解析
+//!
+//! - PostgreSQL: `EXPLAIN (FORMAT JSON)`，按 `Plans` 递归；ANALYZE 附带 `BUFFERS`
+//! - MySQL: `EXPLAIN FORMAT=JSON`，兼容 `query_block` 与 8.x 的 `operation/inputs` 两种格式；
+//!   ANALYZE 使用 MySQL 8 的 `EXPLAIN ANALYZE` 树形文本
+//! - SQLite: `EXPLAIN QUERY PLAN`，按 `id/parent` 还原树（不支持 ANALYZE，退回估算计划）
+//!
+//! 解析结果统一为 `crate::domain::plan::QueryPlan`。
+
+use super::*;
+use crate::domain::plan::{ExplainMode, PlanNode, QueryPlan};
+use crate::domain::result::ResultSet;
+use serde_json::{Map, Value};
+
+/// 去掉末尾分号与空白，避免 `EXPLAIN ...;` 在预编译路径上报错
+fn strip_statement(sql: &str) -> &str {
+    sql.trim().trim_end_matches(';').trim_end()
+}
+
+/// 为目标数据库构造 EXPLAIN 语句
+pub(crate) fn explain_sql(db_type: DatabaseType, sql: &str, mode: ExplainMode) -> String {
+    let sql = strip_statement(sql);
+    match (db_type, mode) {
+        (DatabaseType::PostgreSQL, ExplainMode::Estimate) => {
+            format!("EXPLAIN (FORMAT JSON) {}", sql)
+        }
+        (DatabaseType::PostgreSQL, ExplainMode::Analyze) => {
+            format!("EXPLAIN (ANALYZE, BUFFERS, FORMAT JSON) {}", sql)
+        }
+        (DatabaseType::MySQL, ExplainMode::Estimate) => format!("EXPLAIN FORMAT=JSON {}", sql),
+        (DatabaseType::MySQL, ExplainMode::Analyze) => format!("EXPLAIN ANALYZE {}", sql),
+        (DatabaseType::SQLite, _) => format!("EXPLAIN QUERY PLAN {}", sql),
+    }
+}
+
+/// 将 EXPLAIN 结果集解析为执行计划树
+pub(crate) fn parse_plan(
+    db_type: DatabaseType,
+    mode: ExplainMode,
+    result: &ResultSet,
+) -> Result<QueryPlan, DbError> {
+    match (db_type, mode) {
+        (DatabaseType::PostgreSQL, _) => parse_postgres_plan(&json_document(result)?),
+        (DatabaseType::MySQL, ExplainMode::Estimate) => parse_mysql_plan(&json_document(result)?),
+        (DatabaseType::MySQL, ExplainMode::Analyze) => Ok(parse_mysql_analyze(&plan_text(result)?)),
+        (DatabaseType::SQLite, _) => Ok(parse_sqlite_plan(result)),
+    }
+}
+
+/// 取出单列计划文本
+fn plan_text(result: &ResultSet) -> Result<String, DbError> {
+    if result.row_count == 0 || result.column_count() == 0 {
+        return Err(DbError::Query("EXPLAIN 未返回执行计划".to_string()));
+    }
+    Ok(match result.cell(0, 0) {
+        DbValue::Text(text) => text.clone(),
+        other => other.display(),
+    })
+}
+
+/// 取出单列 JSON 计划文本（PG 为 json 列，MySQL 为 `EXPLAIN` 文本列）
+fn json_document(result: &ResultSet) -> Result<Value, DbError> {
+    if result.row_count == 0 || result.column_count() == 0 {
+        return Err(DbError::Query("EXPLAIN 未返回执行计划".to_string()));
+    }
+    match result.cell(0, 0) {
+        DbValue::Json(value) => Ok(value.clone()),
+        DbValue::Text(text) => serde_json::from_str(text)
+            .map_err(|e| DbError::Query(format!("解析执行计划 JSON 失败: {}", e))),
+        other => serde_json::from_str(&other.display())
+            .map_err(|e| DbError::Query(format!("解析执行计划 JSON 失败: {}", e))),
+    }
+}
+
+fn json_f64(value: Option<&Value>) -> Option<f64> {
+    match value? {
+        Value::Number(n) => n.as_f64(),
+        // MySQL 的 cost_info 以字符串形式给出数值
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn json_str<'a>(map: &'a Map<String, Value>, key: &str) -> Option<&'a str> {
+    map.get(key).and_then(Value::as_str)
+}
+
+fn join_details(parts: Vec<String>) -> Option<String> {
+    (!parts.is_empty()).then(|| parts.join("; "))
+}
+
+// ── PostgreSQL ──
+
+fn parse_postgres_plan(document: &Value) -> Result<QueryPlan, DbError> {
+    let root = document
+        .as_array()
+        .and_then(|items| items.first())
+        .and_then(Value::as_object)
+        .ok_or_else(|| DbError::Query("无法识别的 PostgreSQL 执行计划格式".to_string()))?;
+    let plan = root
+        .get("Plan")
+        .and_then(Value::as_object)
+        .ok_or_else(|| DbError::Query("PostgreSQL 执行计划缺少 Plan 节点".to_string()))?;
+
+    let execution_time_ms = json_f64(root.get("Execution Time"));
+    Ok(QueryPlan {
+        roots: vec![postgres_node(plan)],
+        analyzed: execution_time_ms.is_some(),
+        planning_time_ms: json_f64(root.get("Planning Time")),
+        execution_time_ms,
+    })
+}
+
+fn postgres_node(plan: &Map<String, Value>) -> PlanNode {
+    let operation = json_str(plan, "Node Type").unwrap_or("Unknown").to_string();
+    let relation = json_str(plan, "Relation Name").map(|name| {
+        match (json_str(plan, "Schema"), json_str(plan, "Alias")) {
+            (Some(schema), _) => format!("{}.{}", schema, name),
+            (None, Some(alias)) if alias != name => format!("{} {}", name, alias),
+            _ => name.to_string(),
+        }
+    });
+
+    let mut details = Vec::new();
+    if let Some(strategy) = json_str(plan, "Join Type") {
+        details.push(format!("{} join", strategy));
+    }
+    if let Some(index) = json_str(plan, "Index Name") {
+        details.push(format!("index {}", index));
+    }
+    let hit = json_f64(plan.get("Shared Hit Blocks"));
+    let read = json_f64(plan.get("Shared Read Blocks"));
+    if hit.is_some() || read.is_some() {
+        details.push(format!(
+            "buffers hit={} read={}",
+            hit.unwrap_or(0.0),
+            read.unwrap_or(0.0)
+        ));
+    }
+    for key in [
+        "Index Cond",
+        "Hash Cond",
+        "Merge Cond",
+        "Join Filter",
+        "Filter",
+        "Sort Key",
+        "Group Key",
+    ] {
+        match plan.get(key) {
+            Some(Value::String(cond)) => details.push(format!("{}: {}", key, cond)),
+            Some(Value::Array(items)) => {
+                let items: Vec<&str> = items.iter().filter_map(Value::as_str).collect();
+                details.push(format!("{}: {}", key, items.join(", ")));
+            }
+            _ => {}
+        }
+    }
+
+    let loops = json_f64(plan.get("Actual Loops")).unwrap_or(1.0);
+    PlanNode {
+        full_scan: operation == "Seq Scan",
+        operation,
+        relation,
+        detail: join_details(details),
+        total_cost: json_f64(plan.get("Total Cost")),
+        estimated_rows: json_f64(plan.get("Plan Rows")),
+        actual_rows: json_f64(plan.get("Actual Rows")).map(|rows| rows * loops),
+        actual_time_ms: json_f64(plan.get("Actual Total Time")).map(|time| time * loops),
+        children: plan
+            .get("Plans")
+            .and_then(Value::as_array)
+            .map(|plans| {
+                plans
+                    .iter()
+                    .filter_map(Value::as_object)
+                    .map(postgres_node)
+                    .collect()
+            })
+            .unwrap_or_default(),
+    }
+}
+
+// ── MySQL ──
+
+/// 作为独立节点展示的 `query_block` 子对象
+const MYSQL_OPERATION_KEYS: &[&str] = &[
+    "ordering_operation",
+    "grouping_operation",
+    "duplicates_removal",
+    "windowing",
+    "buffer_result",
+    "union_result",
+    "materialized_from_subquery",
+];
+
+/// 作为节点展示的数组（元素各自包含 `table` 或 `query_block`）
+const MYSQL_ARRAY_KEYS: &[&str] = &[
+    "nested_loop",
+    "query_specifications",
+    "attached_subqueries",
+    "optimized_away_subqueries",
+    "order_by_subqueries",
+    "group_by_subqueries",
+    "having_subqueries",
+    "select_list_subqueries",
+    "update_value_subqueries",
+];
+
+fn parse_mysql_plan(document: &Value) -> Result<QueryPlan, DbError> {
+    let root = document
+        .as_object()
+        .ok_or_else(|| DbError::Query("无法识别的 MySQL 执行计划格式".to_string()))?;
+
+    // explain_json_format_version=2：{"query": ..., "operation": ..., "inputs": [...]}
+    if root.contains_key("operation") {
+        return Ok(QueryPlan {
+            roots: vec![mysql_iterator_node(root)],
+            ..QueryPlan::default()
+        });
+    }
+
+    let block = root
+        .get("query_block")
+        .and_then(Value::as_object)
+        .ok_or_else(|| DbError::Query("MySQL 执行计划缺少 query_block".to_string()))?;
+    Ok(QueryPlan {
+        roots: vec![mysql_query_block(block)],
+        ..QueryPlan::default()
+    })
+}
+
+fn humanize_key(key: &str) -> String {
+    let mut label = key.replace('_', " ");
+    if let Some(first) = label.get_mut(0..1) {
+        first.make_ascii_uppercase();
+    }
+    label
+}
+
+fn mysql_query_block(block: &Map<String, Value>) -> PlanNode {
+    let operation = match block.get("select_id").and_then(Value::as_u64) {
+        Some(id) => format!("Query block #{}", id),
+        None => "Query block".to_string(),
+    };
+    PlanNode {
+        operation,
+        detail: json_str(block, "message").map(str::to_string),
+        total_cost: block
+            .get("cost_info")
+            .and_then(Value::as_object)
+            .and_then(|cost| json_f64(cost.get("query_cost"))),
+        children: mysql_children(block),
+        ..PlanNode::default()
+    }
+}
+
+/// 收集对象中可展示为节点的子项
+fn mysql_children(map: &Map<String, Value>) -> Vec<PlanNode> {
+    let mut children = Vec::new();
+    for (key, value) in map {
+        match value {
+            Value::Object(child) if key == "table" => children.push(mysql_table_node(child)),
+            Value::Object(child) if key == "query_block" => children.push(mysql_query_block(child)),
+            Value::Object(child) if MYSQL_OPERATION_KEYS.contains(&key.as_str()) => {
+                let mut details = Vec::new();
+                for (flag, label) in [
+                    ("using_filesort", "filesort"),
+                    ("using_temporary_table", "temporary table"),
+                ] {
+                    if child.get(flag).and_then(Value::as_bool) == Some(true) {
+                        details.push(label.to_string());
+                    }
+                }
+                children.push(PlanNode {
+                    operation: humanize_key(key),
+                    detail: join_details(details),
+                    children: mysql_children(child),
+                    ..PlanNode::default()
+                });
+            }
+            Value::Array(items) if MYSQL_ARRAY_KEYS.contains(&key.as_str()) => {
+                let nested: Vec<PlanNode> = items
+                    .iter()
+                    .filter_map(Value::as_object)
+                    .flat_map(mysql_children)
+                    .collect();
+                if !nested.is_empty() {
+                    children.push(PlanNode {
+                        operation: humanize_key(key),
+                        children: nested,
+                        ..PlanNode::default()
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    children
+}
+
+fn mysql_table_node(table: &Map<String, Value>) -> PlanNode {
+    let access_type = json_str(table, "access_type").unwrap_or("unknown");
+    let mut details = Vec::new();
+    if let Some(key) = json_str(table, "key") {
+        details.push(format!("key {}", key));
+    }
+    if let Some(extra) = json_str(table, "attached_condition") {
+        details.push(format!("condition: {}", extra));
+    }
+
+    // 表节点只给出自身的 read/eval 开销；prefix_cost 为累计值，不用于比较
+    let cost = table.get("cost_info").and_then(Value::as_object);
+    let own_cost = cost.and_then(|cost| {
+        let read = json_f64(cost.get("read_cost"));
+        let eval = json_f64(cost.get("eval_cost"));
+        match (read, eval) {
+            (None, None) => None,
+            (read, eval) => Some(read.unwrap_or(0.0) + eval.unwrap_or(0.0)),
+        }
+    });
+
+    PlanNode {
+        operation: format!("Table access ({})", access_type),
+        relation: json_str(table, "table_name").map(str::to_string),
+        detail: join_details(details),
+        total_cost: own_cost,
+        estimated_rows: json_f64(table.get("rows_examined_per_scan")),
+        full_scan: matches!(access_type, "ALL" | "index"),
+        children: mysql_children(table),
+        ..PlanNode::default()
+    }
+}
+
+/// MySQL 8.x 迭代器格式（explain_json_format_version=2 / EXPLAIN ANALYZE FORMAT=JSON）
+fn mysql_iterator_node(node: &Map<String, Value>) -> PlanNode {
+    let access_type = json_str(node, "access_type");
+    let relation = json_str(node, "table_name").map(str::to_string);
+    let mut details = Vec::new();
+    if let Some(index) = json_str(node, "index_name") {
+        details.push(format!("index {}", index));
+    }
+    if let Some(condition) = json_str(node, "condition") {
+        details.push(format!("condition: {}", condition));
+    }
+
+    let loops = json_f64(node.get("actual_loops")).unwrap_or(1.0);
+    PlanNode {
+        operation: json_str(node, "operation").unwrap_or("Unknown").to_string(),
+        relation,
+        detail: join_details(details),
+        total_cost: json_f64(node.get("estimated_total_cost")),
+        estimated_rows: json_f64(node.get("estimated_rows")),
+        actual_rows: json_f64(node.get("actual_rows")).map(|rows| rows * loops),
+        actual_time_ms: json_f64(node.get("actual_last_row_ms")).map(|time| time * loops),
+        full_scan: access_type == Some("table"),
+        children: node
+            .get("inputs")
+            .and_then(Value::as_array)
+            .map(|inputs| {
+                inputs
+                    .iter()
+                    .filter_map(Value::as_object)
+                    .map(mysql_iterator_node)
+                    .collect()
+            })
+            .unwrap_or_default(),
+    }
+}
+
+/// MySQL 8 `EXPLAIN ANALYZE` 树形文本
+///
+/// 每行形如 `-> Table scan on t  (cost=1.25 rows=10) (actual time=0.02..0.05 rows=10 loops=1)`，
+/// 以 `->` 前的缩进表示层级。
+fn parse_mysql_analyze(text: &str) -> QueryPlan {
+    // (缩进, 节点)；按缩进出栈还原父子关系
+    let mut stack: Vec<(usize, PlanNode)> = Vec::new();
+    let mut roots = Vec::new();
+
+    fn pop_into(stack: &mut Vec<(usize, PlanNode)>, roots: &mut Vec<PlanNode>) {
+        if let Some((_, node)) = stack.pop() {
+            match stack.last_mut() {
+                Some((_, parent)) => parent.children.push(node),
+                None => roots.push(node),
+            }
+        }
+    }
+
+    for line in text.lines() {
+        let Some(arrow) = line.find("-> ") else {
+            continue;
+        };
+        let indent = line[..arrow].chars().count();
+        let node = mysql_analyze_node(&line[arrow + 3..]);
+        while stack.last().is_some_and(|(depth, _)| *depth >= indent) {
+            pop_into(&mut stack, &mut roots);
+        }
+        stack.push((indent, node));
+    }
+    while !stack.is_empty() {
+        pop_into(&mut stack, &mut roots);
+    }
+
+    QueryPlan {
+        execution_time_ms: roots.first().and_then(|root| root.actual_time_ms),
+        roots,
+        analyzed: true,
+        planning_time_ms: None,
+    }
+}
+
+fn mysql_analyze_node(line: &str) -> PlanNode {
+    let (label, metrics) = match line.find("  (") {
+        Some(pos) => (&line[..pos], &line[pos..]),
+        None => match line.find(" (actual") {
+            Some(pos) => (&line[..pos], &line[pos..]),
+            None => (line, ""),
+        },
+    };
+
+    /// 读取括号组中 `key=value` 的数值
+    fn metric(group: &str, key: &str) -> Option<f64> {
+        group
+            .split_whitespace()
+            .find_map(|part| part.strip_prefix(key)?.strip_prefix('='))
+            .and_then(|value| value.trim_end_matches(')').parse().ok())
+    }
+
+    let mut node = PlanNode {
+        full_scan: label.starts_with("Table scan on") || label.starts_with("Index scan on"),
+        operation: label.trim().to_string(),
+        ..PlanNode::default()
+    };
+    for group in metrics.split('(').map(str::trim).filter(|g| !g.is_empty()) {
+        if let Some(actual) = group.strip_prefix("actual ") {
+            let loops = metric(actual, "loops").unwrap_or(1.0);
+            // actual time=first..last，取最后一行返回时间
+            node.actual_time_ms = actual
+                .split_whitespace()
+                .find_map(|part| part.strip_prefix("time="))
+                .and_then(|range| range.split("..").nth(1))
+                .and_then(|last| last.parse::<f64>().ok())
+                .map(|time| time * loops);
+            node.actual_rows = metric(actual, "rows").map(|rows| rows * loops);
+        } else if group.starts_with("cost=") || group.starts_with("rows=") {
+            node.total_cost = metric(group, "cost");
+            node.estimated_rows = metric(group, "rows");
+        }
+    }
+    node
+}
+
+// ── SQLite ──
+
+fn parse_sqlite_plan(result: &ResultSet) -> QueryPlan {
+    let column = |name: &str| result.columns.iter().position(|c| c.name == name);
+    let detail_col = column("detail").unwrap_or(result.column_count().saturating_sub(1));
+    let id_col = column("id");
+    let parent_col = column("parent");
+
+    let as_i64 = |value: &DbValue| match value {
+        DbValue::Int(v) => Some(*v),
+        DbValue::UInt(v) => i64::try_from(*v).ok(),
+        other => other.display().parse().ok(),
+    };
+
+    // (id, parent, node)；缺少 id/parent 列的旧版输出按平铺处理
+    let mut entries: Vec<(i64, i64, PlanNode)> = Vec::with_capacity(result.row_count);
+    for row in 0..result.row_count {
+        let detail = result.cell(row, detail_col).display();
+        let id = id_col
+            .and_then(|col| as_i64(result.cell(row, col)))
+            .unwrap_or(row as i64 + 1);
+        let parent = parent_col
+            .and_then(|col| as_i64(result.cell(row, col)))
+            .unwrap_or(0);
+        let node = PlanNode {
+            full_scan: detail.starts_with("SCAN "),
+            operation: detail,
+            ..PlanNode::default()
+        };
+        entries.push((id, parent, node));
+    }
+
+    fn attach(parent: i64, entries: &mut Vec<(i64, i64, PlanNode)>) -> Vec<PlanNode> {
+        let mut children = Vec::new();
+        let mut index = 0;
+        while index < entries.len() {
+            if entries[index].1 == parent {
+                let (id, _, mut node) = entries.remove(index);
+                node.children = attach(id, entries);
+                children.push(node);
+            } else {
+                index += 1;
+            }
+        }
+        children
+    }
+
+    let mut roots = attach(0, &mut entries);
+    // 父节点缺失的残余行也要展示
+    roots.extend(entries.into_iter().map(|(_, _, node)| node));
+    QueryPlan {
+        roots,
+        ..QueryPlan::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::result::{ResultColumn, ResultCompleteness};
+    use crate::domain::value::{DbTypeFamily, DbTypeInfo};
+
+    fn result_set(columns: &[&str], rows: Vec<Vec<DbValue>>) -> ResultSet {
+        let columns: Vec<ResultColumn> = columns
+            .iter()
+            .map(|name| ResultColumn {
+                name: name.to_string(),
+                type_info: DbTypeInfo {
+                    family: DbTypeFamily::Text,
+                    native_name: "TEXT".to_string(),
+                    nullable: None,
+                },
+            })
+            .collect();
+        ResultSet {
+            columns: columns.into(),
+            row_count: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+            completeness: ResultCompleteness::Complete,
+        }
+    }
+
+    #[test]
+    fn explain_sql_uses_backend_json_formats() {
+        assert_eq!(
+            explain_sql(
+                DatabaseType::PostgreSQL,
+                "SELECT 1;  ",
+                ExplainMode::Estimate
+            ),
+            "EXPLAIN (FORMAT JSON) SELECT 1"
+        );
+        assert_eq!(
+            explain_sql(DatabaseType::MySQL, "SELECT 1", ExplainMode::Estimate),
+            "EXPLAIN FORMAT=JSON SELECT 1"
+        );
+        assert_eq!(
+            explain_sql(DatabaseType::SQLite, "SELECT 1", ExplainMode::Estimate),
+            "EXPLAIN QUERY PLAN SELECT 1"
+        );
+        assert_eq!(
+            explain_sql(
+                DatabaseType::PostgreSQL,
+                "DELETE FROM t",
+                ExplainMode::Analyze
+            ),
+            "EXPLAIN (ANALYZE, BUFFERS, FORMAT JSON) DELETE FROM t"
+        );
+        assert_eq!(
+            explain_sql(DatabaseType::MySQL, "SELECT 1", ExplainMode::Analyze),
+            "EXPLAIN ANALYZE SELECT 1"
+        );
+    }
+
+    #[test]
+    fn postgres_plan_builds_tree_and_finds_hottest_node() {
+        let json = r#"[{"Plan": {
+            "Node Type": "Hash Join", "Join Type": "Inner", "Total Cost": 120.0, "Plan Rows": 50,
+            "Hash Cond": "(o.user_id = u.id)",
+            "Plans": [
+                {"Node Type": "Seq Scan", "Relation Name": "orders", "Alias": "o",
+                 "Total Cost": 90.0, "Plan Rows": 1000},
+                {"Node Type": "Hash", "Total Cost": 20.0, "Plan Rows": 10, "Plans": [
+                    {"Node Type": "Index Scan", "Relation Name": "users", "Alias": "u",
+                     "Index Name": "users_pkey", "Total Cost": 18.0, "Plan Rows": 10}
+                ]}
+            ]}, "Planning Time": 0.2}]"#;
+        let result = result_set(&["QUERY PLAN"], vec![vec![DbValue::Text(json.into())]]);
+
+        let plan = parse_plan(DatabaseType::PostgreSQL, ExplainMode::Estimate, &result).unwrap();
+        assert!(!plan.analyzed);
+        assert_eq!(plan.node_count(), 4);
+        assert_eq!(plan.full_scan_count(), 1);
+        let root = &plan.roots[0];
+        assert_eq!(root.operation, "Hash Join");
+        assert_eq!(root.children[0].relation.as_deref(), Some("orders o"));
+        assert!(root.children[0].full_scan);
+        assert!(
+            root.children[1].children[0]
+                .detail
+                .as_deref()
+                .unwrap()
+                .contains("users_pkey")
+        );
+        // Seq Scan 自身 90，Hash Join 自身 10，Hash 自身 2
+        assert_eq!(plan.hottest_path(), Some(vec![0, 0]));
+    }
+
+    #[test]
+    fn mysql_query_block_plan_flattens_nested_loop() {
+        let json = r#"{"query_block": {
+            "select_id": 1, "cost_info": {"query_cost": "12.50"},
+            "nested_loop": [
+                {"table": {"table_name": "o", "access_type": "ALL",
+                           "rows_examined_per_scan": 100,
+                           "cost_info": {"read_cost": "8.00", "eval_cost": "2.00", "prefix_cost": "10.00"}}},
+                {"table": {"table_name": "u", "access_type": "eq_ref", "key": "PRIMARY",
+                           "rows_examined_per_scan": 1,
+                           "cost_info": {"read_cost": "1.50", "eval_cost": "1.00", "prefix_cost": "12.50"}}}
+            ]}}"#;
+        let result = result_set(&["EXPLAIN"], vec![vec![DbValue::Text(json.into())]]);
+
+        let plan = parse_plan(DatabaseType::MySQL, ExplainMode::Estimate, &result).unwrap();
+        let root = &plan.roots[0];
+        assert_eq!(root.operation, "Query block #1");
+        assert_eq!(root.total_cost, Some(12.5));
+        let nested = &root.children[0];
+        assert_eq!(nested.operation, "Nested loop");
+        assert_eq!(nested.children.len(), 2);
+        assert!(nested.children[0].full_scan);
+        assert!(!nested.children[1].full_scan);
+        assert_eq!(plan.hottest_path(), Some(vec![0, 0, 0]));
+    }
+
+    #[test]
+    fn mysql_analyze_tree_uses_actual_times() {
+        let text = "-> Nested loop inner join  (cost=4.50 rows=10) (actual time=0.100..2.000 rows=10 loops=1)
+    -> Table scan on o  (cost=1.25 rows=10) (actual time=0.050..1.500 rows=10 loops=1)
+    -> Single-row index lookup on u using PRIMARY (id=o.user_id)  (cost=0.25 rows=1) (actual time=0.010..0.020 rows=1 loops=10)
+";
+        let result = result_set(&["EXPLAIN"], vec![vec![DbValue::Text(text.into())]]);
+
+        let plan = parse_plan(DatabaseType::MySQL, ExplainMode::Analyze, &result).unwrap();
+        assert!(plan.analyzed);
+        assert_eq!(plan.execution_time_ms, Some(2.0));
+        let root = &plan.roots[0];
+        assert_eq!(root.operation, "Nested loop inner join");
+        assert_eq!(root.children.len(), 2);
+        assert!(root.children[0].full_scan);
+        assert_eq!(root.children[1].actual_rows, Some(10.0));
+        assert_eq!(root.children[1].total_cost, Some(0.25));
+        // 扫描 1.5ms 高于查找 0.2ms 与连接自身 0.3ms
+        assert_eq!(plan.hottest_path(), Some(vec![0, 0]));
+    }
+
+    #[test]
+    fn sqlite_plan_rebuilds_tree_from_parent_ids() {
+        let result = result_set(
+            &["id", "parent", "notused", "detail"],
+            vec![
+                vec![
+                    DbValue::Int(2),
+                    DbValue::Int(0),
+                    DbValue::Int(0),
+                    DbValue::Text("SCAN orders".into()),
+                ],
+                vec![
+                    DbValue::Int(5),
+                    DbValue::Int(0),
+                    DbValue::Int(0),
+                    DbValue::Text("CORRELATED SCALAR SUBQUERY 1".into()),
+                ],
+                vec![
+                    DbValue::Int(7),
+                    DbValue::Int(5),
+                    DbValue::Int(0),
+                    DbValue::Text("SEARCH users USING INTEGER PRIMARY KEY (rowid=?)".into()),
+                ],
+            ],
+        );
+
+        let plan = parse_plan(DatabaseType::SQLite, ExplainMode::Estimate, &result).unwrap();
+        assert_eq!(plan.roots.len(), 2);
+        assert!(plan.roots[0].full_scan);
+        assert_eq!(plan.roots[1].children.len(), 1);
+        assert!(!plan.roots[1].children[0].full_scan);
+        assert_eq!(plan.hottest_path(), None);
+    }
+}
@@ -5,8 +5,10 @@
 
 #![allow(dead_code)] // 公开 API，部分功能预留
 
+pub(crate) mod explain;
 pub(crate) mod mysql;
 pub(crate) mod postgres;
+pub(crate) mod session;
 pub(crate) mod sqlite;
 
 use super::ssh_tunnel::{SSH_TUNNEL_MANAGER, SshTunnel};
@@ -97,6 +99,61 @@ pub async fn get_tables_for_database(
     }
 }
 
+/// 估算表的行数，用于分页浏览时显示总量
+///
+/// PostgreSQL 读取 `pg_class.reltuples`，MySQL 读取 `information_schema.TABLES.TABLE_ROWS`，
+/// 二者均为统计值；SQLite 没有统计信息，直接 `COUNT(*)`。无法估算时返回 `None`。
+pub async fn estimate_table_rows(
+    config: &ConnectionConfig,
+    table: &str,
+) -> Result<Option<u64>, DbError> {
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+    let table = table.to_string();
+
+    match effective_config.db_type {
+        DatabaseType::SQLite => {
+            task::spawn_blocking(move || sqlite::estimate_table_rows(&effective_config, &table))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        DatabaseType::PostgreSQL => postgres::estimate_table_rows(&effective_config, &table).await,
+        DatabaseType::MySQL => mysql::estimate_table_rows(&effective_config, &table).await,
+    }
+}
+
+/// 生成结构化执行计划
+///
+/// 按后端包装为 EXPLAIN 语句后走可取消执行路径，再解析为统一的计划树。
+/// `ExplainMode::Analyze` 会真实执行语句，因此在始终回滚的事务中执行。
+pub async fn explain_query(
+    config: &ConnectionConfig,
+    sql: &str,
+    mode: crate::domain::plan::ExplainMode,
+    cancellation: &tokio_util::sync::CancellationToken,
+) -> Result<crate::domain::plan::QueryPlan, DbError> {
+    use crate::domain::execution::StatementOutcome;
+    use crate::domain::plan::ExplainMode;
+
+    let explain_sql = explain::explain_sql(config.db_type, sql, mode);
+    let outcome = match mode {
+        ExplainMode::Estimate => {
+            execute_typed_cancellable(config, &explain_sql, cancellation).await?
+        }
+        ExplainMode::Analyze => {
+            execute_typed_cancellable_rolled_back(config, &explain_sql, cancellation).await?
+        }
+    };
+    let result = outcome
+        .statements
+        .into_iter()
+        .find_map(|statement| match statement {
+            StatementOutcome::ResultSet(result) => Some(result),
+            _ => None,
+        })
+        .ok_or_else(|| DbError::Query("EXPLAIN 未返回执行计划".to_string()))?;
+    explain::parse_plan(config.db_type, mode, &result)
+}
+
 /// 执行 SQL 并返回类型化 ExecutionOutcome
 ///
 /// 三后端均提供原生类型转换路径，避免 String→DbValue 二次转换。
@@ -148,6 +205,157 @@ pub async fn execute_typed_cancellable(
     }
 }
 
+/// 在始终回滚的事务中执行可取消的类型化 SQL
+///
+/// 与 `execute_typed_cancellable` 共用取消路径，用于 EXPLAIN ANALYZE 等会真实执行语句的测量，
+/// 保证不改动数据。SQLite 的 EXPLAIN QUERY PLAN 不执行语句，无需事务，取消时中断正在执行的语句。
+pub async fn execute_typed_cancellable_rolled_back(
+    config: &ConnectionConfig,
+    sql: &str,
+    cancellation: &tokio_util::sync::CancellationToken,
+) -> Result<crate::domain::execution::ExecutionOutcome, DbError> {
+    if cancellation.is_cancelled() {
+        return Err(DbError::Cancelled);
+    }
+
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+    match effective_config.db_type {
+        DatabaseType::SQLite => {
+            sqlite::execute_typed_interruptible(&effective_config, sql, cancellation).await
+        }
+        DatabaseType::PostgreSQL => {
+            postgres::execute_typed_cancellable_with(
+                &effective_config,
+                sql,
+                cancellation,
+                TransactionEnd::Rollback,
+            )
+            .await
+        }
+        DatabaseType::MySQL => {
+            mysql::execute_typed_cancellable_with(
+                &effective_config,
+                sql,
+                cancellation,
+                TransactionEnd::Rollback,
+            )
+            .await
+        }
+    }
+}
+
+/// 查询所在事务的结束方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TransactionEnd {
+    /// 正常提交
+    Commit,
+    /// 始终回滚（测量类执行）
+    Rollback,
+}
+
+/// 流式执行 SQL，将查询结果按批发送到 `sink`
+///
+/// 查询语句至少发送一批（可能为空，用于传递列信息），行数不受
+/// `MAX_RESULT_SET_ROWS` 限制；接收端关闭后停止拉取并返回 `stopped_early`。
+/// 非查询语句不发送批次，直接返回影响行数。
+pub async fn execute_streaming(
+    config: &ConnectionConfig,
+    sql: &str,
+    batch_size: usize,
+    sink: tokio::sync::mpsc::Sender<crate::domain::result::ResultBatch>,
+    cancellation: &tokio_util::sync::CancellationToken,
+) -> Result<crate::domain::execution::StreamOutcome, DbError> {
+    if cancellation.is_cancelled() {
+        return Err(DbError::Cancelled);
+    }
+
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+    match effective_config.db_type {
+        DatabaseType::SQLite => {
+            let config = effective_config.clone();
+            let sql = sql.to_string();
+            let cancellation = cancellation.clone();
+            tokio::task::spawn_blocking(move || {
+                sqlite::execute_streaming(&config, &sql, batch_size, &sink, &cancellation)
+            })
+            .await
+            .map_err(|e| DbError::Query(e.to_string()))?
+        }
+        DatabaseType::PostgreSQL => {
+            postgres::execute_streaming(&effective_config, sql, batch_size, &sink, cancellation)
+                .await
+        }
+        DatabaseType::MySQL => {
+            mysql::execute_streaming(&effective_config, sql, batch_size, &sink, cancellation).await
+        }
+    }
+}
+
+/// 流式执行类型化 SQL，第一批行到达即通过 `on_first_page` 回传
+///
+/// 其余批次继续累积为完整 `ExecutionOutcome`，超过 `MAX_RESULT_SET_ROWS` 时关闭通道
+/// 停止拉取并标记为截断，内存上限与 `execute_typed` 相同，但首屏不必等待全部行。
+pub async fn execute_typed_progressive(
+    config: &ConnectionConfig,
+    sql: &str,
+    cancellation: &tokio_util::sync::CancellationToken,
+    on_first_page: impl FnOnce(crate::domain::result::ResultSet),
+) -> Result<crate::domain::execution::ExecutionOutcome, DbError> {
+    use crate::domain::execution::{ExecutionOutcome, StreamOutcome};
+    use crate::domain::result::{ResultCompleteness, ResultSet};
+
+    let (sink, batches) = tokio::sync::mpsc::channel(PROGRESSIVE_CHANNEL_CAPACITY);
+    let producer = execute_streaming(
+        config,
+        sql,
+        constants::database::STREAM_BATCH_ROWS,
+        sink,
+        cancellation,
+    );
+    let consumer = async {
+        let mut batches = batches;
+        let max_rows = constants::database::MAX_RESULT_SET_ROWS;
+        let mut on_first_page = Some(on_first_page);
+        let mut collected: Option<ResultSet> = None;
+
+        while let Some(batch) = batches.recv().await {
+            let result = collected.get_or_insert_with(|| ResultSet {
+                columns: batch.columns.clone(),
+                cells: Vec::new(),
+                row_count: 0,
+                completeness: ResultCompleteness::Complete,
+            });
+            let taken = batch.row_count.min(max_rows - result.row_count);
+            result
+                .cells
+                .extend(batch.cells.into_iter().take(taken * result.columns.len()));
+            result.row_count += taken;
+
+            if let Some(callback) = on_first_page.take() {
+                callback(result.clone());
+            }
+            if taken < batch.row_count {
+                result.completeness = ResultCompleteness::Truncated {
+                    displayed: result.row_count,
+                };
+                break;
+            }
+        }
+        collected
+    };
+
+    let (streamed, collected) = tokio::join!(producer, consumer);
+    match streamed? {
+        StreamOutcome::AffectedRows { rows } => Ok(ExecutionOutcome::affected_rows(rows)),
+        StreamOutcome::Rows { .. } => Ok(collected
+            .map(ExecutionOutcome::single_result)
+            .unwrap_or_else(ExecutionOutcome::empty)),
+    }
+}
+
+/// `execute_typed_progressive` 在途批次上限
+const PROGRESSIVE_CHANNEL_CAPACITY: usize = 4;
+
 /// 加载数据库 Schema 目录
 ///
 /// 从数据库元数据（information_schema / sqlite_master）一次性加载所有表、列、
@@ -190,6 +398,12 @@ pub async fn apply_mutations(
     }
 }
 
+/// 按批接收的导入语句；生成端出错时以 `Err` 结束，执行端据此放弃（事务模式下回滚）
+pub(crate) type ImportBatchReceiver = tokio::sync::mpsc::Receiver<Result<Vec<String>, String>>;
+
+/// 流式导入在途批次上限
+const IMPORT_CHANNEL_CAPACITY: usize = 4;
+
 /// 批量执行 SQL（用于导入）
 pub async fn execute_import_batch(
     config: &ConnectionConfig,
@@ -197,44 +411,86 @@ pub async fn execute_import_batch(
     use_transaction: bool,
     stop_on_error: bool,
 ) -> Result<ImportExecutionReport, DbError> {
-    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
     let statements: Vec<String> = statements
         .into_iter()
         .filter(|s| !s.trim().is_empty())
         .collect();
+    execute_import_batches(
+        config,
+        single_import_batch(statements),
+        use_transaction,
+        stop_on_error,
+    )
+    .await
+}
+
+/// 边生成边执行导入语句
+///
+/// `produce` 在阻塞线程中运行，把语句逐批交给回调；批次经有界通道送到执行端，
+/// 内存中只保留在途的少量批次。回调返回 `false` 表示执行已提前结束，生成端应停止读取；
+/// 生成端返回错误时执行端放弃导入，事务模式下整体回滚。
+pub async fn execute_import_stream<P>(
+    config: &ConnectionConfig,
+    produce: P,
+    use_transaction: bool,
+    stop_on_error: bool,
+) -> Result<ImportExecutionReport, DbError>
+where
+    P: FnOnce(&mut dyn FnMut(Vec<String>) -> bool) -> Result<(), String> + Send + 'static,
+{
+    let (sender, batches) = tokio::sync::mpsc::channel(IMPORT_CHANNEL_CAPACITY);
+    let producer = task::spawn_blocking(move || {
+        let result = produce(&mut |batch| sender.blocking_send(Ok(batch)).is_ok());
+        if let Err(error) = result {
+            let _ = sender.blocking_send(Err(error));
+        }
+    });
+
+    // 执行端返回时丢弃接收端，仍在读取的生成端随之停止
+    let report = execute_import_batches(config, batches, use_transaction, stop_on_error).await;
+    if let Err(error) = producer.await {
+        tracing::warn!(error = %error, "导入语句生成任务异常退出");
+    }
+    report
+}
+
+async fn execute_import_batches(
+    config: &ConnectionConfig,
+    batches: ImportBatchReceiver,
+    use_transaction: bool,
+    stop_on_error: bool,
+) -> Result<ImportExecutionReport, DbError> {
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
 
     match effective_config.db_type {
         DatabaseType::SQLite => task::spawn_blocking(move || {
-            sqlite::execute_batch(
-                &effective_config,
-                &statements,
-                use_transaction,
-                stop_on_error,
-            )
+            sqlite::execute_batch(&effective_config, batches, use_transaction, stop_on_error)
         })
         .await
         .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?,
         DatabaseType::PostgreSQL => {
-            postgres::execute_batch(
-                &effective_config,
-                &statements,
-                use_transaction,
-                stop_on_error,
-            )
-            .await
+            postgres::execute_batch(&effective_config, batches, use_transaction, stop_on_error)
+                .await
         }
         DatabaseType::MySQL => {
-            mysql::execute_batch(
-                &effective_config,
-                &statements,
-                use_transaction,
-                stop_on_error,
-            )
-            .await
+            mysql::execute_batch(&effective_config, batches, use_transaction, stop_on_error).await
         }
     }
 }
 
+/// 已在内存中的语句作为单个批次发送
+fn single_import_batch(statements: Vec<String>) -> ImportBatchReceiver {
+    let (sender, receiver) = tokio::sync::mpsc::channel(1);
+    // 容量为 1 的新通道必然能放下这一批
+    let _ = sender.try_send(Ok(statements));
+    receiver
+}
+
+/// 生成端读取失败时返回给调用方的错误
+fn import_source_error(error: String) -> DbError {
+    DbError::Query(format!("读取导入数据失败: {}", error))
+}
+
 /// 删除数据库。
 pub async fn drop_database(config: &ConnectionConfig, database: &str) -> Result<(), DbError> {
     let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
@@ -442,6 +698,47 @@ pub(crate) fn is_query_statement(sql: &str, db_type: &DatabaseType) -> bool {
     false
 }
 
+/// 事务控制语句
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TransactionControl {
+    Begin,
+    Commit,
+    Rollback,
+}
+
+/// 识别开启、提交、回滚事务的单条语句
+///
+/// `ROLLBACK TO`、`COMMIT PREPARED`、`COMMIT AND CHAIN` 等保存点/两阶段语句返回 `None`，
+/// 按普通语句执行。`BEGIN` 的隔离级别等修饰会被忽略。
+pub(crate) fn transaction_control(sql: &str) -> Option<TransactionControl> {
+    let mut words = Vec::new();
+    let mut i = skip_sql_ws_and_comments(sql, 0);
+    while let Some(word) = read_sql_keyword(sql, &mut i) {
+        words.push(word);
+        i = skip_sql_ws_and_comments(sql, i);
+    }
+    if !sql[i..].trim_start_matches(';').trim().is_empty() {
+        return None;
+    }
+
+    let (first, rest) = words.split_first()?;
+    if rest
+        .iter()
+        .any(|word| matches!(word.as_str(), "to" | "prepared" | "and" | "release"))
+    {
+        return None;
+    }
+    match first.as_str() {
+        "begin" => Some(TransactionControl::Begin),
+        "start" if rest.first().is_some_and(|word| word == "transaction") => {
+            Some(TransactionControl::Begin)
+        }
+        "commit" | "end" => Some(TransactionControl::Commit),
+        "rollback" | "abort" => Some(TransactionControl::Rollback),
+        _ => None,
+    }
+}
+
 /// 供 UI 使用的 SQL 动作提示
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub(crate) struct SqlUiHints {
@@ -898,6 +1195,23 @@ fn skip_balanced_parens(sql: &str, mut i: usize) -> Option<usize> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn infer_type_family_does_not_mistake_int_like_names() {
+        assert_eq!(infer_type_family("integer[]"), DbTypeFamily::Array);
+        assert_eq!(infer_type_family("_int4"), DbTypeFamily::Array);
+        assert_eq!(infer_type_family("interval"), DbTypeFamily::Other);
+        assert_eq!(infer_type_family("point"), DbTypeFamily::Other);
+        assert_eq!(
+            infer_type_family("time without time zone"),
+            DbTypeFamily::Time
+        );
+        assert_eq!(
+            infer_type_family("timestamp with time zone"),
+            DbTypeFamily::DateTime
+        );
+        assert_eq!(infer_type_family("bigint"), DbTypeFamily::Integer);
+    }
+
     #[test]
     fn test_is_query_statement_with_select() {
         let sql = "WITH cte AS (SELECT 1 AS id) SELECT id FROM cte";
@@ -922,6 +1236,31 @@ mod tests {
         assert!(!is_query_statement(sql, &DatabaseType::SQLite));
     }
 
+    #[test]
+    fn test_transaction_control_recognizes_begin_commit_rollback() {
+        assert_eq!(
+            transaction_control("BEGIN;"),
+            Some(TransactionControl::Begin)
+        );
+        assert_eq!(
+            transaction_control("start transaction read only"),
+            Some(TransactionControl::Begin)
+        );
+        assert_eq!(
+            transaction_control("  -- done\nCOMMIT WORK"),
+            Some(TransactionControl::Commit)
+        );
+        assert_eq!(transaction_control("END"), Some(TransactionControl::Commit));
+        assert_eq!(
+            transaction_control("rollback;"),
+            Some(TransactionControl::Rollback)
+        );
+        assert_eq!(transaction_control("ROLLBACK TO SAVEPOINT sp1"), None);
+        assert_eq!(transaction_control("COMMIT PREPARED 'tx1'"), None);
+        assert_eq!(transaction_control("BEGIN; UPDATE t SET a = 1"), None);
+        assert_eq!(transaction_control("SELECT 1"), None);
+    }
+
     #[test]
     fn test_is_query_statement_table_and_desc_shortcut() {
         assert!(is_query_statement("TABLE users", &DatabaseType::PostgreSQL));
@@ -1103,6 +1442,16 @@ pub async fn get_routines(config: &ConnectionConfig) -> Result<Vec<RoutineInfo>,
 pub fn infer_type_family(data_type: &str) -> DbTypeFamily {
     let t = data_type.to_ascii_lowercase();
 
+    // 数组（需最先判断，避免 "_int4"、"boolean[]" 按元素类型归类）
+    if t.starts_with('_') || t.ends_with("[]") || t.contains("array") {
+        return DbTypeFamily::Array;
+    }
+
+    // 名称中含 "int" 但不是整数的类型
+    if t.contains("interval") || t.contains("point") || t.contains("range") {
+        return DbTypeFamily::Other;
+    }
+
     // 布尔
     if t.contains("bool") {
         return DbTypeFamily::Bool;
@@ -1132,7 +1481,7 @@ pub fn infer_type_family(data_type: &str) -> DbTypeFamily {
     if t == "date" {
         return DbTypeFamily::Date;
     }
-    if t == "time" || t.contains("timetz") {
+    if t == "time" || t.starts_with("time ") || t.contains("timetz") {
         return DbTypeFamily::Time;
     }
     if t.contains("timestamp") || t.contains("datetime") {
@@ -1149,11 +1498,6 @@ pub fn infer_type_family(data_type: &str) -> DbTypeFamily {
         return DbTypeFamily::Uuid;
     }
 
-    // Array
-    if t.starts_with('_') || t.ends_with("[]") || t.contains("array") {
-        return DbTypeFamily::Array;
-    }
-
     // 默认：文本
     DbTypeFamily::Text
 }
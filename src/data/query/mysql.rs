@@ -1,9 +1,15 @@
 //! MySQL 查询实现
 
-use super::{ImportExecutionReport, RoutineInfo, RoutineType, TriggerInfo, is_query_statement};
+use super::{
+    ImportBatchReceiver, ImportExecutionReport, RoutineInfo, RoutineType, TransactionEnd,
+    TriggerInfo, import_source_error, is_query_statement,
+};
 use crate::core::constants;
 
+use super::session::{SessionCancel, SessionCommand, SessionState};
 use crate::data::{ConnectionConfig, DatabaseType, DbError, POOL_MANAGER, PoolManager};
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedReceiver;
 
 /// 获取 MySQL 数据库列表
 pub(crate) async fn get_databases(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
@@ -55,6 +61,30 @@ pub(crate) async fn get_tables(
     Ok(tables)
 }
 
+/// 按 `information_schema.TABLES.TABLE_ROWS` 估算表行数（InnoDB 为统计值）
+pub(crate) async fn estimate_table_rows(
+    config: &ConnectionConfig,
+    table: &str,
+) -> Result<Option<u64>, DbError> {
+    let pool = POOL_MANAGER.get_mysql_pool(config).await?;
+
+    let mut conn = pool
+        .get_conn()
+        .await
+        .map_err(|e| DbError::Connection(format!("MySQL 获取连接失败: {}", e)))?;
+
+    let estimate: Option<Option<u64>> = conn
+        .exec_first(
+            "SELECT TABLE_ROWS FROM information_schema.TABLES \
+             WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ?",
+            (table,),
+        )
+        .await
+        .map_err(|e| DbError::Query(format!("估算表行数失败: {}", e)))?;
+
+    Ok(estimate.flatten())
+}
+
 /// 删除 MySQL 数据库。
 pub(crate) async fn drop_database(
     config: &ConnectionConfig,
@@ -118,7 +148,7 @@ fn quote_mysql_identifier(name: &str) -> String {
 /// 批量执行 MySQL 语句（用于导入）
 pub(crate) async fn execute_batch(
     config: &ConnectionConfig,
-    statements: &[String],
+    mut batches: ImportBatchReceiver,
     use_transaction: bool,
     stop_on_error: bool,
 ) -> Result<ImportExecutionReport, DbError> {
@@ -129,10 +159,7 @@ pub(crate) async fn execute_batch(
         .await
         .map_err(|e| DbError::Connection(format!("MySQL 获取连接失败: {}", e)))?;
 
-    let mut report = ImportExecutionReport::new(statements.len());
-    if statements.is_empty() {
-        return Ok(report);
-    }
+    let mut report = ImportExecutionReport::new(0);
 
     if use_transaction {
         conn.query_drop("START TRANSACTION")
@@ -140,33 +167,49 @@ pub(crate) async fn execute_batch(
             .map_err(|e| DbError::Query(format!("开启事务失败: {}", e)))?;
     }
 
-    for (index, statement) in statements.iter().enumerate() {
-        let exec_result = conn.query_iter(statement).await;
-        match exec_result {
-            Ok(result) => {
-                drop(result);
-                report.succeeded += 1;
+    while let Some(batch) = batches.recv().await {
+        let batch = match batch {
+            Ok(batch) => batch,
+            Err(error) => {
+                // 源文件读取失败时不提交已执行的部分
+                if use_transaction && let Err(rollback_err) = conn.query_drop("ROLLBACK").await {
+                    return Err(DbError::Query(format!(
+                        "事务回滚失败（原错误: 读取导入数据失败: {}，回滚错误: {}）",
+                        error, rollback_err
+                    )));
+                }
+                return Err(import_source_error(error));
             }
-            Err(e) => {
-                let err_msg = format!("第 {} 条语句执行失败: {}", index + 1, e);
-
-                if use_transaction {
-                    if let Err(rollback_err) = conn.query_drop("ROLLBACK").await {
-                        return Err(DbError::Query(format!(
-                            "事务回滚失败（原错误: {}，回滚错误: {}）",
-                            err_msg, rollback_err
-                        )));
-                    }
-                    return Err(DbError::Query(format!("事务已回滚，{}", err_msg)));
+        };
+        for statement in batch {
+            report.total += 1;
+            let exec_result = conn.query_iter(statement.as_str()).await;
+            match exec_result {
+                Ok(result) => {
+                    drop(result);
+                    report.succeeded += 1;
                 }
+                Err(e) => {
+                    let err_msg = format!("第 {} 条语句执行失败: {}", report.total, e);
+
+                    if use_transaction {
+                        if let Err(rollback_err) = conn.query_drop("ROLLBACK").await {
+                            return Err(DbError::Query(format!(
+                                "事务回滚失败（原错误: {}，回滚错误: {}）",
+                                err_msg, rollback_err
+                            )));
+                        }
+                        return Err(DbError::Query(format!("事务已回滚，{}", err_msg)));
+                    }
 
-                report.failed += 1;
-                if report.first_error.is_none() {
-                    report.first_error = Some(err_msg.clone());
-                }
+                    report.failed += 1;
+                    if report.first_error.is_none() {
+                        report.first_error = Some(err_msg.clone());
+                    }
 
-                if stop_on_error {
-                    return Err(DbError::Query(err_msg));
+                    if stop_on_error {
+                        return Err(DbError::Query(err_msg));
+                    }
                 }
             }
         }
@@ -337,7 +380,8 @@ pub(crate) async fn get_routines(config: &ConnectionConfig) -> Result<Vec<Routin
 use super::infer_type_family;
 use crate::domain::ids::SchemaRevision;
 use crate::domain::metadata::{
-    ColumnMetadata, ForeignKeyMetadata, KeyMetadata, SchemaCatalog, TableMetadata,
+    CheckConstraintMetadata, ColumnMetadata, ForeignKeyMetadata, IndexMetadata, KeyMetadata,
+    SchemaCatalog, TableMetadata, ViewMetadata,
 };
 
 /// 从 information_schema 加载 MySQL schema 元数据
@@ -464,23 +508,111 @@ pub(crate) async fn load_catalog(
             })
             .collect();
 
+        // 5. 索引 — STATISTICS 每列一行，按 SEQ_IN_INDEX 聚合；函数索引的列名为 NULL
+        let index_rows: Vec<mysql_async::Row> = conn
+            .exec(
+                "SELECT INDEX_NAME, NON_UNIQUE, INDEX_TYPE, COLUMN_NAME \
+                 FROM information_schema.STATISTICS \
+                 WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ? \
+                 ORDER BY INDEX_NAME = 'PRIMARY' DESC, INDEX_NAME, SEQ_IN_INDEX",
+                (table_name.as_str(),),
+            )
+            .await
+            .map_err(|e| DbError::Query(format!("查询索引失败: {}", e)))?;
+
+        let mut indexes: Vec<IndexMetadata> = Vec::new();
+        for row in &index_rows {
+            let name = row.get::<String, _>(0).unwrap_or_default();
+            let column = row
+                .get::<Option<String>, _>(3)
+                .flatten()
+                .unwrap_or_else(|| "(表达式)".to_string());
+            match indexes.iter_mut().find(|index| index.name == name) {
+                Some(index) => index.columns.push(column),
+                None => indexes.push(IndexMetadata {
+                    is_unique: row.get::<i64, _>(1).unwrap_or(1) == 0,
+                    is_primary: name == "PRIMARY",
+                    method: row.get::<Option<String>, _>(2).flatten(),
+                    predicate: None,
+                    columns: vec![column],
+                    name,
+                }),
+            }
+        }
+
+        // 6. CHECK 约束 — MySQL 8.0.16 之前没有 CHECK_CONSTRAINTS 表，查询失败时视为无约束
+        let check_constraints: Vec<CheckConstraintMetadata> = match conn
+            .exec::<mysql_async::Row, _, _>(
+                "SELECT cc.CONSTRAINT_NAME, cc.CHECK_CLAUSE \
+                 FROM information_schema.CHECK_CONSTRAINTS cc \
+                 JOIN information_schema.TABLE_CONSTRAINTS tc \
+                   ON tc.CONSTRAINT_SCHEMA = cc.CONSTRAINT_SCHEMA \
+                  AND tc.CONSTRAINT_NAME = cc.CONSTRAINT_NAME \
+                 WHERE tc.TABLE_SCHEMA = DATABASE() AND tc.TABLE_NAME = ? \
+                   AND tc.CONSTRAINT_TYPE = 'CHECK' \
+                 ORDER BY cc.CONSTRAINT_NAME",
+                (table_name.as_str(),),
+            )
+            .await
+        {
+            Ok(rows) => rows
+                .iter()
+                .map(|r| CheckConstraintMetadata {
+                    name: r.get::<Option<String>, _>(0).flatten(),
+                    expression: r.get::<String, _>(1).unwrap_or_default(),
+                })
+                .collect(),
+            Err(e) => {
+                tracing::debug!(error = %e, "MySQL 不支持 CHECK_CONSTRAINTS，跳过");
+                Vec::new()
+            }
+        };
+
         tables.push(TableMetadata {
             name: table_name.clone(),
             schema: Some(schema_name.clone()),
             columns,
             primary_key,
-            unique_keys: Vec::new(),
+            unique_keys: indexes
+                .iter()
+                .filter_map(IndexMetadata::unique_key)
+                .collect(),
             foreign_keys,
+            indexes,
+            check_constraints,
         });
     }
 
-    Ok(SchemaCatalog { revision, tables })
+    // 7. 视图（MySQL 没有物化视图与独立序列）
+    let views = conn
+        .query(
+            "SELECT TABLE_NAME, VIEW_DEFINITION FROM information_schema.VIEWS \
+             WHERE TABLE_SCHEMA = DATABASE() ORDER BY TABLE_NAME",
+        )
+        .await
+        .map_err(|e| DbError::Query(format!("查询视图失败: {}", e)))?
+        .into_iter()
+        .map(|row: mysql_async::Row| ViewMetadata {
+            name: row.get::<String, _>(0).unwrap_or_default(),
+            schema: Some(schema_name.clone()),
+            definition: row.get::<Option<String>, _>(1).flatten(),
+            materialized: false,
+        })
+        .collect();
+
+    Ok(SchemaCatalog {
+        revision,
+        default_schema: None,
+        tables,
+        views,
+        sequences: Vec::new(),
+    })
 }
 
 // ── Typed ResultSet 执行 (Phase 4 / Sprint 2) ──
 
-use crate::domain::execution::ExecutionOutcome;
-use crate::domain::result::{ResultColumn, ResultCompleteness, ResultSet};
+use crate::domain::execution::{ExecutionOutcome, StreamOutcome};
+use crate::domain::result::{ResultBatch, ResultColumn, ResultCompleteness, ResultSet};
 use crate::domain::value::{DbTypeFamily, DbTypeInfo, DbValue};
 use mysql_async::consts::{ColumnFlags, ColumnType};
 
@@ -507,6 +639,18 @@ pub(crate) async fn execute_typed_cancellable(
     config: &ConnectionConfig,
     sql: &str,
     cancellation: &tokio_util::sync::CancellationToken,
+) -> Result<ExecutionOutcome, DbError> {
+    execute_typed_cancellable_with(config, sql, cancellation, TransactionEnd::Commit).await
+}
+
+/// 可取消执行；`TransactionEnd::Rollback` 时语句在显式事务中执行并始终回滚
+///
+/// 非事务引擎（如 MyISAM）的改动无法回滚。
+pub(crate) async fn execute_typed_cancellable_with(
+    config: &ConnectionConfig,
+    sql: &str,
+    cancellation: &tokio_util::sync::CancellationToken,
+    end: TransactionEnd,
 ) -> Result<ExecutionOutcome, DbError> {
     if cancellation.is_cancelled() {
         return Err(DbError::Cancelled);
@@ -524,22 +668,217 @@ pub(crate) async fn execute_typed_cancellable(
     if cancellation.is_cancelled() {
         return Err(DbError::Cancelled);
     }
+    if end == TransactionEnd::Rollback {
+        conn.query_drop("START TRANSACTION")
+            .await
+            .map_err(|e| DbError::Query(format!("MySQL 开启事务失败: {}", e)))?;
+    }
     let connection_id = conn.id();
-    let query = execute_typed_with_conn(&mut conn, sql);
-    tokio::pin!(query);
-
-    tokio::select! {
-        biased;
-        _ = cancellation.cancelled() => {
-            let mut control = open_mysql_control_connection(config).await?;
-            cancel_mysql_query(&mut control, connection_id).await?;
-            let _ = query.await;
-            Err(DbError::Cancelled)
+    let mut abandoned = false;
+    let result = {
+        let query = execute_typed_with_conn(&mut conn, sql);
+        tokio::pin!(query);
+
+        tokio::select! {
+            biased;
+            _ = cancellation.cancelled() => {
+                // KILL 尽力发送；发送失败时不再等待语句结束，执行连接直接断开
+                let killed = match open_mysql_control_connection(config).await {
+                    Ok(mut control) => cancel_mysql_query(&mut control, connection_id).await,
+                    Err(error) => Err(error),
+                };
+                match killed {
+                    Ok(()) => {
+                        let _ = query.await;
+                        Err(DbError::Cancelled)
+                    }
+                    Err(error) => {
+                        abandoned = true;
+                        Err(error)
+                    }
+                }
+            }
+            result = &mut query => result,
+        }
+    };
+
+    // 断开连接时服务端丢弃未提交的事务，连接也不会回到连接池
+    if abandoned {
+        let _ = conn.disconnect().await;
+        return result;
+    }
+    if end == TransactionEnd::Rollback
+        && let Err(e) = conn.query_drop("ROLLBACK").await
+    {
+        let _ = conn.disconnect().await;
+        return Err(DbError::Query(format!("MySQL 回滚事务失败: {}", e)));
+    }
+    result
+}
+
+/// 手动提交会话的 MySQL 命令循环
+///
+/// 从连接池取出一条连接独占到会话结束；DDL 等会隐式提交的语句仍按 MySQL 语义生效。
+pub(super) async fn run_pinned_session(
+    config: ConnectionConfig,
+    first: SessionCommand,
+    mut commands: UnboundedReceiver<SessionCommand>,
+    state: Arc<SessionState>,
+) {
+    let mut connection: Option<mysql_async::Conn> = None;
+    let mut next = Some(first);
+    loop {
+        let command = match next.take() {
+            Some(command) => command,
+            None => match commands.recv().await {
+                Some(command) => command,
+                None => break,
+            },
+        };
+        if connection.is_none() {
+            match open_pinned_connection(&config).await {
+                Ok(conn) => {
+                    state.set_cancel(Some(SessionCancel::MySql {
+                        config: config.clone(),
+                        connection_id: conn.id(),
+                    }));
+                    connection = Some(conn);
+                }
+                Err(error) => {
+                    command.fail(error);
+                    continue;
+                }
+            }
+        }
+        let Some(conn) = connection.as_mut() else {
+            continue;
+        };
+        match command {
+            SessionCommand::Begin { reply } => {
+                let result = begin_pinned_transaction(conn, &state).await;
+                if result.is_err() {
+                    check_pinned_connection(&mut connection, &state).await;
+                }
+                let _ = reply.send(result);
+            }
+            SessionCommand::Execute { sql, reply } => {
+                let result = match begin_pinned_transaction(conn, &state).await {
+                    Ok(()) => execute_typed_with_conn(conn, &sql).await,
+                    Err(error) => Err(error),
+                };
+                if result.is_ok() {
+                    state.record_statement();
+                } else {
+                    check_pinned_connection(&mut connection, &state).await;
+                }
+                let _ = reply.send(result);
+            }
+            SessionCommand::End { end, reply } => {
+                let result = if state.in_transaction() {
+                    end_pinned_transaction(conn, end).await
+                } else {
+                    Ok(())
+                };
+                match (&result, end) {
+                    (Ok(()), _) => state.end(),
+                    // 回滚失败时无法确认服务端事务状态，断开连接让服务端丢弃事务
+                    (Err(_), TransactionEnd::Rollback) => {
+                        discard_pinned_connection(&mut connection, &state).await;
+                    }
+                    // 提交失败（如锁等待超时）时事务仍然打开，保留未提交状态
+                    (Err(_), TransactionEnd::Commit) => {
+                        check_pinned_connection(&mut connection, &state).await;
+                    }
+                }
+                let _ = reply.send(result);
+            }
+        }
+    }
+
+    // 句柄已全部丢弃，未提交的改动不保留
+    if let Some(mut conn) = connection {
+        if state.in_transaction()
+            && let Err(error) = end_pinned_transaction(&mut conn, TransactionEnd::Rollback).await
+        {
+            tracing::warn!(error = %error, "MySQL 独占会话关闭时回滚失败");
         }
-        result = &mut query => result,
+        state.end();
+        let _ = conn.disconnect().await;
+    }
+}
+
+/// 命令失败后检查会话连接
+///
+/// 连接已断开（网络中断、被 KILL 等）时服务端已丢弃事务，清除会话状态，下一条命令重新建立连接。
+async fn check_pinned_connection(connection: &mut Option<mysql_async::Conn>, state: &SessionState) {
+    if let Some(conn) = connection.as_mut()
+        && conn.ping().await.is_err()
+    {
+        discard_pinned_connection(connection, state).await;
+    }
+}
+
+/// 断开会话连接并清除事务状态
+async fn discard_pinned_connection(
+    connection: &mut Option<mysql_async::Conn>,
+    state: &SessionState,
+) {
+    state.set_cancel(None);
+    state.end();
+    if let Some(conn) = connection.take() {
+        let _ = conn.disconnect().await;
+    }
+}
+
+async fn open_pinned_connection(config: &ConnectionConfig) -> Result<mysql_async::Conn, DbError> {
+    let pool = POOL_MANAGER
+        .get_mysql_pool(config)
+        .await
+        .map_err(|e| DbError::Connection(format!("MySQL 连接池获取失败: {}", e)))?;
+    pool.get_conn()
+        .await
+        .map_err(|e| DbError::Connection(format!("MySQL 独占连接获取失败: {}", e)))
+}
+
+async fn begin_pinned_transaction(
+    conn: &mut mysql_async::Conn,
+    state: &SessionState,
+) -> Result<(), DbError> {
+    if state.in_transaction() {
+        return Ok(());
+    }
+    conn.query_drop("START TRANSACTION")
+        .await
+        .map_err(|e| DbError::Query(format!("MySQL 开启事务失败: {}", e)))?;
+    state.begin();
+    Ok(())
+}
+
+async fn end_pinned_transaction(
+    conn: &mut mysql_async::Conn,
+    end: TransactionEnd,
+) -> Result<(), DbError> {
+    match end {
+        TransactionEnd::Commit => conn
+            .query_drop("COMMIT")
+            .await
+            .map_err(|e| DbError::Query(format!("MySQL 提交事务失败: {}", e))),
+        TransactionEnd::Rollback => conn
+            .query_drop("ROLLBACK")
+            .await
+            .map_err(|e| DbError::Query(format!("MySQL 回滚事务失败: {}", e))),
     }
 }
 
+/// 打开控制连接并中断指定连接上正在执行的查询
+pub(super) async fn cancel_query_on_connection(
+    config: &ConnectionConfig,
+    connection_id: u32,
+) -> Result<(), DbError> {
+    let mut control = open_mysql_control_connection(config).await?;
+    cancel_mysql_query(&mut control, connection_id).await
+}
+
 async fn open_mysql_control_connection(
     config: &ConnectionConfig,
 ) -> Result<mysql_async::Conn, DbError> {
@@ -587,10 +926,7 @@ async fn execute_typed_with_conn(
         .map_err(|e| DbError::Query(format!("MySQL exec_iter 失败: {}", e)))?;
 
     let columns: Vec<mysql_async::Column> = result.columns_ref().to_vec();
-    let col_names: Vec<String> = columns.iter().map(|c| c.name_str().into_owned()).collect();
-    let col_types: Vec<ColumnType> = columns.iter().map(|column| column.column_type()).collect();
-
-    let col_count = col_names.len();
+    let col_count = columns.len();
     let max_rows = constants::database::MAX_RESULT_SET_ROWS;
 
     let mut cells: Vec<DbValue> = Vec::new();
@@ -622,26 +958,141 @@ async fn execute_typed_with_conn(
         ResultCompleteness::Complete
     };
 
-    let typed_columns: std::sync::Arc<[ResultColumn]> = col_names
-        .iter()
-        .enumerate()
-        .map(|(i, name)| ResultColumn {
-            name: name.clone(),
-            type_info: DbTypeInfo {
-                family: mysql_type_to_family(&col_types[i]),
-                native_name: format!("{:?}", col_types[i]),
-                nullable: None,
-            },
-        })
-        .collect();
-
     Ok(ExecutionOutcome::single_result(ResultSet {
-        columns: typed_columns,
+        columns: mysql_result_columns(&columns),
         cells,
         row_count,
         completeness,
     }))
 }
+
+fn mysql_result_columns(columns: &[mysql_async::Column]) -> std::sync::Arc<[ResultColumn]> {
+    columns
+        .iter()
+        .map(|column| {
+            let column_type = column.column_type();
+            ResultColumn {
+                name: column.name_str().into_owned(),
+                type_info: DbTypeInfo {
+                    family: mysql_type_to_family(&column_type),
+                    native_name: format!("{:?}", column_type),
+                    nullable: None,
+                },
+            }
+        })
+        .collect()
+}
+
+/// 流式执行 SQL，按批通过通道发送行（MySQL 原生路径）
+///
+/// `exec_iter` 逐行读取协议流，攒满 `batch_size` 行即发送；接收端关闭后停止读取，
+/// 未读完的结果由连接归还连接池时清理。取消沿用 `KILL QUERY` 控制连接。
+pub(crate) async fn execute_streaming(
+    config: &ConnectionConfig,
+    sql: &str,
+    batch_size: usize,
+    sink: &tokio::sync::mpsc::Sender<ResultBatch>,
+    cancellation: &tokio_util::sync::CancellationToken,
+) -> Result<StreamOutcome, DbError> {
+    if cancellation.is_cancelled() {
+        return Err(DbError::Cancelled);
+    }
+
+    let pool = POOL_MANAGER
+        .get_mysql_pool(config)
+        .await
+        .map_err(|e| DbError::Connection(format!("MySQL 连接池获取失败: {}", e)))?;
+    let mut conn = pool
+        .get_conn()
+        .await
+        .map_err(|e| DbError::Connection(format!("MySQL 执行连接获取失败: {}", e)))?;
+    let connection_id = conn.id();
+
+    if !is_query_statement(sql, &DatabaseType::MySQL) {
+        {
+            let execute = conn.exec_drop(sql, ());
+            tokio::pin!(execute);
+            tokio::select! {
+                biased;
+                result = &mut execute => result.map_err(|e| DbError::Query(e.to_string()))?,
+                _ = cancellation.cancelled() => {
+                    let mut control = open_mysql_control_connection(config).await?;
+                    cancel_mysql_query(&mut control, connection_id).await?;
+                    let _ = execute.await;
+                    return Err(DbError::Cancelled);
+                }
+            }
+        }
+        return Ok(StreamOutcome::AffectedRows {
+            rows: conn.affected_rows(),
+        });
+    }
+
+    let mut result = conn
+        .exec_iter(sql, ())
+        .await
+        .map_err(|e| DbError::Query(format!("MySQL exec_iter 失败: {}", e)))?;
+    let columns: Vec<mysql_async::Column> = result.columns_ref().to_vec();
+    let typed_columns = mysql_result_columns(&columns);
+    let batch_size = batch_size.max(1);
+    let mut cells: Vec<DbValue> = Vec::with_capacity(batch_size * columns.len());
+    let mut buffered = 0usize;
+    let mut total = 0usize;
+    let mut sent_any = false;
+
+    loop {
+        let next = tokio::select! {
+            biased;
+            next = result.next() => next.map_err(|e| DbError::Query(e.to_string()))?,
+            _ = cancellation.cancelled() => {
+                let mut control = open_mysql_control_connection(config).await?;
+                cancel_mysql_query(&mut control, connection_id).await?;
+                return Err(DbError::Cancelled);
+            }
+        };
+        let exhausted = next.is_none();
+        if let Some(row) = next {
+            for (index, column) in columns.iter().enumerate() {
+                let value: mysql_async::Value = row.get(index).unwrap_or(mysql_async::Value::NULL);
+                cells.push(mysql_value_to_dbvalue(value, column));
+            }
+            buffered += 1;
+        }
+
+        // 第一批即使为空也要发送，保证消费者拿到列信息
+        if buffered >= batch_size || (exhausted && (buffered > 0 || !sent_any)) {
+            let batch = ResultBatch {
+                columns: typed_columns.clone(),
+                cells: std::mem::replace(
+                    &mut cells,
+                    Vec::with_capacity(batch_size * columns.len()),
+                ),
+                row_count: buffered,
+            };
+            let sent = tokio::select! {
+                biased;
+                sent = sink.send(batch) => sent.is_ok(),
+                _ = cancellation.cancelled() => return Err(DbError::Cancelled),
+            };
+            if !sent {
+                return Ok(StreamOutcome::Rows {
+                    total,
+                    stopped_early: true,
+                });
+            }
+            sent_any = true;
+            total += buffered;
+            buffered = 0;
+        }
+
+        if exhausted {
+            return Ok(StreamOutcome::Rows {
+                total,
+                stopped_early: false,
+            });
+        }
+    }
+}
 fn mysql_value_to_dbvalue(val: mysql_async::Value, column: &mysql_async::Column) -> DbValue {
     use mysql_async::Value;
     match val {
@@ -1,6 +1,9 @@
 //! PostgreSQL 查询实现
 
-use super::{ImportExecutionReport, RoutineInfo, RoutineType, TriggerInfo, is_query_statement};
+use super::{
+    ImportBatchReceiver, ImportExecutionReport, RoutineInfo, RoutineType, TransactionEnd,
+    TriggerInfo, import_source_error, is_query_statement,
+};
 use crate::core::constants;
 use crate::data::{ConnectionConfig, DatabaseType, DbError, POOL_MANAGER};
 use tokio_postgres::types::{Format, FromSql, IsNull, ToSql, Type};
@@ -14,6 +17,32 @@ async fn current_schema(client: &tokio_postgres::Client) -> Result<String, DbErr
     Ok(schema.unwrap_or_else(|| "public".to_string()))
 }
 
+/// 当前用户可访问的 schema（排除系统 schema），默认 schema 排在最前
+async fn visible_schemas(client: &tokio_postgres::Client) -> Result<Vec<String>, DbError> {
+    let rows = client
+        .query(
+            "SELECT n.nspname::text FROM pg_namespace n \
+             WHERE n.nspname NOT IN ('pg_catalog', 'information_schema') \
+               AND n.nspname NOT LIKE 'pg\\_toast%' \
+               AND n.nspname NOT LIKE 'pg\\_temp\\_%' \
+               AND has_schema_privilege(n.oid, 'USAGE') \
+             ORDER BY COALESCE(n.nspname = current_schema(), false) DESC, n.nspname",
+            &[],
+        )
+        .await
+        .map_err(|e| DbError::Query(format!("查询 schema 列表失败: {}", e)))?;
+    Ok(rows.iter().map(|row| row.get(0)).collect())
+}
+
+/// 表列表中的名称：默认 schema 的表不带前缀，其余 schema 的表为 `schema.table`
+fn display_table_name(default_schema: &str, schema: &str, table: &str) -> String {
+    if schema == default_schema {
+        table.to_string()
+    } else {
+        format!("{}.{}", schema, table)
+    }
+}
+
 fn normalize_identifier(name: &str) -> String {
     let trimmed = name.trim();
     if trimmed.len() >= 2 {
@@ -79,6 +108,9 @@ pub(crate) async fn get_databases(config: &ConnectionConfig) -> Result<Vec<Strin
 }
 
 /// 获取 PostgreSQL 指定数据库的表列表
+///
+/// 覆盖所有可访问的 schema：默认 schema（`search_path` 解析出的 `current_schema()`）
+/// 的表排在最前且不带前缀，其余为 `schema.table`。
 pub(crate) async fn get_tables(
     config: &ConnectionConfig,
     database: &str,
@@ -89,17 +121,47 @@ pub(crate) async fn get_tables(
 
     let client = POOL_MANAGER.get_pg_client(&db_config).await?;
     let client = client.lock().await;
-    let schema = current_schema(&client).await?;
+    let default_schema = current_schema(&client).await?;
+    let schemas = visible_schemas(&client).await?;
 
     let rows = client
         .query(
-            "SELECT tablename FROM pg_tables WHERE schemaname = $1 ORDER BY tablename",
-            &[&schema],
+            "SELECT schemaname::text, tablename::text FROM pg_tables \
+             WHERE schemaname::text = ANY($1) \
+             ORDER BY schemaname::text = $2 DESC, schemaname, tablename",
+            &[&schemas, &default_schema],
         )
         .await
         .map_err(|e| DbError::Query(e.to_string()))?;
 
-    Ok(rows.iter().map(|r| r.get(0)).collect())
+    Ok(rows
+        .iter()
+        .map(|r| display_table_name(&default_schema, r.get(0), r.get(1)))
+        .collect())
+}
+
+/// 按 `pg_class.reltuples` 估算表行数（从未 ANALYZE 的表返回 `None`）
+pub(crate) async fn estimate_table_rows(
+    config: &ConnectionConfig,
+    table: &str,
+) -> Result<Option<u64>, DbError> {
+    let client = POOL_MANAGER.get_pg_client(config).await?;
+    let client = client.lock().await;
+    let (schema, table_name) = parse_table_ref(table);
+
+    let row = client
+        .query_opt(
+            "SELECT c.reltuples::bigint FROM pg_class c \
+             JOIN pg_namespace n ON n.oid = c.relnamespace \
+             WHERE c.relname = $1 AND n.nspname = COALESCE($2, current_schema())",
+            &[&table_name, &schema],
+        )
+        .await
+        .map_err(|e| DbError::Query(format!("估算表行数失败: {}", e)))?;
+
+    Ok(row
+        .and_then(|row| row.get::<_, Option<i64>>(0))
+        .and_then(|estimate| u64::try_from(estimate).ok()))
 }
 
 /// 删除 PostgreSQL 数据库。
@@ -139,17 +201,14 @@ pub(crate) async fn drop_database(
 /// 批量执行 PostgreSQL 语句（用于导入）
 pub(crate) async fn execute_batch(
     config: &ConnectionConfig,
-    statements: &[String],
+    mut batches: ImportBatchReceiver,
     use_transaction: bool,
     stop_on_error: bool,
 ) -> Result<ImportExecutionReport, DbError> {
     let client = POOL_MANAGER.get_pg_client(config).await?;
     let client = client.lock().await;
 
-    let mut report = ImportExecutionReport::new(statements.len());
-    if statements.is_empty() {
-        return Ok(report);
-    }
+    let mut report = ImportExecutionReport::new(0);
 
     if use_transaction {
         client
@@ -158,30 +217,47 @@ pub(crate) async fn execute_batch(
             .map_err(|e| DbError::Query(format!("开启事务失败: {}", e)))?;
     }
 
-    for (index, statement) in statements.iter().enumerate() {
-        if let Err(e) = client.batch_execute(statement).await {
-            let err_msg = format!("第 {} 条语句执行失败: {}", index + 1, e);
-
-            if use_transaction {
-                if let Err(rollback_err) = client.batch_execute("ROLLBACK").await {
+    while let Some(batch) = batches.recv().await {
+        let batch = match batch {
+            Ok(batch) => batch,
+            Err(error) => {
+                // 源文件读取失败时不提交已执行的部分
+                if use_transaction && let Err(rollback_err) = client.batch_execute("ROLLBACK").await
+                {
                     return Err(DbError::Query(format!(
-                        "事务回滚失败（原错误: {}，回滚错误: {}）",
-                        err_msg, rollback_err
+                        "事务回滚失败（原错误: 读取导入数据失败: {}，回滚错误: {}）",
+                        error, rollback_err
                     )));
                 }
-                return Err(DbError::Query(format!("事务已回滚，{}", err_msg)));
+                return Err(import_source_error(error));
             }
+        };
+        for statement in batch {
+            report.total += 1;
+            if let Err(e) = client.batch_execute(&statement).await {
+                let err_msg = format!("第 {} 条语句执行失败: {}", report.total, e);
 
-            report.failed += 1;
-            if report.first_error.is_none() {
-                report.first_error = Some(err_msg.clone());
-            }
+                if use_transaction {
+                    if let Err(rollback_err) = client.batch_execute("ROLLBACK").await {
+                        return Err(DbError::Query(format!(
+                            "事务回滚失败（原错误: {}，回滚错误: {}）",
+                            err_msg, rollback_err
+                        )));
+                    }
+                    return Err(DbError::Query(format!("事务已回滚，{}", err_msg)));
+                }
+
+                report.failed += 1;
+                if report.first_error.is_none() {
+                    report.first_error = Some(err_msg.clone());
+                }
 
-            if stop_on_error {
-                return Err(DbError::Query(err_msg));
+                if stop_on_error {
+                    return Err(DbError::Query(err_msg));
+                }
+            } else {
+                report.succeeded += 1;
             }
-        } else {
-            report.succeeded += 1;
         }
     }
 
@@ -311,10 +387,14 @@ pub(crate) async fn get_routines(config: &ConnectionConfig) -> Result<Vec<Routin
 use super::infer_type_family;
 use crate::domain::ids::SchemaRevision;
 use crate::domain::metadata::{
-    ColumnMetadata, ForeignKeyMetadata, KeyMetadata, SchemaCatalog, TableMetadata,
+    CheckConstraintMetadata, ColumnMetadata, ForeignKeyMetadata, IndexMetadata, KeyMetadata,
+    SchemaCatalog, SequenceMetadata, TableMetadata, ViewMetadata,
 };
 
 /// 从 information_schema 加载 PostgreSQL schema 元数据
+///
+/// 逐个 schema 加载所有可访问 schema 中的对象；对象名与 `get_tables` 一致，
+/// 默认 schema 之外的对象以 `schema.name` 命名，`schema` 字段保存实际 schema。
 pub(crate) async fn load_catalog(
     config: &ConnectionConfig,
     revision: SchemaRevision,
@@ -325,27 +405,28 @@ pub(crate) async fn load_catalog(
         .map_err(|e| DbError::Connection(format!("PG 连接池获取失败: {}", e)))?;
     let client = client.lock().await;
 
-    let schema = current_schema(&client).await?;
+    let default_schema = current_schema(&client).await?;
+    let schemas = visible_schemas(&client).await?;
 
-    // 1. 获取表列表
+    // 1. 获取表列表（schema, table）
     let table_rows = client
         .query(
-            "SELECT TABLE_NAME FROM information_schema.TABLES \
-             WHERE TABLE_SCHEMA = $1 AND TABLE_TYPE = 'BASE TABLE' \
-             ORDER BY TABLE_NAME",
-            &[&schema],
+            "SELECT TABLE_SCHEMA::text, TABLE_NAME::text FROM information_schema.TABLES \
+             WHERE TABLE_SCHEMA::text = ANY($1) AND TABLE_TYPE = 'BASE TABLE' \
+             ORDER BY TABLE_SCHEMA::text = $2 DESC, TABLE_SCHEMA, TABLE_NAME",
+            &[&schemas, &default_schema],
         )
         .await
         .map_err(|e| DbError::Query(format!("查询表列表失败: {}", e)))?;
 
-    let table_names: Vec<String> = table_rows
+    let table_refs: Vec<(String, String)> = table_rows
         .iter()
-        .map(|row| row.get::<_, String>(0))
+        .map(|row| (row.get::<_, String>(0), row.get::<_, String>(1)))
         .collect();
 
-    let mut tables = Vec::with_capacity(table_names.len());
+    let mut tables = Vec::with_capacity(table_refs.len());
 
-    for table_name in &table_names {
+    for (schema, table_name) in &table_refs {
         // 2. 列信息
         let col_rows = client
             .query(
@@ -395,7 +476,8 @@ pub(crate) async fn load_catalog(
                 "SELECT kcu.COLUMN_NAME, \
                         ccu.TABLE_NAME AS REFERENCED_TABLE_NAME, \
                         ccu.COLUMN_NAME AS REFERENCED_COLUMN_NAME, \
-                        kcu.CONSTRAINT_NAME \
+                        kcu.CONSTRAINT_NAME, \
+                        ccu.TABLE_SCHEMA::text AS REFERENCED_TABLE_SCHEMA \
                  FROM information_schema.KEY_COLUMN_USAGE kcu \
                  JOIN information_schema.REFERENTIAL_CONSTRAINTS rc \
                    ON kcu.CONSTRAINT_NAME = rc.CONSTRAINT_NAME \
@@ -418,7 +500,8 @@ pub(crate) async fn load_catalog(
         for row in &fk_rows {
             let constraint: String = row.get(3);
             let col: String = row.get(0);
-            let ref_table: String = row.get(1);
+            let ref_schema: String = row.get(4);
+            let ref_table = display_table_name(&default_schema, &ref_schema, row.get(1));
             let ref_col: String = row.get(2);
             let entry = fk_map
                 .entry(constraint.clone())
@@ -464,24 +547,155 @@ pub(crate) async fn load_catalog(
             })
             .collect();
 
+        // 5. 索引 — 表达式索引的列取 pg_get_indexdef 文本，INCLUDE 列不计入
+        let index_rows = client
+            .query(
+                "SELECT i.relname::text, ix.indisunique, ix.indisprimary, am.amname::text, \
+                        pg_get_expr(ix.indpred, ix.indrelid), \
+                        ARRAY(SELECT pg_get_indexdef(ix.indexrelid, k, true) \
+                              FROM generate_series(1, ix.indnkeyatts) AS k ORDER BY k) \
+                 FROM pg_index ix \
+                 JOIN pg_class t ON t.oid = ix.indrelid \
+                 JOIN pg_class i ON i.oid = ix.indexrelid \
+                 JOIN pg_namespace n ON n.oid = t.relnamespace \
+                 JOIN pg_am am ON am.oid = i.relam \
+                 WHERE n.nspname = $1 AND t.relname = $2 \
+                 ORDER BY ix.indisprimary DESC, i.relname",
+                &[&schema, &table_name.as_str()],
+            )
+            .await
+            .map_err(|e| DbError::Query(format!("查询索引失败: {}", e)))?;
+        let indexes: Vec<IndexMetadata> = index_rows
+            .iter()
+            .map(|r| IndexMetadata {
+                name: r.get(0),
+                is_unique: r.get(1),
+                is_primary: r.get(2),
+                method: Some(r.get(3)),
+                predicate: r.get(4),
+                columns: r.get(5),
+            })
+            .collect();
+
+        // 6. CHECK 约束
+        let check_rows = client
+            .query(
+                "SELECT c.conname::text, pg_get_constraintdef(c.oid, true) \
+                 FROM pg_constraint c \
+                 JOIN pg_class t ON t.oid = c.conrelid \
+                 JOIN pg_namespace n ON n.oid = t.relnamespace \
+                 WHERE c.contype = 'c' AND n.nspname = $1 AND t.relname = $2 \
+                 ORDER BY c.conname",
+                &[&schema, &table_name.as_str()],
+            )
+            .await
+            .map_err(|e| DbError::Query(format!("查询 CHECK 约束失败: {}", e)))?;
+        let check_constraints: Vec<CheckConstraintMetadata> = check_rows
+            .iter()
+            .map(|r| CheckConstraintMetadata {
+                name: Some(r.get(0)),
+                expression: r.get(1),
+            })
+            .collect();
+
         tables.push(TableMetadata {
-            name: table_name.clone(),
+            name: display_table_name(&default_schema, schema, table_name),
             schema: Some(schema.clone()),
             columns,
             primary_key,
-            unique_keys: Vec::new(),
+            unique_keys: indexes
+                .iter()
+                .filter_map(IndexMetadata::unique_key)
+                .collect(),
             foreign_keys,
+            indexes,
+            check_constraints,
         });
     }
 
-    Ok(SchemaCatalog { revision, tables })
+    // 7. 视图与物化视图
+    let view_rows = client
+        .query(
+            "SELECT v.schema_name, v.view_name, v.definition, v.materialized FROM ( \
+                 SELECT schemaname::text AS schema_name, viewname::text AS view_name, \
+                        definition, false AS materialized \
+                 FROM pg_views WHERE schemaname::text = ANY($1) \
+                 UNION ALL \
+                 SELECT schemaname::text, matviewname::text, definition, true \
+                 FROM pg_matviews WHERE schemaname::text = ANY($1) \
+             ) v \
+             ORDER BY v.schema_name = $2 DESC, v.schema_name, v.view_name",
+            &[&schemas, &default_schema],
+        )
+        .await
+        .map_err(|e| DbError::Query(format!("查询视图失败: {}", e)))?;
+    let views = view_rows
+        .iter()
+        .map(|r| {
+            let schema: String = r.get(0);
+            ViewMetadata {
+                name: display_table_name(&default_schema, &schema, r.get(1)),
+                schema: Some(schema),
+                definition: r.get(2),
+                materialized: r.get(3),
+            }
+        })
+        .collect();
+
+    // 8. 序列 — owned_by 来自 SERIAL / IDENTITY 列的依赖关系
+    let sequence_rows = client
+        .query(
+            "SELECT n.nspname::text, s.relname::text, seq.seqtypid::regtype::text, \
+                    seq.seqstart, seq.seqincrement, \
+                    (SELECT t.relname::text || '.' || a.attname::text \
+                     FROM pg_depend d \
+                     JOIN pg_class t ON t.oid = d.refobjid \
+                     JOIN pg_attribute a ON a.attrelid = d.refobjid AND a.attnum = d.refobjsubid \
+                     WHERE d.classid = 'pg_class'::regclass AND d.objid = s.oid \
+                       AND d.deptype IN ('a', 'i') \
+                     LIMIT 1) \
+             FROM pg_class s \
+             JOIN pg_namespace n ON n.oid = s.relnamespace \
+             JOIN pg_sequence seq ON seq.seqrelid = s.oid \
+             WHERE s.relkind = 'S' AND n.nspname::text = ANY($1) \
+             ORDER BY n.nspname::text = $2 DESC, n.nspname, s.relname",
+            &[&schemas, &default_schema],
+        )
+        .await
+        .map_err(|e| DbError::Query(format!("查询序列失败: {}", e)))?;
+    let sequences = sequence_rows
+        .iter()
+        .map(|r| {
+            let schema: String = r.get(0);
+            SequenceMetadata {
+                name: display_table_name(&default_schema, &schema, r.get(1)),
+                schema: Some(schema),
+                data_type: r.get(2),
+                start_value: r.get(3),
+                increment: r.get(4),
+                owned_by: r.get(5),
+            }
+        })
+        .collect();
+
+    Ok(SchemaCatalog {
+        revision,
+        default_schema: Some(default_schema),
+        tables,
+        views,
+        sequences,
+    })
 }
 
 // ── Typed ResultSet 执行 ──
 
-use crate::domain::execution::ExecutionOutcome;
-use crate::domain::result::{ResultColumn, ResultCompleteness, ResultSet};
+use crate::domain::execution::{ExecutionOutcome, StreamOutcome};
+use crate::domain::result::{ResultBatch, ResultColumn, ResultCompleteness, ResultSet};
 use crate::domain::value::{DbTypeFamily, DbTypeInfo, DbValue};
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+use super::session::{SessionCancel, SessionCommand, SessionState};
 
 /// 执行 SQL 并返回类型化 ResultSet（PostgreSQL 原生路径）
 pub(crate) async fn execute_typed(
@@ -490,7 +704,7 @@ pub(crate) async fn execute_typed(
 ) -> Result<ExecutionOutcome, DbError> {
     let client = POOL_MANAGER.get_pg_client(config).await?;
     let mut client = client.lock().await;
-    execute_typed_with_client(&mut client, sql).await
+    execute_typed_with_client(&mut client, sql, TransactionEnd::Commit).await
 }
 
 /// 使用 PostgreSQL CancelRequest 协作取消正在执行的查询。
@@ -498,6 +712,16 @@ pub(crate) async fn execute_typed_cancellable(
     config: &ConnectionConfig,
     sql: &str,
     cancellation: &tokio_util::sync::CancellationToken,
+) -> Result<ExecutionOutcome, DbError> {
+    execute_typed_cancellable_with(config, sql, cancellation, TransactionEnd::Commit).await
+}
+
+/// 可取消执行，并按 `end` 提交或回滚语句所在事务
+pub(crate) async fn execute_typed_cancellable_with(
+    config: &ConnectionConfig,
+    sql: &str,
+    cancellation: &tokio_util::sync::CancellationToken,
+    end: TransactionEnd,
 ) -> Result<ExecutionOutcome, DbError> {
     if cancellation.is_cancelled() {
         return Err(DbError::Cancelled);
@@ -506,7 +730,7 @@ pub(crate) async fn execute_typed_cancellable(
     let client = POOL_MANAGER.get_pg_client(config).await?;
     let mut client = client.lock().await;
     let cancel_token = client.cancel_token();
-    let query = execute_typed_with_client(&mut client, sql);
+    let query = execute_typed_with_client(&mut client, sql, end);
     tokio::pin!(query);
 
     tokio::select! {
@@ -522,7 +746,7 @@ pub(crate) async fn execute_typed_cancellable(
     }
 }
 
-async fn cancel_pg_query(
+pub(super) async fn cancel_pg_query(
     config: &ConnectionConfig,
     token: tokio_postgres::CancelToken,
 ) -> Result<(), DbError> {
@@ -548,8 +772,10 @@ async fn cancel_pg_query(
 async fn execute_typed_with_client(
     client: &mut tokio_postgres::Client,
     sql: &str,
+    end: TransactionEnd,
 ) -> Result<ExecutionOutcome, DbError> {
-    if !is_query_statement(sql, &DatabaseType::PostgreSQL) {
+    // 需要回滚时，非查询语句同样进入事务
+    if end == TransactionEnd::Commit && !is_query_statement(sql, &DatabaseType::PostgreSQL) {
         let affected = client
             .execute(sql, &[])
             .await
@@ -561,6 +787,261 @@ async fn execute_typed_with_client(
         .transaction()
         .await
         .map_err(|e| DbError::Query(format!("PG BEGIN result query: {e}")))?;
+    let result = query_in_transaction(&transaction, sql).await;
+
+    match (result, end) {
+        (Ok(outcome), TransactionEnd::Commit) => transaction
+            .commit()
+            .await
+            .map(|()| outcome)
+            .map_err(|e| DbError::Query(format!("PG COMMIT result query: {e}"))),
+        (Ok(outcome), TransactionEnd::Rollback) => transaction
+            .rollback()
+            .await
+            .map(|()| outcome)
+            .map_err(|e| DbError::Query(format!("PG ROLLBACK result query: {e}"))),
+        (Err(error), _) => rollback_pg_transaction(transaction, error).await,
+    }
+}
+
+/// 在已开启的事务中以有界 portal 读取查询结果
+async fn query_in_transaction(
+    transaction: &tokio_postgres::Transaction<'_>,
+    sql: &str,
+) -> Result<ExecutionOutcome, DbError> {
+    let statement = transaction
+        .prepare(sql)
+        .await
+        .map_err(|e| DbError::Query(format!("PG prepare query: {e}")))?;
+    let columns = statement.columns();
+    let typed_columns = pg_result_columns(columns);
+    let portal = transaction
+        .bind(&statement, &[])
+        .await
+        .map_err(|e| DbError::Query(format!("PG bind query portal: {e}")))?;
+    let max_rows = constants::database::MAX_RESULT_SET_ROWS;
+    let portal_limit = max_rows
+        .checked_add(1)
+        .and_then(|limit| i32::try_from(limit).ok())
+        .ok_or_else(|| {
+            DbError::Query("PG result row limit exceeds portal protocol limit".into())
+        })?;
+    let rows = transaction
+        .query_portal(&portal, portal_limit)
+        .await
+        .map_err(|e| DbError::Query(format!("PG fetch bounded query portal: {e}")))?;
+    let is_truncated = rows.len() > max_rows;
+    let displayed_rows = rows.into_iter().take(max_rows);
+    let mut cells = Vec::with_capacity(displayed_rows.len() * columns.len());
+
+    for row in displayed_rows {
+        for (index, column) in columns.iter().enumerate() {
+            cells.push(pg_row_value(&row, index, column.type_())?);
+        }
+    }
+
+    let row_count = cells.len() / columns.len().max(1);
+    let completeness = if is_truncated {
+        ResultCompleteness::Truncated {
+            displayed: row_count,
+        }
+    } else {
+        ResultCompleteness::Complete
+    };
+    Ok(ExecutionOutcome::single_result(ResultSet {
+        columns: typed_columns,
+        cells,
+        row_count,
+        completeness,
+    }))
+}
+
+/// 手动提交会话的 PostgreSQL 命令循环
+///
+/// 专用连接在首条命令时建立，不进入连接池。事务内每条语句包在 savepoint 中，
+/// 单条语句失败只撤销自身，事务不会进入 aborted 状态。
+pub(super) async fn run_pinned_session(
+    config: ConnectionConfig,
+    first: SessionCommand,
+    mut commands: UnboundedReceiver<SessionCommand>,
+    state: Arc<SessionState>,
+) {
+    let mut connection = None;
+    let mut next = Some(first);
+    loop {
+        let command = match next.take() {
+            Some(command) => command,
+            None => match commands.recv().await {
+                Some(command) => command,
+                None => break,
+            },
+        };
+        if connection
+            .as_ref()
+            .is_some_and(|(client, _): &(tokio_postgres::Client, _)| client.is_closed())
+        {
+            state.set_cancel(None);
+            connection = None;
+        }
+        if connection.is_none() {
+            match POOL_MANAGER.open_dedicated_pg_client(&config).await {
+                Ok((client, driver)) => {
+                    state.set_cancel(Some(SessionCancel::Postgres {
+                        config: config.clone(),
+                        token: client.cancel_token(),
+                    }));
+                    connection = Some((client, driver));
+                }
+                Err(error) => {
+                    command.fail(error);
+                    continue;
+                }
+            }
+        }
+        let Some((client, _)) = connection.as_mut() else {
+            continue;
+        };
+        match command {
+            // 没有打开的事务，提交/回滚无事可做
+            SessionCommand::End { reply, .. } => {
+                let _ = reply.send(Ok(()));
+            }
+            command => run_pinned_transaction(client, command, &mut commands, &state).await,
+        }
+    }
+    // 丢弃客户端后后台连接任务自行结束
+}
+
+/// 开启事务并处理命令，直到提交、回滚或所有句柄被丢弃
+async fn run_pinned_transaction(
+    client: &mut tokio_postgres::Client,
+    first: SessionCommand,
+    commands: &mut UnboundedReceiver<SessionCommand>,
+    state: &SessionState,
+) {
+    let mut transaction = match client.transaction().await {
+        Ok(transaction) => transaction,
+        Err(e) => {
+            first.fail(DbError::Query(format!("PG 开启事务失败: {e}")));
+            return;
+        }
+    };
+    state.begin();
+
+    let mut next = Some(first);
+    loop {
+        let command = match next.take() {
+            Some(command) => command,
+            None => match commands.recv().await {
+                Some(command) => command,
+                None => break,
+            },
+        };
+        match command {
+            SessionCommand::Begin { reply } => {
+                let _ = reply.send(Ok(()));
+            }
+            SessionCommand::Execute { sql, reply } => {
+                let result = execute_in_savepoint(&mut transaction, &sql).await;
+                if result.is_ok() {
+                    state.record_statement();
+                }
+                let _ = reply.send(result);
+            }
+            SessionCommand::End { end, reply } => {
+                let result = match end {
+                    TransactionEnd::Commit => transaction
+                        .commit()
+                        .await
+                        .map_err(|e| DbError::Query(format!("PG 提交事务失败: {e}"))),
+                    TransactionEnd::Rollback => transaction
+                        .rollback()
+                        .await
+                        .map_err(|e| DbError::Query(format!("PG 回滚事务失败: {e}"))),
+                };
+                state.end();
+                let _ = reply.send(result);
+                return;
+            }
+        }
+    }
+
+    // 句柄已全部丢弃，未提交的改动不保留
+    if let Err(e) = transaction.rollback().await {
+        tracing::warn!(error = %e, "PostgreSQL 独占会话关闭时回滚失败");
+    }
+    state.end();
+}
+
+async fn execute_in_savepoint(
+    transaction: &mut tokio_postgres::Transaction<'_>,
+    sql: &str,
+) -> Result<ExecutionOutcome, DbError> {
+    let savepoint = transaction
+        .savepoint("gridix_stmt")
+        .await
+        .map_err(|e| DbError::Query(format!("PG SAVEPOINT 失败: {e}")))?;
+    let result = if is_query_statement(sql, &DatabaseType::PostgreSQL) {
+        query_in_transaction(&savepoint, sql).await
+    } else {
+        savepoint
+            .execute(sql, &[])
+            .await
+            .map(ExecutionOutcome::affected_rows)
+            .map_err(|e| DbError::Query(e.to_string()))
+    };
+    match result {
+        Ok(outcome) => savepoint
+            .commit()
+            .await
+            .map(|()| outcome)
+            .map_err(|e| DbError::Query(format!("PG RELEASE SAVEPOINT 失败: {e}"))),
+        Err(error) => {
+            let _ = savepoint.rollback().await;
+            Err(error)
+        }
+    }
+}
+
+/// 流式执行 SQL，按批通过通道发送行（PostgreSQL 原生路径）
+///
+/// 在事务内绑定 portal，每次 `query_portal` 只拉取 `batch_size` 行并发送，
+/// 服务端游标保持打开；接收端关闭后不再拉取，直接提交结束 portal。
+pub(crate) async fn execute_streaming(
+    config: &ConnectionConfig,
+    sql: &str,
+    batch_size: usize,
+    sink: &tokio::sync::mpsc::Sender<ResultBatch>,
+    cancellation: &tokio_util::sync::CancellationToken,
+) -> Result<StreamOutcome, DbError> {
+    if cancellation.is_cancelled() {
+        return Err(DbError::Cancelled);
+    }
+
+    let client = POOL_MANAGER.get_pg_client(config).await?;
+    let mut client = client.lock().await;
+    let cancel_token = client.cancel_token();
+
+    if !is_query_statement(sql, &DatabaseType::PostgreSQL) {
+        let execute = client.execute(sql, &[]);
+        tokio::pin!(execute);
+        return tokio::select! {
+            biased;
+            result = &mut execute => result
+                .map(|rows| StreamOutcome::AffectedRows { rows })
+                .map_err(|e| DbError::Query(e.to_string())),
+            _ = cancellation.cancelled() => {
+                cancel_pg_query(config, cancel_token).await?;
+                let _ = execute.await;
+                Err(DbError::Cancelled)
+            }
+        };
+    }
+
+    let transaction = client
+        .transaction()
+        .await
+        .map_err(|e| DbError::Query(format!("PG BEGIN stream query: {e}")))?;
     let result = async {
         let statement = transaction
             .prepare(sql)
@@ -572,41 +1053,62 @@ async fn execute_typed_with_client(
             .bind(&statement, &[])
             .await
             .map_err(|e| DbError::Query(format!("PG bind query portal: {e}")))?;
-        let max_rows = constants::database::MAX_RESULT_SET_ROWS;
-        let portal_limit = max_rows
-            .checked_add(1)
-            .and_then(|limit| i32::try_from(limit).ok())
-            .ok_or_else(|| {
-                DbError::Query("PG result row limit exceeds portal protocol limit".into())
-            })?;
-        let rows = transaction
-            .query_portal(&portal, portal_limit)
-            .await
-            .map_err(|e| DbError::Query(format!("PG fetch bounded query portal: {e}")))?;
-        let is_truncated = rows.len() > max_rows;
-        let displayed_rows = rows.into_iter().take(max_rows);
-        let mut cells = Vec::with_capacity(displayed_rows.len() * columns.len());
-
-        for row in displayed_rows {
-            for (index, column) in columns.iter().enumerate() {
-                cells.push(pg_row_value(&row, index, column.type_())?);
+        let batch_size = batch_size.max(1);
+        let fetch_limit = i32::try_from(batch_size).unwrap_or(i32::MAX);
+        let mut total = 0usize;
+        let mut sent_any = false;
+
+        loop {
+            let fetch = transaction.query_portal(&portal, fetch_limit);
+            tokio::pin!(fetch);
+            let rows = tokio::select! {
+                biased;
+                rows = &mut fetch => rows
+                    .map_err(|e| DbError::Query(format!("PG fetch stream portal: {e}")))?,
+                _ = cancellation.cancelled() => {
+                    cancel_pg_query(config, cancel_token.clone()).await?;
+                    let _ = fetch.await;
+                    return Err(DbError::Cancelled);
+                }
+            };
+            let exhausted = rows.len() < batch_size;
+            if rows.is_empty() && sent_any {
+                break;
             }
-        }
 
-        let row_count = cells.len() / columns.len().max(1);
-        let completeness = if is_truncated {
-            ResultCompleteness::Truncated {
-                displayed: row_count,
+            let mut cells = Vec::with_capacity(rows.len() * columns.len());
+            for row in &rows {
+                for (index, column) in columns.iter().enumerate() {
+                    cells.push(pg_row_value(row, index, column.type_())?);
+                }
             }
-        } else {
-            ResultCompleteness::Complete
-        };
-        Ok(ExecutionOutcome::single_result(ResultSet {
-            columns: typed_columns,
-            cells,
-            row_count,
-            completeness,
-        }))
+            let batch = ResultBatch {
+                columns: typed_columns.clone(),
+                cells,
+                row_count: rows.len(),
+            };
+            let sent = tokio::select! {
+                biased;
+                sent = sink.send(batch) => sent.is_ok(),
+                _ = cancellation.cancelled() => return Err(DbError::Cancelled),
+            };
+            if !sent {
+                return Ok(StreamOutcome::Rows {
+                    total,
+                    stopped_early: true,
+                });
+            }
+            sent_any = true;
+            total += rows.len();
+            if exhausted {
+                break;
+            }
+        }
+
+        Ok(StreamOutcome::Rows {
+            total,
+            stopped_early: false,
+        })
     }
     .await;
 
@@ -615,15 +1117,15 @@ async fn execute_typed_with_client(
             .commit()
             .await
             .map(|()| outcome)
-            .map_err(|e| DbError::Query(format!("PG COMMIT result query: {e}"))),
+            .map_err(|e| DbError::Query(format!("PG COMMIT stream query: {e}"))),
         Err(error) => rollback_pg_transaction(transaction, error).await,
     }
 }
 
-async fn rollback_pg_transaction(
+async fn rollback_pg_transaction<T>(
     transaction: tokio_postgres::Transaction<'_>,
     original_error: DbError,
-) -> Result<ExecutionOutcome, DbError> {
+) -> Result<T, DbError> {
     match transaction.rollback().await {
         Ok(()) => Err(original_error),
         Err(rollback_error) => Err(DbError::Query(format!(
@@ -930,6 +1432,34 @@ impl ToSql for PgNumericText {
 
     tokio_postgres::types::to_sql_checked!();
 }
+/// 以文本格式发送的参数，由服务端按目标列类型解析
+///
+/// 日期时间、JSON、UUID、数组以及结果解码不支持的类型都走这里，
+/// 避免为每种 PG 类型实现二进制编码。
+#[derive(Debug)]
+struct PgTextLiteral(String);
+
+impl ToSql for PgTextLiteral {
+    fn to_sql(
+        &self,
+        _: &Type,
+        output: &mut tokio_postgres::types::private::BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        output.extend_from_slice(self.0.as_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn accepts(_: &Type) -> bool {
+        true
+    }
+
+    fn encode_format(&self, _: &Type) -> Format {
+        Format::Text
+    }
+
+    tokio_postgres::types::to_sql_checked!();
+}
+
 use crate::domain::mutation::{
     ExpectedRows, InputValue, Mutation, MutationBatch, MutationBatchResult, RowIdentity,
 };
@@ -947,9 +1477,16 @@ fn pg_param(value: &DbValue, target_type: &Type) -> Result<Box<dyn ToSql + Sync
             pg_integer_param(value, target_type)?
         }
         DbValue::Float(value) => pg_float_param(*value, target_type)?,
-        DbValue::Text(value) => Box::new(value.clone()),
+        DbValue::Text(value) if <String as ToSql>::accepts(target_type) => Box::new(value.clone()),
+        DbValue::Text(value) => Box::new(PgTextLiteral(value.clone())),
         DbValue::Bytes(value) => Box::new(value.to_vec()),
         DbValue::Decimal(value) => pg_decimal_param(value, target_type)?,
+        DbValue::Date(_)
+        | DbValue::Time(_)
+        | DbValue::DateTime(_)
+        | DbValue::Json(_)
+        | DbValue::Uuid(_)
+        | DbValue::Array(_) => Box::new(PgTextLiteral(pg_text_literal(value)?)),
         _ => {
             return Err(DbError::Unsupported {
                 capability: "PG param",
@@ -958,6 +1495,57 @@ fn pg_param(value: &DbValue, target_type: &Type) -> Result<Box<dyn ToSql + Sync
     })
 }
 
+/// DbValue → PG 文本格式字面量（不含 SQL 引号）
+fn pg_text_literal(value: &DbValue) -> Result<String, DbError> {
+    Ok(match value {
+        DbValue::Date(date) => format!("{:04}-{:02}-{:02}", date.year, date.month, date.day),
+        DbValue::Time(time) => pg_time_literal(time),
+        DbValue::DateTime(datetime) => format!(
+            "{:04}-{:02}-{:02} {}",
+            datetime.date.year,
+            datetime.date.month,
+            datetime.date.day,
+            pg_time_literal(&datetime.time)
+        ),
+        DbValue::Json(json) => json.to_string(),
+        DbValue::Uuid(uuid) => uuid.to_string(),
+        DbValue::Array(items) => {
+            let elements = items
+                .iter()
+                .map(|item| match item {
+                    DbValue::Null => Ok("NULL".to_string()),
+                    item => pg_text_literal(item).map(|text| pg_array_element(&text)),
+                })
+                .collect::<Result<Vec<_>, DbError>>()?;
+            format!("{{{}}}", elements.join(","))
+        }
+        DbValue::Bool(value) => value.to_string(),
+        DbValue::Int(_) | DbValue::UInt(_) | DbValue::Float(_) | DbValue::Decimal(_) => {
+            value.display()
+        }
+        DbValue::Text(text) => text.clone(),
+        _ => {
+            return Err(DbError::Unsupported {
+                capability: "PG text literal",
+            });
+        }
+    })
+}
+
+fn pg_time_literal(time: &crate::domain::value::DbTime) -> String {
+    let mut text = format!("{:02}:{:02}:{:02}", time.hour, time.minute, time.second);
+    if time.nanos > 0 {
+        // PG 精度为微秒
+        text.push_str(&format!(".{:06}", time.nanos / 1_000));
+    }
+    text
+}
+
+/// 数组元素一律加双引号，元素内的 `\` 与 `"` 转义
+fn pg_array_element(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 fn pg_integer_param(
     value: i64,
     target_type: &Type,
@@ -1090,7 +1678,7 @@ pub(crate) async fn apply_mutations(
                     if included.is_empty() {
                         let sql = format!(
                             "INSERT INTO {} DEFAULT VALUES",
-                            IdentifierDialect::PostgreSql.quote(&table.name)
+                            IdentifierDialect::PostgreSql.quote_table(&table.name)
                         );
                         client
                             .execute(&sql, &[])
@@ -1105,7 +1693,7 @@ pub(crate) async fn apply_mutations(
                             (1..=included.len()).map(|i| format!("${i}")).collect();
                         let sql = format!(
                             "INSERT INTO {} ({}) VALUES ({})",
-                            IdentifierDialect::PostgreSql.quote(&table.name),
+                            IdentifierDialect::PostgreSql.quote_table(&table.name),
                             cols.join(", "),
                             ph.join(", ")
                         );
@@ -1164,7 +1752,7 @@ pub(crate) async fn apply_mutations(
                         .collect();
                     let sql = format!(
                         "UPDATE {} SET {} WHERE {}",
-                        IdentifierDialect::PostgreSql.quote(&table.name),
+                        IdentifierDialect::PostgreSql.quote_table(&table.name),
                         set_sql.join(", "),
                         where_sql.join(" AND ")
                     );
@@ -1234,7 +1822,7 @@ pub(crate) async fn apply_mutations(
                         .collect();
                     let sql = format!(
                         "DELETE FROM {} WHERE {}",
-                        IdentifierDialect::PostgreSql.quote(&table.name),
+                        IdentifierDialect::PostgreSql.quote_table(&table.name),
                         where_sql.join(" AND ")
                     );
                     let statement = client
@@ -1305,3 +1893,39 @@ async fn rollback_pg_mutation_batch(
         ))),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value::{DbDate, DbDateTime, DbTime};
+
+    #[test]
+    fn typed_values_encode_as_pg_text_literals() {
+        let datetime = DbValue::DateTime(DbDateTime {
+            date: DbDate {
+                year: 2024,
+                month: 2,
+                day: 29,
+            },
+            time: DbTime {
+                hour: 8,
+                minute: 5,
+                second: 0,
+                nanos: 120_000_000,
+            },
+        });
+        assert_eq!(
+            pg_text_literal(&datetime).unwrap(),
+            "2024-02-29 08:05:00.120000"
+        );
+
+        let array = DbValue::Array(vec![
+            DbValue::Text("a,\"b\"".to_string()),
+            DbValue::Null,
+            DbValue::Int(3),
+        ]);
+        assert_eq!(pg_text_literal(&array).unwrap(), r#"{"a,\"b\"",NULL,"3"}"#);
+        assert!(pg_param(&array, &Type::TEXT_ARRAY).is_ok());
+        assert!(pg_param(&DbValue::Text("1 day".to_string()), &Type::INTERVAL).is_ok());
+    }
+}
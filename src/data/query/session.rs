@@ -0,0 +1,385 @@
+//! 交互式事务会话
+//!
+//! 手动提交模式下，查询 Tab 独占一条连接：普通执行每次从连接池取连接，跨执行的
+//! `BEGIN` / `COMMIT` 会落在不同会话上。`PinnedSession` 由后台任务持有连接，通过
+//! 通道串行接收命令，首条语句前开启事务，直到显式提交或回滚。
+//!
+//! 所有句柄被丢弃后后台任务退出，未提交的事务随之回滚，连接关闭。
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use tokio::sync::{mpsc, oneshot};
+
+use super::{TransactionControl, TransactionEnd, setup_ssh_tunnel_if_enabled, transaction_control};
+use crate::data::{ConnectionConfig, DatabaseType, DbError};
+use crate::domain::execution::ExecutionOutcome;
+
+/// 发送给会话后台任务的命令
+pub(crate) enum SessionCommand {
+    /// 执行一条语句；没有打开的事务时先开启
+    Execute {
+        sql: String,
+        reply: oneshot::Sender<Result<ExecutionOutcome, DbError>>,
+    },
+    /// 开启事务（已开启时忽略）
+    Begin {
+        reply: oneshot::Sender<Result<(), DbError>>,
+    },
+    /// 提交或回滚当前事务（没有打开的事务时忽略）
+    End {
+        end: TransactionEnd,
+        reply: oneshot::Sender<Result<(), DbError>>,
+    },
+}
+
+impl SessionCommand {
+    /// 以错误回复命令（连接建立失败等）
+    pub(crate) fn fail(self, error: DbError) {
+        match self {
+            Self::Execute { reply, .. } => {
+                let _ = reply.send(Err(error));
+            }
+            Self::Begin { reply } | Self::End { reply, .. } => {
+                let _ = reply.send(Err(error));
+            }
+        }
+    }
+}
+
+/// 中断会话上正在执行的语句所需的信息
+pub(crate) enum SessionCancel {
+    Postgres {
+        config: ConnectionConfig,
+        token: tokio_postgres::CancelToken,
+    },
+    MySql {
+        config: ConnectionConfig,
+        connection_id: u32,
+    },
+    Sqlite(rusqlite::InterruptHandle),
+}
+
+/// 会话后台任务与句柄共享的状态
+#[derive(Default)]
+pub(crate) struct SessionState {
+    in_transaction: AtomicBool,
+    statements: AtomicUsize,
+    closed: AtomicBool,
+    cancel: std::sync::Mutex<Option<SessionCancel>>,
+}
+
+impl SessionState {
+    pub(crate) fn in_transaction(&self) -> bool {
+        self.in_transaction.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn begin(&self) {
+        self.statements.store(0, Ordering::Release);
+        self.in_transaction.store(true, Ordering::Release);
+    }
+
+    pub(crate) fn record_statement(&self) {
+        self.statements.fetch_add(1, Ordering::AcqRel);
+    }
+
+    pub(crate) fn end(&self) {
+        self.in_transaction.store(false, Ordering::Release);
+        self.statements.store(0, Ordering::Release);
+    }
+
+    /// 连接建立后登记取消方式；连接断开时传入 `None`
+    pub(crate) fn set_cancel(&self, cancel: Option<SessionCancel>) {
+        if let Ok(mut slot) = self.cancel.lock() {
+            *slot = cancel;
+        }
+    }
+}
+
+/// 标记会话已关闭（后台任务退出时释放）
+struct ClosedOnDrop(Arc<SessionState>);
+
+impl Drop for ClosedOnDrop {
+    fn drop(&mut self) {
+        self.0.end();
+        self.0.set_cancel(None);
+        self.0.closed.store(true, Ordering::Release);
+    }
+}
+
+/// 查询 Tab 独占的连接会话
+#[derive(Clone)]
+pub struct PinnedSession {
+    db_type: DatabaseType,
+    commands: mpsc::UnboundedSender<SessionCommand>,
+    state: Arc<SessionState>,
+}
+
+impl PinnedSession {
+    /// 创建会话句柄及驱动它的后台任务
+    ///
+    /// 返回的 future 需由调用方在 tokio 运行时中 spawn；连接在收到第一条命令时建立，
+    /// 失败后下一条命令会重试。
+    pub fn new(
+        config: ConnectionConfig,
+    ) -> (Self, impl std::future::Future<Output = ()> + Send + 'static) {
+        let (commands, receiver) = mpsc::unbounded_channel();
+        let state = Arc::new(SessionState::default());
+        let session = Self {
+            db_type: config.db_type,
+            commands,
+            state: state.clone(),
+        };
+        (session, drive_session(config, receiver, state))
+    }
+
+    /// 是否有打开且未提交的事务
+    pub fn in_transaction(&self) -> bool {
+        self.state.in_transaction()
+    }
+
+    /// 当前事务中已成功执行的语句数
+    pub fn statement_count(&self) -> usize {
+        self.state.statements.load(Ordering::Acquire)
+    }
+
+    /// 后台任务是否已退出（连接无法建立或已关闭）
+    pub fn is_closed(&self) -> bool {
+        self.state.closed.load(Ordering::Acquire) || self.commands.is_closed()
+    }
+
+    /// 在会话中执行一条语句
+    ///
+    /// `BEGIN` / `COMMIT` / `ROLLBACK` 等事务控制语句映射为会话自身的开启、提交、回滚，
+    /// 保证事务状态与指示器一致。取消时中断服务端语句，事务保持打开。
+    pub async fn execute(
+        &self,
+        sql: &str,
+        cancellation: &tokio_util::sync::CancellationToken,
+    ) -> Result<ExecutionOutcome, DbError> {
+        if cancellation.is_cancelled() {
+            return Err(DbError::Cancelled);
+        }
+        match transaction_control(sql) {
+            Some(TransactionControl::Begin) => {
+                let (reply, response) = oneshot::channel();
+                self.send(SessionCommand::Begin { reply }, response)
+                    .await
+                    .map(|()| ExecutionOutcome::affected_rows(0))
+            }
+            Some(TransactionControl::Commit) => self
+                .commit()
+                .await
+                .map(|()| ExecutionOutcome::affected_rows(0)),
+            Some(TransactionControl::Rollback) => self
+                .rollback()
+                .await
+                .map(|()| ExecutionOutcome::affected_rows(0)),
+            None => {
+                let (reply, response) = oneshot::channel();
+                let command = SessionCommand::Execute {
+                    sql: sql.to_string(),
+                    reply,
+                };
+                let execution = self.send(command, response);
+                tokio::pin!(execution);
+                tokio::select! {
+                    biased;
+                    result = &mut execution => result,
+                    _ = cancellation.cancelled() => {
+                        self.cancel_running().await?;
+                        match execution.await {
+                            Err(_) => Err(DbError::Cancelled),
+                            Ok(outcome) => Ok(outcome),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// 提交当前事务
+    pub async fn commit(&self) -> Result<(), DbError> {
+        let (reply, response) = oneshot::channel();
+        let end = TransactionEnd::Commit;
+        self.send(SessionCommand::End { end, reply }, response)
+            .await
+    }
+
+    /// 回滚当前事务
+    pub async fn rollback(&self) -> Result<(), DbError> {
+        let (reply, response) = oneshot::channel();
+        let end = TransactionEnd::Rollback;
+        self.send(SessionCommand::End { end, reply }, response)
+            .await
+    }
+
+    async fn send<T>(
+        &self,
+        command: SessionCommand,
+        response: oneshot::Receiver<Result<T, DbError>>,
+    ) -> Result<T, DbError> {
+        self.commands
+            .send(command)
+            .map_err(|_| DbError::Connection("独占连接已关闭".to_string()))?;
+        response
+            .await
+            .map_err(|_| DbError::Connection("独占连接已关闭".to_string()))?
+    }
+
+    /// 中断会话上正在执行的语句
+    async fn cancel_running(&self) -> Result<(), DbError> {
+        let cancel = {
+            let Ok(slot) = self.state.cancel.lock() else {
+                return Ok(());
+            };
+            match slot.as_ref() {
+                Some(SessionCancel::Postgres { config, token }) => SessionCancel::Postgres {
+                    config: config.clone(),
+                    token: token.clone(),
+                },
+                Some(SessionCancel::MySql {
+                    config,
+                    connection_id,
+                }) => SessionCancel::MySql {
+                    config: config.clone(),
+                    connection_id: *connection_id,
+                },
+                Some(SessionCancel::Sqlite(handle)) => {
+                    handle.interrupt();
+                    return Ok(());
+                }
+                None => return Ok(()),
+            }
+        };
+        match cancel {
+            SessionCancel::Postgres { config, token } => {
+                super::postgres::cancel_pg_query(&config, token).await
+            }
+            SessionCancel::MySql {
+                config,
+                connection_id,
+            } => super::mysql::cancel_query_on_connection(&config, connection_id).await,
+            SessionCancel::Sqlite(_) => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Debug for PinnedSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PinnedSession")
+            .field("db_type", &self.db_type)
+            .field("in_transaction", &self.in_transaction())
+            .field("statements", &self.statement_count())
+            .field("closed", &self.is_closed())
+            .finish()
+    }
+}
+
+/// 会话后台任务：建立 SSH 隧道后交给各后端的命令循环
+async fn drive_session(
+    config: ConnectionConfig,
+    mut commands: mpsc::UnboundedReceiver<SessionCommand>,
+    state: Arc<SessionState>,
+) {
+    let _closed = ClosedOnDrop(state.clone());
+    // 收到命令时才建立隧道；失败时以错误回复该命令，下一条命令重试。
+    // 隧道引用需要在会话期间保持存活
+    let (first, effective_config, _tunnel) = loop {
+        let Some(command) = commands.recv().await else {
+            return;
+        };
+        match setup_ssh_tunnel_if_enabled(&config).await {
+            Ok((effective_config, tunnel)) => break (command, effective_config, tunnel),
+            Err(error) => command.fail(error),
+        }
+    };
+
+    match effective_config.db_type {
+        DatabaseType::PostgreSQL => {
+            super::postgres::run_pinned_session(effective_config, first, commands, state).await
+        }
+        DatabaseType::MySQL => {
+            super::mysql::run_pinned_session(effective_config, first, commands, state).await
+        }
+        DatabaseType::SQLite => {
+            let task = tokio::task::spawn_blocking(move || {
+                super::sqlite::run_pinned_session(&effective_config, first, commands, &state)
+            });
+            if let Err(error) = task.await {
+                tracing::warn!(error = %error, "SQLite 独占会话任务异常退出");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::NamedTempFile;
+    use tokio_util::sync::CancellationToken;
+
+    fn sqlite_config(path: &std::path::Path) -> ConnectionConfig {
+        ConnectionConfig {
+            db_type: DatabaseType::SQLite,
+            database: path.to_string_lossy().into_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn count_rows(path: &std::path::Path) -> i64 {
+        rusqlite::Connection::open(path)
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM t", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn pinned_session_keeps_transaction_open_until_commit_or_rollback() {
+        let file = NamedTempFile::new().unwrap();
+        rusqlite::Connection::open(file.path())
+            .unwrap()
+            .execute_batch("CREATE TABLE t (id INTEGER)")
+            .unwrap();
+        let (session, driver) = PinnedSession::new(sqlite_config(file.path()));
+        tokio::spawn(driver);
+        let cancel = CancellationToken::new();
+
+        session
+            .execute("INSERT INTO t VALUES (1)", &cancel)
+            .await
+            .unwrap();
+        assert!(session.in_transaction());
+        assert_eq!(session.statement_count(), 1);
+        session.rollback().await.unwrap();
+        assert!(!session.in_transaction());
+        assert_eq!(count_rows(file.path()), 0);
+
+        session
+            .execute("INSERT INTO t VALUES (2)", &cancel)
+            .await
+            .unwrap();
+        session.execute("COMMIT", &cancel).await.unwrap();
+        assert!(!session.in_transaction());
+        assert_eq!(count_rows(file.path()), 1);
+    }
+
+    #[tokio::test]
+    async fn dropping_pinned_session_rolls_back_open_transaction() {
+        let file = NamedTempFile::new().unwrap();
+        rusqlite::Connection::open(file.path())
+            .unwrap()
+            .execute_batch("CREATE TABLE t (id INTEGER)")
+            .unwrap();
+        let (session, driver) = PinnedSession::new(sqlite_config(file.path()));
+        let driver = tokio::spawn(driver);
+
+        session
+            .execute("INSERT INTO t VALUES (1)", &CancellationToken::new())
+            .await
+            .unwrap();
+        drop(session);
+        driver.await.unwrap();
+        assert_eq!(count_rows(file.path()), 0);
+    }
+}
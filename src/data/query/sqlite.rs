@@ -1,14 +1,19 @@
 //! SQLite 查询实现
 
-use super::{ImportExecutionReport, TriggerInfo, is_query_statement};
+use super::session::{SessionCancel, SessionCommand, SessionState};
+use super::{
+    ImportBatchReceiver, ImportExecutionReport, TransactionEnd, TriggerInfo, import_source_error,
+    is_query_statement,
+};
 use crate::core::constants;
 use crate::data::{ConnectionConfig, DatabaseType, DbError};
-use crate::domain::execution::ExecutionOutcome;
+use crate::domain::execution::{ExecutionOutcome, StreamOutcome};
 use crate::domain::identifier::IdentifierDialect;
-use crate::domain::result::{ResultColumn, ResultCompleteness, ResultSet};
+use crate::domain::result::{ResultBatch, ResultColumn, ResultCompleteness, ResultSet};
 use crate::domain::value::{DbTypeFamily, DbTypeInfo, DbValue};
 use rusqlite::{Connection as SqliteConn, types::ValueRef};
 use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedReceiver;
 
 /// 连接 SQLite 并获取表列表
 pub(crate) fn connect(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
@@ -27,6 +32,25 @@ pub(crate) fn connect(config: &ConnectionConfig) -> Result<Vec<String>, DbError>
     tables.map_err(|e| DbError::Query(e.to_string()))
 }
 
+/// SQLite 没有行数统计，直接 `COUNT(*)`
+pub(crate) fn estimate_table_rows(
+    config: &ConnectionConfig,
+    table: &str,
+) -> Result<Option<u64>, DbError> {
+    let conn = SqliteConn::open(&config.database)
+        .map_err(|e| DbError::Connection(format!("SQLite 连接失败: {}", e)))?;
+
+    let sql = format!(
+        "SELECT COUNT(*) FROM {}",
+        IdentifierDialect::SQLite.quote(table)
+    );
+    let count: i64 = conn
+        .query_row(&sql, [], |row| row.get(0))
+        .map_err(|e| DbError::Query(format!("统计表行数失败: {}", e)))?;
+
+    Ok(u64::try_from(count).ok())
+}
+
 // ── Typed Mutation execution (Phase 7) ──
 
 use crate::domain::mutation::{
@@ -290,32 +314,32 @@ fn dbvalue_to_rusqlite(v: &DbValue) -> Result<rusqlite::types::Value, DbError> {
 /// 批量执行 SQLite 语句（用于导入）
 pub(crate) fn execute_batch(
     config: &ConnectionConfig,
-    statements: &[String],
+    mut batches: ImportBatchReceiver,
     use_transaction: bool,
     stop_on_error: bool,
 ) -> Result<ImportExecutionReport, DbError> {
     let mut conn = SqliteConn::open(&config.database)
         .map_err(|e| DbError::Connection(format!("SQLite 连接失败: {}", e)))?;
 
-    let mut report = ImportExecutionReport::new(statements.len());
-    if statements.is_empty() {
-        return Ok(report);
-    }
+    let mut report = ImportExecutionReport::new(0);
 
     if use_transaction {
         let tx = conn
             .transaction()
             .map_err(|e| DbError::Query(format!("开启事务失败: {}", e)))?;
 
-        for (index, statement) in statements.iter().enumerate() {
-            if let Err(e) = tx.execute_batch(statement) {
-                return Err(DbError::Query(format!(
-                    "事务已回滚，第 {} 条语句执行失败: {}",
-                    index + 1,
-                    e
-                )));
+        // 提前返回时 tx 析构即回滚
+        while let Some(batch) = batches.blocking_recv() {
+            for statement in batch.map_err(import_source_error)? {
+                report.total += 1;
+                if let Err(e) = tx.execute_batch(&statement) {
+                    return Err(DbError::Query(format!(
+                        "事务已回滚，第 {} 条语句执行失败: {}",
+                        report.total, e
+                    )));
+                }
+                report.succeeded += 1;
             }
-            report.succeeded += 1;
         }
 
         tx.commit()
@@ -323,23 +347,27 @@ pub(crate) fn execute_batch(
         return Ok(report);
     }
 
-    for (index, statement) in statements.iter().enumerate() {
-        if let Err(e) = conn.execute_batch(statement) {
-            report.failed += 1;
-            if report.first_error.is_none() {
-                report.first_error = Some(format!("第 {} 条语句执行失败: {}", index + 1, e));
-            }
+    while let Some(batch) = batches.blocking_recv() {
+        for statement in batch.map_err(import_source_error)? {
+            report.total += 1;
+            let index = report.total;
+            if let Err(e) = conn.execute_batch(&statement) {
+                report.failed += 1;
+                if report.first_error.is_none() {
+                    report.first_error = Some(format!("第 {} 条语句执行失败: {}", index, e));
+                }
 
-            if stop_on_error {
-                return Err(DbError::Query(
-                    report
-                        .first_error
-                        .clone()
-                        .unwrap_or_else(|| format!("第 {} 条语句执行失败", index + 1)),
-                ));
+                if stop_on_error {
+                    return Err(DbError::Query(
+                        report
+                            .first_error
+                            .clone()
+                            .unwrap_or_else(|| format!("第 {} 条语句执行失败", index)),
+                    ));
+                }
+            } else {
+                report.succeeded += 1;
             }
-        } else {
-            report.succeeded += 1;
         }
     }
 
@@ -358,6 +386,125 @@ pub(crate) fn execute_typed(
     execute_typed_with_connection(&conn, sql)
 }
 
+/// 可取消执行：取消时通过 interrupt handle 中断正在执行的语句
+pub(crate) async fn execute_typed_interruptible(
+    config: &ConnectionConfig,
+    sql: &str,
+    cancellation: &tokio_util::sync::CancellationToken,
+) -> Result<ExecutionOutcome, DbError> {
+    let path = config.database.clone();
+    let conn = tokio::task::spawn_blocking(move || SqliteConn::open(path))
+        .await
+        .map_err(|e| DbError::Query(e.to_string()))?
+        .map_err(|e| DbError::Connection(format!("SQLite 连接失败: {}", e)))?;
+    let interrupt = conn.get_interrupt_handle();
+    let sql = sql.to_string();
+    let execution = tokio::task::spawn_blocking(move || execute_typed_with_connection(&conn, &sql));
+    tokio::pin!(execution);
+
+    tokio::select! {
+        biased;
+        result = &mut execution => result.map_err(|e| DbError::Query(e.to_string()))?,
+        _ = cancellation.cancelled() => {
+            interrupt.interrupt();
+            match execution.await {
+                Ok(Ok(outcome)) => Ok(outcome),
+                _ => Err(DbError::Cancelled),
+            }
+        }
+    }
+}
+
+/// 手动提交会话的 SQLite 命令循环（在阻塞线程中运行）
+pub(super) fn run_pinned_session(
+    config: &ConnectionConfig,
+    first: SessionCommand,
+    mut commands: UnboundedReceiver<SessionCommand>,
+    state: &SessionState,
+) {
+    let mut connection: Option<SqliteConn> = None;
+    let mut next = Some(first);
+    loop {
+        let command = match next.take() {
+            Some(command) => command,
+            None => match commands.blocking_recv() {
+                Some(command) => command,
+                None => break,
+            },
+        };
+        if connection.is_none() {
+            match SqliteConn::open(&config.database) {
+                Ok(conn) => {
+                    state.set_cancel(Some(SessionCancel::Sqlite(conn.get_interrupt_handle())));
+                    connection = Some(conn);
+                }
+                Err(e) => {
+                    command.fail(DbError::Connection(format!("SQLite 连接失败: {}", e)));
+                    continue;
+                }
+            }
+        }
+        let Some(conn) = connection.as_ref() else {
+            continue;
+        };
+        match command {
+            SessionCommand::Begin { reply } => {
+                let _ = reply.send(begin_pinned_transaction(conn, state));
+            }
+            SessionCommand::Execute { sql, reply } => {
+                let result = begin_pinned_transaction(conn, state)
+                    .and_then(|()| execute_typed_with_connection(conn, &sql));
+                if result.is_ok() {
+                    state.record_statement();
+                }
+                let _ = reply.send(result);
+            }
+            SessionCommand::End { end, reply } => {
+                let result = if state.in_transaction() {
+                    end_pinned_transaction(conn, end)
+                } else {
+                    Ok(())
+                };
+                // 提交失败（如 SQLITE_BUSY / 锁等待超时）时事务仍然打开，保留未提交状态
+                if result.is_ok() {
+                    state.end();
+                }
+                let _ = reply.send(result);
+            }
+        }
+    }
+
+    // 句柄已全部丢弃，未提交的改动不保留
+    if let Some(conn) = connection
+        && state.in_transaction()
+        && let Err(error) = end_pinned_transaction(&conn, TransactionEnd::Rollback)
+    {
+        tracing::warn!(error = %error, "SQLite 独占会话关闭时回滚失败");
+    }
+    state.end();
+}
+
+fn begin_pinned_transaction(conn: &SqliteConn, state: &SessionState) -> Result<(), DbError> {
+    if state.in_transaction() {
+        return Ok(());
+    }
+    conn.execute_batch("BEGIN")
+        .map_err(|e| DbError::Query(format!("SQLite 开启事务失败: {}", e)))?;
+    state.begin();
+    Ok(())
+}
+
+fn end_pinned_transaction(conn: &SqliteConn, end: TransactionEnd) -> Result<(), DbError> {
+    match end {
+        TransactionEnd::Commit => conn
+            .execute_batch("COMMIT")
+            .map_err(|e| DbError::Query(format!("SQLite 提交事务失败: {}", e))),
+        TransactionEnd::Rollback => conn
+            .execute_batch("ROLLBACK")
+            .map_err(|e| DbError::Query(format!("SQLite 回滚事务失败: {}", e))),
+    }
+}
+
 fn execute_typed_with_connection(
     conn: &SqliteConn,
     sql: &str,
@@ -414,8 +561,23 @@ fn execute_typed_with_connection(
         ResultCompleteness::Complete
     };
 
-    // 从数据推断列类型（第一行非 NULL 值）
-    let columns: Arc<[ResultColumn]> = col_names
+    let columns = infer_result_columns(&col_names, &cells);
+
+    Ok(ExecutionOutcome::single_result(ResultSet {
+        columns,
+        cells,
+        row_count,
+        completeness,
+    }))
+}
+
+/// 从数据推断列类型（第一行非 NULL 值）
+///
+/// SQLite 列没有可靠的声明类型，只能按样本值推断；全为 NULL 的列按文本处理。
+fn infer_result_columns(col_names: &[String], cells: &[DbValue]) -> Arc<[ResultColumn]> {
+    let col_count = col_names.len();
+    let row_count = cells.len() / col_count.max(1);
+    col_names
         .iter()
         .enumerate()
         .map(|(col_idx, name)| {
@@ -432,14 +594,94 @@ fn execute_typed_with_connection(
                 },
             }
         })
-        .collect();
+        .collect()
+}
 
-    Ok(ExecutionOutcome::single_result(ResultSet {
-        columns,
-        cells,
-        row_count,
-        completeness,
-    }))
+/// 流式执行 SQL，按批通过通道发送行（SQLite 原生路径）
+///
+/// 在阻塞线程中调用：rusqlite 行迭代器逐行拉取，攒满 `batch_size` 行即 `blocking_send`，
+/// 内存占用只与批大小有关。列类型按第一批数据推断，后续批次沿用。
+pub(crate) fn execute_streaming(
+    config: &ConnectionConfig,
+    sql: &str,
+    batch_size: usize,
+    sink: &tokio::sync::mpsc::Sender<ResultBatch>,
+    cancellation: &tokio_util::sync::CancellationToken,
+) -> Result<StreamOutcome, DbError> {
+    let conn = SqliteConn::open(&config.database)
+        .map_err(|e| DbError::Connection(format!("SQLite 连接失败: {}", e)))?;
+    execute_streaming_with_connection(&conn, sql, batch_size, sink, cancellation)
+}
+
+fn execute_streaming_with_connection(
+    conn: &SqliteConn,
+    sql: &str,
+    batch_size: usize,
+    sink: &tokio::sync::mpsc::Sender<ResultBatch>,
+    cancellation: &tokio_util::sync::CancellationToken,
+) -> Result<StreamOutcome, DbError> {
+    if !is_query_statement(sql, &DatabaseType::SQLite) {
+        let affected = conn
+            .execute(sql, [])
+            .map_err(|e| DbError::Query(e.to_string()))? as u64;
+        return Ok(StreamOutcome::AffectedRows { rows: affected });
+    }
+
+    let mut stmt = conn
+        .prepare(sql)
+        .map_err(|e| DbError::Query(e.to_string()))?;
+    let col_names: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+    let col_count = col_names.len();
+    let batch_size = batch_size.max(1);
+
+    let mut rows = stmt.query([]).map_err(|e| DbError::Query(e.to_string()))?;
+    let mut columns: Option<Arc<[ResultColumn]>> = None;
+    let mut cells: Vec<DbValue> = Vec::with_capacity(batch_size * col_count);
+    let mut total = 0usize;
+
+    loop {
+        if cancellation.is_cancelled() {
+            return Err(DbError::Cancelled);
+        }
+        let row = rows.next().map_err(|e| DbError::Query(e.to_string()))?;
+        let exhausted = row.is_none();
+        if let Some(row) = row {
+            for index in 0..col_count {
+                let value = row
+                    .get_ref(index)
+                    .map_err(|e| DbError::Query(e.to_string()))?;
+                cells.push(value_ref_to_dbvalue(value));
+            }
+        }
+
+        let buffered = cells.len() / col_count.max(1);
+        // 第一批即使为空也要发送，保证消费者拿到列信息
+        let must_flush = exhausted && (buffered > 0 || columns.is_none());
+        if buffered >= batch_size || must_flush {
+            let batch_columns = columns
+                .get_or_insert_with(|| infer_result_columns(&col_names, &cells))
+                .clone();
+            let batch = ResultBatch {
+                columns: batch_columns,
+                cells: std::mem::replace(&mut cells, Vec::with_capacity(batch_size * col_count)),
+                row_count: buffered,
+            };
+            if sink.blocking_send(batch).is_err() {
+                return Ok(StreamOutcome::Rows {
+                    total,
+                    stopped_early: true,
+                });
+            }
+            total += buffered;
+        }
+
+        if exhausted {
+            return Ok(StreamOutcome::Rows {
+                total,
+                stopped_early: false,
+            });
+        }
+    }
 }
 /// 将 rusqlite ValueRef 转换为 DbValue
 fn value_ref_to_dbvalue(val: ValueRef<'_>) -> DbValue {
@@ -460,7 +702,8 @@ fn value_ref_to_dbvalue(val: ValueRef<'_>) -> DbValue {
 use super::infer_type_family;
 use crate::domain::ids::SchemaRevision;
 use crate::domain::metadata::{
-    ColumnMetadata as CatalogColumn, ForeignKeyMetadata, KeyMetadata, SchemaCatalog, TableMetadata,
+    CheckConstraintMetadata, ColumnMetadata as CatalogColumn, ForeignKeyMetadata, IndexMetadata,
+    KeyMetadata, SchemaCatalog, TableMetadata, ViewMetadata,
 };
 
 /// 一次性加载 SQLite 数据库的完整 schema catalog。
@@ -469,6 +712,9 @@ use crate::domain::metadata::{
 /// 1. `sqlite_master` → 表列表
 /// 2. 每表 `PRAGMA table_info` → 列信息（含 PK）
 /// 3. 每表 `PRAGMA foreign_key_list` → 外键
+/// 4. 每表 `PRAGMA index_list` / `index_info` → 索引，部分索引条件取自索引 DDL
+/// 5. CHECK 约束从建表语句中解析（SQLite 没有对应的 PRAGMA）
+/// 6. `sqlite_master` → 视图及其定义
 ///
 /// 返回的 SchemaCatalog 可直接用于 autocomplete、grid PK、ER 图。
 pub(crate) fn load_catalog(
@@ -478,22 +724,22 @@ pub(crate) fn load_catalog(
     let conn = SqliteConn::open(&config.database)
         .map_err(|e| DbError::Connection(format!("SQLite 连接失败: {}", e)))?;
 
-    // 1. 获取表列表
+    // 1. 获取表列表（连同建表语句，用于解析 CHECK 约束）
     let mut stmt = conn
         .prepare(
-            "SELECT name FROM sqlite_master WHERE type='table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+            "SELECT name, sql FROM sqlite_master WHERE type='table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
         )
         .map_err(|e| DbError::Query(e.to_string()))?;
 
-    let table_names: Vec<String> = stmt
-        .query_map([], |row| row.get(0))
+    let table_entries: Vec<(String, Option<String>)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
         .map_err(|e| DbError::Query(e.to_string()))?
         .filter_map(|r| r.ok())
         .collect();
 
-    let mut tables = Vec::with_capacity(table_names.len());
+    let mut tables = Vec::with_capacity(table_entries.len());
 
-    for table_name in &table_names {
+    for (table_name, table_sql) in &table_entries {
         // 2. PRAGMA table_info → 列信息
         let pragma_sql = format!("PRAGMA table_info('{}')", table_name.replace('\'', "''"));
         let mut col_stmt = conn
@@ -578,17 +824,221 @@ pub(crate) fn load_catalog(
             })
             .collect();
 
+        // 4. PRAGMA index_list → 索引
+        let indexes = load_indexes(&conn, table_name)?;
+
         tables.push(TableMetadata {
             name: table_name.clone(),
             schema: None,
             columns,
             primary_key,
-            unique_keys: Vec::new(),
+            unique_keys: indexes
+                .iter()
+                .filter_map(IndexMetadata::unique_key)
+                .collect(),
             foreign_keys,
+            indexes,
+            check_constraints: table_sql
+                .as_deref()
+                .map(parse_check_constraints)
+                .unwrap_or_default(),
         });
     }
 
-    Ok(SchemaCatalog { revision, tables })
+    // 6. 视图 — SQLite 只保存完整的 CREATE VIEW 文本
+    let mut view_stmt = conn
+        .prepare("SELECT name, sql FROM sqlite_master WHERE type='view' ORDER BY name")
+        .map_err(|e| DbError::Query(e.to_string()))?;
+    let views: Vec<ViewMetadata> = view_stmt
+        .query_map([], |row| {
+            Ok(ViewMetadata {
+                name: row.get(0)?,
+                schema: None,
+                definition: row.get(1)?,
+                materialized: false,
+            })
+        })
+        .map_err(|e| DbError::Query(e.to_string()))?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(SchemaCatalog {
+        revision,
+        default_schema: None,
+        tables,
+        views,
+        sequences: Vec::new(),
+    })
+}
+
+/// 加载单表索引：`origin` 为 `pk` 的是主键索引，表达式列以 `(表达式)` 表示
+fn load_indexes(conn: &SqliteConn, table_name: &str) -> Result<Vec<IndexMetadata>, DbError> {
+    let list_sql = format!("PRAGMA index_list('{}')", table_name.replace('\'', "''"));
+    let mut list_stmt = conn
+        .prepare(&list_sql)
+        .map_err(|e| DbError::Query(e.to_string()))?;
+    let entries: Vec<(String, bool, String, bool)> = list_stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(1)?, // name
+                row.get::<_, bool>(2)?,   // unique
+                row.get::<_, String>(3)?, // origin: c / u / pk
+                row.get::<_, bool>(4)?,   // partial
+            ))
+        })
+        .map_err(|e| DbError::Query(e.to_string()))?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut indexes = Vec::with_capacity(entries.len());
+    for (name, is_unique, origin, partial) in entries {
+        let info_sql = format!("PRAGMA index_info('{}')", name.replace('\'', "''"));
+        let mut info_stmt = conn
+            .prepare(&info_sql)
+            .map_err(|e| DbError::Query(e.to_string()))?;
+        let columns: Vec<String> = info_stmt
+            .query_map([], |row| row.get::<_, Option<String>>(2))
+            .map_err(|e| DbError::Query(e.to_string()))?
+            .filter_map(|r| r.ok())
+            .map(|column| column.unwrap_or_else(|| "(表达式)".to_string()))
+            .collect();
+
+        let predicate = if partial {
+            conn.query_row(
+                "SELECT sql FROM sqlite_master WHERE type='index' AND name = ?1",
+                [&name],
+                |row| row.get::<_, Option<String>>(0),
+            )
+            .ok()
+            .flatten()
+            .and_then(|sql| partial_index_predicate(&sql))
+        } else {
+            None
+        };
+
+        indexes.push(IndexMetadata {
+            name,
+            columns,
+            is_unique,
+            is_primary: origin == "pk",
+            method: None,
+            predicate,
+        });
+    }
+    // 主键索引排在最前，其余保持 PRAGMA 顺序
+    indexes.sort_by_key(|index| !index.is_primary);
+    Ok(indexes)
+}
+
+/// 标记 SQL 文本中每个字节是否位于引号/标识符引用之外
+fn unquoted_mask(sql: &str) -> Vec<bool> {
+    let mut mask = Vec::with_capacity(sql.len());
+    let mut quote: Option<u8> = None;
+    for &byte in sql.as_bytes() {
+        match quote {
+            Some(close) => {
+                mask.push(false);
+                if byte == close {
+                    quote = None;
+                }
+            }
+            None => {
+                quote = match byte {
+                    b'\'' | b'"' | b'`' => Some(byte),
+                    b'[' => Some(b']'),
+                    _ => None,
+                };
+                mask.push(quote.is_none());
+            }
+        }
+    }
+    mask
+}
+
+/// 在引号之外按单词边界查找关键字（`upper` 为大写后的 SQL）
+fn find_keyword(upper: &str, mask: &[bool], keyword: &str, from: usize) -> Option<usize> {
+    let bytes = upper.as_bytes();
+    let is_word = |byte: Option<&u8>| byte.is_some_and(|b| b.is_ascii_alphanumeric() || *b == b'_');
+    let mut start = from;
+    while let Some(offset) = upper.get(start..)?.find(keyword) {
+        let pos = start + offset;
+        let end = pos + keyword.len();
+        if mask[pos]
+            && !is_word(pos.checked_sub(1).and_then(|p| bytes.get(p)))
+            && !is_word(bytes.get(end))
+        {
+            return Some(pos);
+        }
+        start = end;
+    }
+    None
+}
+
+/// 部分索引 DDL 中 WHERE 之后的条件
+fn partial_index_predicate(index_sql: &str) -> Option<String> {
+    let upper = index_sql.to_ascii_uppercase();
+    let mask = unquoted_mask(index_sql);
+    let pos = find_keyword(&upper, &mask, "WHERE", 0)?;
+    let predicate = index_sql[pos + "WHERE".len()..]
+        .trim()
+        .trim_end_matches(';');
+    (!predicate.is_empty()).then(|| predicate.trim().to_string())
+}
+
+/// 从 CREATE TABLE 文本中提取列级与表级 CHECK 约束
+fn parse_check_constraints(create_sql: &str) -> Vec<CheckConstraintMetadata> {
+    let upper = create_sql.to_ascii_uppercase();
+    let bytes = upper.as_bytes();
+    let mask = unquoted_mask(create_sql);
+    let mut constraints = Vec::new();
+    let mut from = 0;
+
+    while let Some(pos) = find_keyword(&upper, &mask, "CHECK", from) {
+        from = pos + "CHECK".len();
+        let Some(open) = (from..bytes.len()).find(|&p| !bytes[p].is_ascii_whitespace()) else {
+            break;
+        };
+        if bytes[open] != b'(' {
+            continue;
+        }
+        let mut depth = 0usize;
+        let mut close = None;
+        for p in open..bytes.len() {
+            if !mask[p] {
+                continue;
+            }
+            match bytes[p] {
+                b'(' => depth += 1,
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        close = Some(p);
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        let Some(close) = close else {
+            break;
+        };
+
+        // `CONSTRAINT name CHECK (...)` 形式的约束名
+        let mut preceding = create_sql[..pos].split_whitespace().rev();
+        let name = match (preceding.next(), preceding.next()) {
+            (Some(name), Some(keyword)) if keyword.eq_ignore_ascii_case("CONSTRAINT") => Some(
+                name.trim_matches(|c| matches!(c, '"' | '`' | '[' | ']'))
+                    .to_string(),
+            ),
+            _ => None,
+        };
+        constraints.push(CheckConstraintMetadata {
+            name,
+            expression: create_sql[open + 1..close].trim().to_string(),
+        });
+        from = close + 1;
+    }
+    constraints
 }
 /// SQLite 声明类型 → DbTypeFamily
 fn sqlite_decl_type_to_family(decl_type: &str) -> DbTypeFamily {
@@ -793,7 +1243,12 @@ mod tests {
             "UPDATE \"users\" SET \"name\" = NULL WHERE \"id\" = 1;".to_string(),
         ];
 
-        let result = execute_batch(&config, &statements, true, true);
+        let result = execute_batch(
+            &config,
+            crate::data::query::single_import_batch(statements),
+            true,
+            true,
+        );
         assert!(result.is_err(), "batch with a failing statement must error");
 
         // 第一条 UPDATE 也必须被回滚：name 仍为原值 alice。
@@ -802,6 +1257,30 @@ mod tests {
             .unwrap();
         assert_eq!(name, "alice", "transaction must roll back the whole batch");
     }
+
+    #[test]
+    fn execute_batch_rolls_back_when_the_statement_source_fails() {
+        let db = NamedTempFile::new().unwrap();
+        let conn = rusqlite::Connection::open(db.path()).unwrap();
+        conn.execute("CREATE TABLE users (id INTEGER PRIMARY KEY)", [])
+            .unwrap();
+
+        let config = test_config_for_path(db.path().to_string_lossy().into_owned());
+        let (sender, batches) = tokio::sync::mpsc::channel(2);
+        sender
+            .try_send(Ok(vec!["INSERT INTO users (id) VALUES (1);".to_string()]))
+            .unwrap();
+        sender.try_send(Err("第 2 行读取失败".to_string())).unwrap();
+        drop(sender);
+
+        let error = execute_batch(&config, batches, true, true).unwrap_err();
+        assert!(error.to_string().contains("读取导入数据失败"));
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM users", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(count, 0, "a failed source must not commit earlier batches");
+    }
     // ── Mutation contract tests (Phase 7 Convergence) ──
 
     use crate::domain::mutation::{
@@ -1316,6 +1795,69 @@ mod tests {
         assert_eq!(catalog.revision, SchemaRevision(1));
     }
 
+    #[test]
+    fn catalog_loads_indexes_with_partial_predicate() {
+        let (_db, config) = temp_db(
+            "CREATE TABLE t (id INTEGER PRIMARY KEY, email TEXT UNIQUE, name TEXT, deleted INT);
+             CREATE INDEX idx_t_name ON t (name, deleted) WHERE deleted = 0;",
+        );
+        let catalog = super::load_catalog(&config, SchemaRevision(1)).unwrap();
+        let t = catalog.table("t").expect("table t should exist");
+
+        let partial = t
+            .indexes
+            .iter()
+            .find(|i| i.name == "idx_t_name")
+            .expect("partial index should exist");
+        assert_eq!(partial.columns, vec!["name", "deleted"]);
+        assert!(!partial.is_unique);
+        assert_eq!(partial.predicate.as_deref(), Some("deleted = 0"));
+
+        let unique = t
+            .indexes
+            .iter()
+            .find(|i| i.is_unique)
+            .expect("UNIQUE constraint creates an index");
+        assert_eq!(unique.columns, vec!["email"]);
+        assert_eq!(t.unique_keys.len(), 1);
+        assert_eq!(t.unique_keys[0].columns, vec!["email"]);
+    }
+
+    #[test]
+    fn catalog_loads_check_constraints() {
+        let (_db, config) = temp_db(
+            "CREATE TABLE t (
+                 age INT CHECK (age >= 0),
+                 label TEXT DEFAULT 'check (x)',
+                 CONSTRAINT label_len CHECK (length(label) < 10)
+             );",
+        );
+        let catalog = super::load_catalog(&config, SchemaRevision(1)).unwrap();
+        let t = catalog.table("t").expect("table t should exist");
+        assert_eq!(t.check_constraints.len(), 2);
+        assert_eq!(t.check_constraints[0].name, None);
+        assert_eq!(t.check_constraints[0].expression, "age >= 0");
+        assert_eq!(t.check_constraints[1].name.as_deref(), Some("label_len"));
+        assert_eq!(t.check_constraints[1].expression, "length(label) < 10");
+    }
+
+    #[test]
+    fn catalog_separates_views_from_tables() {
+        let (_db, config) = temp_db(
+            "CREATE TABLE t (id INTEGER PRIMARY KEY);
+             CREATE VIEW v AS SELECT id FROM t;",
+        );
+        let catalog = super::load_catalog(&config, SchemaRevision(1)).unwrap();
+        assert!(catalog.table("v").is_none());
+        let view = catalog.view("v").expect("view v should exist");
+        assert!(!view.materialized);
+        assert!(
+            view.definition
+                .as_deref()
+                .is_some_and(|sql| sql.contains("SELECT id FROM t"))
+        );
+    }
+
     #[test]
     fn catalog_handles_special_table_name() {
         // SQLite allows unusual but valid table names
@@ -1327,4 +1869,27 @@ mod tests {
             .expect("table with hyphen should exist");
         assert_eq!(t.columns.len(), 2);
     }
+
+    #[tokio::test]
+    async fn execute_typed_interruptible_interrupts_running_statement_on_cancel() {
+        let cancellation = tokio_util::sync::CancellationToken::new();
+        let cancel = cancellation.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            cancel.cancel();
+        });
+
+        let result = tokio::time::timeout(
+            std::time::Duration::from_secs(10),
+            execute_typed_interruptible(
+                &test_config(),
+                "WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c) \
+                 SELECT COUNT(*) FROM c",
+                &cancellation,
+            ),
+        )
+        .await
+        .expect("interrupted statement must finish");
+        assert!(matches!(result, Err(DbError::Cancelled)));
+    }
 }
@@ -53,3 +53,15 @@ impl ExecutionOutcome {
         }
     }
 }
+
+/// 流式执行的结束状态
+///
+/// 行数据本身已通过通道以 `ResultBatch` 发出，这里只汇总结果。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamOutcome {
+    /// 查询语句：已发送的总行数；`stopped_early` 表示接收端提前关闭，剩余行未再拉取
+    Rows { total: usize, stopped_early: bool },
+
+    /// 非查询语句的影响行数
+    AffectedRows { rows: u64 },
+}
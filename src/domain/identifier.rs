@@ -14,7 +14,7 @@ impl Identifier {
 }
 
 /// 标识符引用方言
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IdentifierDialect {
     /// PostgreSQL / SQLite：双引号 `"…"`，内部 `"` 转义为 `""`
     PostgreSql,
@@ -37,6 +37,28 @@ impl IdentifierDialect {
         }
     }
 
+    /// 拆分表引用
+    ///
+    /// 只有 PostgreSQL 的表列表会出现 `schema.table`（非默认 schema 的表），
+    /// MySQL / SQLite 的表名按原样处理。
+    pub fn split_table_ref<'a>(&self, name: &'a str) -> (Option<&'a str>, &'a str) {
+        match self {
+            Self::PostgreSql => match name.split_once('.') {
+                Some((schema, table)) => (Some(schema), table),
+                None => (None, name),
+            },
+            Self::MySql | Self::SQLite => (None, name),
+        }
+    }
+
+    /// 安全引用一个表名，`schema.table` 逐段引用
+    pub fn quote_table(&self, name: &str) -> String {
+        match self.split_table_ref(name) {
+            (Some(schema), table) => format!("{}.{}", self.quote(schema), self.quote(table)),
+            (None, table) => self.quote(table),
+        }
+    }
+
     /// 从 DatabaseType 推断方言
     pub fn from_db_type(db_type: crate::types::DatabaseType) -> Self {
         match db_type {
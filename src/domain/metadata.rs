@@ -13,7 +13,14 @@ use super::value::DbTypeInfo;
 #[derive(Debug, Clone)]
 pub struct SchemaCatalog {
     pub revision: SchemaRevision,
+    /// 未限定名称所属的 schema（PostgreSQL 的 `current_schema()`）；
+    /// 其他 schema 中的对象以 `schema.name` 命名
+    pub default_schema: Option<String>,
     pub tables: Vec<TableMetadata>,
+    /// 视图与物化视图
+    pub views: Vec<ViewMetadata>,
+    /// 序列（SQLite / MySQL 为空）
+    pub sequences: Vec<SequenceMetadata>,
 }
 
 /// 单表元数据
@@ -26,6 +33,9 @@ pub struct TableMetadata {
     pub primary_key: Option<KeyMetadata>,
     pub unique_keys: Vec<KeyMetadata>,
     pub foreign_keys: Vec<ForeignKeyMetadata>,
+    /// 索引（含主键、唯一约束对应的索引）
+    pub indexes: Vec<IndexMetadata>,
+    pub check_constraints: Vec<CheckConstraintMetadata>,
 }
 
 /// 列元数据
@@ -56,14 +66,78 @@ pub struct ForeignKeyMetadata {
     pub ref_columns: Vec<String>,
 }
 
+/// 索引
+#[derive(Debug, Clone)]
+pub struct IndexMetadata {
+    pub name: String,
+    /// 索引列；表达式索引的列为表达式文本
+    pub columns: Vec<String>,
+    pub is_unique: bool,
+    pub is_primary: bool,
+    /// 索引方法（btree / hash / gin / BTREE / FULLTEXT 等），SQLite 为空
+    pub method: Option<String>,
+    /// 部分索引的 WHERE 条件
+    pub predicate: Option<String>,
+}
+
+/// CHECK 约束
+#[derive(Debug, Clone)]
+pub struct CheckConstraintMetadata {
+    pub name: Option<String>,
+    pub expression: String,
+}
+
+/// 视图或物化视图
+#[derive(Debug, Clone)]
+pub struct ViewMetadata {
+    pub name: String,
+    pub schema: Option<String>,
+    /// 视图定义（SELECT 语句或完整 CREATE VIEW 文本）
+    pub definition: Option<String>,
+    /// 是否为物化视图（仅 PostgreSQL）
+    pub materialized: bool,
+}
+
+/// 序列
+#[derive(Debug, Clone)]
+pub struct SequenceMetadata {
+    pub name: String,
+    pub schema: Option<String>,
+    pub data_type: Option<String>,
+    pub start_value: Option<i64>,
+    pub increment: Option<i64>,
+    /// 拥有该序列的列（`table.column`）
+    pub owned_by: Option<String>,
+}
+
+impl IndexMetadata {
+    /// 非部分的唯一索引可视为唯一键（主键索引除外）
+    pub fn unique_key(&self) -> Option<KeyMetadata> {
+        (self.is_unique && !self.is_primary && self.predicate.is_none()).then(|| KeyMetadata {
+            name: Some(self.name.clone()),
+            columns: self.columns.clone(),
+        })
+    }
+}
+
 impl SchemaCatalog {
     pub fn empty(revision: SchemaRevision) -> Self {
         Self {
             revision,
+            default_schema: None,
             tables: Vec::new(),
+            views: Vec::new(),
+            sequences: Vec::new(),
         }
     }
 
+    /// 按名称查找视图或物化视图
+    pub fn view(&self, name: &str) -> Option<&ViewMetadata> {
+        self.views
+            .iter()
+            .find(|v| v.name.eq_ignore_ascii_case(name))
+    }
+
     /// 按名称查找表
     pub fn table(&self, name: &str) -> Option<&TableMetadata> {
         self.tables
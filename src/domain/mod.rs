@@ -7,5 +7,6 @@ pub mod identifier;
 pub mod ids;
 pub mod metadata;
 pub mod mutation;
+pub mod plan;
 pub mod result;
 pub mod value;
@@ -0,0 +1,130 @@
+//! 执行计划类型 — 三种后端 EXPLAIN 输出的统一树形表示
+//!
+//! PostgreSQL / MySQL 的 JSON 计划与 SQLite 的 `EXPLAIN QUERY PLAN` 行
+//! 都被解析为 `PlanNode` 树，UI 只依赖这一种结构。
+
+/// EXPLAIN 模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExplainMode {
+    /// 仅估算计划，不执行语句
+    #[default]
+    Estimate,
+    /// 实际执行语句并采集耗时（在回滚事务中执行）
+    Analyze,
+}
+
+/// 执行计划中的单个节点
+#[derive(Debug, Clone, Default)]
+pub struct PlanNode {
+    /// 算子名称（如 `Seq Scan`、`Nested loop`、`SCAN users`）
+    pub operation: String,
+    /// 访问的表（如果有）
+    pub relation: Option<String>,
+    /// 附加信息（索引、过滤条件、连接条件等）
+    pub detail: Option<String>,
+    /// 估算总开销（含子节点，单位由后端决定）
+    pub total_cost: Option<f64>,
+    /// 估算行数
+    pub estimated_rows: Option<f64>,
+    /// 实际行数（仅 ANALYZE）
+    pub actual_rows: Option<f64>,
+    /// 实际耗时毫秒（含子节点与循环次数，仅 ANALYZE）
+    pub actual_time_ms: Option<f64>,
+    /// 是否为全表/全索引扫描
+    pub full_scan: bool,
+    /// 子节点
+    pub children: Vec<PlanNode>,
+}
+
+impl PlanNode {
+    /// 节点度量值：ANALYZE 计划取实际耗时，否则取估算开销
+    fn metric(&self, analyzed: bool) -> Option<f64> {
+        if analyzed {
+            self.actual_time_ms
+        } else {
+            self.total_cost
+        }
+    }
+
+    /// 子树度量值：节点自身缺失时累加子节点，用于扣除无开销的中间节点
+    fn subtree_metric(&self, analyzed: bool) -> Option<f64> {
+        self.metric(analyzed).or_else(|| {
+            self.children
+                .iter()
+                .filter_map(|child| child.subtree_metric(analyzed))
+                .reduce(|a, b| a + b)
+        })
+    }
+
+    /// 节点自身的度量值（扣除子节点），无度量信息时返回 `None`
+    pub fn exclusive_metric(&self, analyzed: bool) -> Option<f64> {
+        let total = self.metric(analyzed)?;
+        let children: f64 = self
+            .children
+            .iter()
+            .filter_map(|child| child.subtree_metric(analyzed))
+            .sum();
+        Some((total - children).max(0.0))
+    }
+}
+
+/// 一次 EXPLAIN 的完整结果
+#[derive(Debug, Clone, Default)]
+pub struct QueryPlan {
+    /// 根节点（SQLite 可能存在多个并列根）
+    pub roots: Vec<PlanNode>,
+    /// 是否为 EXPLAIN ANALYZE 结果
+    pub analyzed: bool,
+    /// 规划耗时毫秒（PostgreSQL）
+    pub planning_time_ms: Option<f64>,
+    /// 执行耗时毫秒（仅 ANALYZE）
+    pub execution_time_ms: Option<f64>,
+}
+
+impl QueryPlan {
+    /// 节点总数
+    pub fn node_count(&self) -> usize {
+        fn count(node: &PlanNode) -> usize {
+            1 + node.children.iter().map(count).sum::<usize>()
+        }
+        self.roots.iter().map(count).sum()
+    }
+
+    /// 全表扫描节点数
+    pub fn full_scan_count(&self) -> usize {
+        fn count(node: &PlanNode) -> usize {
+            usize::from(node.full_scan) + node.children.iter().map(count).sum::<usize>()
+        }
+        self.roots.iter().map(count).sum()
+    }
+
+    /// 自身开销最高的节点路径（逐层子节点下标，首个元素为根下标）
+    ///
+    /// 计划中没有任何开销信息（如 SQLite）时返回 `None`。
+    pub fn hottest_path(&self) -> Option<Vec<usize>> {
+        fn visit(
+            node: &PlanNode,
+            analyzed: bool,
+            path: &mut Vec<usize>,
+            best: &mut Option<(f64, Vec<usize>)>,
+        ) {
+            if let Some(value) = node.exclusive_metric(analyzed)
+                && best.as_ref().is_none_or(|(max, _)| value > *max)
+            {
+                *best = Some((value, path.clone()));
+            }
+            for (index, child) in node.children.iter().enumerate() {
+                path.push(index);
+                visit(child, analyzed, path, best);
+                path.pop();
+            }
+        }
+
+        let mut best = None;
+        for (index, root) in self.roots.iter().enumerate() {
+            let mut path = vec![index];
+            visit(root, self.analyzed, &mut path, &mut best);
+        }
+        best.map(|(_, path)| path)
+    }
+}
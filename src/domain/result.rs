@@ -86,3 +86,40 @@ impl ResultSet {
         }
     }
 }
+
+/// 流式执行产生的一批行
+///
+/// 每批都携带同一份列描述（`Arc` 共享），消费者拿到第一批即可渲染表头。
+/// 查询语句至少会产生一批（可能为空），以便传递列信息。
+#[derive(Debug, Clone)]
+pub struct ResultBatch {
+    /// 列描述（同一次执行的所有批次共享）
+    pub columns: Arc<[ResultColumn]>,
+
+    /// flat row-major 存储，与 `ResultSet::cells` 布局一致
+    pub cells: Vec<DbValue>,
+
+    /// 本批行数
+    pub row_count: usize,
+}
+
+impl ResultBatch {
+    /// 构建空批次（只携带列描述）
+    pub fn empty(columns: Arc<[ResultColumn]>) -> Self {
+        Self {
+            columns,
+            cells: Vec::new(),
+            row_count: 0,
+        }
+    }
+
+    /// 转换为完整的 `ResultSet`，复用单元格存储
+    pub fn into_result_set(self) -> ResultSet {
+        ResultSet {
+            columns: self.columns,
+            cells: self.cells,
+            row_count: self.row_count,
+            completeness: ResultCompleteness::Complete,
+        }
+    }
+}
@@ -16,6 +16,7 @@
 //!
 //! ## 模块结构
 //!
+//! - `cli`: 无界面命令行子命令（复用已保存的连接）
 //! - `core`: 核心功能（配置、主题、导出、语法高亮等）
 //! - `data`: 数据库连接和查询
 //! - `ui`: 用户界面组件
@@ -27,6 +28,7 @@
 
 pub(crate) mod app;
 pub mod bootstrap;
+pub mod cli;
 pub mod core;
 pub mod data;
 pub mod domain;
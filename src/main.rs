@@ -2,7 +2,7 @@ use clap::Parser;
 
 /// Gridix — 跨平台数据库管理工具，支持 SQLite / PostgreSQL / MySQL。
 ///
-/// 默认行为：无参数启动图形界面。
+/// 默认行为：无参数启动图形界面；指定子命令时以无界面模式运行。
 #[derive(Parser)]
 #[command(
     name = "gridix",
@@ -14,6 +14,9 @@ struct Cli {
     /// 测试 CI 检查（打印成功消息后退出）
     #[arg(long, hide = true)]
     ci_check: bool,
+
+    #[command(subcommand)]
+    command: Option<gridix::cli::Command>,
 }
 
 fn main() {
@@ -24,6 +27,14 @@ fn main() {
         return;
     }
 
+    if let Some(command) = cli.command {
+        if let Err(err) = gridix::cli::run(command) {
+            eprintln!("gridix: {err}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
     // 默认：启动图形界面
     if let Err(err) = gridix::bootstrap::run() {
         eprintln!("Gridix 启动失败: {err}");
@@ -35,4 +35,6 @@ pub enum Message {
         u64,
         Result<Vec<RoutineInfo>, String>,
     ),
+    /// 手动提交事务结束 (连接名, 是否为提交, 结果)
+    TransactionEnded(String, bool, Result<(), String>),
 }
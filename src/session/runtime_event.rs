@@ -41,6 +41,21 @@ pub enum RuntimeOutcome {
         elapsed_ms: u64,
     },
 
+    /// 查询首批结果到达（任务仍在执行，完整结果随后以 `ExecutionFinished` 送达）
+    ExecutionFirstPage {
+        document: DocumentId,
+        tab_id: String,
+        result: crate::domain::result::ResultSet,
+    },
+
+    /// 执行计划生成完成
+    PlanExplained {
+        tab_id: String,
+        sql: String,
+        result: Result<crate::domain::plan::QueryPlan, String>,
+        elapsed_ms: u64,
+    },
+
     /// 元数据加载完成
     MetadataLoaded {
         scope: MetadataScope,
@@ -89,4 +104,19 @@ pub enum RuntimeOutcome {
         database: Option<String>,
         result: Result<Vec<crate::data::RoutineInfo>, String>,
     },
+
+    /// 分页浏览的表行数估算完成
+    TableRowsEstimated {
+        tab_id: String,
+        table: String,
+        result: Result<Option<u64>, String>,
+    },
+
+    /// 外键候选值加载完成
+    ReferenceCandidatesLoaded {
+        table: String,
+        key_column: String,
+        search: String,
+        result: Result<crate::ui::ReferenceCandidates, String>,
+    },
 }
@@ -43,6 +43,45 @@ pub struct QueryTab {
     pub uses_grid_workspace: bool,
     /// 当前进行中的请求 ID（用于丢弃过期回包）
     pub pending_request_id: Option<u64>,
+    /// 分页浏览游标（打开表时建立，执行其它 SQL 后清除）
+    pub table_pager: Option<crate::core::TablePager>,
+    /// Explain 面板展示的执行计划
+    pub query_plan: Option<QueryPlanState>,
+    /// 手动提交模式：执行在独占连接的事务中进行，直到显式提交或回滚
+    pub manual_commit: bool,
+    /// 手动提交模式下独占的连接会话
+    pub pinned_session: Option<PinnedTabSession>,
+}
+
+/// 查询 Tab 独占的连接会话及其所属连接
+#[derive(Clone, Debug)]
+pub struct PinnedTabSession {
+    pub connection_name: String,
+    pub session: crate::data::PinnedSession,
+}
+
+/// 单个 Tab 的执行计划状态
+#[derive(Clone)]
+pub enum QueryPlanState {
+    /// 正在生成，`progress_id` 对应任务面板中的可取消任务
+    Loading { sql: String, progress_id: u64 },
+    /// 已解析的计划
+    Ready {
+        sql: String,
+        plan: crate::domain::plan::QueryPlan,
+        elapsed_ms: u64,
+    },
+    /// 生成或解析失败
+    Failed { sql: String, error: String },
+}
+
+impl QueryPlanState {
+    /// 对应的原始 SQL
+    pub fn sql(&self) -> &str {
+        match self {
+            Self::Loading { sql, .. } | Self::Ready { sql, .. } | Self::Failed { sql, .. } => sql,
+        }
+    }
 }
 
 impl QueryTab {
@@ -64,6 +103,10 @@ impl QueryTab {
             search_column: None,
             uses_grid_workspace: false,
             pending_request_id: None,
+            table_pager: None,
+            query_plan: None,
+            manual_commit: false,
+            pinned_session: None,
         }
     }
 
@@ -122,6 +165,13 @@ impl QueryTab {
         }
     }
 
+    /// 有未提交事务的独占会话
+    pub fn open_transaction(&self) -> Option<&PinnedTabSession> {
+        self.pinned_session
+            .as_ref()
+            .filter(|pinned| pinned.session.in_transaction())
+    }
+
     /// 更新标题
     pub fn update_title(&mut self) {
         if self.table_name.is_none() {
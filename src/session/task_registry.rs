@@ -21,6 +21,9 @@ pub enum OperationKey {
     Query {
         document: DocumentId,
     },
+    Explain {
+        document: DocumentId,
+    },
     Metadata {
         connection: ConnectionId,
         scope: MetadataScope,
@@ -46,6 +49,8 @@ pub enum MetadataScope {
     Triggers,
     Routines,
     ForeignKeys,
+    RowEstimate,
+    ReferenceCandidates,
 }
 
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
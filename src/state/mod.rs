@@ -4,10 +4,11 @@
 //! 更多字段将在后续提交中逐步迁移。
 
 use crate::core::{HighlightColors, ThemeManager};
-use crate::ui::{CreateDbDialogState, CreateUserDialogState, DdlDialogState, HelpState};
 use crate::ui::{
-    DataGridState, ERDiagramState, EditorMode, ExportConfig, FocusArea, ImportState, SidebarSection,
+    BytesViewerState, DataGridState, ERDiagramState, EditorMode, ExportConfig, FocusArea,
+    ImportState, SidebarSection,
 };
+use crate::ui::{CreateDbDialogState, CreateUserDialogState, DdlDialogState, HelpState};
 use crate::ui::{HistoryPanelState, KeyBindingsDialogState, SidebarPanelState};
 use crate::ui::{ToolbarMenuDialogState, ToolbarThemeDialogState};
 
@@ -18,6 +19,15 @@ pub use workbench::{
     WorkbenchSurfaceId, WorkbenchSurfaceKind, WorkbenchSurfaceRole,
 };
 
+/// 会丢弃未提交事务、等待用户确认的操作
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TransactionDiscard {
+    /// 关闭这些查询 Tab（按 Tab ID）
+    CloseTabs(Vec<String>),
+    /// 断开连接
+    Disconnect(String),
+}
+
 /// UI 状态（逐步从 DbManagerApp 提取中）
 pub struct UiState {
     pub theme_manager: ThemeManager,
@@ -40,6 +50,7 @@ pub struct UiState {
     pub show_export_dialog: bool,
     pub show_import_dialog: bool,
     pub show_delete_confirm: bool,
+    pub show_transaction_discard_confirm: bool,
     pub show_history_panel: bool,
     pub show_help: bool,
     pub show_about: bool,
@@ -67,6 +78,7 @@ pub struct UiState {
     pub editing_connection_name: Option<String>,
     pub export_status: Option<Result<String, String>>,
     pub(crate) pending_delete_target: Option<crate::ui::SidebarDeleteTarget>,
+    pub(crate) pending_transaction_discard: Option<TransactionDiscard>,
     pub(crate) welcome_status: crate::ui::WelcomeStatusSummary,
     pub welcome_setup_target: crate::data::DatabaseType,
     pub welcome_setup_action_index: usize,
@@ -74,6 +86,7 @@ pub struct UiState {
     pub pending_filter_input_focus: Option<usize>,
     pub(crate) active_dialog_owner: Option<crate::app::dialogs::host::DialogId>,
     pub(crate) grid_state: DataGridState,
+    pub(crate) bytes_viewer: BytesViewerState,
     pub(crate) sidebar_panel_state: SidebarPanelState,
     pub(crate) er_diagram_state: ERDiagramState,
     pub(crate) workbench: WorkbenchState,
@@ -104,6 +117,7 @@ impl Default for UiState {
             show_export_dialog: false,
             show_import_dialog: false,
             show_delete_confirm: false,
+            show_transaction_discard_confirm: false,
             show_history_panel: false,
             show_help: false,
             show_about: false,
@@ -128,11 +142,13 @@ impl Default for UiState {
             toolbar_index: 0,
             help_scroll_offset: 0.0,
             grid_state: DataGridState::default(),
+            bytes_viewer: BytesViewerState::default(),
             selected_table: None,
             new_config: crate::data::ConnectionConfig::default(),
             editing_connection_name: None,
             export_status: None,
             pending_delete_target: None,
+            pending_transaction_discard: None,
             welcome_status: crate::ui::WelcomeStatusSummary::default(),
             welcome_setup_target: crate::data::DatabaseType::SQLite,
             welcome_setup_action_index: 0,
@@ -387,6 +387,7 @@ fn right_inspector_mode_key(mode: RightInspectorTab) -> &'static str {
         RightInspectorTab::Schema => "schema",
         RightInspectorTab::Row => "row",
         RightInspectorTab::Cell => "cell",
+        RightInspectorTab::Profile => "profile",
         RightInspectorTab::ErSelection => "er",
         RightInspectorTab::Connection => "connection",
     }
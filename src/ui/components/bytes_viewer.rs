@@ -0,0 +1,355 @@
+//! 二进制值查看器
+//!
+//! Cell 检查器中查看 BLOB / bytea 单元格：十六进制 + ASCII 转储、UTF-8 文本、
+//! base64，以及 PNG / JPEG 内容的图片预览。文件读写由调用方根据返回的动作完成。
+
+use crate::ui::styles::{GRAY, theme_error};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use egui::{self, RichText, TextEdit};
+use std::sync::Arc;
+
+/// 每行转储的字节数
+const HEX_DUMP_WIDTH: usize = 16;
+/// 文本与 base64 视图最多展示的字节数
+const TEXT_VIEW_LIMIT: usize = 64 * 1024;
+/// 预览图片的最大边长，超出时缩小后再上传纹理
+const IMAGE_PREVIEW_MAX_SIDE: u32 = 1024;
+
+/// 查看方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BytesViewMode {
+    #[default]
+    Hex,
+    Utf8,
+    Base64,
+    Image,
+}
+
+impl BytesViewMode {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Hex => "Hex",
+            Self::Utf8 => "UTF-8",
+            Self::Base64 => "Base64",
+            Self::Image => "图片",
+        }
+    }
+}
+
+/// 查看器发起、需要调用方处理的动作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BytesViewerAction {
+    /// 把当前值保存到文件
+    SaveToFile,
+    /// 从文件读取并替换当前值
+    ReplaceFromFile,
+    /// 设为 NULL
+    SetNull,
+}
+
+/// 查看器状态：当前查看方式与已解码的预览图
+#[derive(Default)]
+pub struct BytesViewerState {
+    pub mode: BytesViewMode,
+    /// (值的标识, 解码结果)，值变化后重新解码
+    preview: Option<(BytesKey, Result<egui::TextureHandle, String>)>,
+}
+
+/// 以共享缓冲区的地址与长度标识一个值，避免每帧对内容求哈希
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BytesKey {
+    ptr: usize,
+    len: usize,
+}
+
+impl BytesKey {
+    fn of(bytes: &Arc<[u8]>) -> Self {
+        Self {
+            ptr: bytes.as_ptr() as usize,
+            len: bytes.len(),
+        }
+    }
+}
+
+/// 按文件头识别可预览的图片格式
+pub fn image_format(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("PNG")
+    } else if bytes.starts_with(&[0xff, 0xd8, 0xff]) {
+        Some("JPEG")
+    } else {
+        None
+    }
+}
+
+/// 十六进制转储的一行：偏移、16 个字节的十六进制与可打印 ASCII
+pub fn hex_dump_line(bytes: &[u8], offset: usize) -> String {
+    let chunk = &bytes[offset.min(bytes.len())..(offset + HEX_DUMP_WIDTH).min(bytes.len())];
+    let mut hex = String::with_capacity(HEX_DUMP_WIDTH * 3 + 1);
+    for index in 0..HEX_DUMP_WIDTH {
+        if index == HEX_DUMP_WIDTH / 2 {
+            hex.push(' ');
+        }
+        match chunk.get(index) {
+            Some(byte) => hex.push_str(&format!("{:02x} ", byte)),
+            None => hex.push_str("   "),
+        }
+    }
+    let ascii: String = chunk
+        .iter()
+        .map(|byte| {
+            if byte.is_ascii_graphic() || *byte == b' ' {
+                char::from(*byte)
+            } else {
+                '.'
+            }
+        })
+        .collect();
+    format!("{:08x}  {} |{}|", offset, hex, ascii)
+}
+
+/// 大小的可读形式
+pub fn format_byte_size(len: usize) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+    if len < 1024 {
+        return format!("{} B", len);
+    }
+    let mut size = len as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {} ({} B)", size, UNITS[unit], len)
+}
+
+/// 显示二进制值；`bytes` 为 `None` 表示当前没有字节（NULL 等），`editable` 控制替换与置空动作
+pub fn show_bytes_viewer(
+    ui: &mut egui::Ui,
+    state: &mut BytesViewerState,
+    id_salt: impl std::hash::Hash,
+    bytes: Option<&Arc<[u8]>>,
+    editable: bool,
+) -> Option<BytesViewerAction> {
+    let id = ui.make_persistent_id(id_salt);
+    let mut action = None;
+    let format = bytes.and_then(|bytes| image_format(bytes));
+
+    if let Some(bytes) = bytes {
+        ui.horizontal_wrapped(|ui| {
+            ui.label(RichText::new(format_byte_size(bytes.len())).strong());
+            if let Some(format) = format {
+                ui.label(RichText::new(format).small().color(GRAY));
+            }
+        });
+    }
+    ui.horizontal_wrapped(|ui| {
+        if ui
+            .add_enabled(bytes.is_some(), egui::Button::new("保存到文件…"))
+            .clicked()
+        {
+            action = Some(BytesViewerAction::SaveToFile);
+        }
+        if ui
+            .add_enabled(editable, egui::Button::new("从文件替换…"))
+            .on_hover_text("读取文件内容作为新值，保存表格时一并提交")
+            .clicked()
+        {
+            action = Some(BytesViewerAction::ReplaceFromFile);
+        }
+        if ui
+            .add_enabled(editable && bytes.is_some(), egui::Button::new("设为 NULL"))
+            .clicked()
+        {
+            action = Some(BytesViewerAction::SetNull);
+        }
+    });
+
+    let Some(bytes) = bytes else {
+        return action;
+    };
+    ui.add_space(4.0);
+    ui.horizontal(|ui| {
+        for mode in [
+            BytesViewMode::Hex,
+            BytesViewMode::Utf8,
+            BytesViewMode::Base64,
+        ] {
+            ui.selectable_value(&mut state.mode, mode, mode.label());
+        }
+        ui.add_enabled_ui(format.is_some(), |ui| {
+            ui.selectable_value(
+                &mut state.mode,
+                BytesViewMode::Image,
+                BytesViewMode::Image.label(),
+            )
+            .on_disabled_hover_text("仅支持预览 PNG / JPEG");
+        });
+    });
+    ui.add_space(4.0);
+
+    let mode = match state.mode {
+        BytesViewMode::Image if format.is_none() => BytesViewMode::Hex,
+        mode => mode,
+    };
+    match mode {
+        BytesViewMode::Hex => show_hex_dump(ui, id, bytes),
+        BytesViewMode::Utf8 => {
+            let shown = &bytes[..bytes.len().min(TEXT_VIEW_LIMIT)];
+            let text = String::from_utf8_lossy(shown);
+            if std::str::from_utf8(shown).is_err() {
+                ui.label(
+                    RichText::new("不是有效的 UTF-8，无法解码的字节显示为 �")
+                        .small()
+                        .color(theme_error(ui.visuals())),
+                );
+            }
+            show_read_only_text(ui, id.with("utf8"), &text);
+            show_truncated_note(ui, bytes.len());
+        }
+        BytesViewMode::Base64 => {
+            let text = STANDARD.encode(&bytes[..bytes.len().min(TEXT_VIEW_LIMIT)]);
+            show_read_only_text(ui, id.with("base64"), &text);
+            show_truncated_note(ui, bytes.len());
+        }
+        BytesViewMode::Image => show_image_preview(ui, state, bytes),
+    }
+    action
+}
+
+fn show_hex_dump(ui: &mut egui::Ui, id: egui::Id, bytes: &[u8]) {
+    let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+    let rows = bytes.len().div_ceil(HEX_DUMP_WIDTH);
+    egui::ScrollArea::both()
+        .id_salt(id.with("hex_scroll"))
+        .max_height(320.0)
+        .auto_shrink([false, true])
+        .show_rows(ui, row_height, rows, |ui, range| {
+            for row in range {
+                ui.add(
+                    egui::Label::new(
+                        RichText::new(hex_dump_line(bytes, row * HEX_DUMP_WIDTH)).monospace(),
+                    )
+                    .extend(),
+                );
+            }
+        });
+}
+
+fn show_read_only_text(ui: &mut egui::Ui, id: egui::Id, text: &str) {
+    let mut text = text;
+    egui::ScrollArea::vertical()
+        .id_salt(id.with("scroll"))
+        .max_height(320.0)
+        .show(ui, |ui| {
+            ui.add(
+                TextEdit::multiline(&mut text)
+                    .id_salt(id)
+                    .desired_width(f32::INFINITY)
+                    .font(egui::TextStyle::Monospace),
+            );
+        });
+}
+
+fn show_truncated_note(ui: &mut egui::Ui, len: usize) {
+    if len > TEXT_VIEW_LIMIT {
+        ui.label(
+            RichText::new(format!(
+                "仅显示前 {}，完整内容请保存到文件",
+                format_byte_size(TEXT_VIEW_LIMIT)
+            ))
+            .small()
+            .color(GRAY),
+        );
+    }
+}
+
+fn show_image_preview(ui: &mut egui::Ui, state: &mut BytesViewerState, bytes: &Arc<[u8]>) {
+    let key = BytesKey::of(bytes);
+    if state
+        .preview
+        .as_ref()
+        .is_none_or(|(cached, _)| *cached != key)
+    {
+        let texture = decode_image(bytes).map(|image| {
+            ui.ctx()
+                .load_texture("bytes_viewer_preview", image, egui::TextureOptions::LINEAR)
+        });
+        state.preview = Some((key, texture));
+    }
+    match state.preview.as_ref().map(|(_, texture)| texture) {
+        Some(Ok(texture)) => {
+            let [width, height] = texture.size();
+            ui.label(
+                RichText::new(format!("{} × {}", width, height))
+                    .small()
+                    .color(GRAY),
+            );
+            let max_width = ui.available_width();
+            ui.add(
+                egui::Image::new(texture)
+                    .max_width(max_width)
+                    .maintain_aspect_ratio(true),
+            );
+        }
+        Some(Err(error)) => {
+            ui.label(RichText::new(error).color(theme_error(ui.visuals())));
+        }
+        None => {}
+    }
+}
+
+fn decode_image(bytes: &[u8]) -> Result<egui::ColorImage, String> {
+    let image =
+        image::load_from_memory(bytes).map_err(|error| format!("无法解码图片: {}", error))?;
+    let image = if image.width().max(image.height()) > IMAGE_PREVIEW_MAX_SIDE {
+        image.thumbnail(IMAGE_PREVIEW_MAX_SIDE, IMAGE_PREVIEW_MAX_SIDE)
+    } else {
+        image
+    };
+    let rgba = image.into_rgba8();
+    let size = [rgba.width() as usize, rgba.height() as usize];
+    Ok(egui::ColorImage::from_rgba_unmultiplied(
+        size,
+        rgba.as_raw(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_dump_pads_the_last_row_and_masks_control_bytes() {
+        let bytes = b"Hello,\x00world!\n\xffGridix";
+        assert_eq!(
+            hex_dump_line(bytes, 0),
+            "00000000  48 65 6c 6c 6f 2c 00 77  6f 72 6c 64 21 0a ff 47  |Hello,.world!..G|"
+        );
+        assert_eq!(
+            hex_dump_line(bytes, 16),
+            format!("00000010  72 69 64 69 78 {}|ridix|", " ".repeat(35))
+        );
+    }
+
+    #[test]
+    fn image_content_is_detected_by_magic_bytes_and_decoded() {
+        let mut png = Vec::new();
+        image::RgbaImage::from_pixel(3, 2, image::Rgba([255, 0, 0, 255]))
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+        assert_eq!(image_format(&png), Some("PNG"));
+        assert_eq!(image_format(&[0xff, 0xd8, 0xff, 0xe0]), Some("JPEG"));
+        assert_eq!(image_format(b"GIF89a"), None);
+
+        let image = decode_image(&png).unwrap();
+        assert_eq!(image.size, [3, 2]);
+        assert!(decode_image(&png[..png.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn byte_sizes_are_human_readable() {
+        assert_eq!(format_byte_size(512), "512 B");
+        assert_eq!(format_byte_size(1536), "1.5 KiB (1536 B)");
+    }
+}
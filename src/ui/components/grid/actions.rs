@@ -1,5 +1,6 @@
 //! 表格操作和 SQL 生成
 
+use super::cell_edit::{CellEdit, cell_input_value, column_edit_types};
 use super::state::DataGridState;
 
 /// 焦点转移方向
@@ -13,6 +14,15 @@ pub enum FocusTransfer {
     QueryTabs,
 }
 
+/// 外键导航方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceNavigation {
+    /// 打开当前外键单元格引用的行
+    Referenced,
+    /// 列出引用当前行的表
+    Referencing,
+}
+
 /// 表格操作返回值
 #[derive(Default)]
 pub struct DataGridActions {
@@ -36,6 +46,16 @@ pub struct DataGridActions {
     pub scroll_to_bottom: bool,
     /// 请求切换到指定的查询Tab (1-indexed)
     pub switch_to_tab: Option<usize>,
+    /// 分页浏览时请求翻页
+    pub page_request: Option<crate::core::PageRequest>,
+    /// 请求按当前筛选条件重新查询表（服务端筛选）
+    pub apply_server_filters: bool,
+    /// 请求外键导航（光标所在的行与列）
+    pub reference_navigation: Option<ReferenceNavigation>,
+    /// 请求加载外键列的候选值
+    pub reference_lookup: Option<super::reference_picker::ReferenceLookup>,
+    /// 请求保存被引用表的显示列
+    pub reference_label: Option<super::reference_picker::ReferenceLabelChange>,
 }
 
 /// SQL 危险保留字（可能被用于注入攻击）
@@ -96,6 +116,19 @@ pub fn quote_identifier(name: &str, use_backticks: bool) -> Result<String, Strin
     }
 }
 
+/// 为 SQL 查询引用表名，PostgreSQL 的 `schema.table` 逐段验证并引用
+pub fn quote_table_identifier(
+    name: &str,
+    dialect: crate::domain::identifier::IdentifierDialect,
+) -> Result<String, String> {
+    let (schema, table) = dialect.split_table_ref(name);
+    if let Some(schema) = schema {
+        escape_identifier(schema)?;
+    }
+    escape_identifier(table)?;
+    Ok(dialect.quote_table(name))
+}
+
 /// 转义 SQL 字符串值
 ///
 /// 处理单引号转义，防止 SQL 注入
@@ -133,6 +166,7 @@ pub(crate) fn build_mutation_batch(
     table_name: &str,
 ) -> Result<MutationBatch, String> {
     let primary_key_indices = collect_primary_key_indices(result, state)?;
+    let column_types = column_edit_types(result, state.table_metadata.as_deref());
     let deleted_rows: BTreeSet<usize> = state.rows_to_delete.iter().copied().collect();
     let table = ColumnRef {
         name: table_name.to_string(),
@@ -144,6 +178,7 @@ pub(crate) fn build_mutation_batch(
         result,
         state,
         &table,
+        &column_types,
         &primary_key_indices,
         &deleted_rows,
     )?;
@@ -154,7 +189,7 @@ pub(crate) fn build_mutation_batch(
         &primary_key_indices,
         &deleted_rows,
     )?;
-    append_insert_mutations(&mut batch, result, state, &table)?;
+    append_insert_mutations(&mut batch, result, state, &table, &column_types)?;
 
     if batch.is_empty() {
         return Err("没有需要保存的修改".to_string());
@@ -190,10 +225,11 @@ fn append_update_mutations(
     result: &ResultSet,
     state: &DataGridState,
     table: &ColumnRef,
+    column_types: &[DbTypeInfo],
     primary_key_indices: &[usize],
     deleted_rows: &BTreeSet<usize>,
 ) -> Result<(), String> {
-    let mut changes_by_row: BTreeMap<usize, Vec<(usize, &String)>> = BTreeMap::new();
+    let mut changes_by_row: BTreeMap<usize, Vec<(usize, &CellEdit)>> = BTreeMap::new();
     for ((row_index, column_index), value) in &state.modified_cells {
         if !deleted_rows.contains(row_index) {
             changes_by_row
@@ -205,7 +241,7 @@ fn append_update_mutations(
 
     for (row_index, changes) in changes_by_row {
         let identity = build_row_identity(result, row_index, primary_key_indices)?;
-        let changes = build_column_changes(result, changes)?;
+        let changes = build_column_changes(result, column_types, row_index, changes)?;
         batch.mutations.push(Mutation::Update {
             table: table.clone(),
             identity,
@@ -238,16 +274,21 @@ fn append_insert_mutations(
     result: &ResultSet,
     state: &DataGridState,
     table: &ColumnRef,
+    column_types: &[DbTypeInfo],
 ) -> Result<(), String> {
-    for row in &state.new_rows {
+    for (new_index, row) in state.new_rows.iter().enumerate() {
         if row.len() != result.column_count() {
             return Err("新增行列数与结果集不一致".to_string());
         }
         let values = row
             .iter()
-            .zip(result.columns.iter())
-            .map(|(value, column)| input_value_from_text(value, &column.type_info))
-            .collect();
+            .zip(result.columns.iter().zip(column_types))
+            .map(|(edit, (column, type_info))| {
+                cell_input_value(edit, type_info, true).map_err(|error| {
+                    format!("新增第 {} 行列 '{}': {}", new_index + 1, column.name, error)
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
         let columns = result
             .columns
             .iter()
@@ -296,36 +337,38 @@ fn build_row_identity(
 
 fn build_column_changes(
     result: &ResultSet,
-    mut changes: Vec<(usize, &String)>,
+    column_types: &[DbTypeInfo],
+    row_index: usize,
+    mut changes: Vec<(usize, &CellEdit)>,
 ) -> Result<Vec<(ColumnRef, InputValue)>, String> {
     changes.sort_by_key(|(column_index, _)| *column_index);
     changes
         .into_iter()
-        .map(|(column_index, value)| {
-            let column = result
-                .columns
-                .get(column_index)
-                .ok_or_else(|| "修改的列已不在当前结果集中".to_string())?;
+        .map(|(column_index, edit)| {
+            let (Some(column), Some(type_info)) = (
+                result.columns.get(column_index),
+                column_types.get(column_index),
+            ) else {
+                return Err("修改的列已不在当前结果集中".to_string());
+            };
+            let value = cell_input_value(edit, type_info, false).map_err(|error| {
+                format!("第 {} 行列 '{}': {}", row_index + 1, column.name, error)
+            })?;
             Ok((
                 ColumnRef {
                     name: column.name.clone(),
                 },
-                input_value_from_text(value, &column.type_info),
+                value,
             ))
         })
         .collect()
 }
 
-fn input_value_from_text(value: &str, type_info: &DbTypeInfo) -> InputValue {
-    if value.is_empty() {
-        return InputValue::Value(DbValue::Text(String::new()));
-    }
-    InputValue::Value(crate::data::infer_value(value, type_info))
-}
 #[cfg(test)]
 mod tests {
     use super::{
-        DataGridActions, build_mutation_batch, cancel_pending_mutations, confirm_pending_mutations,
+        CellEdit, DataGridActions, build_mutation_batch, cancel_pending_mutations,
+        confirm_pending_mutations,
     };
     use crate::domain::metadata::{KeyMetadata, TableMetadata};
     use crate::domain::mutation::{InputValue, Mutation, RowIdentity};
@@ -371,6 +414,8 @@ mod tests {
             }),
             unique_keys: vec![],
             foreign_keys: vec![],
+            indexes: vec![],
+            check_constraints: vec![],
         })
     }
 
@@ -385,6 +430,8 @@ mod tests {
             }),
             unique_keys: vec![],
             foreign_keys: vec![],
+            indexes: vec![],
+            check_constraints: vec![],
         })
     }
 
@@ -395,12 +442,15 @@ mod tests {
             table_metadata: Some(single_pk_metadata()),
             ..Default::default()
         };
-        state.modified_cells.insert((0, 1), "bob".to_string());
+        state.modified_cells.insert((0, 1), "bob".into());
 
         let batch = build_mutation_batch(&result, &state, "users").expect("batch");
 
         assert_eq!(batch.len(), 1);
-        assert_eq!(state.modified_cells.get(&(0, 1)), Some(&"bob".to_string()));
+        assert_eq!(
+            state.modified_cells.get(&(0, 1)),
+            Some(&CellEdit::from("bob"))
+        );
         assert!(state.has_changes());
     }
 
@@ -411,7 +461,7 @@ mod tests {
             table_metadata: Some(single_pk_metadata()),
             ..Default::default()
         };
-        state.modified_cells.insert((0, 1), String::new());
+        state.modified_cells.insert((0, 1), CellEdit::default());
 
         let batch = build_mutation_batch(&result, &state, "users").expect("batch");
 
@@ -424,6 +474,44 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn build_mutation_batch_rejects_values_that_do_not_fit_the_column_type() {
+        let result = sample_result();
+        let mut state = DataGridState {
+            table_metadata: Some(single_pk_metadata()),
+            ..Default::default()
+        };
+        state.modified_cells.insert((0, 0), "12a".into());
+
+        let error = build_mutation_batch(&result, &state, "users").expect_err("invalid int");
+        assert!(error.contains("第 1 行列 'id'"), "{error}");
+
+        state.modified_cells.insert((0, 0), CellEdit::Null);
+        let error = build_mutation_batch(&result, &state, "users").expect_err("not null");
+        assert!(error.contains("不允许 NULL"), "{error}");
+    }
+
+    #[test]
+    fn build_mutation_batch_new_row_keeps_default_and_explicit_null_apart() {
+        let mut result = sample_result();
+        let mut columns = result.columns.to_vec();
+        columns[1].type_info.nullable = Some(true);
+        result.columns = columns.into();
+        let mut state = DataGridState {
+            table_metadata: Some(single_pk_metadata()),
+            ..Default::default()
+        };
+        state.new_rows.push(vec![CellEdit::Default, CellEdit::Null]);
+
+        let batch = build_mutation_batch(&result, &state, "users").expect("batch");
+
+        let Mutation::Insert { values, .. } = &batch.mutations[0] else {
+            panic!("expected insert mutation");
+        };
+        assert!(matches!(values[0], InputValue::Default));
+        assert!(matches!(values[1], InputValue::Null));
+    }
+
     #[test]
     fn build_mutation_batch_composite_primary_key_uses_all_key_columns() {
         let result = ResultSet {
@@ -465,7 +553,7 @@ mod tests {
             table_metadata: Some(composite_pk_metadata()),
             ..Default::default()
         };
-        state.modified_cells.insert((0, 2), "bob".to_string());
+        state.modified_cells.insert((0, 2), "bob".into());
 
         let batch = build_mutation_batch(&result, &state, "users").expect("batch");
 
@@ -485,7 +573,7 @@ mod tests {
             table_metadata: Some(single_pk_metadata()),
             ..Default::default()
         };
-        state.modified_cells.insert((1, 1), "bob".to_string());
+        state.modified_cells.insert((1, 1), "bob".into());
 
         let error = build_mutation_batch(&result, &state, "users").expect_err("missing row");
 
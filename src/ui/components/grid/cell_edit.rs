@@ -0,0 +1,625 @@
+//! 单元格编辑值与按列类型的校验
+//!
+//! 网格中的编辑保存为文本，或显式的 NULL / DEFAULT。保存前按列的 `DbTypeFamily`
+//! 严格解析文本，无法解析的单元格在构建 `MutationBatch` 之前就报告给用户，
+//! 不再像 `infer_value` 那样静默退化为文本。二进制值不经文本往返，直接保存字节。
+
+use crate::domain::metadata::TableMetadata;
+use crate::domain::mutation::InputValue;
+use crate::domain::result::ResultSet;
+use crate::domain::value::{DbDate, DbDateTime, DbTime, DbTypeFamily, DbTypeInfo, DbValue};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// 单元格的编辑值
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CellEdit {
+    /// 按列类型解析的文本
+    Text(String),
+    /// SQL NULL
+    Null,
+    /// 数据库默认值（仅新增行可用，INSERT 时省略该列）
+    Default,
+    /// 二进制值（原值或从文件载入），原样写入
+    Bytes(Arc<[u8]>),
+}
+
+impl Default for CellEdit {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl From<String> for CellEdit {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for CellEdit {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+/// 编辑器的取值方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CellEditKind {
+    /// 输入的值
+    #[default]
+    Value,
+    /// 显式 NULL
+    Null,
+    /// 数据库默认值
+    Default,
+}
+
+impl CellEditKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Value => "值",
+            Self::Null => "NULL",
+            Self::Default => "DEFAULT",
+        }
+    }
+}
+
+impl CellEdit {
+    /// 已有单元格的初始编辑值
+    pub fn from_value(value: &DbValue) -> Self {
+        match value {
+            DbValue::Null => Self::Null,
+            DbValue::Bytes(bytes) => Self::Bytes(bytes.clone()),
+            value => Self::Text(edit_text_for_value(value)),
+        }
+    }
+
+    /// 由编辑器的取值方式与文本组合
+    pub fn from_parts(kind: CellEditKind, text: &str) -> Self {
+        match kind {
+            CellEditKind::Value => Self::Text(text.to_string()),
+            CellEditKind::Null => Self::Null,
+            CellEditKind::Default => Self::Default,
+        }
+    }
+
+    /// 由编辑器内容组合；内容与 `initial` 一致时返回 `initial`
+    ///
+    /// 二进制值在编辑器中没有文本，未改动时据此保留原字节。
+    pub fn from_editor(kind: CellEditKind, text: &str, initial: &CellEdit) -> Self {
+        if kind == initial.kind() && text == initial.text() {
+            initial.clone()
+        } else {
+            Self::from_parts(kind, text)
+        }
+    }
+
+    pub fn kind(&self) -> CellEditKind {
+        match self {
+            Self::Text(_) | Self::Bytes(_) => CellEditKind::Value,
+            Self::Null => CellEditKind::Null,
+            Self::Default => CellEditKind::Default,
+        }
+    }
+
+    /// 编辑文本；NULL / DEFAULT 与二进制值没有文本
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Text(text) => text,
+            Self::Null | Self::Default | Self::Bytes(_) => "",
+        }
+    }
+
+    /// 显示与复制用的文本
+    pub fn display_text(&self) -> Cow<'_, str> {
+        match self {
+            Self::Text(text) => Cow::Borrowed(text),
+            Self::Null => Cow::Borrowed("NULL"),
+            Self::Default => Cow::Borrowed("DEFAULT"),
+            Self::Bytes(bytes) => Cow::Owned(format!("<{} bytes>", bytes.len())),
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+}
+
+/// Cell 检查器中尚未应用的编辑
+///
+/// 切换单元格或表格中的值被改动（`base` 不再一致）时丢弃草稿。
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellDraft {
+    /// (行键, 列)
+    pub cell: (usize, usize),
+    /// 开始编辑时单元格的值
+    pub base: CellEdit,
+    pub kind: CellEditKind,
+    pub text: String,
+}
+
+impl CellDraft {
+    pub fn new(cell: (usize, usize), base: CellEdit) -> Self {
+        Self {
+            cell,
+            kind: base.kind(),
+            text: base.text().to_string(),
+            base,
+        }
+    }
+
+    /// 草稿是否仍对应该单元格的当前值
+    pub fn matches(&self, cell: (usize, usize), current: &CellEdit) -> bool {
+        self.cell == cell && self.base == *current
+    }
+
+    pub fn edit(&self) -> CellEdit {
+        CellEdit::from_editor(self.kind, &self.text, &self.base)
+    }
+}
+
+/// 编辑时使用的初始文本
+///
+/// 数组展开为 JSON 数组，元素逐个编辑时不会被逗号拆错；其余类型与显示文本一致。
+pub fn edit_text_for_value(value: &DbValue) -> String {
+    match value {
+        DbValue::Array(items) => {
+            serde_json::Value::Array(items.iter().map(array_element_json).collect()).to_string()
+        }
+        DbValue::Time(time) if time.nanos > 0 => format!(
+            "{:02}:{:02}:{:02}.{}",
+            time.hour,
+            time.minute,
+            time.second,
+            fraction_digits(time.nanos)
+        ),
+        DbValue::DateTime(datetime) if datetime.time.nanos > 0 => format!(
+            "{} {}",
+            DbValue::Date(datetime.date).display(),
+            edit_text_for_value(&DbValue::Time(datetime.time))
+        ),
+        value => value.display(),
+    }
+}
+
+fn array_element_json(value: &DbValue) -> serde_json::Value {
+    match value {
+        DbValue::Null => serde_json::Value::Null,
+        DbValue::Array(items) => {
+            serde_json::Value::Array(items.iter().map(array_element_json).collect())
+        }
+        value => serde_json::Value::String(edit_text_for_value(value)),
+    }
+}
+
+fn fraction_digits(nanos: u32) -> String {
+    format!("{:09}", nanos).trim_end_matches('0').to_string()
+}
+
+/// 各列用于编辑与校验的类型
+///
+/// 优先使用目录元数据（类型更准确且带可空性），按列名匹配不到时退回结果集的列类型。
+pub fn column_edit_types(result: &ResultSet, metadata: Option<&TableMetadata>) -> Vec<DbTypeInfo> {
+    result
+        .columns
+        .iter()
+        .map(|column| {
+            metadata
+                .and_then(|metadata| {
+                    metadata
+                        .columns
+                        .iter()
+                        .find(|candidate| candidate.name == column.name)
+                })
+                .map(|candidate| candidate.type_info.clone())
+                .unwrap_or_else(|| column.type_info.clone())
+        })
+        .collect()
+}
+
+/// 校验编辑值并转换为写入参数
+///
+/// `for_insert` 为真时（新增行）DEFAULT 表示省略该列；UPDATE 不支持 DEFAULT。
+pub(crate) fn cell_input_value(
+    edit: &CellEdit,
+    type_info: &DbTypeInfo,
+    for_insert: bool,
+) -> Result<InputValue, String> {
+    match edit {
+        CellEdit::Null if type_info.nullable == Some(false) => Err("该列不允许 NULL".to_string()),
+        CellEdit::Null => Ok(InputValue::Null),
+        CellEdit::Default if for_insert => Ok(InputValue::Default),
+        CellEdit::Default => Err("DEFAULT 只能用于新增行".to_string()),
+        CellEdit::Text(text) => parse_cell_text(text, type_info).map(InputValue::Value),
+        CellEdit::Bytes(bytes) => Ok(InputValue::Value(DbValue::Bytes(bytes.clone()))),
+    }
+}
+
+/// 按列类型严格解析编辑文本
+///
+/// 二进制列接受 `0x` / `\x` 开头的十六进制文本，其余文本按原样写入。
+pub fn parse_cell_text(text: &str, type_info: &DbTypeInfo) -> Result<DbValue, String> {
+    let trimmed = text.trim();
+    match type_info.family {
+        DbTypeFamily::Bytes => Ok(parse_hex_literal(trimmed)
+            .map(|bytes| DbValue::Bytes(bytes.into()))
+            .unwrap_or_else(|| DbValue::Text(text.to_string()))),
+        DbTypeFamily::Text | DbTypeFamily::Other | DbTypeFamily::Null => {
+            Ok(DbValue::Text(text.to_string()))
+        }
+        _ if trimmed.is_empty() => Err("值不能为空；如需清空请设为 NULL".to_string()),
+        DbTypeFamily::Bool => parse_bool(trimmed)
+            .map(DbValue::Bool)
+            .ok_or_else(|| format!("'{}' 不是有效的布尔值（true / false）", trimmed)),
+        DbTypeFamily::Integer => trimmed
+            .parse::<i64>()
+            .map(DbValue::Int)
+            .map_err(|_| format!("'{}' 不是有效的整数", trimmed)),
+        DbTypeFamily::Float => trimmed
+            .parse::<f64>()
+            .map(DbValue::Float)
+            .map_err(|_| format!("'{}' 不是有效的浮点数", trimmed)),
+        DbTypeFamily::Decimal => is_decimal_literal(trimmed)
+            .then(|| DbValue::Decimal(trimmed.to_string()))
+            .ok_or_else(|| format!("'{}' 不是有效的数值", trimmed)),
+        DbTypeFamily::Date => parse_date(trimmed)
+            .map(DbValue::Date)
+            .ok_or_else(|| format!("'{}' 不是有效的日期（YYYY-MM-DD）", trimmed)),
+        DbTypeFamily::Time => parse_time(trimmed)
+            .map(DbValue::Time)
+            .ok_or_else(|| format!("'{}' 不是有效的时间（HH:MM:SS）", trimmed)),
+        DbTypeFamily::DateTime => parse_datetime(trimmed)
+            .map(DbValue::DateTime)
+            .ok_or_else(|| format!("'{}' 不是有效的日期时间（YYYY-MM-DD HH:MM:SS）", trimmed)),
+        DbTypeFamily::Uuid => uuid::Uuid::parse_str(trimmed)
+            .map(DbValue::Uuid)
+            .map_err(|_| format!("'{}' 不是有效的 UUID", trimmed)),
+        DbTypeFamily::Json => parse_json(trimmed).map(DbValue::Json),
+        DbTypeFamily::Array => parse_array(trimmed).map(DbValue::Array),
+    }
+}
+
+/// 解析 `0x` / `\x` 开头的十六进制字节串
+pub(crate) fn parse_hex_literal(text: &str) -> Option<Vec<u8>> {
+    let digits = text
+        .strip_prefix("0x")
+        .or_else(|| text.strip_prefix("0X"))
+        .or_else(|| text.strip_prefix("\\x"))?;
+    if digits.len() % 2 != 0 || !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..digits.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(digits.get(index..index + 2)?, 16).ok())
+        .collect()
+}
+
+fn parse_bool(text: &str) -> Option<bool> {
+    match text.to_ascii_lowercase().as_str() {
+        "true" | "t" | "1" | "yes" | "y" | "on" => Some(true),
+        "false" | "f" | "0" | "no" | "n" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+fn is_decimal_literal(text: &str) -> bool {
+    let unsigned = text.strip_prefix(['+', '-']).unwrap_or(text);
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (unsigned, None),
+    };
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    let mantissa_ok =
+        !(whole.is_empty() && fraction.is_empty()) && digits(whole) && digits(fraction);
+    let exponent_ok = exponent.is_none_or(|exponent| {
+        let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        !exponent.is_empty() && digits(exponent)
+    });
+    mantissa_ok && exponent_ok
+}
+
+pub(crate) fn parse_date(text: &str) -> Option<DbDate> {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d")
+        .ok()
+        .map(db_date)
+}
+
+pub(crate) fn parse_time(text: &str) -> Option<DbTime> {
+    ["%H:%M:%S%.f", "%H:%M"]
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(text, format).ok())
+        .map(db_time)
+}
+
+pub(crate) fn parse_datetime(text: &str) -> Option<DbDateTime> {
+    const FORMATS: [&str; 4] = [
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M",
+    ];
+    FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .map(|datetime| DbDateTime {
+            date: db_date(datetime.date()),
+            time: db_time(datetime.time()),
+        })
+}
+
+fn db_date(date: NaiveDate) -> DbDate {
+    DbDate {
+        year: date.year(),
+        month: date.month() as u8,
+        day: date.day() as u8,
+    }
+}
+
+fn db_time(time: NaiveTime) -> DbTime {
+    DbTime {
+        hour: time.hour() as u8,
+        minute: time.minute() as u8,
+        second: time.second() as u8,
+        nanos: time.nanosecond(),
+    }
+}
+
+fn parse_json(text: &str) -> Result<serde_json::Value, String> {
+    serde_json::from_str(text).map_err(|error| {
+        // serde_json 的错误文本自带 "at line L column C"，位置改用中文单独给出
+        let message = error.to_string();
+        let reason = message.split(" at line ").next().unwrap_or(&message);
+        format!(
+            "JSON 无效（第 {} 行第 {} 列）: {}",
+            error.line(),
+            error.column(),
+            reason
+        )
+    })
+}
+
+/// 解析数组文本：JSON 数组（`["a", "b"]`），或 `{a,b}` / `[a, b]` 形式的逗号分隔列表
+fn parse_array(text: &str) -> Result<Vec<DbValue>, String> {
+    if let Ok(serde_json::Value::Array(items)) = serde_json::from_str::<serde_json::Value>(text) {
+        return Ok(items.iter().map(array_element_value).collect());
+    }
+    let inner = text
+        .strip_prefix('{')
+        .and_then(|rest| rest.strip_suffix('}'))
+        .or_else(|| {
+            text.strip_prefix('[')
+                .and_then(|rest| rest.strip_suffix(']'))
+        })
+        .ok_or_else(|| "数组应写成 JSON 数组，如 [\"a\", \"b\"]，或 {a,b}".to_string())?;
+    if inner.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    Ok(inner
+        .split(',')
+        .map(str::trim)
+        .map(|item| {
+            if item.eq_ignore_ascii_case("null") {
+                DbValue::Null
+            } else {
+                DbValue::Text(item.trim_matches('"').to_string())
+            }
+        })
+        .collect())
+}
+
+fn array_element_value(value: &serde_json::Value) -> DbValue {
+    match value {
+        serde_json::Value::Null => DbValue::Null,
+        serde_json::Value::String(text) => DbValue::Text(text.clone()),
+        serde_json::Value::Array(items) => {
+            DbValue::Array(items.iter().map(array_element_value).collect())
+        }
+        other => DbValue::Text(other.to_string()),
+    }
+}
+
+/// 把 JSON 文本格式化为缩进形式
+pub fn pretty_json(text: &str) -> Result<String, String> {
+    parse_json(text.trim())
+        .and_then(|value| serde_json::to_string_pretty(&value).map_err(|error| error.to_string()))
+}
+
+/// 把 JSON 文本压缩为单行
+pub fn compact_json(text: &str) -> Result<String, String> {
+    parse_json(text.trim()).map(|value| value.to_string())
+}
+
+/// 数组文本拆成逐个编辑的元素；NULL 元素写作 `NULL`
+///
+/// 文本不是合法数组时返回 `None`，编辑器退回原始文本编辑。
+pub fn split_array_elements(text: &str) -> Option<Vec<String>> {
+    if text.trim().is_empty() {
+        return Some(Vec::new());
+    }
+    let items = parse_array(text.trim()).ok()?;
+    Some(
+        items
+            .iter()
+            .map(|item| match item {
+                DbValue::Null => "NULL".to_string(),
+                item => edit_text_for_value(item),
+            })
+            .collect(),
+    )
+}
+
+/// 由元素重新组成数组文本（JSON 数组），与 [`split_array_elements`] 互逆
+pub fn join_array_elements(elements: &[String]) -> String {
+    serde_json::Value::Array(
+        elements
+            .iter()
+            .map(|element| match element.as_str() {
+                "NULL" => serde_json::Value::Null,
+                element => serde_json::Value::String(element.to_string()),
+            })
+            .collect(),
+    )
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_info(family: DbTypeFamily, nullable: Option<bool>) -> DbTypeInfo {
+        DbTypeInfo {
+            family,
+            native_name: String::new(),
+            nullable,
+        }
+    }
+
+    #[test]
+    fn typed_text_is_parsed_strictly_by_family() {
+        let parse = |text: &str, family| parse_cell_text(text, &type_info(family, None));
+
+        assert_eq!(parse(" 42 ", DbTypeFamily::Integer), Ok(DbValue::Int(42)));
+        assert!(parse("4x", DbTypeFamily::Integer).is_err());
+        assert_eq!(parse("off", DbTypeFamily::Bool), Ok(DbValue::Bool(false)));
+        assert!(parse("maybe", DbTypeFamily::Bool).is_err());
+        assert_eq!(
+            parse("-1.5e3", DbTypeFamily::Decimal),
+            Ok(DbValue::Decimal("-1.5e3".to_string()))
+        );
+        assert!(parse("1.2.3", DbTypeFamily::Decimal).is_err());
+        assert!(parse("2024-02-30", DbTypeFamily::Date).is_err());
+        assert_eq!(
+            parse("2024-02-29T08:30", DbTypeFamily::DateTime),
+            Ok(DbValue::DateTime(DbDateTime {
+                date: DbDate {
+                    year: 2024,
+                    month: 2,
+                    day: 29
+                },
+                time: DbTime {
+                    hour: 8,
+                    minute: 30,
+                    second: 0,
+                    nanos: 0
+                },
+            }))
+        );
+        assert!(parse("", DbTypeFamily::Integer).is_err());
+        assert_eq!(
+            parse("", DbTypeFamily::Text),
+            Ok(DbValue::Text(String::new()))
+        );
+
+        let error = parse("{\"a\": }", DbTypeFamily::Json).unwrap_err();
+        assert!(error.contains("第 1 行"), "{error}");
+    }
+
+    #[test]
+    fn null_and_default_follow_column_rules() {
+        let not_null = type_info(DbTypeFamily::Integer, Some(false));
+        let nullable = type_info(DbTypeFamily::Integer, None);
+
+        assert!(cell_input_value(&CellEdit::Null, &not_null, false).is_err());
+        assert!(matches!(
+            cell_input_value(&CellEdit::Null, &nullable, false),
+            Ok(InputValue::Null)
+        ));
+        assert!(matches!(
+            cell_input_value(&CellEdit::Default, &nullable, true),
+            Ok(InputValue::Default)
+        ));
+        assert!(cell_input_value(&CellEdit::Default, &nullable, false).is_err());
+    }
+
+    #[test]
+    fn array_elements_round_trip_through_edit_text() {
+        let value = DbValue::Array(vec![
+            DbValue::Text("a,b".to_string()),
+            DbValue::Null,
+            DbValue::Int(3),
+        ]);
+        let text = edit_text_for_value(&value);
+        let elements = split_array_elements(&text).unwrap();
+        assert_eq!(elements, vec!["a,b", "NULL", "3"]);
+        assert_eq!(join_array_elements(&elements), text);
+
+        assert_eq!(
+            parse_cell_text("{x, NULL}", &type_info(DbTypeFamily::Array, None)),
+            Ok(DbValue::Array(vec![
+                DbValue::Text("x".to_string()),
+                DbValue::Null
+            ]))
+        );
+        assert!(split_array_elements("not an array").is_none());
+    }
+
+    #[test]
+    fn json_can_be_pretty_printed_and_compacted() {
+        let pretty = pretty_json("{\"a\":[1,2]}").unwrap();
+        assert!(pretty.contains('\n'));
+        assert_eq!(compact_json(&pretty).unwrap(), "{\"a\":[1,2]}");
+        assert!(pretty_json("{").is_err());
+    }
+
+    #[test]
+    fn bytes_survive_an_unchanged_editor_and_accept_hex_text() {
+        let bytes: Arc<[u8]> = Arc::from(&[0x89, b'P', b'N', b'G'][..]);
+        let original = CellEdit::from_value(&DbValue::Bytes(bytes.clone()));
+        assert_eq!(original.display_text(), "<4 bytes>");
+
+        let draft = CellDraft::new((0, 0), original.clone());
+        assert_eq!(draft.edit(), original);
+        assert!(matches!(
+            cell_input_value(&draft.edit(), &type_info(DbTypeFamily::Bytes, None), false),
+            Ok(InputValue::Value(DbValue::Bytes(value))) if value == bytes
+        ));
+
+        let blob = type_info(DbTypeFamily::Bytes, None);
+        assert_eq!(
+            parse_cell_text("0x00fF", &blob),
+            Ok(DbValue::Bytes(Arc::from(&[0x00, 0xff][..])))
+        );
+        assert_eq!(parse_hex_literal("\\xdead"), Some(vec![0xde, 0xad]));
+        assert_eq!(parse_hex_literal("0x+f"), None);
+        assert_eq!(
+            parse_cell_text("0xabc", &blob),
+            Ok(DbValue::Text("0xabc".to_string()))
+        );
+    }
+
+    #[test]
+    fn reverting_a_cell_drops_the_modification_and_resets_stale_drafts() {
+        use crate::domain::result::{ResultColumn, ResultCompleteness};
+        use crate::ui::DataGridState;
+
+        let result = ResultSet {
+            columns: std::sync::Arc::new([ResultColumn {
+                name: "n".into(),
+                type_info: type_info(DbTypeFamily::Integer, None),
+            }]),
+            cells: vec![DbValue::Int(1)],
+            row_count: 1,
+            completeness: ResultCompleteness::Complete,
+        };
+        let mut state = DataGridState::new();
+        state.new_rows.push(vec![CellEdit::Default]);
+
+        state.set_cell_edit(&result, (0, 0), CellEdit::Null);
+        assert_eq!(state.cell_edit(&result, (0, 0)), Some(CellEdit::Null));
+        let draft = CellDraft::new((0, 0), CellEdit::Null);
+        state.set_cell_edit(&result, (0, 0), "1".into());
+        assert!(state.modified_cells.is_empty());
+        assert!(!draft.matches((0, 0), &state.cell_edit(&result, (0, 0)).unwrap()));
+
+        state.set_cell_edit(&result, (1, 0), "7".into());
+        assert_eq!(state.new_rows[0][0], CellEdit::from("7"));
+        assert_eq!(state.cell_edit(&result, (2, 0)), None);
+    }
+}
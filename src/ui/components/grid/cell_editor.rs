@@ -0,0 +1,345 @@
+//! 按列类型选择的单元格编辑器
+//!
+//! 表格的弹出编辑器与右侧 Cell 检查器共用：日期/时间用选择器，布尔用开关，
+//! JSON 用可格式化的多行编辑，数组逐个元素编辑，并可显式设为 NULL / DEFAULT。
+
+use super::cell_edit::{
+    CellEdit, CellEditKind, cell_input_value, compact_json, join_array_elements, parse_date,
+    parse_datetime, parse_time, pretty_json, split_array_elements,
+};
+use crate::domain::value::{DbTypeFamily, DbTypeInfo};
+use crate::ui::styles::{GRAY, theme_error};
+use chrono::{Datelike, NaiveDate, Timelike};
+use egui::{self, RichText, TextEdit};
+
+/// 是否需要弹出编辑器（单行文本框不便编辑的类型）
+pub(crate) fn uses_popup_editor(type_info: &DbTypeInfo) -> bool {
+    matches!(
+        type_info.family,
+        DbTypeFamily::Bool
+            | DbTypeFamily::Date
+            | DbTypeFamily::Time
+            | DbTypeFamily::DateTime
+            | DbTypeFamily::Json
+            | DbTypeFamily::Array
+    )
+}
+
+/// 显示类型编辑器，返回当前值的校验结果（`Err` 为错误说明）
+///
+/// `allow_default` 仅对新增行为真。
+pub fn show_cell_editor(
+    ui: &mut egui::Ui,
+    id_salt: impl std::hash::Hash,
+    type_info: &DbTypeInfo,
+    kind: &mut CellEditKind,
+    text: &mut String,
+    allow_default: bool,
+) -> Result<(), String> {
+    let id = ui.make_persistent_id(id_salt);
+    ui.horizontal(|ui| {
+        let kinds: &[CellEditKind] = if allow_default {
+            &[
+                CellEditKind::Value,
+                CellEditKind::Null,
+                CellEditKind::Default,
+            ]
+        } else {
+            &[CellEditKind::Value, CellEditKind::Null]
+        };
+        for option in kinds {
+            ui.selectable_value(kind, *option, option.label());
+        }
+        ui.label(RichText::new(type_label(type_info)).small().color(GRAY));
+    });
+
+    if *kind == CellEditKind::Value {
+        match type_info.family {
+            DbTypeFamily::Bool => show_bool_editor(ui, text),
+            DbTypeFamily::Date => show_date_editor(ui, id, text),
+            DbTypeFamily::Time => show_time_editor(ui, id, text),
+            DbTypeFamily::DateTime => show_datetime_editor(ui, id, text),
+            DbTypeFamily::Json => show_json_editor(ui, id, text),
+            DbTypeFamily::Array => show_array_editor(ui, id, text),
+            DbTypeFamily::Text | DbTypeFamily::Other => {
+                ui.add(
+                    TextEdit::multiline(text)
+                        .id_salt(id.with("text"))
+                        .desired_rows(3)
+                        .desired_width(f32::INFINITY)
+                        .font(egui::TextStyle::Monospace),
+                );
+            }
+            _ => {
+                ui.add(
+                    TextEdit::singleline(text)
+                        .id_salt(id.with("text"))
+                        .desired_width(f32::INFINITY)
+                        .font(egui::TextStyle::Monospace),
+                );
+            }
+        }
+    }
+
+    let validation =
+        cell_input_value(&CellEdit::from_parts(*kind, text), type_info, allow_default).map(|_| ());
+    if let Err(error) = &validation {
+        ui.label(
+            RichText::new(error)
+                .small()
+                .color(theme_error(ui.visuals())),
+        );
+    }
+    validation
+}
+
+fn type_label(type_info: &DbTypeInfo) -> String {
+    let nullable = match type_info.nullable {
+        Some(false) => " · NOT NULL",
+        _ => "",
+    };
+    if type_info.native_name.is_empty() {
+        format!("{:?}{}", type_info.family, nullable)
+    } else {
+        format!("{}{}", type_info.native_name, nullable)
+    }
+}
+
+fn show_bool_editor(ui: &mut egui::Ui, text: &mut String) {
+    let current = match text.trim().to_ascii_lowercase().as_str() {
+        "true" | "t" | "1" | "yes" | "y" | "on" => Some(true),
+        "false" | "f" | "0" | "no" | "n" | "off" => Some(false),
+        _ => None,
+    };
+    ui.horizontal(|ui| {
+        for (value, label) in [(true, "true"), (false, "false")] {
+            if ui.selectable_label(current == Some(value), label).clicked() {
+                *text = label.to_string();
+            }
+        }
+    });
+}
+
+/// 日期选择按钮，返回是否修改
+///
+/// egui_extras 的选择器使用 jiff 日期，这里与 chrono 日期互转。
+fn date_picker(ui: &mut egui::Ui, date: &mut NaiveDate, id_salt: &str) -> bool {
+    let Ok(mut picked) =
+        jiff::civil::Date::new(date.year() as i16, date.month() as i8, date.day() as i8)
+    else {
+        return false;
+    };
+    let changed = ui
+        .add(egui_extras::DatePickerButton::new(&mut picked).id_salt(id_salt))
+        .changed();
+    if changed
+        && let Some(value) = NaiveDate::from_ymd_opt(
+            i32::from(picked.year()),
+            picked.month() as u32,
+            picked.day() as u32,
+        )
+    {
+        *date = value;
+    }
+    changed
+}
+
+fn show_date_editor(ui: &mut egui::Ui, id: egui::Id, text: &mut String) {
+    ui.horizontal(|ui| {
+        let mut date = parse_date(text.trim())
+            .and_then(|date| {
+                NaiveDate::from_ymd_opt(date.year, u32::from(date.month), u32::from(date.day))
+            })
+            .unwrap_or_else(|| chrono::Local::now().date_naive());
+        let picker_id = format!("{:?}_date", id);
+        if date_picker(ui, &mut date, &picker_id) {
+            *text = date.format("%Y-%m-%d").to_string();
+        }
+        ui.add(
+            TextEdit::singleline(text)
+                .id_salt(id.with("text"))
+                .hint_text("YYYY-MM-DD")
+                .desired_width(120.0)
+                .font(egui::TextStyle::Monospace),
+        );
+    });
+}
+
+/// 时、分、秒调节框，返回是否修改
+fn time_drag_values(ui: &mut egui::Ui, time: &mut (u32, u32, u32)) -> bool {
+    let mut changed = false;
+    changed |= ui
+        .add(
+            egui::DragValue::new(&mut time.0)
+                .range(0..=23)
+                .suffix(" 时"),
+        )
+        .changed();
+    changed |= ui
+        .add(
+            egui::DragValue::new(&mut time.1)
+                .range(0..=59)
+                .suffix(" 分"),
+        )
+        .changed();
+    changed |= ui
+        .add(
+            egui::DragValue::new(&mut time.2)
+                .range(0..=59)
+                .suffix(" 秒"),
+        )
+        .changed();
+    changed
+}
+
+fn show_time_editor(ui: &mut egui::Ui, id: egui::Id, text: &mut String) {
+    ui.horizontal(|ui| {
+        let mut time = parse_time(text.trim())
+            .map(|time| {
+                (
+                    u32::from(time.hour),
+                    u32::from(time.minute),
+                    u32::from(time.second),
+                )
+            })
+            .unwrap_or_default();
+        if time_drag_values(ui, &mut time) {
+            *text = format!("{:02}:{:02}:{:02}", time.0, time.1, time.2);
+        }
+        ui.add(
+            TextEdit::singleline(text)
+                .id_salt(id.with("text"))
+                .hint_text("HH:MM:SS")
+                .desired_width(110.0)
+                .font(egui::TextStyle::Monospace),
+        );
+    });
+}
+
+fn show_datetime_editor(ui: &mut egui::Ui, id: egui::Id, text: &mut String) {
+    let now = chrono::Local::now().naive_local();
+    let (mut date, mut time) = parse_datetime(text.trim())
+        .and_then(|datetime| {
+            let date = NaiveDate::from_ymd_opt(
+                datetime.date.year,
+                u32::from(datetime.date.month),
+                u32::from(datetime.date.day),
+            )?;
+            let time = (
+                u32::from(datetime.time.hour),
+                u32::from(datetime.time.minute),
+                u32::from(datetime.time.second),
+            );
+            Some((date, time))
+        })
+        .unwrap_or_else(|| (now.date(), (now.hour(), now.minute(), now.second())));
+
+    ui.horizontal(|ui| {
+        let picker_id = format!("{:?}_datetime", id);
+        let mut changed = date_picker(ui, &mut date, &picker_id);
+        changed |= time_drag_values(ui, &mut time);
+        if ui.small_button("现在").clicked() {
+            date = now.date();
+            time = (now.hour(), now.minute(), now.second());
+            changed = true;
+        }
+        if changed {
+            *text = format!(
+                "{} {:02}:{:02}:{:02}",
+                date.format("%Y-%m-%d"),
+                time.0,
+                time.1,
+                time.2
+            );
+        }
+    });
+    ui.add(
+        TextEdit::singleline(text)
+            .id_salt(id.with("text"))
+            .hint_text("YYYY-MM-DD HH:MM:SS")
+            .desired_width(f32::INFINITY)
+            .font(egui::TextStyle::Monospace),
+    );
+}
+
+fn show_json_editor(ui: &mut egui::Ui, id: egui::Id, text: &mut String) {
+    ui.horizontal(|ui| {
+        if ui.small_button("格式化").clicked()
+            && let Ok(pretty) = pretty_json(text)
+        {
+            *text = pretty;
+        }
+        if ui.small_button("压缩").clicked()
+            && let Ok(compact) = compact_json(text)
+        {
+            *text = compact;
+        }
+    });
+    egui::ScrollArea::vertical()
+        .id_salt(id.with("json_scroll"))
+        .max_height(240.0)
+        .show(ui, |ui| {
+            ui.add(
+                TextEdit::multiline(text)
+                    .id_salt(id.with("text"))
+                    .code_editor()
+                    .desired_rows(8)
+                    .desired_width(f32::INFINITY),
+            );
+        });
+}
+
+fn show_array_editor(ui: &mut egui::Ui, id: egui::Id, text: &mut String) {
+    let Some(mut elements) = split_array_elements(text) else {
+        // 不是合法数组时退回原始文本，校验提示会说明格式
+        ui.add(
+            TextEdit::singleline(text)
+                .id_salt(id.with("text"))
+                .desired_width(f32::INFINITY)
+                .font(egui::TextStyle::Monospace),
+        );
+        return;
+    };
+
+    let mut changed = false;
+    let mut remove: Option<usize> = None;
+    egui::ScrollArea::vertical()
+        .id_salt(id.with("array_scroll"))
+        .max_height(220.0)
+        .show(ui, |ui| {
+            for (index, element) in elements.iter_mut().enumerate() {
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new(format!("[{}]", index + 1))
+                            .small()
+                            .color(GRAY),
+                    );
+                    changed |= ui
+                        .add(
+                            TextEdit::singleline(element)
+                                .id_salt(id.with(("element", index)))
+                                .desired_width(ui.available_width() - 28.0)
+                                .font(egui::TextStyle::Monospace),
+                        )
+                        .changed();
+                    if ui.small_button("✕").on_hover_text("删除元素").clicked() {
+                        remove = Some(index);
+                    }
+                });
+            }
+        });
+    ui.horizontal(|ui| {
+        if ui.small_button("+ 元素").clicked() {
+            elements.push(String::new());
+            changed = true;
+        }
+        ui.label(RichText::new("元素写 NULL 表示空元素").small().color(GRAY));
+    });
+    if let Some(index) = remove {
+        elements.remove(index);
+        changed = true;
+    }
+    if changed {
+        *text = join_array_elements(&elements);
+    }
+}
@@ -4,7 +4,7 @@
 //! 对于大数据集（超过 PARALLEL_FILTER_THRESHOLD 行），使用并行处理。
 
 use super::condition::ColumnFilter;
-use super::logic::FilterLogic;
+use super::group::FilterGroup;
 use super::operators::check_filter_match_typed;
 use crate::domain::result::ResultSet;
 use std::collections::hash_map::DefaultHasher;
@@ -65,6 +65,7 @@ fn compute_filter_hash(filters: &[ColumnFilter]) -> u64 {
         f.case_sensitive.hash(&mut hasher);
         std::mem::discriminant(&f.operator).hash(&mut hasher);
         std::mem::discriminant(&f.logic).hash(&mut hasher);
+        f.groups.hash(&mut hasher);
     }
     hasher.finish()
 }
@@ -123,6 +124,7 @@ fn filter_result_set_rows(
                 .position(|column| column.name == filter.column)
         })
         .collect();
+    let tree = FilterGroup::build(active_filters.iter().copied().enumerate());
 
     (0..result.row_count)
         .filter(|row_idx| {
@@ -132,6 +134,7 @@ fn filter_result_set_rows(
                 search_text,
                 &search_lower,
                 search_col_idx,
+                &tree,
                 &active_filters,
                 &filter_col_indices,
             )
@@ -139,12 +142,14 @@ fn filter_result_set_rows(
         .collect()
 }
 
+#[allow(clippy::too_many_arguments)]
 fn row_matches_result_set(
     result: &ResultSet,
     row_idx: usize,
     search_text: &str,
     search_lower: &str,
     search_col_idx: Option<usize>,
+    tree: &FilterGroup,
     active_filters: &[&ColumnFilter],
     filter_col_indices: &[Option<usize>],
 ) -> bool {
@@ -165,10 +170,9 @@ fn row_matches_result_set(
         }
     }
 
-    let mut is_match = true;
-    let mut logic = FilterLogic::And;
-    for (index, filter) in active_filters.iter().enumerate() {
-        let is_filter_match = filter_col_indices
+    tree.evaluate(|index| {
+        let filter = active_filters[index];
+        filter_col_indices
             .get(index)
             .and_then(|column_idx| *column_idx)
             .is_some_and(|column_idx| {
@@ -180,15 +184,6 @@ fn row_matches_result_set(
                     &filter.value2,
                     filter.case_sensitive,
                 )
-            });
-        if index == 0 {
-            is_match = is_filter_match;
-        } else if logic == FilterLogic::And {
-            is_match = is_match && is_filter_match;
-        } else {
-            is_match = is_match || is_filter_match;
-        }
-        logic = filter.logic;
-    }
-    is_match
+            })
+    })
 }
@@ -4,6 +4,7 @@
 
 use super::logic::FilterLogic;
 use super::operators::FilterOperator;
+use crate::core::FilterPresetCondition;
 
 /// 列筛选条件
 #[derive(Clone)]
@@ -22,6 +23,8 @@ pub struct ColumnFilter {
     pub case_sensitive: bool,
     /// 与下一个条件的逻辑关系
     pub logic: FilterLogic,
+    /// 所在分组的编号，由外到内；相邻且编号相同的条件属于同一分组
+    pub groups: Vec<u32>,
 }
 
 impl Default for ColumnFilter {
@@ -34,6 +37,7 @@ impl Default for ColumnFilter {
             enabled: true,
             case_sensitive: false,
             logic: FilterLogic::And,
+            groups: Vec::new(),
         }
     }
 }
@@ -78,6 +82,12 @@ impl ColumnFilter {
         self
     }
 
+    /// 设置所在分组（Builder 模式）
+    pub fn with_groups(mut self, groups: Vec<u32>) -> Self {
+        self.groups = groups;
+        self
+    }
+
     /// 检查条件是否有效（可以应用）
     pub fn is_valid(&self) -> bool {
         if self.column.is_empty() {
@@ -95,3 +105,74 @@ impl ColumnFilter {
         true
     }
 }
+
+impl ColumnFilter {
+    /// 转换为可持久化的预设条件
+    pub fn to_preset_condition(&self) -> FilterPresetCondition {
+        FilterPresetCondition {
+            column: self.column.clone(),
+            operator: self.operator.key().to_string(),
+            value: self.value.clone(),
+            value2: self.value2.clone(),
+            enabled: self.enabled,
+            case_sensitive: self.case_sensitive,
+            logic: match self.logic {
+                FilterLogic::And => "and",
+                FilterLogic::Or => "or",
+            }
+            .to_string(),
+            groups: self.groups.clone(),
+        }
+    }
+
+    /// 从预设条件还原；操作符无法识别时返回 `None`
+    pub fn from_preset_condition(condition: &FilterPresetCondition) -> Option<Self> {
+        let operator = FilterOperator::from_key(&condition.operator)?;
+        let logic = if condition.logic.eq_ignore_ascii_case("or") {
+            FilterLogic::Or
+        } else {
+            FilterLogic::And
+        };
+        Some(Self {
+            column: condition.column.clone(),
+            operator,
+            value: condition.value.clone(),
+            value2: condition.value2.clone(),
+            enabled: condition.enabled,
+            case_sensitive: condition.case_sensitive,
+            logic,
+            groups: condition.groups.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preset_condition_round_trip_keeps_operator_logic_and_groups() {
+        let filter = ColumnFilter::new("amount".to_string())
+            .with_operator(FilterOperator::Between)
+            .with_value("1".to_string())
+            .with_value2("9".to_string())
+            .with_logic(FilterLogic::Or)
+            .with_groups(vec![2, 5]);
+
+        let condition = filter.to_preset_condition();
+        assert_eq!(condition.operator, "between");
+        assert_eq!(condition.logic, "or");
+
+        let restored = ColumnFilter::from_preset_condition(&condition).expect("known operator");
+        assert_eq!(restored.operator, FilterOperator::Between);
+        assert_eq!(restored.logic, FilterLogic::Or);
+        assert_eq!(restored.groups, vec![2, 5]);
+        assert_eq!(restored.value2, "9");
+
+        let unknown = FilterPresetCondition {
+            operator: "sounds_like".to_string(),
+            ..condition
+        };
+        assert!(ColumnFilter::from_preset_condition(&unknown).is_none());
+    }
+}
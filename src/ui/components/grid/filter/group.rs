@@ -0,0 +1,253 @@
+//! 筛选条件分组
+//!
+//! 条件列表仍按显示顺序保存，每个条件用 `groups` 记录所在的分组路径（由外到内的编号），
+//! 相邻且编号相同的条件组成一个分组，分组可以嵌套。`FilterGroup` 把列表还原为树，
+//! 客户端求值与 SQL 下推共用同一棵树：分组先求值，组内与组间仍按从左到右的顺序结合。
+
+use super::condition::ColumnFilter;
+use super::logic::FilterLogic;
+
+/// 分组树中的节点
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterNode {
+    /// 条件在构建时传入序列中的下标
+    Condition(usize),
+    /// 嵌套分组（相当于一对括号）
+    Group(FilterGroup),
+}
+
+/// 一组按顺序结合的节点，`links[i]` 连接 `nodes[i]` 与 `nodes[i + 1]`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FilterGroup {
+    pub nodes: Vec<FilterNode>,
+    pub links: Vec<FilterLogic>,
+}
+
+impl FilterGroup {
+    /// 由（下标, 条件）序列构建分组树
+    ///
+    /// 调用方负责挑选参与求值的条件（如只取启用的条件）；分组以外的条件与分组之间
+    /// 用前一项最后一个条件的 `logic` 连接。
+    pub fn build<'a>(filters: impl IntoIterator<Item = (usize, &'a ColumnFilter)>) -> Self {
+        let entries: Vec<(usize, &ColumnFilter)> = filters.into_iter().collect();
+        build_level(&entries, 0)
+    }
+
+    /// 自左向右折叠整棵树：`leaf` 处理单个条件，`combine` 结合相邻两项
+    ///
+    /// 空树返回 `None`。
+    pub fn fold<T>(
+        &self,
+        leaf: &mut impl FnMut(usize) -> T,
+        combine: &mut impl FnMut(T, FilterLogic, T) -> T,
+    ) -> Option<T> {
+        let mut folded: Option<T> = None;
+        for (position, node) in self.nodes.iter().enumerate() {
+            let value = match node {
+                FilterNode::Condition(index) => Some(leaf(*index)),
+                FilterNode::Group(group) => group.fold(leaf, combine),
+            };
+            let Some(value) = value else {
+                continue;
+            };
+            folded = Some(match folded {
+                None => value,
+                Some(previous) => combine(previous, self.links[position - 1], value),
+            });
+        }
+        folded
+    }
+
+    /// 按分组求值；没有条件时视为匹配
+    pub fn evaluate(&self, mut leaf: impl FnMut(usize) -> bool) -> bool {
+        self.fold(&mut leaf, &mut |left, logic, right| match logic {
+            FilterLogic::And => left && right,
+            FilterLogic::Or => left || right,
+        })
+        .unwrap_or(true)
+    }
+}
+
+fn build_level(entries: &[(usize, &ColumnFilter)], depth: usize) -> FilterGroup {
+    let mut group = FilterGroup::default();
+    let mut start = 0;
+    while start < entries.len() {
+        let (index, filter) = entries[start];
+        let (node, end) = match filter.groups.get(depth) {
+            None => (FilterNode::Condition(index), start + 1),
+            Some(id) => {
+                let len = entries[start..]
+                    .iter()
+                    .take_while(|(_, member)| member.groups.get(depth) == Some(id))
+                    .count();
+                let members = &entries[start..start + len];
+                (
+                    FilterNode::Group(build_level(members, depth + 1)),
+                    start + len,
+                )
+            }
+        };
+        if start > 0 {
+            group.links.push(entries[start - 1].1.logic);
+        }
+        group.nodes.push(node);
+        start = end;
+    }
+    group
+}
+
+/// 把条件缩进一层
+///
+/// 上一个条件处在更深的分组中时并入该分组，否则以该条件新开一个分组。
+pub fn indent_filter(filters: &mut [ColumnFilter], index: usize) -> bool {
+    if index >= filters.len() {
+        return false;
+    }
+    let depth = filters[index].groups.len();
+    let joined = index
+        .checked_sub(1)
+        .map(|previous| &filters[previous].groups)
+        .filter(|previous| previous.len() > depth && previous[..depth] == filters[index].groups[..])
+        .map(|previous| previous[depth]);
+    let id = joined.unwrap_or_else(|| next_group_id(filters));
+    filters[index].groups.push(id);
+    true
+}
+
+/// 以该条件开始一个新的分组，即使紧挨着另一个同层分组
+pub fn begin_filter_group(filters: &mut [ColumnFilter], index: usize) -> bool {
+    if index >= filters.len() {
+        return false;
+    }
+    let id = next_group_id(filters);
+    filters[index].groups.push(id);
+    true
+}
+
+/// 把条件移出最内层分组
+///
+/// 分组中排在它后面的条件另成一组，保证每个分组在列表中仍然连续。
+pub fn outdent_filter(filters: &mut [ColumnFilter], index: usize) -> bool {
+    let Some(id) = filters
+        .get(index)
+        .and_then(|filter| filter.groups.last().copied())
+    else {
+        return false;
+    };
+    let depth = filters[index].groups.len() - 1;
+    filters[index].groups.pop();
+    let prefix = filters[index].groups.clone();
+    let split_id = next_group_id(filters);
+    for filter in &mut filters[index + 1..] {
+        if filter.groups.get(depth) != Some(&id) || filter.groups[..depth] != prefix[..] {
+            break;
+        }
+        filter.groups[depth] = split_id;
+    }
+    true
+}
+
+/// 显示用的括号数：（该条件前打开的分组数, 该条件后关闭的分组数）
+pub fn group_brackets(filters: &[ColumnFilter], index: usize) -> (usize, usize) {
+    let Some(filter) = filters.get(index) else {
+        return (0, 0);
+    };
+    let depth = filter.groups.len();
+    let shared_with = |other: Option<&ColumnFilter>| {
+        other.map_or(0, |other| common_depth(&filter.groups, &other.groups))
+    };
+    let opened = depth - shared_with(index.checked_sub(1).and_then(|i| filters.get(i)));
+    let closed = depth - shared_with(filters.get(index + 1));
+    (opened, closed)
+}
+
+/// 两个条件共同所在的分组层数（连接它们的 AND/OR 位于这一层）
+pub fn common_depth(left: &[u32], right: &[u32]) -> usize {
+    left.iter()
+        .zip(right)
+        .take_while(|(left, right)| left == right)
+        .count()
+}
+
+fn next_group_id(filters: &[ColumnFilter]) -> u32 {
+    filters
+        .iter()
+        .flat_map(|filter| filter.groups.iter().copied())
+        .max()
+        .map_or(1, |id| id + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filters(count: usize) -> Vec<ColumnFilter> {
+        (0..count)
+            .map(|index| ColumnFilter::new(format!("c{}", index)))
+            .collect()
+    }
+
+    fn evaluate(filters: &[ColumnFilter], matches: &[bool]) -> bool {
+        FilterGroup::build(filters.iter().enumerate()).evaluate(|index| matches[index])
+    }
+
+    #[test]
+    fn flat_list_keeps_left_to_right_semantics() {
+        let mut list = filters(3);
+        list[0].logic = FilterLogic::Or;
+        // (a OR b) AND c
+        assert!(!evaluate(&list, &[true, false, false]));
+        assert!(evaluate(&list, &[false, true, true]));
+        assert!(evaluate(&[], &[]));
+    }
+
+    #[test]
+    fn groups_bind_before_surrounding_conditions() {
+        // a AND (b OR c)
+        let mut list = filters(3);
+        list[1].logic = FilterLogic::Or;
+        list[1].groups = vec![1];
+        list[2].groups = vec![1];
+        assert!(evaluate(&list, &[true, false, true]));
+        assert!(!evaluate(&list, &[false, true, true]));
+
+        let tree = FilterGroup::build(list.iter().enumerate());
+        assert_eq!(tree.nodes.len(), 2);
+        assert_eq!(tree.links, vec![FilterLogic::And]);
+    }
+
+    #[test]
+    fn indent_joins_previous_group_and_begin_starts_a_new_one() {
+        // (a OR b) AND (c OR d)
+        let mut list = filters(4);
+        list[0].logic = FilterLogic::Or;
+        list[2].logic = FilterLogic::Or;
+        assert!(indent_filter(&mut list, 0));
+        assert!(indent_filter(&mut list, 1));
+        assert!(begin_filter_group(&mut list, 2));
+        assert!(indent_filter(&mut list, 3));
+        assert_eq!(list[1].groups, list[0].groups);
+        assert_eq!(list[3].groups, list[2].groups);
+        assert_ne!(list[0].groups, list[2].groups);
+
+        assert!(evaluate(&list, &[false, true, true, false]));
+        assert!(!evaluate(&list, &[true, true, false, false]));
+        assert_eq!(group_brackets(&list, 0), (1, 0));
+        assert_eq!(group_brackets(&list, 1), (0, 1));
+        assert_eq!(common_depth(&list[1].groups, &list[2].groups), 0);
+    }
+
+    #[test]
+    fn outdent_splits_the_rest_of_the_group() {
+        let mut list = filters(3);
+        for filter in &mut list {
+            filter.groups = vec![1];
+        }
+        assert!(outdent_filter(&mut list, 1));
+        assert!(list[1].groups.is_empty());
+        assert_eq!(list[0].groups, vec![1]);
+        assert_eq!(list[2].groups.len(), 1);
+        assert_ne!(list[2].groups, list[0].groups);
+        assert!(!outdent_filter(&mut list, 1));
+    }
+}
@@ -4,14 +4,18 @@
 
 mod cache;
 mod condition;
+mod group;
 mod logic;
 mod operators;
+mod sql;
 mod ui;
 
 // 重新导出公共接口
 pub use cache::FilterCache;
 pub(crate) use cache::filter_result_set_cached;
 pub use condition::ColumnFilter;
+pub use group::{begin_filter_group, common_depth, group_brackets, indent_filter, outdent_filter};
 pub use logic::FilterLogic;
 pub use operators::{FilterOperator, check_filter_match};
+pub use sql::{FilterClause, compile_filter_clause};
 pub(crate) use ui::show_filter_bar;
@@ -87,6 +87,36 @@ impl FilterOperator {
         }
     }
 
+    /// 稳定的标识（用于持久化筛选预设）
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Contains => "contains",
+            Self::NotContains => "not_contains",
+            Self::Equals => "equals",
+            Self::NotEquals => "not_equals",
+            Self::StartsWith => "starts_with",
+            Self::EndsWith => "ends_with",
+            Self::GreaterThan => "greater_than",
+            Self::GreaterOrEqual => "greater_or_equal",
+            Self::LessThan => "less_than",
+            Self::LessOrEqual => "less_or_equal",
+            Self::Between => "between",
+            Self::NotBetween => "not_between",
+            Self::In => "in",
+            Self::NotIn => "not_in",
+            Self::IsNull => "is_null",
+            Self::IsNotNull => "is_not_null",
+            Self::IsEmpty => "is_empty",
+            Self::IsNotEmpty => "is_not_empty",
+            Self::Regex => "regex",
+        }
+    }
+
+    /// 由 [`FilterOperator::key`] 还原操作符
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::all().into_iter().find(|op| op.key() == key)
+    }
+
     /// 是否需要输入值
     pub fn needs_value(&self) -> bool {
         !matches!(
@@ -140,7 +170,6 @@ impl FilterOperator {
     }
 
     /// 获取所有操作符
-    #[allow(dead_code)] // 公开 API，供外部使用
     pub fn all() -> Vec<FilterOperator> {
         let mut ops = Vec::new();
         ops.extend_from_slice(Self::text_operators());
@@ -0,0 +1,535 @@
+//! 筛选条件下推
+//!
+//! 将筛选条件编译为数据库端的 WHERE 谓词，使筛选覆盖整张表而不只是已加载的页。
+//! 条件之间的 AND/OR 与客户端筛选一致：分组先结合，其余按顺序从左到右结合，不区分优先级。
+//! 用户输入的值先按列类型解析，再由 `FilterClause` 按方言转义为字面量，不直接拼进 SQL 文本。
+
+use super::condition::ColumnFilter;
+use super::group::FilterGroup;
+use super::logic::FilterLogic;
+use super::operators::FilterOperator;
+use crate::domain::identifier::IdentifierDialect;
+use crate::domain::result::ResultColumn;
+use crate::domain::value::{DbTypeFamily, DbTypeInfo, DbValue};
+
+/// LIKE 模式的转义字符（三种方言都不把它当作默认转义符，字面量中也无需额外转义）
+const LIKE_ESCAPE: char = '!';
+
+#[derive(Debug, Clone, PartialEq)]
+enum ClausePart {
+    Sql(String),
+    Param(usize),
+}
+
+/// 编译后的筛选谓词
+#[derive(Debug, Clone)]
+pub struct FilterClause {
+    dialect: IdentifierDialect,
+    parts: Vec<ClausePart>,
+    params: Vec<DbValue>,
+}
+
+impl FilterClause {
+    /// 将参数按方言写成字面量，得到可直接执行的谓词
+    ///
+    /// 表格浏览走文本 SQL 执行路径，参数统一在这里转义；无法写成字面量的值返回错误。
+    pub fn inline_sql(&self) -> Result<String, String> {
+        let mut sql = String::new();
+        for part in &self.parts {
+            match part {
+                ClausePart::Sql(fragment) => sql.push_str(fragment),
+                ClausePart::Param(index) => {
+                    let value = &self.params[*index];
+                    let literal = crate::core::sql_literal(value, self.dialect)
+                        .ok_or_else(|| format!("筛选值 '{}' 无法写入 SQL", value.display()))?;
+                    sql.push_str(&literal);
+                }
+            }
+        }
+        Ok(sql)
+    }
+}
+
+/// 将启用且有效的筛选条件编译为 WHERE 谓词
+///
+/// 没有可用条件时返回 `Ok(None)`；筛选列不在 `columns` 中或方言不支持的操作符返回错误。
+pub fn compile_filter_clause(
+    filters: &[ColumnFilter],
+    columns: &[ResultColumn],
+    dialect: IdentifierDialect,
+) -> Result<Option<FilterClause>, String> {
+    let mut compiler = ClauseCompiler {
+        dialect,
+        params: Vec::new(),
+    };
+    let active: Vec<&ColumnFilter> = filters
+        .iter()
+        .filter(|f| f.enabled && f.is_valid())
+        .collect();
+    let mut conditions = Vec::with_capacity(active.len());
+    for filter in &active {
+        let column = columns
+            .iter()
+            .find(|column| column.name == filter.column)
+            .ok_or_else(|| format!("筛选列 '{}' 不在当前结果中", filter.column))?;
+        conditions.push(Some(compiler.condition(filter, &column.type_info)?));
+    }
+
+    let tree = FilterGroup::build(active.iter().copied().enumerate());
+    let combined = tree.fold(
+        &mut |index| conditions[index].take().unwrap_or_default(),
+        &mut |mut previous, logic, condition| {
+            let joiner = match logic {
+                FilterLogic::And => " AND ",
+                FilterLogic::Or => " OR ",
+            };
+            previous.insert(0, ClausePart::Sql("(".to_string()));
+            previous.push(ClausePart::Sql(joiner.to_string()));
+            previous.extend(condition);
+            previous.push(ClausePart::Sql(")".to_string()));
+            previous
+        },
+    );
+
+    Ok(combined.map(|parts| FilterClause {
+        dialect,
+        parts,
+        params: compiler.params,
+    }))
+}
+
+struct ClauseCompiler {
+    dialect: IdentifierDialect,
+    params: Vec<DbValue>,
+}
+
+impl ClauseCompiler {
+    /// 编译单个条件；结果自成一体，可直接与其它谓词以 AND/OR 拼接
+    fn condition(
+        &mut self,
+        filter: &ColumnFilter,
+        type_info: &DbTypeInfo,
+    ) -> Result<Vec<ClausePart>, String> {
+        let column = self.dialect.quote(&filter.column);
+        let positive = match &filter.operator {
+            FilterOperator::Contains | FilterOperator::NotContains => {
+                self.pattern(filter, type_info, &column, true, true)
+            }
+            FilterOperator::StartsWith => self.pattern(filter, type_info, &column, false, true),
+            FilterOperator::EndsWith => self.pattern(filter, type_info, &column, true, false),
+            FilterOperator::Equals | FilterOperator::NotEquals => {
+                self.equality(filter, type_info, &column)
+            }
+            FilterOperator::In | FilterOperator::NotIn => self.in_list(filter, type_info, &column),
+            FilterOperator::GreaterThan => self.comparison(&column, ">", &filter.value, type_info),
+            FilterOperator::GreaterOrEqual => {
+                self.comparison(&column, ">=", &filter.value, type_info)
+            }
+            FilterOperator::LessThan => self.comparison(&column, "<", &filter.value, type_info),
+            FilterOperator::LessOrEqual => self.comparison(&column, "<=", &filter.value, type_info),
+            FilterOperator::Between | FilterOperator::NotBetween => vec![
+                ClausePart::Sql(format!("{} BETWEEN ", column)),
+                self.param(crate::data::infer_value(&filter.value, type_info)),
+                ClausePart::Sql(" AND ".to_string()),
+                self.param(crate::data::infer_value(&filter.value2, type_info)),
+            ],
+            FilterOperator::IsNull => vec![ClausePart::Sql(format!("{} IS NULL", column))],
+            FilterOperator::IsNotNull => {
+                vec![ClausePart::Sql(format!("{} IS NOT NULL", column))]
+            }
+            // 与 NULL 比较的结果为 NULL，两者都自然排除 NULL 行
+            FilterOperator::IsEmpty => vec![ClausePart::Sql(format!(
+                "{} = ''",
+                self.text_expr(&column, type_info)
+            ))],
+            FilterOperator::IsNotEmpty => vec![ClausePart::Sql(format!(
+                "{} <> ''",
+                self.text_expr(&column, type_info)
+            ))],
+            FilterOperator::Regex => {
+                let operator = match self.dialect {
+                    IdentifierDialect::PostgreSql => "~",
+                    IdentifierDialect::MySql => "REGEXP",
+                    IdentifierDialect::SQLite => {
+                        return Err(
+                            "SQLite 未提供 REGEXP 运算符，正则筛选请使用客户端筛选".to_string()
+                        );
+                    }
+                };
+                vec![
+                    ClausePart::Sql(format!(
+                        "{} {} ",
+                        self.text_expr(&column, type_info),
+                        operator
+                    )),
+                    self.param(DbValue::Text(filter.value.clone())),
+                ]
+            }
+        };
+
+        // 客户端筛选中 NULL 不满足正向条件，因此满足取反条件
+        let negated = matches!(
+            filter.operator,
+            FilterOperator::NotContains
+                | FilterOperator::NotEquals
+                | FilterOperator::NotIn
+                | FilterOperator::NotBetween
+        );
+        if !negated {
+            return Ok(positive);
+        }
+        let mut parts = vec![ClausePart::Sql(format!("({} IS NULL OR NOT (", column))];
+        parts.extend(positive);
+        parts.push(ClausePart::Sql("))".to_string()));
+        Ok(parts)
+    }
+
+    /// 包含 / 开头 / 结尾：LIKE（SQLite 区分大小写时用 GLOB）
+    fn pattern(
+        &mut self,
+        filter: &ColumnFilter,
+        type_info: &DbTypeInfo,
+        column: &str,
+        leading_wildcard: bool,
+        trailing_wildcard: bool,
+    ) -> Vec<ClausePart> {
+        let operand = self.text_operand(column, type_info, filter.case_sensitive);
+        let value = case_folded(&filter.value, filter.case_sensitive);
+        // SQLite 的 LIKE 对 ASCII 不区分大小写
+        if filter.case_sensitive && self.dialect == IdentifierDialect::SQLite {
+            let pattern = wrap_pattern(
+                &escape_glob(&value),
+                '*',
+                leading_wildcard,
+                trailing_wildcard,
+            );
+            return vec![
+                ClausePart::Sql(format!("{} GLOB ", operand)),
+                self.param(DbValue::Text(pattern)),
+            ];
+        }
+        let pattern = wrap_pattern(
+            &escape_like(&value),
+            '%',
+            leading_wildcard,
+            trailing_wildcard,
+        );
+        vec![
+            ClausePart::Sql(format!("{} LIKE ", operand)),
+            self.param(DbValue::Text(pattern)),
+            ClausePart::Sql(format!(" ESCAPE '{}'", LIKE_ESCAPE)),
+        ]
+    }
+
+    fn equality(
+        &mut self,
+        filter: &ColumnFilter,
+        type_info: &DbTypeInfo,
+        column: &str,
+    ) -> Vec<ClausePart> {
+        match typed_value(&filter.value, type_info) {
+            Some(value) => vec![ClausePart::Sql(format!("{} = ", column)), self.param(value)],
+            None => {
+                let operand = self.text_operand(column, type_info, filter.case_sensitive);
+                let value = case_folded(&filter.value, filter.case_sensitive);
+                vec![
+                    ClausePart::Sql(format!("{} = ", operand)),
+                    self.param(DbValue::Text(value)),
+                ]
+            }
+        }
+    }
+
+    /// 列表值以逗号分隔；全部能按列类型解析时按类型比较，否则按文本比较
+    fn in_list(
+        &mut self,
+        filter: &ColumnFilter,
+        type_info: &DbTypeInfo,
+        column: &str,
+    ) -> Vec<ClausePart> {
+        let items: Vec<&str> = filter.value.split(',').map(str::trim).collect();
+        let typed = items
+            .iter()
+            .map(|item| typed_value(item, type_info))
+            .collect::<Option<Vec<_>>>();
+        let (operand, values) = match typed {
+            Some(values) => (column.to_string(), values),
+            None => (
+                self.text_operand(column, type_info, filter.case_sensitive),
+                items
+                    .iter()
+                    .map(|item| DbValue::Text(case_folded(item, filter.case_sensitive)))
+                    .collect(),
+            ),
+        };
+
+        let mut parts = vec![ClausePart::Sql(format!("{} IN (", operand))];
+        for (index, value) in values.into_iter().enumerate() {
+            if index > 0 {
+                parts.push(ClausePart::Sql(", ".to_string()));
+            }
+            parts.push(self.param(value));
+        }
+        parts.push(ClausePart::Sql(")".to_string()));
+        parts
+    }
+
+    fn comparison(
+        &mut self,
+        column: &str,
+        operator: &str,
+        value: &str,
+        type_info: &DbTypeInfo,
+    ) -> Vec<ClausePart> {
+        vec![
+            ClausePart::Sql(format!("{} {} ", column, operator)),
+            self.param(crate::data::infer_value(value, type_info)),
+        ]
+    }
+
+    fn param(&mut self, value: DbValue) -> ClausePart {
+        self.params.push(value);
+        ClausePart::Param(self.params.len() - 1)
+    }
+
+    /// 文本匹配的左操作数：不区分大小写时取 `LOWER`，MySQL 区分大小写时按二进制比较
+    fn text_operand(&self, column: &str, type_info: &DbTypeInfo, case_sensitive: bool) -> String {
+        let expr = self.text_expr(column, type_info);
+        if !case_sensitive {
+            format!("LOWER({})", expr)
+        } else if self.dialect == IdentifierDialect::MySql {
+            format!("BINARY {}", expr)
+        } else {
+            expr
+        }
+    }
+
+    /// 非文本列先转为文本再做模式匹配
+    fn text_expr(&self, column: &str, type_info: &DbTypeInfo) -> String {
+        if type_info.family == DbTypeFamily::Text {
+            return column.to_string();
+        }
+        match self.dialect {
+            IdentifierDialect::MySql => format!("CAST({} AS CHAR)", column),
+            IdentifierDialect::PostgreSql | IdentifierDialect::SQLite => {
+                format!("CAST({} AS TEXT)", column)
+            }
+        }
+    }
+}
+
+/// 非文本列上能按列类型解析的值；文本列或解析失败时返回 `None`（改按文本比较）
+fn typed_value(raw: &str, type_info: &DbTypeInfo) -> Option<DbValue> {
+    if type_info.family == DbTypeFamily::Text {
+        return None;
+    }
+    match crate::data::infer_value(raw, type_info) {
+        DbValue::Text(_) => None,
+        value => Some(value),
+    }
+}
+
+fn case_folded(value: &str, case_sensitive: bool) -> String {
+    if case_sensitive {
+        value.to_string()
+    } else {
+        value.to_lowercase()
+    }
+}
+
+fn wrap_pattern(escaped: &str, wildcard: char, leading: bool, trailing: bool) -> String {
+    let mut pattern = String::with_capacity(escaped.len() + 2);
+    if leading {
+        pattern.push(wildcard);
+    }
+    pattern.push_str(escaped);
+    if trailing {
+        pattern.push(wildcard);
+    }
+    pattern
+}
+
+fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        if matches!(ch, '%' | '_') || ch == LIKE_ESCAPE {
+            escaped.push(LIKE_ESCAPE);
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+/// GLOB 没有转义符，元字符放进字符类中按字面匹配
+fn escape_glob(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '*' | '?' | '[' => {
+                escaped.push('[');
+                escaped.push(ch);
+                escaped.push(']');
+            }
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str, family: DbTypeFamily) -> ResultColumn {
+        ResultColumn {
+            name: name.into(),
+            type_info: DbTypeInfo {
+                family,
+                native_name: String::new(),
+                nullable: Some(true),
+            },
+        }
+    }
+
+    fn columns() -> Vec<ResultColumn> {
+        vec![
+            column("id", DbTypeFamily::Integer),
+            column("name", DbTypeFamily::Text),
+        ]
+    }
+
+    fn filter(column: &str, operator: FilterOperator, value: &str) -> ColumnFilter {
+        ColumnFilter::new(column.to_string())
+            .with_operator(operator)
+            .with_value(value.to_string())
+    }
+
+    fn compile(filters: &[ColumnFilter], dialect: IdentifierDialect) -> FilterClause {
+        compile_filter_clause(filters, &columns(), dialect)
+            .expect("compile")
+            .expect("clause")
+    }
+
+    #[test]
+    fn logic_combines_left_to_right() {
+        let filters = vec![
+            filter("id", FilterOperator::GreaterThan, "10").with_logic(FilterLogic::Or),
+            filter("name", FilterOperator::Equals, "Bob"),
+            filter("id", FilterOperator::Between, "1").with_value2("5".to_string()),
+        ];
+
+        let clause = compile(&filters, IdentifierDialect::PostgreSql);
+        assert_eq!(
+            clause.inline_sql().unwrap(),
+            "((\"id\" > 10 OR LOWER(\"name\") = 'bob') AND \"id\" BETWEEN 1 AND 5)"
+        );
+
+        let clause = compile(&filters, IdentifierDialect::MySql);
+        assert_eq!(
+            clause.inline_sql().unwrap(),
+            "((`id` > 10 OR LOWER(`name`) = 'bob') AND `id` BETWEEN 1 AND 5)"
+        );
+    }
+
+    #[test]
+    fn groups_compile_to_nested_parentheses() {
+        let filters = vec![
+            filter("id", FilterOperator::GreaterThan, "10"),
+            filter("name", FilterOperator::Equals, "Bob")
+                .with_logic(FilterLogic::Or)
+                .with_groups(vec![1]),
+            filter("id", FilterOperator::Between, "1")
+                .with_value2("5".to_string())
+                .with_groups(vec![1]),
+        ];
+
+        let clause = compile(&filters, IdentifierDialect::PostgreSql);
+        assert_eq!(
+            clause.inline_sql().unwrap(),
+            "(\"id\" > 10 AND (LOWER(\"name\") = 'bob' OR \"id\" BETWEEN 1 AND 5))"
+        );
+    }
+
+    #[test]
+    fn disabled_and_incomplete_filters_are_skipped() {
+        let mut disabled = filter("id", FilterOperator::Equals, "1");
+        disabled.enabled = false;
+        let incomplete = filter("name", FilterOperator::Contains, "");
+
+        let clause = compile_filter_clause(
+            &[disabled, incomplete],
+            &columns(),
+            IdentifierDialect::SQLite,
+        )
+        .unwrap();
+        assert!(clause.is_none());
+    }
+
+    #[test]
+    fn patterns_escape_wildcards_and_inline_quotes() {
+        let clause = compile(
+            &[filter("name", FilterOperator::Contains, "50%_o'k!")],
+            IdentifierDialect::SQLite,
+        );
+        assert_eq!(
+            clause.inline_sql().unwrap(),
+            "LOWER(\"name\") LIKE '%50!%!_o''k!!%' ESCAPE '!'"
+        );
+
+        let case_sensitive =
+            filter("name", FilterOperator::StartsWith, "A*").with_case_sensitive(true);
+        let clause = compile(
+            std::slice::from_ref(&case_sensitive),
+            IdentifierDialect::SQLite,
+        );
+        assert_eq!(clause.inline_sql().unwrap(), "\"name\" GLOB 'A[*]*'");
+        let clause = compile(&[case_sensitive], IdentifierDialect::MySql);
+        assert_eq!(
+            clause.inline_sql().unwrap(),
+            "BINARY `name` LIKE 'A*%' ESCAPE '!'"
+        );
+    }
+
+    #[test]
+    fn in_list_and_negations_keep_null_rows() {
+        let clause = compile(
+            &[filter("id", FilterOperator::NotIn, "1, 2,3")],
+            IdentifierDialect::PostgreSql,
+        );
+        assert_eq!(
+            clause.inline_sql().unwrap(),
+            "(\"id\" IS NULL OR NOT (\"id\" IN (1, 2, 3)))"
+        );
+
+        // 无法按整数解析的值改为文本比较
+        let clause = compile(
+            &[filter("id", FilterOperator::In, "1,x")],
+            IdentifierDialect::PostgreSql,
+        );
+        assert_eq!(
+            clause.inline_sql().unwrap(),
+            "LOWER(CAST(\"id\" AS TEXT)) IN ('1', 'x')"
+        );
+    }
+
+    #[test]
+    fn regex_maps_to_dialect_operator() {
+        let regex = filter("name", FilterOperator::Regex, "^a.*\\d$");
+        let clause = compile(std::slice::from_ref(&regex), IdentifierDialect::PostgreSql);
+        assert_eq!(clause.inline_sql().unwrap(), "\"name\" ~ '^a.*\\d$'");
+        let clause = compile(std::slice::from_ref(&regex), IdentifierDialect::MySql);
+        assert_eq!(clause.inline_sql().unwrap(), "`name` REGEXP '^a.*\\\\d$'");
+        assert!(compile_filter_clause(&[regex], &columns(), IdentifierDialect::SQLite).is_err());
+    }
+
+    #[test]
+    fn unknown_column_is_rejected() {
+        let error = compile_filter_clause(
+            &[filter("missing", FilterOperator::IsNull, "")],
+            &columns(),
+            IdentifierDialect::SQLite,
+        )
+        .unwrap_err();
+        assert!(error.contains("missing"));
+    }
+}
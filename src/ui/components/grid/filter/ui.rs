@@ -5,7 +5,7 @@
 use super::condition::ColumnFilter;
 use crate::domain::result::ResultSet;
 use crate::ui::styles::GRAY;
-use crate::ui::{LocalShortcut, local_shortcut_tooltip};
+use crate::ui::{GridPageInfo, LocalShortcut, local_shortcut_tooltip};
 use egui::{self, Color32, RichText};
 
 /// 筛选栏状态
@@ -14,16 +14,29 @@ pub(crate) struct FilterBarState {
     pub filters: Vec<ColumnFilter>,
 }
 
+/// 筛选栏交互结果
+#[derive(Default)]
+pub(crate) struct FilterBarResponse {
+    /// 筛选条件有修改（用于使缓存失效）
+    pub changed: bool,
+    /// 请求按筛选条件重新查询表
+    pub apply_server_filters: bool,
+}
+
 /// 显示筛选状态栏（简洁版，只显示筛选数量）
 ///
-/// 返回是否有修改（用于使缓存失效）
+/// 分页浏览表时额外提供服务端筛选开关：开启后筛选条件在数据库端执行，覆盖整张表。
 pub(crate) fn show_filter_bar(
     ui: &mut egui::Ui,
     _result: &ResultSet,
     filters: &mut Vec<ColumnFilter>,
-) -> bool {
-    if filters.is_empty() {
-        return false;
+    server_side: &mut bool,
+    page_info: Option<&GridPageInfo>,
+) -> FilterBarResponse {
+    let mut response = FilterBarResponse::default();
+    let server_filtered = page_info.is_some_and(|page| page.filtered);
+    if filters.is_empty() && !server_filtered {
+        return response;
     }
 
     let initial_count = filters.len();
@@ -42,6 +55,28 @@ pub(crate) fn show_filter_bar(
                 .color(Color32::from_rgb(130, 160, 200)),
         );
 
+        if page_info.is_some() {
+            if ui
+                .checkbox(server_side, RichText::new("服务端").size(11.0))
+                .on_hover_text("在数据库中按筛选条件重新查询整张表，而不只筛选已加载的当前页")
+                .changed()
+            {
+                response.apply_server_filters = *server_side || server_filtered;
+            }
+            if *server_side
+                && ui
+                    .add(
+                        egui::Label::new(RichText::new("应用").size(11.0).color(GRAY))
+                            .sense(egui::Sense::click()),
+                    )
+                    .on_hover_text("按当前筛选条件重新查询表")
+                    .on_hover_cursor(egui::CursorIcon::PointingHand)
+                    .clicked()
+            {
+                response.apply_server_filters = true;
+            }
+        }
+
         // 清空按钮
         if ui
             .add(
@@ -56,8 +91,12 @@ pub(crate) fn show_filter_bar(
             .clicked()
         {
             filters.clear();
+            if server_filtered {
+                response.apply_server_filters = true;
+            }
         }
     });
 
-    filters.len() != initial_count
+    response.changed = filters.len() != initial_count;
+    response
 }
@@ -0,0 +1,794 @@
+//! 分组 / 透视视图
+//!
+//! 按一列或多列对当前显示的行分组，逐层折叠显示每组的行数与聚合值；
+//! 也可以把一列的不同取值展开为表头，生成透视表。分组键按 `DbValue::cmp_semantic` 比较，
+//! 行数超过 `PARALLEL_FILTER_THRESHOLD` 时用 rayon 并行排序与聚合。
+//! 分组直接读取结果集中的类型化值，不含未保存的修改。
+
+use super::CELL_TRUNCATE_LEN;
+use super::state::DataGridState;
+use super::stats::{
+    DataVersion, ValueStats, format_number, is_numeric_family, value_preview, value_stats,
+};
+use crate::core::constants::database::PARALLEL_FILTER_THRESHOLD;
+use crate::core::constants::grid::{HEADER_HEIGHT, ROW_HEIGHT};
+use crate::domain::result::ResultSet;
+use crate::domain::value::DbValue;
+use crate::ui::styles::{GRAY, theme_accent};
+use egui::{self, RichText};
+use egui_extras::{Column, TableBuilder};
+use rayon::prelude::*;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// 透视表最多展开的列数
+const MAX_PIVOT_COLUMNS: usize = 100;
+/// 每一层最多列出的分组数
+const MAX_VISIBLE_GROUPS: usize = 500;
+/// 展开最底层分组时列出的行数
+const MAX_GROUP_ROWS: usize = 50;
+
+/// 分组视图的展示方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GroupLayout {
+    /// 可折叠的分组
+    #[default]
+    Groups,
+    /// 透视表
+    Pivot,
+}
+
+/// 每组的聚合方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GroupAggregate {
+    /// 未选择值列时为行数，否则为值列中非 NULL 值的个数
+    #[default]
+    Count,
+    Distinct,
+    Sum,
+    Average,
+    Min,
+    Max,
+}
+
+impl GroupAggregate {
+    const ALL: [Self; 6] = [
+        Self::Count,
+        Self::Distinct,
+        Self::Sum,
+        Self::Average,
+        Self::Min,
+        Self::Max,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Count => "计数",
+            Self::Distinct => "去重",
+            Self::Sum => "合计",
+            Self::Average => "平均",
+            Self::Min => "最小",
+            Self::Max => "最大",
+        }
+    }
+
+    fn value_text(self, stats: &ValueStats) -> String {
+        let number = |value: Option<f64>| value.map(format_number).unwrap_or_default();
+        let text =
+            |value: &Option<DbValue>| value.as_ref().map(DbValue::display).unwrap_or_default();
+        match self {
+            Self::Count => (stats.count - stats.nulls).to_string(),
+            Self::Distinct => stats.distinct.to_string(),
+            Self::Sum => number(stats.sum),
+            Self::Average => number(stats.average()),
+            Self::Min => text(&stats.min),
+            Self::Max => text(&stats.max),
+        }
+    }
+}
+
+/// 分组视图的设置；列按名称保存，结果集刷新后仍然有效
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GroupViewConfig {
+    pub layout: GroupLayout,
+    /// 分组列，按层级顺序
+    pub group_columns: Vec<String>,
+    /// 聚合的值列；未选择时只统计行数
+    pub value_column: Option<String>,
+    pub aggregate: GroupAggregate,
+    /// 透视列：其不同取值展开为表头
+    pub pivot_column: Option<String>,
+}
+
+/// 分组视图状态
+#[derive(Debug, Clone, Default)]
+pub struct GroupViewState {
+    /// 是否以分组视图代替表格
+    pub active: bool,
+    pub config: GroupViewConfig,
+    cache: Option<(DataVersion, GroupViewConfig, Arc<GroupOutput>)>,
+}
+
+impl GroupViewState {
+    /// 切换分组视图；首次打开时按光标所在列分组
+    pub(crate) fn toggle(&mut self, cursor_column: Option<&str>) -> bool {
+        self.active = !self.active;
+        if self.active
+            && self.config.group_columns.is_empty()
+            && let Some(column) = cursor_column
+        {
+            self.config.group_columns.push(column.to_string());
+        }
+        self.active
+    }
+}
+
+/// 一个分组
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct GroupNode {
+    pub value: DbValue,
+    pub rows: usize,
+    /// 值列的聚合结果；未选择值列时为 `None`
+    pub aggregate: Option<String>,
+    /// 下一层分组
+    pub children: Vec<GroupNode>,
+    /// 最底层分组中的前若干行（行键）
+    pub sample_rows: Vec<usize>,
+}
+
+/// 透视表的一行：分组键、各透视列的聚合值与整行合计
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PivotRow {
+    pub keys: Vec<DbValue>,
+    pub cells: Vec<Option<String>>,
+    pub total: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PivotTable {
+    /// 透视列的取值，作为表头
+    pub column_values: Vec<DbValue>,
+    pub rows: Vec<PivotRow>,
+    /// 超出列数上限而未展开的取值个数
+    pub dropped_columns: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum GroupOutput {
+    Groups(Vec<GroupNode>),
+    Pivot(PivotTable),
+}
+
+/// 按列名解析出的设置
+struct ResolvedConfig {
+    group_columns: Vec<usize>,
+    /// 值列及其是否为数值列
+    value_column: Option<(usize, bool)>,
+    aggregate: GroupAggregate,
+    pivot_column: Option<usize>,
+}
+
+impl ResolvedConfig {
+    fn new(config: &GroupViewConfig, result: &ResultSet) -> Self {
+        let position = |name: &str| result.columns.iter().position(|column| column.name == name);
+        let value_column = config
+            .value_column
+            .as_deref()
+            .and_then(position)
+            .map(|col| (col, is_numeric_family(result.columns[col].type_info.family)));
+        Self {
+            group_columns: config
+                .group_columns
+                .iter()
+                .filter_map(|name| position(name))
+                .collect(),
+            value_column,
+            aggregate: if value_column.is_some() {
+                config.aggregate
+            } else {
+                GroupAggregate::Count
+            },
+            pivot_column: config.pivot_column.as_deref().and_then(position),
+        }
+    }
+
+    fn aggregate(&self, result: &ResultSet, rows: &[usize]) -> String {
+        match self.value_column {
+            Some((col, numeric)) => self.aggregate.value_text(&value_stats(
+                rows.iter().map(|row| result.cell(*row, col)),
+                numeric,
+            )),
+            None => rows.len().to_string(),
+        }
+    }
+}
+
+fn compare_rows(result: &ResultSet, a: usize, b: usize, columns: &[usize]) -> Ordering {
+    columns
+        .iter()
+        .map(|col| result.cell(a, *col).cmp_semantic(result.cell(b, *col)))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// 按列排序行键；稳定排序，组内保持原来的顺序
+fn sort_rows(result: &ResultSet, rows: &mut [usize], columns: &[usize]) {
+    let compare = |a: &usize, b: &usize| compare_rows(result, *a, *b, columns);
+    if rows.len() > PARALLEL_FILTER_THRESHOLD {
+        rows.par_sort_by(compare);
+    } else {
+        rows.sort_by(compare);
+    }
+}
+
+/// 已排序的行按列切分为取值相同的连续段
+fn runs<'r>(result: &ResultSet, rows: &'r [usize], columns: &[usize]) -> Vec<&'r [usize]> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    for index in 1..=rows.len() {
+        if index == rows.len() || compare_rows(result, rows[start], rows[index], columns).is_ne() {
+            runs.push(&rows[start..index]);
+            start = index;
+        }
+    }
+    runs
+}
+
+/// 逐层构建分组；`rows` 已按全部分组列排序
+fn build_groups(
+    result: &ResultSet,
+    rows: &[usize],
+    columns: &[usize],
+    config: &ResolvedConfig,
+) -> Vec<GroupNode> {
+    let Some((&column, rest)) = columns.split_first() else {
+        return Vec::new();
+    };
+    let build = |run: &[usize]| GroupNode {
+        value: result.cell(run[0], column).clone(),
+        rows: run.len(),
+        aggregate: config
+            .value_column
+            .is_some()
+            .then(|| config.aggregate(result, run)),
+        children: build_groups(result, run, rest, config),
+        sample_rows: if rest.is_empty() {
+            run.iter().take(MAX_GROUP_ROWS).copied().collect()
+        } else {
+            Vec::new()
+        },
+    };
+    let runs = runs(result, rows, &[column]);
+    if rows.len() > PARALLEL_FILTER_THRESHOLD {
+        runs.into_par_iter().map(build).collect()
+    } else {
+        runs.into_iter().map(build).collect()
+    }
+}
+
+/// 构建透视表：行为分组列的组合，列为透视列的取值
+fn build_pivot(
+    result: &ResultSet,
+    rows: &mut [usize],
+    pivot_column: usize,
+    config: &ResolvedConfig,
+) -> PivotTable {
+    sort_rows(result, rows, &[pivot_column]);
+    let mut column_values: Vec<DbValue> = runs(result, rows, &[pivot_column])
+        .into_iter()
+        .map(|run| result.cell(run[0], pivot_column).clone())
+        .collect();
+    let dropped_columns = column_values.len().saturating_sub(MAX_PIVOT_COLUMNS);
+    column_values.truncate(MAX_PIVOT_COLUMNS);
+
+    let mut keys = config.group_columns.clone();
+    keys.push(pivot_column);
+    sort_rows(result, rows, &keys);
+    let row_runs = if rows.is_empty() {
+        Vec::new()
+    } else if config.group_columns.is_empty() {
+        vec![&rows[..]]
+    } else {
+        runs(result, rows, &config.group_columns)
+    };
+
+    let build = |run: &[usize]| {
+        let mut cells = vec![None; column_values.len()];
+        for cell_rows in runs(result, run, &[pivot_column]) {
+            let value = result.cell(cell_rows[0], pivot_column);
+            if let Ok(index) = column_values.binary_search_by(|probe| probe.cmp_semantic(value)) {
+                cells[index] = Some(config.aggregate(result, cell_rows));
+            }
+        }
+        PivotRow {
+            keys: config
+                .group_columns
+                .iter()
+                .map(|col| result.cell(run[0], *col).clone())
+                .collect(),
+            cells,
+            total: config.aggregate(result, run),
+        }
+    };
+    let pivot_rows = if rows.len() > PARALLEL_FILTER_THRESHOLD {
+        row_runs.into_par_iter().map(build).collect()
+    } else {
+        row_runs.into_iter().map(build).collect()
+    };
+    PivotTable {
+        column_values,
+        rows: pivot_rows,
+        dropped_columns,
+    }
+}
+
+/// 按设置对筛选后的行分组；设置不完整时返回 `None`
+fn compute_group_output(
+    result: &ResultSet,
+    filtered_rows: &[usize],
+    config: &GroupViewConfig,
+) -> Option<GroupOutput> {
+    let resolved = ResolvedConfig::new(config, result);
+    let mut rows = filtered_rows.to_vec();
+    match config.layout {
+        GroupLayout::Groups if resolved.group_columns.is_empty() => None,
+        GroupLayout::Groups => {
+            sort_rows(result, &mut rows, &resolved.group_columns);
+            Some(GroupOutput::Groups(build_groups(
+                result,
+                &rows,
+                &resolved.group_columns,
+                &resolved,
+            )))
+        }
+        GroupLayout::Pivot => {
+            let pivot_column = resolved.pivot_column?;
+            Some(GroupOutput::Pivot(build_pivot(
+                result,
+                &mut rows,
+                pivot_column,
+                &resolved,
+            )))
+        }
+    }
+}
+
+/// 显示分组视图，代替表格主体
+pub(super) fn show_group_view(
+    ui: &mut egui::Ui,
+    state: &mut DataGridState,
+    result: &ResultSet,
+    filtered_rows: &[usize],
+) {
+    show_config_bar(ui, &mut state.group_view.config, result);
+    ui.separator();
+
+    let version = DataVersion::new(state, result, filtered_rows);
+    let group_view = &mut state.group_view;
+    let cached = group_view
+        .cache
+        .as_ref()
+        .filter(|(cached_version, cached_config, _)| {
+            *cached_version == version && *cached_config == group_view.config
+        })
+        .map(|(_, _, output)| output.clone());
+    let output = match cached {
+        Some(output) => Some(output),
+        None => {
+            let output =
+                compute_group_output(result, filtered_rows, &group_view.config).map(Arc::new);
+            group_view.cache = output
+                .clone()
+                .map(|output| (version, group_view.config.clone(), output));
+            output
+        }
+    };
+
+    let config = &group_view.config;
+    let Some(output) = output else {
+        let hint = match config.layout {
+            GroupLayout::Groups => "选择一个或多个分组列",
+            GroupLayout::Pivot => "选择透视列：它的每个取值会成为一列",
+        };
+        ui.label(RichText::new(hint).color(GRAY));
+        return;
+    };
+    let aggregate_label = config
+        .value_column
+        .as_ref()
+        .map(|column| format!("{}({})", config.aggregate.label(), column));
+    match output.as_ref() {
+        GroupOutput::Groups(groups) => {
+            let columns = ResolvedConfig::new(config, result).group_columns;
+            ui.label(
+                RichText::new(format!(
+                    "{} 行，{} 个分组",
+                    filtered_rows.len(),
+                    groups.len()
+                ))
+                .small()
+                .color(GRAY),
+            );
+            egui::ScrollArea::both()
+                .id_salt("grid_group_view")
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    show_groups(
+                        ui,
+                        result,
+                        groups,
+                        &columns,
+                        aggregate_label.as_deref(),
+                        egui::Id::new("grid_group_view_groups"),
+                    );
+                });
+        }
+        GroupOutput::Pivot(table) => {
+            let columns = ResolvedConfig::new(config, result).group_columns;
+            let value_label = aggregate_label.unwrap_or_else(|| "行数".to_string());
+            show_pivot(ui, result, table, &columns, &value_label);
+        }
+    }
+}
+
+fn column_combo(
+    ui: &mut egui::Ui,
+    id: &str,
+    selected: &mut Option<String>,
+    result: &ResultSet,
+    empty_label: &str,
+) {
+    egui::ComboBox::from_id_salt(id)
+        .selected_text(selected.as_deref().unwrap_or(empty_label))
+        .show_ui(ui, |ui| {
+            ui.selectable_value(selected, None, empty_label);
+            for column in result.columns.iter() {
+                ui.selectable_value(selected, Some(column.name.clone()), &column.name);
+            }
+        });
+}
+
+fn show_config_bar(ui: &mut egui::Ui, config: &mut GroupViewConfig, result: &ResultSet) {
+    ui.horizontal_wrapped(|ui| {
+        ui.selectable_value(&mut config.layout, GroupLayout::Groups, "分组");
+        ui.selectable_value(&mut config.layout, GroupLayout::Pivot, "透视");
+        ui.separator();
+
+        ui.label(RichText::new("分组列").small().color(GRAY));
+        let mut removed = None;
+        for (index, column) in config.group_columns.iter().enumerate() {
+            if ui
+                .small_button(format!("{} ×", column))
+                .on_hover_text("移除该分组列")
+                .clicked()
+            {
+                removed = Some(index);
+            }
+        }
+        if let Some(index) = removed {
+            config.group_columns.remove(index);
+        }
+        let mut added = None;
+        egui::ComboBox::from_id_salt("grid_group_add_column")
+            .selected_text("+ 添加")
+            .show_ui(ui, |ui| {
+                for column in result.columns.iter() {
+                    if !config.group_columns.contains(&column.name)
+                        && ui.selectable_label(false, &column.name).clicked()
+                    {
+                        added = Some(column.name.clone());
+                    }
+                }
+            });
+        if let Some(column) = added {
+            config.group_columns.push(column);
+        }
+
+        if config.layout == GroupLayout::Pivot {
+            ui.separator();
+            ui.label(RichText::new("透视列").small().color(GRAY));
+            column_combo(
+                ui,
+                "grid_group_pivot_column",
+                &mut config.pivot_column,
+                result,
+                "未选择",
+            );
+        }
+
+        ui.separator();
+        ui.label(RichText::new("值").small().color(GRAY));
+        column_combo(
+            ui,
+            "grid_group_value_column",
+            &mut config.value_column,
+            result,
+            "仅行数",
+        );
+        if config.value_column.is_some() {
+            egui::ComboBox::from_id_salt("grid_group_aggregate")
+                .selected_text(config.aggregate.label())
+                .show_ui(ui, |ui| {
+                    for aggregate in GroupAggregate::ALL {
+                        ui.selectable_value(&mut config.aggregate, aggregate, aggregate.label());
+                    }
+                });
+        }
+    });
+}
+
+fn show_groups(
+    ui: &mut egui::Ui,
+    result: &ResultSet,
+    groups: &[GroupNode],
+    columns: &[usize],
+    aggregate_label: Option<&str>,
+    id: egui::Id,
+) {
+    let Some((&column, rest)) = columns.split_first() else {
+        return;
+    };
+    let column_name = &result.columns[column].name;
+    let accent = theme_accent(ui.visuals());
+    for group in groups.iter().take(MAX_VISIBLE_GROUPS) {
+        let value = group.value.display();
+        let mut header = format!(
+            "{} = {}  ·  {} 行",
+            column_name,
+            value_preview(&value),
+            group.rows
+        );
+        if let (Some(label), Some(aggregate)) = (aggregate_label, &group.aggregate) {
+            header.push_str(&format!("  ·  {} = {}", label, aggregate));
+        }
+        let group_id = id.with(&value);
+        egui::CollapsingHeader::new(RichText::new(header).monospace().color(accent))
+            .id_salt(group_id)
+            .show(ui, |ui| {
+                if rest.is_empty() {
+                    show_group_rows(ui, result, group, group_id);
+                } else {
+                    show_groups(ui, result, &group.children, rest, aggregate_label, group_id);
+                }
+            });
+    }
+    if groups.len() > MAX_VISIBLE_GROUPS {
+        ui.label(
+            RichText::new(format!(
+                "另有 {} 个分组未列出，可先筛选或换用透视表",
+                groups.len() - MAX_VISIBLE_GROUPS
+            ))
+            .small()
+            .color(GRAY),
+        );
+    }
+}
+
+fn show_group_rows(ui: &mut egui::Ui, result: &ResultSet, group: &GroupNode, id: egui::Id) {
+    egui::Grid::new(id.with("rows"))
+        .striped(true)
+        .spacing([12.0, 2.0])
+        .show(ui, |ui| {
+            for column in result.columns.iter() {
+                ui.label(RichText::new(&column.name).small().strong());
+            }
+            ui.end_row();
+            for row in &group.sample_rows {
+                for cell in result.row(*row) {
+                    let text: String = cell.display().chars().take(CELL_TRUNCATE_LEN).collect();
+                    ui.label(RichText::new(text).monospace());
+                }
+                ui.end_row();
+            }
+        });
+    if group.rows > group.sample_rows.len() {
+        ui.label(
+            RichText::new(format!(
+                "仅列出前 {} 行，共 {} 行",
+                group.sample_rows.len(),
+                group.rows
+            ))
+            .small()
+            .color(GRAY),
+        );
+    }
+}
+
+fn show_pivot(
+    ui: &mut egui::Ui,
+    result: &ResultSet,
+    table: &PivotTable,
+    columns: &[usize],
+    value_label: &str,
+) {
+    ui.label(
+        RichText::new(format!(
+            "{} 行 × {} 列，单元格为 {}",
+            table.rows.len(),
+            table.column_values.len(),
+            value_label
+        ))
+        .small()
+        .color(GRAY),
+    );
+    if table.dropped_columns > 0 {
+        ui.label(
+            RichText::new(format!(
+                "透视列的取值过多，另有 {} 个未展开",
+                table.dropped_columns
+            ))
+            .small()
+            .color(GRAY),
+        );
+    }
+
+    let header_count = columns.len() + table.column_values.len() + 1;
+    egui::ScrollArea::horizontal()
+        .id_salt("grid_pivot_view")
+        .show(ui, |ui| {
+            TableBuilder::new(ui)
+                .striped(true)
+                .resizable(true)
+                .columns(Column::auto().at_least(60.0).clip(true), header_count)
+                .header(HEADER_HEIGHT, |mut header| {
+                    for col in columns {
+                        header.col(|ui| {
+                            ui.strong(&result.columns[*col].name);
+                        });
+                    }
+                    for value in &table.column_values {
+                        header.col(|ui| {
+                            ui.strong(value_preview(&value.display()));
+                        });
+                    }
+                    header.col(|ui| {
+                        ui.strong("合计");
+                    });
+                })
+                .body(|body| {
+                    body.rows(ROW_HEIGHT, table.rows.len(), |mut row| {
+                        let pivot_row = &table.rows[row.index()];
+                        for key in &pivot_row.keys {
+                            row.col(|ui| {
+                                ui.label(RichText::new(value_preview(&key.display())).monospace());
+                            });
+                        }
+                        for cell in &pivot_row.cells {
+                            row.col(|ui| {
+                                ui.label(RichText::new(cell.as_deref().unwrap_or("")).monospace());
+                            });
+                        }
+                        row.col(|ui| {
+                            ui.label(RichText::new(&pivot_row.total).monospace().strong());
+                        });
+                    });
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::result::{ResultColumn, ResultCompleteness};
+    use crate::domain::value::{DbTypeFamily, DbTypeInfo};
+
+    fn sample_result() -> ResultSet {
+        let column = |name: &str, family| ResultColumn {
+            name: name.into(),
+            type_info: DbTypeInfo {
+                family,
+                native_name: String::new(),
+                nullable: None,
+            },
+        };
+        let row = |region: &str, year: i64, amount: i64| {
+            [
+                DbValue::Text(region.into()),
+                DbValue::Int(year),
+                DbValue::Int(amount),
+            ]
+        };
+        ResultSet {
+            columns: Arc::new([
+                column("region", DbTypeFamily::Text),
+                column("year", DbTypeFamily::Integer),
+                column("amount", DbTypeFamily::Integer),
+            ]),
+            cells: [
+                row("north", 2024, 10),
+                row("south", 2023, 5),
+                row("north", 2023, 7),
+                row("north", 2024, 3),
+                row("south", 2024, 1),
+            ]
+            .concat(),
+            row_count: 5,
+            completeness: ResultCompleteness::Complete,
+        }
+    }
+
+    #[test]
+    fn groups_nest_by_columns_with_counts_and_aggregates() {
+        let result = sample_result();
+        let config = GroupViewConfig {
+            group_columns: vec!["region".into(), "year".into()],
+            value_column: Some("amount".into()),
+            aggregate: GroupAggregate::Sum,
+            ..Default::default()
+        };
+        let Some(GroupOutput::Groups(groups)) =
+            compute_group_output(&result, &[0, 1, 2, 3, 4], &config)
+        else {
+            panic!("expected groups");
+        };
+        let summary: Vec<(String, usize, Option<String>)> = groups
+            .iter()
+            .map(|group| (group.value.display(), group.rows, group.aggregate.clone()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("north".into(), 3, Some("20".into())),
+                ("south".into(), 2, Some("6".into())),
+            ]
+        );
+        let north_years: Vec<(i64, usize, Vec<usize>)> = groups[0]
+            .children
+            .iter()
+            .map(|group| match group.value {
+                DbValue::Int(year) => (year, group.rows, group.sample_rows.clone()),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(north_years, vec![(2023, 1, vec![2]), (2024, 2, vec![0, 3])]);
+
+        // 筛选后的行才参与分组
+        let Some(GroupOutput::Groups(groups)) = compute_group_output(&result, &[1, 4], &config)
+        else {
+            panic!("expected groups");
+        };
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].aggregate.as_deref(), Some("6"));
+    }
+
+    #[test]
+    fn pivot_spreads_values_into_columns() {
+        let result = sample_result();
+        let mut config = GroupViewConfig {
+            layout: GroupLayout::Pivot,
+            group_columns: vec!["region".into()],
+            pivot_column: Some("year".into()),
+            ..Default::default()
+        };
+        let Some(GroupOutput::Pivot(table)) =
+            compute_group_output(&result, &[0, 1, 2, 3, 4], &config)
+        else {
+            panic!("expected pivot");
+        };
+        assert_eq!(
+            table.column_values,
+            vec![DbValue::Int(2023), DbValue::Int(2024)]
+        );
+        assert_eq!(table.rows[0].keys, vec![DbValue::Text("north".into())]);
+        assert_eq!(
+            table.rows[0].cells,
+            vec![Some("1".to_string()), Some("2".to_string())]
+        );
+        assert_eq!(table.rows[1].total, "2");
+
+        config.value_column = Some("amount".into());
+        config.aggregate = GroupAggregate::Max;
+        config.group_columns.clear();
+        let Some(GroupOutput::Pivot(table)) =
+            compute_group_output(&result, &[0, 1, 2, 3, 4], &config)
+        else {
+            panic!("expected pivot");
+        };
+        assert_eq!(table.rows.len(), 1);
+        assert_eq!(
+            table.rows[0].cells,
+            vec![Some("7".to_string()), Some("10".to_string())]
+        );
+        assert_eq!(table.rows[0].total, "10");
+    }
+}
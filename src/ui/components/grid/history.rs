@@ -0,0 +1,252 @@
+//! 待保存修改的撤销 / 重做历史
+//!
+//! 不在每个修改入口逐一登记，而是把待保存的修改（单元格修改、删除标记、新增行）
+//! 与上一次记录的快照比较，有变化即记为一步。同一帧内的多处修改（粘贴块、清空选区）
+//! 因此合并为一步，Cell 检查器等表格以外的修改同样会被记录。
+
+use super::cell_edit::CellEdit;
+use std::collections::{BTreeSet, HashMap};
+
+/// 最多保留的撤销步数
+const HISTORY_LIMIT: usize = 100;
+
+/// 待保存修改的快照
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct PendingEdits {
+    pub modified_cells: HashMap<(usize, usize), CellEdit>,
+    pub rows_to_delete: Vec<usize>,
+    pub new_rows: Vec<Vec<CellEdit>>,
+}
+
+impl PendingEdits {
+    fn matches(
+        &self,
+        modified_cells: &HashMap<(usize, usize), CellEdit>,
+        rows_to_delete: &[usize],
+        new_rows: &[Vec<CellEdit>],
+    ) -> bool {
+        self.modified_cells == *modified_cells
+            && self.rows_to_delete == rows_to_delete
+            && self.new_rows == new_rows
+    }
+}
+
+/// 一步修改：撤销栈中保存修改前的快照，重做栈中保存修改后的快照
+#[derive(Debug, Clone)]
+pub struct EditStep {
+    /// 修改内容的简述，如“修改 3 个单元格”
+    pub label: String,
+    edits: PendingEdits,
+    /// 发生修改时的光标，撤销或重做后光标回到这里
+    cursor: (usize, usize),
+}
+
+/// 撤销 / 重做历史
+#[derive(Debug, Clone, Default)]
+pub struct EditHistory {
+    /// 最近一次记录的修改状态
+    baseline: PendingEdits,
+    undo: Vec<EditStep>,
+    redo: Vec<EditStep>,
+    /// 每次记录、撤销、重做或清空时递增，用于判断依赖修改的缓存是否过期
+    revision: u64,
+}
+
+impl EditHistory {
+    /// 可撤销的步骤，最早的在前
+    pub fn undo_steps(&self) -> &[EditStep] {
+        &self.undo
+    }
+
+    /// 可重做的步骤，最近撤销的在后
+    pub fn redo_steps(&self) -> &[EditStep] {
+        &self.redo
+    }
+
+    /// 修改状态的版本号
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    pub(crate) fn clear(&mut self) {
+        *self = Self {
+            revision: self.revision + 1,
+            ..Self::default()
+        };
+    }
+
+    /// 与上次记录的状态比较，有变化时记为一步并清空重做栈
+    pub(crate) fn record(
+        &mut self,
+        modified_cells: &HashMap<(usize, usize), CellEdit>,
+        rows_to_delete: &[usize],
+        new_rows: &[Vec<CellEdit>],
+        cursor: (usize, usize),
+    ) {
+        if self
+            .baseline
+            .matches(modified_cells, rows_to_delete, new_rows)
+        {
+            return;
+        }
+        let current = PendingEdits {
+            modified_cells: modified_cells.clone(),
+            rows_to_delete: rows_to_delete.to_vec(),
+            new_rows: new_rows.to_vec(),
+        };
+        let before = std::mem::replace(&mut self.baseline, current);
+        self.undo.push(EditStep {
+            label: describe_change(&before, &self.baseline),
+            edits: before,
+            cursor,
+        });
+        self.revision += 1;
+        if self.undo.len() > HISTORY_LIMIT {
+            self.undo.remove(0);
+        }
+        self.redo.clear();
+    }
+
+    /// 撤销最近一步，返回恢复后的修改状态、光标与该步的简述
+    pub(crate) fn undo(&mut self) -> Option<(PendingEdits, (usize, usize), String)> {
+        let step = self.undo.pop()?;
+        self.revision += 1;
+        Some(Self::swap(&mut self.baseline, step, &mut self.redo))
+    }
+
+    /// 重做最近撤销的一步
+    pub(crate) fn redo(&mut self) -> Option<(PendingEdits, (usize, usize), String)> {
+        let step = self.redo.pop()?;
+        self.revision += 1;
+        Some(Self::swap(&mut self.baseline, step, &mut self.undo))
+    }
+
+    /// 切换到 `step` 保存的快照，当前快照以同一简述压入另一个栈
+    fn swap(
+        baseline: &mut PendingEdits,
+        step: EditStep,
+        other: &mut Vec<EditStep>,
+    ) -> (PendingEdits, (usize, usize), String) {
+        let previous = std::mem::replace(baseline, step.edits.clone());
+        other.push(EditStep {
+            label: step.label.clone(),
+            edits: previous,
+            cursor: step.cursor,
+        });
+        (step.edits, step.cursor, step.label)
+    }
+}
+
+/// 两次快照之间的差异简述
+fn describe_change(before: &PendingEdits, after: &PendingEdits) -> String {
+    let mut parts = Vec::new();
+
+    let added_rows = after.new_rows.len().saturating_sub(before.new_rows.len());
+    let removed_rows = before.new_rows.len().saturating_sub(after.new_rows.len());
+    if added_rows > 0 {
+        parts.push(format!("新增 {} 行", added_rows));
+    }
+    if removed_rows > 0 {
+        parts.push(format!("移除 {} 个新增行", removed_rows));
+    }
+
+    let cells: BTreeSet<&(usize, usize)> = before
+        .modified_cells
+        .keys()
+        .chain(after.modified_cells.keys())
+        .collect();
+    let new_row_cells: usize = before
+        .new_rows
+        .iter()
+        .zip(&after.new_rows)
+        .map(|(before, after)| before.iter().zip(after).filter(|(b, a)| b != a).count())
+        .sum();
+    let changed_cells = cells
+        .into_iter()
+        .filter(|cell| before.modified_cells.get(cell) != after.modified_cells.get(cell))
+        .count()
+        + new_row_cells;
+    if changed_cells > 0 {
+        parts.push(format!("修改 {} 个单元格", changed_cells));
+    }
+
+    let marked = after
+        .rows_to_delete
+        .iter()
+        .filter(|row| !before.rows_to_delete.contains(row))
+        .count();
+    let unmarked = before
+        .rows_to_delete
+        .iter()
+        .filter(|row| !after.rows_to_delete.contains(row))
+        .count();
+    if marked > 0 {
+        parts.push(format!("标记删除 {} 行", marked));
+    }
+    if unmarked > 0 {
+        parts.push(format!("取消删除标记 {} 行", unmarked));
+    }
+
+    if parts.is_empty() {
+        "修改".to_string()
+    } else {
+        parts.join("，")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(value: &str) -> CellEdit {
+        CellEdit::Text(value.to_string())
+    }
+
+    #[test]
+    fn changes_are_recorded_once_and_undone_in_order() {
+        let mut history = EditHistory::default();
+        let mut cells = HashMap::new();
+        history.record(&cells, &[], &[], (0, 0));
+        assert!(history.undo_steps().is_empty());
+
+        cells.insert((0, 1), text("a"));
+        cells.insert((1, 1), text("b"));
+        history.record(&cells, &[], &[], (1, 1));
+        history.record(&cells, &[], &[], (1, 1));
+        history.record(&cells, &[2], &[], (2, 0));
+        let labels: Vec<&str> = history
+            .undo_steps()
+            .iter()
+            .map(|step| step.label.as_str())
+            .collect();
+        assert_eq!(labels, ["修改 2 个单元格", "标记删除 1 行"]);
+
+        let (edits, cursor, label) = history.undo().unwrap();
+        assert_eq!(label, "标记删除 1 行");
+        assert_eq!(cursor, (2, 0));
+        assert!(edits.rows_to_delete.is_empty());
+        assert_eq!(edits.modified_cells, cells);
+
+        let (edits, _, _) = history.undo().unwrap();
+        assert!(edits.modified_cells.is_empty());
+        assert!(history.undo().is_none());
+
+        let (edits, _, label) = history.redo().unwrap();
+        assert_eq!(label, "修改 2 个单元格");
+        assert_eq!(edits.modified_cells, cells);
+        assert_eq!(history.redo_steps().len(), 1);
+    }
+
+    #[test]
+    fn new_changes_drop_the_redo_stack() {
+        let mut history = EditHistory::default();
+        let row = vec![CellEdit::Default, CellEdit::Default];
+        history.record(&HashMap::new(), &[], std::slice::from_ref(&row), (3, 0));
+        history.undo().unwrap();
+        assert_eq!(history.redo_steps().len(), 1);
+
+        history.record(&HashMap::new(), &[], &[row.clone(), row], (3, 0));
+        assert!(history.redo_steps().is_empty());
+        assert_eq!(history.undo_steps()[0].label, "新增 2 行");
+    }
+}
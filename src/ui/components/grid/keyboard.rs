@@ -6,6 +6,8 @@
 //! - `e`: 跳转到行尾
 //! - `gh/gl`: 行首/行尾
 //! - `gg/G`: 文件首/尾
+//! - `gd`: 在新 Tab 中打开当前外键单元格引用的行
+//! - `gr`: 在右侧 Row 检查器中列出引用当前行的表
 //! - `Ctrl+u`: 向上翻半页
 //! - `PageUp/PageDown`: 翻页
 //! - `i/a/c`: 进入插入模式
@@ -15,14 +17,18 @@
 //! - `;`: 折叠选择到单个光标
 //! - `dd`: 标记删除当前行
 //! - `yy`: 复制整行
-//! - `p`: 粘贴
-//! - `u/U`: 撤销/取消删除标记
+//! - `p`: 粘贴（多单元格块先预览）
+//! - `Ctrl+V`: 从系统剪贴板粘贴 TSV / CSV 块，超出末行时追加新行
+//! - `u`: 撤销上一步修改（单元格修改、粘贴、新增行、删除标记、清空选区）
+//! - `Ctrl+Y`: 重做撤销的修改
+//! - `U`: 取消删除标记
 //! - `/`: 打开筛选面板
 //! - `f`: 为当前列添加筛选
 //! - `o/O`: 添加新行
 //! - `:w`: 保存修改
 //! - `q`: 放弃修改
 //! - `Ctrl+R`: 刷新表格数据
+//! - `]/[`: 分页浏览时翻到下一页/上一页（`j/k` 越过页尾/页首时同样翻页）
 //! - `Space+d`: 标记删除行
 //! - `Ctrl+S`: 保存修改
 //!
@@ -38,14 +44,17 @@
 
 #![allow(clippy::too_many_arguments)]
 
-use super::actions::DataGridActions;
+use super::actions::{DataGridActions, ReferenceNavigation};
+use super::cell_edit::{CellEdit, column_edit_types};
 use super::filter::ColumnFilter;
 use super::mode::GridMode;
+use super::paste;
 use super::state::DataGridState;
-use super::view::GridVirtualRows;
-use crate::core::{KeyBinding, KeyBindings, KeyCode, KeyModifiers};
+use super::view::{GridVirtualRow, GridVirtualRows};
+use crate::core::{KeyBinding, KeyBindings, KeyCode, KeyModifiers, PageRequest, TableMarkup};
 use crate::domain::result::ResultSet;
 use egui::{self, Key};
+use std::ops::RangeInclusive;
 use tracing::debug;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -64,6 +73,13 @@ pub(crate) enum GridCommandShortcut {
     ScrollBottom,
     DeleteRow,
     CopyRow,
+    NextPage,
+    PreviousPage,
+    FollowReference,
+    ReferencingRows,
+    Undo,
+    Redo,
+    GroupView,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -120,7 +136,7 @@ impl CmdBuffer {
 }
 
 impl GridCommandShortcut {
-    pub(crate) const ALL: [Self; 14] = [
+    pub(crate) const ALL: [Self; 21] = [
         Self::OpenFilter,
         Self::AddRowBelow,
         Self::AddRowAbove,
@@ -135,6 +151,13 @@ impl GridCommandShortcut {
         Self::ScrollBottom,
         Self::DeleteRow,
         Self::CopyRow,
+        Self::NextPage,
+        Self::PreviousPage,
+        Self::FollowReference,
+        Self::ReferencingRows,
+        Self::Undo,
+        Self::Redo,
+        Self::GroupView,
     ];
 
     pub(crate) fn all() -> &'static [Self] {
@@ -157,6 +180,13 @@ impl GridCommandShortcut {
             Self::ScrollBottom => "grid.normal.scroll_bottom",
             Self::DeleteRow => "grid.normal.delete_row",
             Self::CopyRow => "grid.normal.copy_row",
+            Self::NextPage => "grid.normal.next_page",
+            Self::PreviousPage => "grid.normal.previous_page",
+            Self::FollowReference => "grid.normal.follow_reference",
+            Self::ReferencingRows => "grid.normal.referencing_rows",
+            Self::Undo => "grid.normal.undo",
+            Self::Redo => "grid.normal.redo",
+            Self::GroupView => "grid.normal.group_view",
         }
     }
 
@@ -176,6 +206,13 @@ impl GridCommandShortcut {
             Self::ScrollBottom => &["zb"],
             Self::DeleteRow => &["dd", "Space+d"],
             Self::CopyRow => &["yy"],
+            Self::NextPage => &["]"],
+            Self::PreviousPage => &["["],
+            Self::FollowReference => &["gd"],
+            Self::ReferencingRows => &["gr"],
+            Self::Undo => &["u"],
+            Self::Redo => &["Ctrl+Y"],
+            Self::GroupView => &[":g"],
         }
     }
 
@@ -195,6 +232,13 @@ impl GridCommandShortcut {
             Self::ScrollBottom => GridKeyAction::ScrollCurrentRowBottom,
             Self::DeleteRow => GridKeyAction::DeleteRow,
             Self::CopyRow => GridKeyAction::CopyRow,
+            Self::NextPage => GridKeyAction::NextPage,
+            Self::PreviousPage => GridKeyAction::PreviousPage,
+            Self::FollowReference => GridKeyAction::FollowReference,
+            Self::ReferencingRows => GridKeyAction::ReferencingRows,
+            Self::Undo => GridKeyAction::Undo,
+            Self::Redo => GridKeyAction::Redo,
+            Self::GroupView => GridKeyAction::ToggleGroupView,
         }
     }
 
@@ -245,7 +289,10 @@ pub(super) fn mode_help_text(mode: GridMode, keybindings: &KeyBindings) -> Strin
             first_grid_shortcut(keybindings, GridCommandShortcut::JumpFileStart),
             first_grid_shortcut(keybindings, GridCommandShortcut::JumpFileEnd),
         ),
-        GridMode::Insert => "Esc 退出 | Enter 确认".to_string(),
+        GridMode::Insert => {
+            "Esc 退出 | Enter 确认 | 外键列 ↑↓ 选择候选 | 弹出编辑器中 Ctrl+Enter 应用、Esc 取消"
+                .to_string()
+        }
         GridMode::Select => {
             "hjkl 扩展 | d 清空 | c 编辑 | y 复制 | x 整行 | Esc/; 退出".to_string()
         }
@@ -419,6 +466,17 @@ fn input_matches_binding(i: &egui::InputState, binding: &KeyBinding) -> bool {
     binding.modifiers.matches(&i.modifiers) && i.key_pressed(binding.key.to_egui_key())
 }
 
+/// 是否按下了单键的撤销 / 重做序列；表格 Normal 模式下同键的全局动作需让位
+pub(crate) fn history_shortcut_pressed(i: &egui::InputState, keybindings: &KeyBindings) -> bool {
+    [GridCommandShortcut::Undo, GridCommandShortcut::Redo]
+        .into_iter()
+        .flat_map(|command| effective_grid_command_sequences(keybindings, command))
+        .filter_map(|sequence| parse_grid_sequence(&sequence))
+        .any(|sequence| {
+            sequence.steps.len() == 1 && input_matches_binding(i, &sequence.steps[0].binding)
+        })
+}
+
 fn detect_configured_normal_input(
     i: &egui::InputState,
     cmd: &CmdBuffer,
@@ -439,6 +497,13 @@ fn detect_configured_normal_input(
         GridCommandShortcut::ScrollBottom,
         GridCommandShortcut::DeleteRow,
         GridCommandShortcut::CopyRow,
+        GridCommandShortcut::NextPage,
+        GridCommandShortcut::PreviousPage,
+        GridCommandShortcut::FollowReference,
+        GridCommandShortcut::ReferencingRows,
+        GridCommandShortcut::Undo,
+        GridCommandShortcut::Redo,
+        GridCommandShortcut::GroupView,
     ];
 
     if !cmd.keys.is_empty() {
@@ -551,13 +616,19 @@ enum GridKeyAction {
     SelectAll,
     CollapseSelection,
     Paste,
-    UndoCellChange,
+    Undo,
+    Redo,
     UnmarkDelete,
     ScrollCurrentRowCenter,
     ScrollCurrentRowTop,
     ScrollCurrentRowBottom,
     DeleteRow,
     CopyRow,
+    NextPage,
+    PreviousPage,
+    FollowReference,
+    ReferencingRows,
+    ToggleGroupView,
     Escape,
 }
 
@@ -665,9 +736,6 @@ fn detect_normal_key_action(i: &egui::InputState, cmd: &mut CmdBuffer) -> Option
         if i.key_pressed(Key::P) {
             return Some(GridKeyAction::Paste);
         }
-        if i.key_pressed(Key::U) && !i.modifiers.shift && !i.modifiers.ctrl {
-            return Some(GridKeyAction::UndoCellChange);
-        }
         if i.key_pressed(Key::U) && i.modifiers.shift {
             return Some(GridKeyAction::UnmarkDelete);
         }
@@ -744,6 +812,55 @@ fn copy_current_row(
     }
 }
 
+/// 当前结果是否处于分页浏览且存在目标页
+fn page_available(state: &DataGridState, request: PageRequest) -> bool {
+    state.page_info.as_ref().is_some_and(|page| match request {
+        PageRequest::First => true,
+        PageRequest::Next => page.has_next,
+        PageRequest::Previous => page.has_previous,
+    })
+}
+
+fn request_page(
+    state: &DataGridState,
+    actions: &mut DataGridActions,
+    request: PageRequest,
+    shortcut: &str,
+) {
+    let label = match request {
+        PageRequest::First => "第一页",
+        PageRequest::Next => "下一页",
+        PageRequest::Previous => "上一页",
+    };
+    if state.page_info.is_none() {
+        actions.message = Some(format!("当前结果未分页 ({})", shortcut));
+    } else if page_available(state, request) {
+        actions.page_request = Some(request);
+        actions.message = Some(format!("加载{} ({})", label, shortcut));
+    } else {
+        actions.message = Some(format!("没有{} ({})", label, shortcut));
+    }
+}
+
+/// 外键导航只对已保存的行有意义：新增行尚未写入数据库
+fn request_reference_navigation(
+    state: &DataGridState,
+    row_view: &GridVirtualRows<'_>,
+    actions: &mut DataGridActions,
+    navigation: ReferenceNavigation,
+    shortcut: &str,
+) {
+    match row_view.row_at_row_key(state.cursor.0) {
+        Some(GridVirtualRow::Existing { .. }) => {
+            actions.reference_navigation = Some(navigation);
+        }
+        Some(GridVirtualRow::PendingNew { .. }) => {
+            actions.message = Some(format!("新增行尚未保存，无法跳转外键 ({})", shortcut));
+        }
+        None => {}
+    }
+}
+
 fn display_sequence<'a>(sequence: &'a str, fallback: &'a str) -> &'a str {
     if sequence.is_empty() {
         fallback
@@ -763,32 +880,89 @@ fn clear_selected_cells(state: &mut DataGridState) -> Option<usize> {
     let ((min_r, min_c), (max_r, max_c)) = state.get_selection()?;
     for r in min_r..=max_r {
         for c in min_c..=max_c {
-            state.modified_cells.insert((r, c), String::new());
+            state.modified_cells.insert((r, c), CellEdit::default());
         }
     }
     Some((max_r - min_r + 1) * (max_c - min_c + 1))
 }
 
-fn copy_selected_cells(state: &DataGridState, row_view: &GridVirtualRows<'_>) -> Option<String> {
+/// 选区各行按显示顺序的单元格文本，附带选区的列范围
+fn selected_cell_block(
+    state: &DataGridState,
+    row_view: &GridVirtualRows<'_>,
+) -> Option<(RangeInclusive<usize>, Vec<Vec<String>>)> {
     let ((min_r, min_c), (max_r, max_c)) = state.get_selection()?;
-    let mut text = String::new();
-    for row_key in min_r..=max_r {
-        if let Some(row) = row_view.row_at_row_key(row_key) {
-            let row_data = row.display_row();
-            let row_text = row_data
-                .iter()
+    let rows = (min_r..=max_r)
+        .filter_map(|row_key| row_view.row_at_row_key(row_key))
+        .map(|row| {
+            row.display_row()
+                .into_iter()
                 .skip(min_c)
                 .take(max_c - min_c + 1)
-                .map(String::as_str)
-                .collect::<Vec<_>>()
-                .join("\t");
-            if !text.is_empty() {
-                text.push('\n');
-            }
-            text.push_str(&row_text);
+                .collect()
+        })
+        .collect();
+    Some((min_c..=max_c, rows))
+}
+
+fn copy_selected_cells(state: &DataGridState, row_view: &GridVirtualRows<'_>) -> Option<String> {
+    let (_, rows) = selected_cell_block(state, row_view)?;
+    Some(
+        rows.iter()
+            .map(|row| row.join("\t"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+/// 把选区渲染为 Markdown / HTML / LaTeX 表格，表头取选中列的列名
+pub(super) fn copy_selection_as(
+    state: &DataGridState,
+    result: &ResultSet,
+    row_view: &GridVirtualRows<'_>,
+    markup: TableMarkup,
+) -> Option<String> {
+    let (columns, rows) = selected_cell_block(state, row_view)?;
+    let headers: Vec<String> = columns
+        .filter_map(|col_idx| result.columns.get(col_idx))
+        .map(|column| column.name.clone())
+        .collect();
+    Some(markup.render(&headers, &rows))
+}
+
+/// 系统剪贴板粘贴（Ctrl+V / Cmd+V）送来的文本
+fn pasted_text(i: &egui::InputState) -> Option<&str> {
+    i.events.iter().find_map(|event| match event {
+        egui::Event::Paste(text) => Some(text.as_str()),
+        _ => None,
+    })
+}
+
+/// 从光标处粘贴：单个值直接写入，多单元格块先进入预览
+fn paste_text(
+    state: &mut DataGridState,
+    result: &ResultSet,
+    row_view: &GridVirtualRows<'_>,
+    text: &str,
+    shortcut: &str,
+    actions: &mut DataGridActions,
+) {
+    let block = paste::parse_clipboard_block(text);
+    let column_types = column_edit_types(result, state.table_metadata.as_deref());
+    match paste::plan_paste(&block, state, result, row_view, &column_types) {
+        Ok(plan) if plan.is_single_cell() => {
+            plan.apply(state, result);
+            actions.message = Some(format!("已粘贴 ({})", shortcut));
+        }
+        Ok(plan) => {
+            actions.message = Some(format!(
+                "粘贴 {} 行 × {} 列，请确认 ({})",
+                plan.rows, plan.columns, shortcut
+            ));
+            state.pending_paste = Some(plan);
         }
+        Err(error) => actions.message = Some(error),
     }
-    Some(text)
 }
 
 fn exit_select_mode(state: &mut DataGridState, cmd: &mut CmdBuffer) {
@@ -948,6 +1122,15 @@ pub(crate) fn handle_keyboard(
             return;
         }
 
+        // 系统剪贴板粘贴
+        if state.mode == GridMode::Normal
+            && let Some(text) = pasted_text(i)
+        {
+            paste_text(state, result, row_view, text, "Ctrl+V", actions);
+            cmd.clear();
+            return;
+        }
+
         match state.mode {
             GridMode::Normal => {
                 handle_normal_mode(
@@ -1099,7 +1282,12 @@ fn handle_detected_normal_action(
                     state.cursor.0 = last_row_key;
                     state.scroll_to_row = Some(last_row_key);
                 }
-                actions.focus_transfer = Some(super::actions::FocusTransfer::SqlEditor);
+                if page_available(state, PageRequest::Next) {
+                    actions.page_request = Some(PageRequest::Next);
+                    actions.message = Some("加载下一页 (j)".to_string());
+                } else {
+                    actions.focus_transfer = Some(super::actions::FocusTransfer::SqlEditor);
+                }
             } else {
                 let _ = repeat;
                 move_cursor_by_display_offset(state, row_view, 1, 0, max_col);
@@ -1107,7 +1295,15 @@ fn handle_detected_normal_action(
         }
         GridKeyAction::MoveUp => {
             let _ = repeat;
-            move_cursor_by_display_offset(state, row_view, -1, 0, max_col);
+            let current_display = normalize_cursor_row_key(state, row_view)
+                .and_then(|row_key| row_view.display_index_for_row_key(row_key))
+                .unwrap_or(0);
+            if current_display == 0 && page_available(state, PageRequest::Previous) {
+                actions.page_request = Some(PageRequest::Previous);
+                actions.message = Some("加载上一页 (k)".to_string());
+            } else {
+                move_cursor_by_display_offset(state, row_view, -1, 0, max_col);
+            }
         }
         GridKeyAction::MoveRight => {
             let _ = repeat;
@@ -1159,7 +1355,7 @@ fn handle_detected_normal_action(
             }
         }
         GridKeyAction::AddRowBelow => {
-            let new_row = vec![String::new(); result.column_count()];
+            let new_row = vec![CellEdit::Default; result.column_count()];
             state.new_rows.push(new_row);
             let new_row_index = result.row_count + state.new_rows.len() - 1;
             state.cursor = (new_row_index, 0);
@@ -1167,7 +1363,7 @@ fn handle_detected_normal_action(
             actions.message = Some(format!("已添加新行 ({})", display_sequence(sequence, "o")));
         }
         GridKeyAction::AddRowAbove => {
-            let new_row = vec![String::new(); result.column_count()];
+            let new_row = vec![CellEdit::Default; result.column_count()];
             state.new_rows.insert(0, new_row);
             state.cursor = (result.row_count, 0);
             state.scroll_to_row = Some(result.row_count);
@@ -1186,6 +1382,18 @@ fn handle_detected_normal_action(
         GridKeyAction::DiscardChanges => {
             discard_all_changes(state, actions, display_sequence(sequence, "q"));
         }
+        GridKeyAction::ToggleGroupView => {
+            let cursor_column = result
+                .columns
+                .get(state.cursor.1)
+                .map(|column| column.name.as_str());
+            let label = if state.group_view.toggle(cursor_column) {
+                "打开分组视图"
+            } else {
+                "返回表格"
+            };
+            actions.message = Some(format!("{} ({})", label, display_sequence(sequence, ":g")));
+        }
         GridKeyAction::Refresh => {
             actions.refresh_requested = true;
             actions.message = Some("刷新表格数据 (Ctrl+R)".to_string());
@@ -1212,14 +1420,11 @@ fn handle_detected_normal_action(
                 actions.message = Some("复合主键表暂不支持编辑".to_string());
                 return;
             }
-            state.mode = GridMode::Insert;
-            state.editing_cell = Some(state.cursor);
-            state.edit_text.clear();
-            if let Some(row) = row_view.row_at_row_key(state.cursor.0)
-                && let Some(cell) = row.display_cell(state.cursor.1)
-            {
-                state.original_value = cell;
-            }
+            let original = row_view
+                .row_at_row_key(state.cursor.0)
+                .and_then(|row| row.edit_cell(state.cursor.1))
+                .unwrap_or_default();
+            state.begin_cell_edit(state.cursor, original, CellEdit::default());
             actions.message = Some("修改单元格 (c)".to_string());
         }
         GridKeyAction::ReplaceCell => {
@@ -1232,10 +1437,7 @@ fn handle_detected_normal_action(
                 actions.message = Some("复合主键表暂不支持编辑".to_string());
                 return;
             }
-            state.mode = GridMode::Insert;
-            state.editing_cell = Some(state.cursor);
-            state.edit_text.clear();
-            state.original_value.clear();
+            state.begin_cell_edit(state.cursor, CellEdit::default(), CellEdit::default());
         }
         GridKeyAction::EnterSelect => {
             state.mode = GridMode::Select;
@@ -1264,15 +1466,25 @@ fn handle_detected_normal_action(
             }
         }
         GridKeyAction::Paste => {
-            if let Some(text) = &state.clipboard {
-                state.modified_cells.insert(state.cursor, text.clone());
-                actions.message = Some("已粘贴 (p)".to_string());
+            if let Some(text) = state.clipboard.clone() {
+                paste_text(state, result, row_view, &text, "p", actions);
             }
         }
-        GridKeyAction::UndoCellChange => {
-            if state.modified_cells.remove(&state.cursor).is_some() {
-                actions.message = Some("已撤销修改 (u)".to_string());
-            }
+        GridKeyAction::Undo => {
+            actions.message = Some(match state.undo_edit() {
+                Some(label) => format!("已撤销: {} ({})", label, display_sequence(sequence, "u")),
+                None => "没有可撤销的修改".to_string(),
+            });
+        }
+        GridKeyAction::Redo => {
+            actions.message = Some(match state.redo_edit() {
+                Some(label) => format!(
+                    "已重做: {} ({})",
+                    label,
+                    display_sequence(sequence, "Ctrl+Y")
+                ),
+                None => "没有可重做的修改".to_string(),
+            });
         }
         GridKeyAction::UnmarkDelete => {
             if state.rows_to_delete.contains(&state.cursor.0) {
@@ -1305,6 +1517,40 @@ fn handle_detected_normal_action(
                     message.replace("(yy)", &format!("({})", display_sequence(sequence, "yy")));
             }
         }
+        GridKeyAction::NextPage => {
+            request_page(
+                state,
+                actions,
+                PageRequest::Next,
+                display_sequence(sequence, "]"),
+            );
+        }
+        GridKeyAction::PreviousPage => {
+            request_page(
+                state,
+                actions,
+                PageRequest::Previous,
+                display_sequence(sequence, "["),
+            );
+        }
+        GridKeyAction::FollowReference => {
+            request_reference_navigation(
+                state,
+                row_view,
+                actions,
+                ReferenceNavigation::Referenced,
+                display_sequence(sequence, "gd"),
+            );
+        }
+        GridKeyAction::ReferencingRows => {
+            request_reference_navigation(
+                state,
+                row_view,
+                actions,
+                ReferenceNavigation::Referencing,
+                display_sequence(sequence, "gr"),
+            );
+        }
         GridKeyAction::Escape => {
             if !cmd.keys.is_empty() || cmd.count.is_some() {
                 cmd.clear();
@@ -1351,10 +1597,7 @@ fn handle_select_mode(
             }
             GridSelectAction::ChangeSelection => {
                 let _ = clear_selected_cells(state);
-                state.mode = GridMode::Insert;
-                state.editing_cell = Some(state.cursor);
-                state.edit_text.clear();
-                state.original_value.clear();
+                state.begin_cell_edit(state.cursor, CellEdit::default(), CellEdit::default());
                 state.select_anchor = None;
             }
             GridSelectAction::CopySelection => {
@@ -1379,31 +1622,31 @@ fn enter_insert_mode(state: &mut DataGridState, row_view: &GridVirtualRows<'_>)
     state.mode = GridMode::Insert;
     state.editing_cell = Some(state.cursor);
     if let Some(row) = row_view.row_at_row_key(state.cursor.0)
-        && let Some(cell) = row.display_cell(state.cursor.1)
+        && let Some(cell) = row.edit_cell(state.cursor.1)
     {
-        state.edit_text = state
+        let current = state
             .modified_cells
             .get(&state.cursor)
             .cloned()
             .unwrap_or_else(|| cell.clone());
-        state.original_value = cell;
+        state.begin_cell_edit(state.cursor, cell, current);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        CmdBuffer, clear_selected_cells, copy_selected_cells, handle_keyboard,
-        has_pressed_key_event, should_clear_pending_command,
+        CellEdit, CmdBuffer, clear_selected_cells, copy_selected_cells, copy_selection_as,
+        handle_keyboard, has_pressed_key_event, should_clear_pending_command,
     };
-    use crate::core::KeyBindings;
+    use crate::core::{KeyBindings, PageRequest, TableMarkup};
     use crate::domain::result::ResultSet;
     use crate::domain::result::{ResultColumn, ResultCompleteness};
     use crate::domain::value::{DbTypeFamily, DbTypeInfo, DbValue};
     use crate::ui::DataGridState;
-    use crate::ui::components::grid::GridMode;
     use crate::ui::components::grid::actions::DataGridActions;
     use crate::ui::components::grid::view::GridVirtualRows;
+    use crate::ui::components::grid::{GridMode, GridPageInfo};
     use egui::{Event, Key, Modifiers};
 
     fn key_event(key: Key) -> Event {
@@ -1563,7 +1806,10 @@ mod tests {
         assert_eq!(cleared, Some(6));
         for row in 1..=2 {
             for col in 1..=3 {
-                assert_eq!(state.modified_cells.get(&(row, col)), Some(&String::new()));
+                assert_eq!(
+                    state.modified_cells.get(&(row, col)),
+                    Some(&CellEdit::default())
+                );
             }
         }
     }
@@ -1619,6 +1865,28 @@ mod tests {
         assert_eq!(copied.as_deref(), Some("alice\ta@example.com"));
     }
 
+    #[test]
+    fn copy_selection_as_markdown_uses_selected_column_names() {
+        let mut state = DataGridState::new();
+        state.mode = GridMode::Select;
+        state.select_anchor = Some((2, 2));
+        state.cursor = (1, 1);
+
+        let result = sample_result();
+        let filtered_rows = vec![0, 1, 2];
+        let new_rows = Vec::new();
+        let row_view = GridVirtualRows::new(&result, &filtered_rows, &new_rows);
+
+        let copied = copy_selection_as(&state, &result, &row_view, TableMarkup::Markdown);
+
+        assert_eq!(
+            copied.as_deref(),
+            Some(
+                "| name | email |\n| --- | --- |\n| bob | b@example.com |\n| carol | c@example.com |\n"
+            )
+        );
+    }
+
     #[test]
     fn slash_opens_filter_panel_in_normal_mode() {
         let mut state = DataGridState::new();
@@ -1685,6 +1953,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn paged_grid_requests_adjacent_pages_at_boundaries() {
+        let mut state = DataGridState::new();
+        state.page_info = Some(GridPageInfo {
+            label: "第 2 页".to_string(),
+            has_previous: true,
+            has_next: true,
+            filtered: false,
+        });
+        let result = sample_result();
+
+        state.cursor = (2, 0);
+        let actions = send_key(&mut state, &result, key_event(Key::J));
+        assert_eq!(actions.page_request, Some(PageRequest::Next));
+        assert_eq!(actions.focus_transfer, None);
+
+        state.cursor = (0, 0);
+        let actions = send_key(&mut state, &result, key_event(Key::K));
+        assert_eq!(actions.page_request, Some(PageRequest::Previous));
+
+        state.page_info = Some(GridPageInfo {
+            label: "第 1 页".to_string(),
+            has_previous: false,
+            has_next: true,
+            filtered: false,
+        });
+        let actions = send_key(&mut state, &result, key_event(Key::CloseBracket));
+        assert_eq!(actions.page_request, Some(PageRequest::Next));
+        let actions = send_key(&mut state, &result, key_event(Key::OpenBracket));
+        assert_eq!(actions.page_request, None);
+    }
+
     #[test]
     fn move_left_on_first_column_transfers_to_sidebar() {
         let mut state = DataGridState::new();
@@ -1739,16 +2039,16 @@ mod tests {
         assert_eq!(state.mode, GridMode::Insert);
         assert_eq!(state.editing_cell, Some((1, 1)));
         assert_eq!(state.edit_text, "bob");
-        assert_eq!(state.original_value, "bob");
+        assert_eq!(state.original_value, CellEdit::from("bob"));
     }
 
     #[test]
     fn new_rows_are_part_of_navigation_and_edit_target() {
         let mut state = DataGridState::new();
         state.new_rows.push(vec![
-            String::new(),
-            "draft".to_string(),
-            "draft@example.com".to_string(),
+            CellEdit::Default,
+            "draft".into(),
+            "draft@example.com".into(),
         ]);
         state.cursor = (2, 0);
         let result = sample_result();
@@ -1763,7 +2063,7 @@ mod tests {
         assert_eq!(state.mode, GridMode::Insert);
         assert_eq!(state.editing_cell, Some((3, 1)));
         assert_eq!(state.edit_text, "draft");
-        assert_eq!(state.original_value, "draft");
+        assert_eq!(state.original_value, CellEdit::from("draft"));
     }
 
     #[test]
@@ -1949,7 +2249,7 @@ mod tests {
     #[test]
     fn colon_w_sets_pending_save() {
         let mut state = DataGridState::new();
-        state.modified_cells.insert((0, 1), "updated".to_string());
+        state.modified_cells.insert((0, 1), "updated".into());
         let result = sample_result();
 
         let _ = send_key(
@@ -1967,7 +2267,7 @@ mod tests {
     #[test]
     fn colon_q_discards_existing_changes() {
         let mut state = DataGridState::new();
-        state.modified_cells.insert((0, 1), "updated".to_string());
+        state.modified_cells.insert((0, 1), "updated".into());
         state.rows_to_delete.push(1);
         let result = sample_result();
 
@@ -1984,6 +2284,45 @@ mod tests {
         assert_eq!(actions.message.as_deref(), Some("已放弃所有修改 (:q)"));
     }
 
+    #[test]
+    fn u_and_ctrl_r_step_through_edit_history() {
+        let mut state = DataGridState::new();
+        let result = sample_result();
+        state.modified_cells.insert((0, 1), "updated".into());
+        state.record_edit_history();
+        state.rows_to_delete.push(2);
+
+        let actions = send_key(&mut state, &result, key_event(Key::U));
+        assert!(state.rows_to_delete.is_empty());
+        assert_eq!(state.modified_cells.len(), 1);
+        assert_eq!(
+            actions.message.as_deref(),
+            Some("已撤销: 标记删除 1 行 (u)")
+        );
+
+        let _ = send_key(&mut state, &result, key_event(Key::U));
+        assert!(!state.has_changes());
+        assert_eq!(state.cursor, (0, 0));
+
+        let actions = send_key(
+            &mut state,
+            &result,
+            key_event_with_modifiers(
+                Key::Y,
+                Modifiers {
+                    ctrl: true,
+                    ..Modifiers::NONE
+                },
+            ),
+        );
+        assert_eq!(state.modified_cells.len(), 1);
+        assert!(state.rows_to_delete.is_empty());
+        assert_eq!(
+            actions.message.as_deref(),
+            Some("已重做: 修改 1 个单元格 (Ctrl+Y)")
+        );
+    }
+
     #[test]
     fn custom_grid_sequence_overrides_copy_row() {
         let mut state = DataGridState::new();
@@ -5,6 +5,13 @@
 //! ## 模块结构
 //! - `state`: 表格状态管理
 //! - `mode`: 编辑模式定义
+//! - `cell_edit`: 单元格编辑值与按列类型的校验
+//! - `cell_editor`: 按列类型的单元格编辑器
+//! - `reference_picker`: 外键列的候选值选择器
+//! - `history`: 待保存修改的撤销 / 重做历史
+//! - `paste`: 从剪贴板粘贴多单元格块
+//! - `stats`: 选区与整列的统计
+//! - `grouping`: 分组 / 透视视图
 //! - `filter`: 筛选条件（拆分为多个子模块）
 //! - `keyboard`: 键盘输入处理
 //! - `render`: 单元格渲染
@@ -13,24 +20,40 @@
 #![allow(clippy::too_many_arguments)]
 
 mod actions;
+mod cell_edit;
+mod cell_editor;
 pub(crate) mod filter;
+mod grouping;
+mod history;
 pub(crate) mod keyboard;
 mod mode;
+mod paste;
+mod reference_picker;
 mod render;
 mod state;
+mod stats;
 mod view;
 
 pub use actions::{
-    DataGridActions, FocusTransfer, escape_identifier, escape_value, quote_identifier,
+    DataGridActions, FocusTransfer, ReferenceNavigation, escape_identifier, escape_value,
+    quote_identifier, quote_table_identifier,
+};
+pub use cell_edit::{CellDraft, CellEdit, CellEditKind, column_edit_types};
+pub use cell_editor::show_cell_editor;
+pub use filter::{
+    ColumnFilter, FilterCache, FilterClause, FilterLogic, FilterOperator, begin_filter_group,
+    check_filter_match, common_depth, compile_filter_clause, group_brackets, indent_filter,
+    outdent_filter,
 };
-pub use filter::{ColumnFilter, FilterCache, FilterLogic, FilterOperator, check_filter_match};
 pub(crate) use keyboard::{
     GridCommandShortcut, GridSequenceConflictKind, grid_command_sequence_conflict,
-    grid_command_shortcuts, normalize_grid_command_sequence,
+    grid_command_shortcuts, history_shortcut_pressed, normalize_grid_command_sequence,
 };
 #[allow(unused_imports)] // 公开 API，供外部使用
 pub use mode::GridMode;
-pub use state::DataGridState;
+pub use reference_picker::{ReferenceCandidate, ReferenceCandidates, ReferenceLookup};
+pub use state::{DataGridState, GridPageInfo};
+pub use stats::{StatsScope, format_number, grid_stats, show_value_histogram};
 use view::{GridVirtualRow, GridVirtualRows};
 
 use crate::core::{Action, KeyBindings, constants};
@@ -85,37 +108,74 @@ impl DataGrid {
             return (actions, (0, 0));
         }
 
+        // 上一帧之后在表格以外（Cell 检查器、应用层）做的修改单独记为一步
+        state.record_edit_history();
+
         // 显示模式状态栏和操作按钮
         Self::show_mode_bar(ui, state, result, table_name, keybindings, &mut actions);
 
         ui.add_space(2.0);
 
         let save_confirm_open = state.show_save_confirm;
+        let paste_preview_open = state.pending_paste.is_some();
 
         // 显示保存确认对话框
-        Self::show_save_confirm_dialog(ui.ctx(), state, &mut actions);
+        Self::show_save_confirm_dialog(ui.ctx(), result, state, table_name, &mut actions);
+        Self::show_paste_preview_dialog(ui.ctx(), result, state, &mut actions);
 
         // 显示筛选状态栏（简洁版）
-        let filter_changed = filter::show_filter_bar(ui, result, &mut state.filters);
-        if filter_changed {
+        let filter_bar = filter::show_filter_bar(
+            ui,
+            result,
+            &mut state.filters,
+            &mut state.server_side_filter,
+            state.page_info.as_ref(),
+        );
+        if filter_bar.changed {
             state.filter_cache.invalidate();
         }
+        if filter_bar.apply_server_filters {
+            actions.apply_server_filters = true;
+        }
 
         ui.add_space(4.0);
 
-        // 过滤行（使用缓存）
+        // 过滤行（使用缓存）；筛选已下推到数据库时只保留搜索
+        let client_filters: &[ColumnFilter] = if state.filters_pushed_down() {
+            &[]
+        } else {
+            &state.filters
+        };
         let filtered_rows = filter::filter_result_set_cached(
             result,
             search_text,
             search_column,
-            &state.filters,
+            client_filters,
             &mut state.filter_cache,
         );
+        // 分组视图代替表格主体，Esc 返回表格
+        if state.group_view.active {
+            if state.focused && ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                state.group_view.active = false;
+            } else {
+                grouping::show_group_view(ui, state, result, &filtered_rows);
+                state.record_edit_history();
+                let new_rows_count = state.new_rows.len();
+                return (
+                    actions,
+                    (
+                        filtered_rows.len() + new_rows_count,
+                        result.row_count + new_rows_count,
+                    ),
+                );
+            }
+        }
+
         let keyboard_new_rows = state.new_rows.clone();
         let keyboard_row_view = GridVirtualRows::new(result, &filtered_rows, &keyboard_new_rows);
 
-        // 保存危险确认打开时，本地 overlay 拥有输入，grid 不再继续接管键盘。
-        if !save_confirm_open {
+        // 保存危险确认或粘贴预览打开时，本地 overlay 拥有输入，grid 不再继续接管键盘。
+        if !save_confirm_open && !paste_preview_open {
             Self::show_goto_dialog(ui.ctx(), state, &keyboard_row_view);
 
             // 处理键盘输入
@@ -129,6 +189,16 @@ impl DataGrid {
             );
         }
 
+        // 右键菜单的“复制选区为…”
+        if let Some(markup) = state.pending_copy_as.take()
+            && let Some(text) =
+                keyboard::copy_selection_as(state, result, &keyboard_row_view, markup)
+        {
+            ui.ctx().copy_text(text.clone());
+            state.clipboard = Some(text);
+            actions.message = Some(format!("已复制选区为 {} 表格", markup.display_name()));
+        }
+
         // 处理新增行的编辑
         if let Some((virtual_idx, col_idx, new_value)) = state.pending_new_row_edit.take() {
             let new_row_idx = virtual_idx.saturating_sub(result.row_count);
@@ -140,6 +210,9 @@ impl DataGrid {
         }
 
         let render_new_rows = state.new_rows.clone();
+        let column_types = column_edit_types(result, state.table_metadata.as_deref());
+        let column_references =
+            reference_picker::column_references(result, state.table_metadata.as_deref());
         let row_view = GridVirtualRows::new(result, &filtered_rows, &render_new_rows);
         let new_rows_count = state.new_rows.len();
         let filtered_count = row_view.len();
@@ -274,6 +347,8 @@ impl DataGrid {
                                                 render::render_editable_cell(
                                                     ui,
                                                     cell,
+                                                    &column_types[col_idx],
+                                                    column_references[col_idx].as_ref(),
                                                     row_key,
                                                     col_idx,
                                                     is_cursor_row,
@@ -298,6 +373,8 @@ impl DataGrid {
                                                 render::render_new_row_cell(
                                                     ui,
                                                     cell,
+                                                    &column_types[col_idx],
+                                                    column_references[col_idx].as_ref(),
                                                     row_key,
                                                     col_idx,
                                                     is_cursor_row,
@@ -314,6 +391,13 @@ impl DataGrid {
             state.h_scroll_offset = scroll_output.state.offset.x;
         });
 
+        // 外键候选：编辑结束后关闭选择器，待执行的查询交给应用层
+        actions.reference_label = state.reference_picker.take_pending_label();
+        if state.editing_cell.is_none() || state.mode != GridMode::Insert {
+            state.reference_picker.close();
+        }
+        actions.reference_lookup = state.reference_picker.take_pending_lookup();
+
         // 处理列筛选点击
         for col_name in columns_to_filter {
             if !state.filters.iter().any(|f| f.column == col_name) {
@@ -331,6 +415,8 @@ impl DataGrid {
             actions.request_focus = true;
         }
 
+        state.record_edit_history();
+
         (actions, (filtered_count, total_count))
     }
 
@@ -355,9 +441,10 @@ impl DataGrid {
                     .monospace()
                     .color(GRAY),
             );
-            Self::show_selection_status(ui, state);
+            Self::show_selection_status(ui, state, result);
             Self::show_command_status(ui, state);
             Self::show_truncation_status(ui, result);
+            Self::show_page_status(ui, state, keybindings, actions);
 
             ui.separator();
             let filter_text = if state.filters.is_empty() {
@@ -389,6 +476,7 @@ impl DataGrid {
             {
                 actions.open_filter_panel = true;
             }
+            Self::show_group_view_toggle(ui, state, result, keybindings);
 
             if table_name.is_some() {
                 Self::show_grid_edit_actions(ui, state, result, table_name, keybindings, actions);
@@ -400,18 +488,62 @@ impl DataGrid {
         });
     }
 
-    fn show_selection_status(ui: &mut egui::Ui, state: &DataGridState) {
-        if let Some(((min_row, min_col), (max_row, max_col))) = state.get_selection() {
+    fn show_group_view_toggle(
+        ui: &mut egui::Ui,
+        state: &mut DataGridState,
+        result: &ResultSet,
+        keybindings: &KeyBindings,
+    ) {
+        ui.separator();
+        let text = if state.group_view.active {
+            "返回表格"
+        } else {
+            "分组"
+        };
+        if ui
+            .add(
+                egui::Label::new(
+                    RichText::new(text)
+                        .size(12.0)
+                        .color(theme_accent(ui.visuals()).gamma_multiply(0.8)),
+                )
+                .sense(egui::Sense::click()),
+            )
+            .on_hover_text(shortcut_tooltip(
+                "按列分组或生成透视表",
+                &shortcut_refs(&keyboard::grid_command_shortcuts(
+                    keybindings,
+                    keyboard::GridCommandShortcut::GroupView,
+                )),
+            ))
+            .on_hover_cursor(egui::CursorIcon::PointingHand)
+            .clicked()
+        {
+            let cursor_column = result
+                .columns
+                .get(state.cursor.1)
+                .map(|column| column.name.as_str());
+            state.group_view.toggle(cursor_column);
+        }
+    }
+
+    fn show_selection_status(ui: &mut egui::Ui, state: &mut DataGridState, result: &ResultSet) {
+        if let Some((start, end)) = state.get_selection() {
             ui.separator();
             ui.label(
                 RichText::new(format!(
                     "选择: {}x{}",
-                    max_row - min_row + 1,
-                    max_col - min_col + 1
+                    end.0 - start.0 + 1,
+                    end.1 - start.1 + 1
                 ))
                 .small()
                 .color(COLOR_VISUAL_SELECT),
             );
+            let stats = stats::grid_stats(state, result, StatsScope::Selection(start, end));
+            ui.label(RichText::new(stats.summary()).small().color(GRAY))
+                .on_hover_text(
+                    "选区内当前显示的值（含未保存的修改）；右侧检查器的“统计”页列出高频值",
+                );
         }
     }
 
@@ -446,6 +578,63 @@ impl DataGrid {
         }
     }
 
+    fn show_page_status(
+        ui: &mut egui::Ui,
+        state: &DataGridState,
+        keybindings: &KeyBindings,
+        actions: &mut DataGridActions,
+    ) {
+        let Some(page) = &state.page_info else {
+            return;
+        };
+        ui.separator();
+        if Self::show_page_button(
+            ui,
+            "◀",
+            page.has_previous,
+            "上一页",
+            keyboard::GridCommandShortcut::PreviousPage,
+            keybindings,
+        ) {
+            actions.page_request = Some(crate::core::PageRequest::Previous);
+        }
+        ui.label(RichText::new(&page.label).small().color(GRAY));
+        if Self::show_page_button(
+            ui,
+            "▶",
+            page.has_next,
+            "下一页",
+            keyboard::GridCommandShortcut::NextPage,
+            keybindings,
+        ) {
+            actions.page_request = Some(crate::core::PageRequest::Next);
+        }
+    }
+
+    fn show_page_button(
+        ui: &mut egui::Ui,
+        icon: &str,
+        enabled: bool,
+        label: &str,
+        command: keyboard::GridCommandShortcut,
+        keybindings: &KeyBindings,
+    ) -> bool {
+        let color = if enabled {
+            theme_text(ui.visuals())
+        } else {
+            theme_disabled_text(ui.visuals())
+        };
+        ui.add_enabled(
+            enabled,
+            egui::Button::new(RichText::new(icon).size(11.0).color(color)).frame(false),
+        )
+        .on_hover_text(shortcut_tooltip(
+            label,
+            &shortcut_refs(&keyboard::grid_command_shortcuts(keybindings, command)),
+        ))
+        .clicked()
+    }
+
     fn show_grid_edit_actions(
         ui: &mut egui::Ui,
         state: &mut DataGridState,
@@ -456,7 +645,7 @@ impl DataGrid {
     ) {
         ui.add_space(16.0);
         if Self::show_add_row_button(ui, keybindings) {
-            let new_row = vec![String::new(); result.column_count()];
+            let new_row = vec![CellEdit::Default; result.column_count()];
             state.new_rows.push(new_row);
             let new_row_index = result.row_count + state.new_rows.len() - 1;
             state.cursor = (new_row_index, 0);
@@ -474,6 +663,31 @@ impl DataGrid {
             state.clear_edits();
             actions.message = Some("已放弃所有修改".to_string());
         }
+        let history = &state.edit_history;
+        let undo_label = history.undo_steps().last().map(|step| step.label.clone());
+        let redo_label = history.redo_steps().last().map(|step| step.label.clone());
+        if Self::show_history_button(
+            ui,
+            "↶",
+            "撤销",
+            undo_label.as_deref(),
+            keyboard::GridCommandShortcut::Undo,
+            keybindings,
+        ) && let Some(label) = state.undo_edit()
+        {
+            actions.message = Some(format!("已撤销: {}", label));
+        }
+        if Self::show_history_button(
+            ui,
+            "↷",
+            "重做",
+            redo_label.as_deref(),
+            keyboard::GridCommandShortcut::Redo,
+            keybindings,
+        ) && let Some(label) = state.redo_edit()
+        {
+            actions.message = Some(format!("已重做: {}", label));
+        }
         Self::show_edit_summary(ui, state, has_changes);
     }
 
@@ -558,6 +772,38 @@ impl DataGrid {
         .clicked()
     }
 
+    /// 撤销 / 重做按钮；`step` 为将被撤销或重做的步骤简述，没有时禁用
+    fn show_history_button(
+        ui: &mut egui::Ui,
+        icon: &str,
+        verb: &str,
+        step: Option<&str>,
+        command: keyboard::GridCommandShortcut,
+        keybindings: &KeyBindings,
+    ) -> bool {
+        let color = if step.is_some() {
+            theme_text(ui.visuals())
+        } else {
+            theme_disabled_text(ui.visuals())
+        };
+        let description = match step {
+            Some(step) => format!("{}: {}", verb, step),
+            None => format!("没有可{}的修改", verb),
+        };
+        ui.add_enabled(
+            step.is_some(),
+            egui::Button::new(RichText::new(icon).size(13.0).color(color))
+                .frame(false)
+                .min_size(Vec2::new(24.0, 24.0)),
+        )
+        .on_hover_text(shortcut_tooltip(
+            &description,
+            &shortcut_refs(&keyboard::grid_command_shortcuts(keybindings, command)),
+        ))
+        .on_disabled_hover_text(description.as_str())
+        .clicked()
+    }
+
     fn show_edit_summary(ui: &mut egui::Ui, state: &DataGridState, has_changes: bool) {
         if !has_changes {
             return;
@@ -772,9 +1018,13 @@ impl DataGrid {
     }
 
     /// 显示保存确认对话框（危险操作确认）
+    ///
+    /// 对话框中可逐步撤销 / 重做修改，每一步之后按剩余的修改重新生成变更批次。
     fn show_save_confirm_dialog(
         ctx: &egui::Context,
+        result: &ResultSet,
         state: &mut DataGridState,
+        table_name: Option<&str>,
         actions: &mut DataGridActions,
     ) {
         if !state.show_save_confirm {
@@ -791,6 +1041,8 @@ impl DataGrid {
         struct SaveConfirmOutcome {
             confirmed: bool,
             cancelled: bool,
+            undo: bool,
+            redo: bool,
         }
 
         let outcome = DialogWindow::blocking(ctx, "grid_save_confirm_modal", &DialogStyle::MEDIUM)
@@ -831,6 +1083,42 @@ impl DataGrid {
                         }
                     });
 
+                    let history = &state.edit_history;
+                    ui.collapsing(
+                        format!("修改历史（{} 步）", history.undo_steps().len()),
+                        |ui| {
+                            for (index, step) in history.undo_steps().iter().enumerate().rev() {
+                                ui.label(
+                                    RichText::new(format!("{}. {}", index + 1, step.label))
+                                        .small()
+                                        .color(GRAY),
+                                );
+                            }
+                            for step in history.redo_steps().iter().rev() {
+                                ui.label(
+                                    RichText::new(format!("已撤销: {}", step.label))
+                                        .small()
+                                        .color(theme_disabled_text(ui.visuals())),
+                                );
+                            }
+                        },
+                    );
+                    ui.horizontal(|ui| {
+                        outcome.undo = ui
+                            .add_enabled(
+                                !history.undo_steps().is_empty(),
+                                egui::Button::new(RichText::new("↶ 撤销上一步").size(12.0)),
+                            )
+                            .on_hover_text("撤销最近一步修改，并按剩余修改重新生成预览")
+                            .clicked();
+                        outcome.redo = ui
+                            .add_enabled(
+                                !history.redo_steps().is_empty(),
+                                egui::Button::new(RichText::new("↷ 重做").size(12.0)),
+                            )
+                            .clicked();
+                    });
+
                     ui.add_space(12.0);
 
                     ui.horizontal(|ui| {
@@ -879,13 +1167,145 @@ impl DataGrid {
                 outcome
             });
 
-        if outcome.inner.confirmed {
+        if outcome.inner.undo || outcome.inner.redo {
+            let step = if outcome.inner.undo {
+                state.undo_edit().map(|label| format!("已撤销: {}", label))
+            } else {
+                state.redo_edit().map(|label| format!("已重做: {}", label))
+            };
+            actions.message = step;
+            Self::rebuild_pending_batch(result, state, table_name, actions);
+        } else if outcome.inner.confirmed {
             actions::confirm_pending_mutations(state, actions);
         } else if outcome.inner.cancelled || outcome.should_close() {
             actions::cancel_pending_mutations(state);
         }
     }
 
+    /// 显示多单元格粘贴的预览，确认后写入
+    fn show_paste_preview_dialog(
+        ctx: &egui::Context,
+        result: &ResultSet,
+        state: &mut DataGridState,
+        actions: &mut DataGridActions,
+    ) {
+        let Some(plan) = &state.pending_paste else {
+            return;
+        };
+
+        let outcome = DialogWindow::blocking(ctx, "grid_paste_preview_modal", &DialogStyle::SMALL)
+            .show(ctx, |ui| {
+                let confirm = local_shortcut_pressed(ctx, LocalShortcut::Confirm);
+                let cancel = local_shortcut_pressed(ctx, LocalShortcut::Cancel);
+
+                DialogWindow::apply_modal_width(ui, ctx, &DialogStyle::SMALL);
+                DialogHeader::show_with_icon(ui, "📋", "粘贴预览", &DialogStyle::SMALL);
+
+                ui.label(format!(
+                    "从第 {} 行第 {} 列开始粘贴 {} 行 × {} 列",
+                    plan.start.0 + 1,
+                    plan.start.1 + 1,
+                    plan.rows,
+                    plan.columns
+                ));
+                ui.label(format!("将修改 {} 个单元格", plan.changed));
+                if plan.appended_row_count() > 0 {
+                    ui.label(format!(
+                        "超出末行的部分新增 {} 行",
+                        plan.appended_row_count()
+                    ));
+                }
+                if plan.skipped_columns > 0 {
+                    ui.label(
+                        RichText::new(format!(
+                            "超出最后一列的 {} 列将被忽略",
+                            plan.skipped_columns
+                        ))
+                        .color(GRAY),
+                    );
+                }
+                if plan.invalid > 0 {
+                    ui.label(
+                        RichText::new(format!("{} 个值不符合列类型，保存前需要修正", plan.invalid))
+                            .color(theme_warn(ui.visuals())),
+                    );
+                }
+                ui.add_space(12.0);
+
+                let mut accepted = None;
+                ui.horizontal(|ui| {
+                    let paste_clicked = ui
+                        .add(
+                            egui::Button::new(
+                                RichText::new("↵ 粘贴")
+                                    .size(13.0)
+                                    .color(theme_text(ui.visuals())),
+                            )
+                            .frame(false)
+                            .min_size(Vec2::new(0.0, 24.0)),
+                        )
+                        .on_hover_text(local_shortcut_tooltip("写入表格", LocalShortcut::Confirm))
+                        .clicked();
+                    if paste_clicked || confirm {
+                        accepted = Some(true);
+                    }
+
+                    ui.add_space(16.0);
+
+                    let cancel_clicked = ui
+                        .add(
+                            egui::Button::new(
+                                RichText::new("✕ 取消")
+                                    .size(13.0)
+                                    .color(theme_text(ui.visuals())),
+                            )
+                            .frame(false)
+                            .min_size(Vec2::new(0.0, 24.0)),
+                        )
+                        .on_hover_text(local_shortcut_tooltip("取消粘贴", LocalShortcut::Cancel))
+                        .clicked();
+                    if cancel_clicked || cancel {
+                        accepted = Some(false);
+                    }
+                });
+                accepted
+            });
+
+        match outcome.inner {
+            Some(true) => {
+                if let Some(plan) = state.pending_paste.take() {
+                    let changed = plan.changed;
+                    plan.apply(state, result);
+                    actions.message = Some(format!("已粘贴，修改 {} 个单元格", changed));
+                }
+            }
+            Some(false) => state.pending_paste = None,
+            None if outcome.should_close() => state.pending_paste = None,
+            None => {}
+        }
+    }
+
+    /// 确认对话框中撤销 / 重做之后，按剩余的修改重新生成待确认的批次
+    fn rebuild_pending_batch(
+        result: &ResultSet,
+        state: &mut DataGridState,
+        table_name: Option<&str>,
+        actions: &mut DataGridActions,
+    ) {
+        let Some(table_name) = table_name.filter(|_| state.has_changes()) else {
+            actions::cancel_pending_mutations(state);
+            actions.message = Some("已没有待保存的修改".to_string());
+            return;
+        };
+        match actions::build_mutation_batch(result, state, table_name) {
+            Ok(batch) => state.pending_mutation_batch = Some(batch),
+            Err(error) => {
+                actions::cancel_pending_mutations(state);
+                actions.message = Some(format!("无法保存修改: {}", error));
+            }
+        }
+    }
+
     fn describe_mutation(mutation: &crate::domain::mutation::Mutation) -> String {
         use crate::domain::mutation::Mutation;
 
@@ -0,0 +1,264 @@
+//! 多单元格粘贴
+//!
+//! `copy_selected_cells` 的逆操作：把剪贴板中的 TSV / CSV 块从光标处按显示顺序写入表格，
+//! 超出最后一行的部分追加为新行。每个值先按列类型解析，可解析的值换成规范的编辑文本，
+//! 无法解析的值原样保留，保存前标红。多单元格的粘贴先生成计划并预览，确认后才写入。
+
+use super::cell_edit::{CellEdit, cell_input_value, parse_cell_text};
+use super::state::DataGridState;
+use super::view::GridVirtualRows;
+use crate::domain::result::ResultSet;
+use crate::domain::value::{DbTypeFamily, DbTypeInfo};
+
+/// 单次粘贴最多写入的单元格数
+const MAX_PASTE_CELLS: usize = 100_000;
+
+/// 把剪贴板文本拆成单元格块
+///
+/// 含制表符时按 TSV 解析；否则多行且每行逗号分隔的字段数一致时按 CSV 解析；
+/// 其余情况每行作为一个值。引号包裹的字段可以包含分隔符与换行。
+pub(crate) fn parse_clipboard_block(text: &str) -> Vec<Vec<String>> {
+    if text.contains('\t') {
+        if let Some(block) = parse_delimited(text, b'\t') {
+            return block;
+        }
+    } else if text.contains(',')
+        && let Some(block) = parse_delimited(text, b',')
+        && block.len() > 1
+        && block[0].len() > 1
+        && block.iter().all(|row| row.len() == block[0].len())
+    {
+        return block;
+    }
+    text.lines().map(|line| vec![line.to_string()]).collect()
+}
+
+fn parse_delimited(text: &str, delimiter: u8) -> Option<Vec<Vec<String>>> {
+    csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(delimiter)
+        .from_reader(text.as_bytes())
+        .records()
+        .map(|record| {
+            record
+                .ok()
+                .map(|record| record.iter().map(str::to_string).collect())
+        })
+        .collect()
+}
+
+/// 按列类型转换粘贴的文本
+///
+/// 复制时 NULL 显示为 `NULL`，非文本列的空值也视为 NULL（列不允许 NULL 时除外）。
+fn pasted_edit(text: &str, type_info: &DbTypeInfo) -> CellEdit {
+    let textual = matches!(
+        type_info.family,
+        DbTypeFamily::Text | DbTypeFamily::Other | DbTypeFamily::Null
+    );
+    if type_info.nullable != Some(false) && (text == "NULL" || (!textual && text.trim().is_empty()))
+    {
+        return CellEdit::Null;
+    }
+    match parse_cell_text(text, type_info) {
+        Ok(value) => CellEdit::from_value(&value),
+        Err(_) => CellEdit::Text(text.to_string()),
+    }
+}
+
+/// 粘贴计划：预览中展示统计，确认后写入
+#[derive(Debug, Clone, PartialEq)]
+pub struct PastePlan {
+    /// 起始单元格 (row, col)
+    pub start: (usize, usize),
+    /// 块的行数与列数
+    pub rows: usize,
+    pub columns: usize,
+    /// 值会改变的单元格数（含新增行中的单元格）
+    pub changed: usize,
+    /// 不符合列类型、保存前需要修正的值
+    pub invalid: usize,
+    /// 超出最后一列而被忽略的列数
+    pub skipped_columns: usize,
+    /// 写入已有行与未保存新行的单元格
+    cells: Vec<((usize, usize), CellEdit)>,
+    /// 追加的新行，未覆盖的列为 DEFAULT
+    appended_rows: Vec<Vec<CellEdit>>,
+}
+
+impl PastePlan {
+    pub fn appended_row_count(&self) -> usize {
+        self.appended_rows.len()
+    }
+
+    pub(crate) fn is_single_cell(&self) -> bool {
+        self.rows == 1 && self.columns == 1
+    }
+
+    /// 写入表格状态，同一帧内的写入在撤销历史中合为一步
+    pub(crate) fn apply(self, state: &mut DataGridState, result: &ResultSet) {
+        for (cell, edit) in self.cells {
+            state.set_cell_edit(result, cell, edit);
+        }
+        state.new_rows.extend(self.appended_rows);
+    }
+}
+
+/// 从光标处按显示顺序规划粘贴
+pub(crate) fn plan_paste(
+    block: &[Vec<String>],
+    state: &DataGridState,
+    result: &ResultSet,
+    row_view: &GridVirtualRows<'_>,
+    column_types: &[DbTypeInfo],
+) -> Result<PastePlan, String> {
+    let rows = block.len();
+    let columns = block.iter().map(Vec::len).max().unwrap_or(0);
+    if rows == 0 || columns == 0 {
+        return Err("剪贴板中没有可粘贴的内容".to_string());
+    }
+    if rows.saturating_mul(columns) > MAX_PASTE_CELLS {
+        return Err(format!(
+            "粘贴块过大（{} 行 × {} 列），单次最多 {} 个单元格",
+            rows, columns, MAX_PASTE_CELLS
+        ));
+    }
+    let start = state.cursor;
+    let start_display = row_view
+        .display_index_for_row_key(start.0)
+        .ok_or_else(|| "光标不在当前显示的行上".to_string())?;
+    let column_count = result.column_count();
+    let target_columns = columns.min(column_count.saturating_sub(start.1));
+
+    let mut plan = PastePlan {
+        start,
+        rows,
+        columns,
+        changed: 0,
+        invalid: 0,
+        skipped_columns: columns - target_columns,
+        cells: Vec::new(),
+        appended_rows: Vec::new(),
+    };
+    for (offset, values) in block.iter().enumerate() {
+        let row_key = row_view.row_key_at_display_index(start_display + offset);
+        let for_insert = row_key.is_none_or(|row_key| row_key >= result.row_count);
+        let mut appended = row_key
+            .is_none()
+            .then(|| vec![CellEdit::Default; column_count]);
+        for (index, text) in values.iter().take(target_columns).enumerate() {
+            let col = start.1 + index;
+            let Some(type_info) = column_types.get(col) else {
+                continue;
+            };
+            let edit = pasted_edit(text, type_info);
+            if cell_input_value(&edit, type_info, for_insert).is_err() {
+                plan.invalid += 1;
+            }
+            match (row_key, appended.as_mut()) {
+                (Some(row_key), _) => {
+                    if state.cell_edit(result, (row_key, col)).as_ref() != Some(&edit) {
+                        plan.changed += 1;
+                        plan.cells.push(((row_key, col), edit));
+                    }
+                }
+                (None, Some(row)) => {
+                    plan.changed += 1;
+                    row[col] = edit;
+                }
+                (None, None) => {}
+            }
+        }
+        plan.appended_rows.extend(appended);
+    }
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::result::{ResultColumn, ResultCompleteness};
+    use crate::domain::value::DbValue;
+
+    fn type_info(family: DbTypeFamily) -> DbTypeInfo {
+        DbTypeInfo {
+            family,
+            native_name: String::new(),
+            nullable: None,
+        }
+    }
+
+    fn sample_result() -> ResultSet {
+        let column = |name: &str, family| ResultColumn {
+            name: name.into(),
+            type_info: type_info(family),
+        };
+        ResultSet {
+            columns: std::sync::Arc::new([
+                column("id", DbTypeFamily::Integer),
+                column("name", DbTypeFamily::Text),
+            ]),
+            cells: vec![
+                DbValue::Int(1),
+                DbValue::Text("alice".into()),
+                DbValue::Int(2),
+                DbValue::Text("bob".into()),
+            ],
+            row_count: 2,
+            completeness: ResultCompleteness::Complete,
+        }
+    }
+
+    #[test]
+    fn clipboard_blocks_are_split_as_tsv_csv_or_lines() {
+        assert_eq!(
+            parse_clipboard_block("1\t\"a\tb\"\n2\tc\r\n"),
+            vec![vec!["1", "a\tb"], vec!["2", "c"]]
+        );
+        assert_eq!(
+            parse_clipboard_block("1,\"x, y\"\n2,z"),
+            vec![vec!["1", "x, y"], vec!["2", "z"]]
+        );
+        assert_eq!(
+            parse_clipboard_block("Smith, John\nDoe"),
+            vec![vec!["Smith, John"], vec!["Doe"]]
+        );
+    }
+
+    #[test]
+    fn paste_coerces_values_and_extends_into_new_rows() {
+        let result = sample_result();
+        let mut state = DataGridState {
+            cursor: (1, 0),
+            ..Default::default()
+        };
+        let filtered: Vec<usize> = (0..result.row_count).collect();
+        let new_rows = Vec::new();
+        let row_view = GridVirtualRows::new(&result, &filtered, &new_rows);
+        let types = vec![
+            type_info(DbTypeFamily::Integer),
+            type_info(DbTypeFamily::Text),
+        ];
+        let block = parse_clipboard_block("2\tbobby\textra\n x \tNULL\n\t\n");
+
+        let plan = plan_paste(&block, &state, &result, &row_view, &types).unwrap();
+        assert_eq!((plan.rows, plan.columns, plan.skipped_columns), (3, 3, 1));
+        assert_eq!(plan.appended_row_count(), 2);
+        assert_eq!(plan.changed, 5);
+        assert_eq!(plan.invalid, 1);
+
+        plan.apply(&mut state, &result);
+        assert_eq!(
+            state.modified_cells.get(&(1, 1)),
+            Some(&CellEdit::from("bobby"))
+        );
+        assert!(!state.modified_cells.contains_key(&(1, 0)));
+        assert_eq!(
+            state.new_rows,
+            vec![
+                vec![CellEdit::from(" x "), CellEdit::Null],
+                vec![CellEdit::Null, CellEdit::from("")],
+            ]
+        );
+    }
+}
@@ -0,0 +1,388 @@
+//! 外键取值选择器
+//!
+//! Insert 模式编辑单列外键时，在单元格下方列出被引用表中的候选行（键值 + 显示列），
+//! 输入即按键值或显示列搜索。候选由应用层以有界查询按需加载，
+//! 选择器只记录要查询的内容并展示回填的结果。
+
+use crate::domain::metadata::TableMetadata;
+use crate::domain::result::ResultSet;
+use crate::ui::styles::{GRAY, theme_error};
+use egui::{self, Key, Modifiers, RichText};
+use std::time::Duration;
+
+/// 输入停顿多久后才按新的搜索文本查询（秒）
+const SEARCH_DEBOUNCE_SECS: f64 = 0.25;
+/// 下拉列表的宽度
+const PICKER_WIDTH: f32 = 320.0;
+/// 候选列表的最大高度
+const PICKER_LIST_HEIGHT: f32 = 220.0;
+
+/// 单列外键：当前列引用的表与列
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnReference {
+    pub table: String,
+    pub column: String,
+}
+
+/// 各列引用的表与列；复合外键无法逐格选择，不计入
+pub(crate) fn column_references(
+    result: &ResultSet,
+    metadata: Option<&TableMetadata>,
+) -> Vec<Option<ColumnReference>> {
+    result
+        .columns
+        .iter()
+        .map(|column| {
+            metadata?
+                .foreign_keys
+                .iter()
+                .find(|foreign_key| {
+                    foreign_key.from_columns.len() == 1
+                        && foreign_key.ref_columns.len() == 1
+                        && foreign_key.from_columns[0] == column.name
+                })
+                .map(|foreign_key| ColumnReference {
+                    table: foreign_key.ref_table.clone(),
+                    column: foreign_key.ref_columns[0].clone(),
+                })
+        })
+        .collect()
+}
+
+/// 一次候选查询：被引用的表与键列，以及搜索文本（空串表示不筛选）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceLookup {
+    pub table: String,
+    pub key_column: String,
+    pub search: String,
+}
+
+/// 候选行：键值与显示列的值
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceCandidate {
+    pub key: String,
+    pub label: Option<String>,
+}
+
+/// 一次候选查询的结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReferenceCandidates {
+    /// 显示列；`None` 表示只显示键值
+    pub label_column: Option<String>,
+    /// 被引用表中可作为显示列的列
+    pub label_options: Vec<String>,
+    pub rows: Vec<ReferenceCandidate>,
+    /// 是否达到查询上限，还有未列出的行
+    pub truncated: bool,
+}
+
+/// 修改某张被引用表的显示列；`column` 为 `None` 表示只显示键值
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceLabelChange {
+    pub table: String,
+    pub column: Option<String>,
+}
+
+/// 选择器状态
+#[derive(Debug, Default, Clone)]
+pub struct ReferencePickerState {
+    /// 最近一次发出的查询
+    lookup: Option<ReferenceLookup>,
+    /// 最近一次收到的结果；加载新结果期间继续显示
+    result: Option<Result<ReferenceCandidates, String>>,
+    /// 是否在等待 `lookup` 的结果
+    loading: bool,
+    /// 尚未查询的搜索文本及其最后修改时间，用于输入防抖
+    typing: Option<(String, f64)>,
+    /// 键盘高亮的候选
+    highlighted: Option<usize>,
+    /// 待应用层执行的查询
+    pending_lookup: Option<ReferenceLookup>,
+    /// 待应用层保存的显示列
+    pending_label: Option<ReferenceLabelChange>,
+}
+
+impl ReferencePickerState {
+    /// 写入查询结果；与最近一次查询不符的过期结果被忽略
+    pub fn set_result(
+        &mut self,
+        lookup: &ReferenceLookup,
+        result: Result<ReferenceCandidates, String>,
+    ) {
+        if self.lookup.as_ref() != Some(lookup) {
+            return;
+        }
+        self.loading = false;
+        self.highlighted = None;
+        self.result = Some(result);
+    }
+
+    /// 最近一次发出的查询，修改显示列后据此重新加载
+    pub fn current_lookup(&self) -> Option<&ReferenceLookup> {
+        self.lookup.as_ref()
+    }
+
+    /// 编辑结束后关闭选择器
+    pub(crate) fn close(&mut self) {
+        *self = Self::default();
+    }
+
+    pub(crate) fn take_pending_lookup(&mut self) -> Option<ReferenceLookup> {
+        self.pending_lookup.take()
+    }
+
+    pub(crate) fn take_pending_label(&mut self) -> Option<ReferenceLabelChange> {
+        self.pending_label.take()
+    }
+
+    fn request(&mut self, lookup: ReferenceLookup) {
+        if self.lookup.as_ref().is_none_or(|previous| {
+            previous.table != lookup.table || previous.key_column != lookup.key_column
+        }) {
+            self.result = None;
+        }
+        self.lookup = Some(lookup.clone());
+        self.pending_lookup = Some(lookup);
+        self.loading = true;
+        self.typing = None;
+        self.highlighted = None;
+    }
+
+    /// 按当前输入决定是否发出新查询：换了外键立即查询，同一外键的搜索文本防抖后查询
+    fn sync_lookup(&mut self, ctx: &egui::Context, reference: &ColumnReference, text: &str) {
+        let wanted = ReferenceLookup {
+            table: reference.table.clone(),
+            key_column: reference.column.clone(),
+            search: text.trim().to_string(),
+        };
+        let Some(current) = &self.lookup else {
+            self.request(wanted);
+            return;
+        };
+        if *current == wanted {
+            self.typing = None;
+            return;
+        }
+        if current.table != wanted.table || current.key_column != wanted.key_column {
+            self.request(wanted);
+            return;
+        }
+
+        let now = ctx.input(|input| input.time);
+        match &self.typing {
+            Some((search, since)) if *search == wanted.search => {
+                let waited = now - since;
+                if waited >= SEARCH_DEBOUNCE_SECS {
+                    self.request(wanted);
+                } else {
+                    ctx.request_repaint_after(Duration::from_secs_f64(
+                        SEARCH_DEBOUNCE_SECS - waited,
+                    ));
+                }
+            }
+            _ => {
+                self.typing = Some((wanted.search, now));
+                ctx.request_repaint_after(Duration::from_secs_f64(SEARCH_DEBOUNCE_SECS));
+            }
+        }
+    }
+
+    fn candidate_count(&self) -> usize {
+        match &self.result {
+            Some(Ok(candidates)) => candidates.rows.len(),
+            _ => 0,
+        }
+    }
+
+    /// 上下方向键移动高亮，回车选择高亮的候选
+    fn handle_keys(&mut self, ui: &egui::Ui) -> Option<String> {
+        let count = self.candidate_count();
+        if count == 0 {
+            return None;
+        }
+        let (down, up) = ui.input_mut(|input| {
+            (
+                input.consume_key(Modifiers::NONE, Key::ArrowDown),
+                input.consume_key(Modifiers::NONE, Key::ArrowUp),
+            )
+        });
+        if down {
+            self.highlighted = Some(self.highlighted.map_or(0, |index| (index + 1) % count));
+        }
+        if up {
+            self.highlighted = Some(
+                self.highlighted
+                    .map_or(count - 1, |index| (index + count - 1) % count),
+            );
+        }
+        let index = self.highlighted?;
+        if !ui.input_mut(|input| input.consume_key(Modifiers::NONE, Key::Enter)) {
+            return None;
+        }
+        match &self.result {
+            Some(Ok(candidates)) => candidates.rows.get(index).map(|row| row.key.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// 在单元格下方显示候选列表，返回选中的键值
+///
+/// 需在单元格输入框之前调用：方向键与回车先由选择器处理。
+pub(crate) fn show_reference_picker(
+    ui: &mut egui::Ui,
+    picker: &mut ReferencePickerState,
+    reference: &ColumnReference,
+    text: &str,
+    cell: (usize, usize),
+) -> Option<String> {
+    picker.sync_lookup(ui.ctx(), reference, text);
+    let mut picked = picker.handle_keys(ui);
+
+    egui::Area::new(ui.id().with(("grid_reference_picker", cell)))
+        .order(egui::Order::Foreground)
+        .fixed_pos(ui.max_rect().left_bottom())
+        .show(ui.ctx(), |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.set_width(PICKER_WIDTH);
+                show_picker_header(ui, picker, reference);
+                ui.separator();
+                if let Some(key) = show_candidates(ui, picker) {
+                    picked = Some(key);
+                }
+            });
+        });
+    picked
+}
+
+fn show_picker_header(
+    ui: &mut egui::Ui,
+    picker: &mut ReferencePickerState,
+    reference: &ColumnReference,
+) {
+    ui.horizontal(|ui| {
+        ui.label(
+            RichText::new(format!("→ {}.{}", reference.table, reference.column))
+                .small()
+                .color(GRAY),
+        );
+        if picker.loading {
+            ui.spinner();
+        }
+        let Some(Ok(candidates)) = &picker.result else {
+            return;
+        };
+        if candidates.label_options.is_empty() {
+            return;
+        }
+        let mut selected = candidates.label_column.clone();
+        egui::ComboBox::from_id_salt(("grid_reference_label", &reference.table))
+            .selected_text(selected.as_deref().unwrap_or("仅键值"))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut selected, None, "仅键值");
+                for option in &candidates.label_options {
+                    ui.selectable_value(&mut selected, Some(option.clone()), option);
+                }
+            })
+            .response
+            .on_hover_text("候选行旁显示的列，按被引用表保存");
+        if selected != candidates.label_column {
+            picker.pending_label = Some(ReferenceLabelChange {
+                table: reference.table.clone(),
+                column: selected,
+            });
+        }
+    });
+}
+
+fn show_candidates(ui: &mut egui::Ui, picker: &mut ReferencePickerState) -> Option<String> {
+    let candidates = match &picker.result {
+        None => {
+            ui.label(RichText::new("正在加载候选行…").small().color(GRAY));
+            return None;
+        }
+        Some(Err(error)) => {
+            ui.label(
+                RichText::new(format!("无法加载候选行: {}", error))
+                    .small()
+                    .color(theme_error(ui.visuals())),
+            );
+            return None;
+        }
+        Some(Ok(candidates)) => candidates,
+    };
+    if candidates.rows.is_empty() {
+        ui.label(RichText::new("没有匹配的行").small().color(GRAY));
+        return None;
+    }
+
+    let mut picked = None;
+    egui::ScrollArea::vertical()
+        .id_salt("grid_reference_candidates")
+        .max_height(PICKER_LIST_HEIGHT)
+        .show(ui, |ui| {
+            for (index, row) in candidates.rows.iter().enumerate() {
+                let text = match &row.label {
+                    Some(label) => format!("{}  {}", row.key, label),
+                    None => row.key.clone(),
+                };
+                let highlighted = picker.highlighted == Some(index);
+                let response = ui.selectable_label(highlighted, RichText::new(text).monospace());
+                if highlighted {
+                    response.scroll_to_me(None);
+                }
+                if response.clicked() {
+                    picked = Some(row.key.clone());
+                }
+            }
+        });
+    if candidates.truncated {
+        ui.label(
+            RichText::new(format!(
+                "仅列出前 {} 行，继续输入以缩小范围",
+                candidates.rows.len()
+            ))
+            .small()
+            .color(GRAY),
+        );
+    } else {
+        ui.label(RichText::new("↑↓ 选择，Enter 填入").small().color(GRAY));
+    }
+    picked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(search: &str) -> ReferenceLookup {
+        ReferenceLookup {
+            table: "customers".to_string(),
+            key_column: "id".to_string(),
+            search: search.to_string(),
+        }
+    }
+
+    #[test]
+    fn stale_results_are_ignored() {
+        let mut picker = ReferencePickerState::default();
+        picker.request(lookup("a"));
+        picker.request(lookup("ab"));
+        assert_eq!(picker.take_pending_lookup(), Some(lookup("ab")));
+
+        picker.set_result(&lookup("a"), Ok(ReferenceCandidates::default()));
+        assert!(picker.loading);
+        assert!(picker.result.is_none());
+
+        let candidates = ReferenceCandidates {
+            rows: vec![ReferenceCandidate {
+                key: "7".to_string(),
+                label: Some("Alice".to_string()),
+            }],
+            ..Default::default()
+        };
+        picker.set_result(&lookup("ab"), Ok(candidates.clone()));
+        assert!(!picker.loading);
+        assert_eq!(picker.result, Some(Ok(candidates)));
+    }
+}
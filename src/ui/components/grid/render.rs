@@ -2,18 +2,25 @@
 
 #![allow(clippy::too_many_arguments)]
 
+use super::cell_edit::{CellEdit, CellEditKind, cell_input_value};
+use super::cell_editor::{show_cell_editor, uses_popup_editor};
 use super::mode::GridMode;
+use super::reference_picker::{ColumnReference, show_reference_picker};
 use super::state::DataGridState;
 use super::{
     CELL_TRUNCATE_LEN, COLOR_CELL_EDITING, COLOR_CELL_MODIFIED, COLOR_CELL_SELECTED,
     COLOR_VISUAL_SELECT,
 };
+use crate::core::TableMarkup;
+use crate::domain::value::{DbTypeInfo, DbValue};
 use crate::ui::styles::{GRAY, contrasting_text, theme_error, theme_success, theme_text};
-use crate::ui::{LocalShortcut, consume_local_shortcut};
+use crate::ui::{LocalShortcut, consume_local_shortcut, local_shortcut_tooltip};
 use egui::{self, Color32, RichText, Sense, TextEdit, Vec2};
 
 // NULL 值颜色
 const COLOR_NULL: Color32 = Color32::from_rgb(120, 120, 140);
+/// 弹出式类型编辑器的宽度
+const CELL_EDITOR_WIDTH: f32 = 320.0;
 
 /// 渲染列头
 pub(crate) fn render_column_header(
@@ -159,7 +166,9 @@ pub(crate) fn render_row_number(
 /// 渲染可编辑的数据单元格。
 pub(crate) fn render_editable_cell(
     ui: &mut egui::Ui,
-    cell: &crate::domain::value::DbValue,
+    cell: &DbValue,
+    type_info: &DbTypeInfo,
+    reference: Option<&ColumnReference>,
     row_idx: usize,
     col_idx: usize,
     _is_cursor_row: bool,
@@ -168,20 +177,13 @@ pub(crate) fn render_editable_cell(
 ) {
     let is_cursor = state.cursor == (row_idx, col_idx);
     let is_editing = state.editing_cell == Some((row_idx, col_idx));
-    let is_modified = state.modified_cells.contains_key(&(row_idx, col_idx));
+    let modified = state.modified_cells.get(&(row_idx, col_idx)).cloned();
+    let is_modified = modified.is_some();
     let is_selected = state.mode == GridMode::Select && state.is_in_selection(row_idx, col_idx);
-    let stored_value = cell.display();
-    let is_null = matches!(cell, crate::domain::value::DbValue::Null);
-    let display_value = state
-        .modified_cells
-        .get(&(row_idx, col_idx))
-        .cloned()
-        .unwrap_or_else(|| stored_value.clone());
-    let display_is_null = state
-        .modified_cells
-        .get(&(row_idx, col_idx))
-        .is_some_and(|value| value.eq_ignore_ascii_case("null"))
-        || (!is_modified && is_null);
+    // 修改过的单元格按列类型校验，保存前就能看到哪些值无法写入
+    let invalid_reason = modified
+        .as_ref()
+        .and_then(|edit| cell_input_value(edit, type_info, false).err());
 
     let bg_color = if is_row_deleted {
         let error = theme_error(ui.visuals());
@@ -203,14 +205,18 @@ pub(crate) fn render_editable_cell(
         .inner_margin(4.0)
         .show(ui, |ui| {
             if is_editing && state.mode == GridMode::Insert {
-                render_editing_cell(ui, state, row_idx, col_idx);
+                render_editing_cell(ui, state, type_info, reference, row_idx, col_idx);
             } else {
+                let display = modified.unwrap_or_else(|| match cell {
+                    DbValue::Null => CellEdit::Null,
+                    cell => CellEdit::Text(cell.display()),
+                });
                 render_display_cell(
                     ui,
                     state,
-                    &stored_value,
-                    &display_value,
-                    display_is_null,
+                    cell,
+                    &display,
+                    invalid_reason.as_deref(),
                     row_idx,
                     col_idx,
                     is_cursor,
@@ -223,47 +229,150 @@ pub(crate) fn render_editable_cell(
 fn render_editing_cell(
     ui: &mut egui::Ui,
     state: &mut DataGridState,
+    type_info: &DbTypeInfo,
+    reference: Option<&ColumnReference>,
     row_idx: usize,
     col_idx: usize,
 ) {
-    let response = ui.add(
-        TextEdit::singleline(&mut state.edit_text)
-            .desired_width(ui.available_width() - 8.0)
-            .font(egui::TextStyle::Monospace),
-    );
-
-    let should_exit = consume_grid_edit_finish(ui);
+    let Some(apply) =
+        render_cell_editor(ui, state, type_info, reference, (row_idx, col_idx), false)
+    else {
+        return;
+    };
+    if apply {
+        let edit = state.current_edit();
+        if edit != state.original_value {
+            state.modified_cells.insert((row_idx, col_idx), edit);
+        }
+    }
+    state.editing_cell = None;
+    state.mode = GridMode::Normal;
+}
 
-    if should_exit || response.lost_focus() {
-        if state.edit_text != state.original_value {
-            state
-                .modified_cells
-                .insert((row_idx, col_idx), state.edit_text.clone());
+/// 渲染编辑中的单元格
+///
+/// 数字、文本等简单类型直接在格内输入；布尔、日期时间、JSON 与数组弹出类型编辑器。
+/// 单列外键在格内输入的同时列出被引用表的候选行，选中即填入键值。
+/// 返回 `Some(true)` 表示应用修改、`Some(false)` 表示放弃，`None` 表示仍在编辑。
+fn render_cell_editor(
+    ui: &mut egui::Ui,
+    state: &mut DataGridState,
+    type_info: &DbTypeInfo,
+    reference: Option<&ColumnReference>,
+    cell: (usize, usize),
+    allow_default: bool,
+) -> Option<bool> {
+    if !uses_popup_editor(type_info) {
+        if let Some(reference) = reference
+            && let Some(key) = show_reference_picker(
+                ui,
+                &mut state.reference_picker,
+                reference,
+                &state.edit_text,
+                cell,
+            )
+        {
+            state.edit_text = key;
+            state.edit_kind = CellEditKind::Value;
+            return Some(true);
         }
-        state.editing_cell = None;
-        state.mode = GridMode::Normal;
+        // 二进制值没有编辑文本：留空保留原值，也可输入 0x 开头的十六进制
+        let bytes_hint = (matches!(state.edit_initial, CellEdit::Bytes(_))
+            && state.edit_text.is_empty())
+        .then(|| state.edit_initial.display_text().into_owned());
+        let mut text_edit = TextEdit::singleline(&mut state.edit_text)
+            .desired_width(ui.available_width() - 8.0)
+            .font(egui::TextStyle::Monospace);
+        if state.edit_kind != CellEditKind::Value {
+            text_edit = text_edit.hint_text(state.edit_kind.label());
+        } else if let Some(hint) = bytes_hint {
+            text_edit = text_edit.hint_text(hint);
+        }
+        let response = ui.add(text_edit);
+        if response.changed() {
+            state.edit_kind = CellEditKind::Value;
+        }
+
+        let should_exit = consume_grid_edit_finish(ui);
+        response.request_focus();
+        return (should_exit || response.lost_focus()).then_some(true);
     }
 
-    response.request_focus();
+    ui.label(
+        RichText::new(state.current_edit().display_text())
+            .monospace()
+            .italics(),
+    );
+    let mut outcome = None;
+    egui::Area::new(ui.id().with(("grid_cell_editor", cell)))
+        .order(egui::Order::Foreground)
+        .fixed_pos(ui.max_rect().left_bottom())
+        .show(ui.ctx(), |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.set_width(CELL_EDITOR_WIDTH);
+                let _ = show_cell_editor(
+                    ui,
+                    ("grid_cell_editor", cell),
+                    type_info,
+                    &mut state.edit_kind,
+                    &mut state.edit_text,
+                    allow_default,
+                );
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .button("应用")
+                        .on_hover_text(local_shortcut_tooltip(
+                            "应用修改",
+                            LocalShortcut::GridCellEditorApply,
+                        ))
+                        .clicked()
+                    {
+                        outcome = Some(true);
+                    }
+                    if ui
+                        .button("取消")
+                        .on_hover_text(local_shortcut_tooltip(
+                            "放弃本次编辑",
+                            LocalShortcut::GridCellEditorCancel,
+                        ))
+                        .clicked()
+                    {
+                        outcome = Some(false);
+                    }
+                });
+            });
+        });
+
+    outcome.or_else(|| {
+        ui.input_mut(|input| {
+            if consume_local_shortcut(input, LocalShortcut::GridCellEditorApply) {
+                Some(true)
+            } else if consume_local_shortcut(input, LocalShortcut::GridCellEditorCancel) {
+                Some(false)
+            } else {
+                None
+            }
+        })
+    })
 }
 
 fn render_display_cell(
     ui: &mut egui::Ui,
     state: &mut DataGridState,
-    cell: &str,
-    display_value: &str,
-    display_is_null: bool,
+    cell: &DbValue,
+    display: &CellEdit,
+    invalid_reason: Option<&str>,
     row_idx: usize,
     col_idx: usize,
     is_cursor: bool,
     is_row_deleted: bool,
 ) {
-    let cell_text = format_cell_text(display_value, display_is_null, is_cursor);
-    let hover_value = if display_is_null {
-        "NULL"
-    } else {
-        display_value
-    };
+    let mut cell_text = format_cell_text(display, is_cursor);
+    if invalid_reason.is_some() {
+        cell_text = cell_text.color(theme_error(ui.visuals()));
+    }
+    let hover_value = display.display_text();
     let response = ui.add(egui::Label::new(cell_text).sense(Sense::click()));
 
     if response.clicked() {
@@ -272,13 +381,11 @@ fn render_display_cell(
     }
 
     if response.double_clicked() && !is_row_deleted {
-        state.mode = GridMode::Insert;
-        state.editing_cell = Some((row_idx, col_idx));
-        state.edit_text = display_value.to_string();
-        state.original_value = cell.to_string();
+        let current = edit_value(state, cell, (row_idx, col_idx));
+        state.begin_cell_edit((row_idx, col_idx), CellEdit::from_value(cell), current);
     }
 
-    let show_hover = display_is_null || display_value.len() > CELL_TRUNCATE_LEN;
+    let show_hover = display.is_null() || hover_value.len() > CELL_TRUNCATE_LEN;
 
     // 右键菜单 - 无边框按钮
     response.context_menu(|ui| {
@@ -297,10 +404,8 @@ fn render_display_cell(
         };
 
         if menu_btn(ui, "✏", "编辑", "编辑单元格 (i)") {
-            state.mode = GridMode::Insert;
-            state.editing_cell = Some((row_idx, col_idx));
-            state.edit_text = display_value.to_string();
-            state.original_value = cell.to_string();
+            let current = edit_value(state, cell, (row_idx, col_idx));
+            state.begin_cell_edit((row_idx, col_idx), CellEdit::from_value(cell), current);
             ui.close();
         }
         if menu_btn(ui, "📋", "复制", "复制内容 (y)") {
@@ -308,11 +413,22 @@ fn render_display_cell(
             ui.ctx().copy_text(hover_value.to_string());
             ui.close();
         }
+        show_copy_as_items(ui, state, row_idx, col_idx, menu_btn);
         if menu_btn(ui, "📥", "粘贴", "粘贴内容 (p)") {
             if let Some(text) = &state.clipboard {
                 state
                     .modified_cells
-                    .insert((row_idx, col_idx), text.clone());
+                    .insert((row_idx, col_idx), CellEdit::Text(text.clone()));
+            }
+            ui.close();
+        }
+        if !display.is_null() && menu_btn(ui, "∅", "设为 NULL", "将单元格设为 NULL") {
+            if matches!(cell, DbValue::Null) {
+                state.modified_cells.remove(&(row_idx, col_idx));
+            } else {
+                state
+                    .modified_cells
+                    .insert((row_idx, col_idx), CellEdit::Null);
             }
             ui.close();
         }
@@ -324,19 +440,53 @@ fn render_display_cell(
         }
     });
 
-    if show_hover {
-        response.on_hover_text(hover_value);
+    if let Some(reason) = invalid_reason {
+        response.on_hover_text(format!("{}\n{}", hover_value, reason));
+    } else if show_hover {
+        response.on_hover_text(hover_value.into_owned());
+    }
+}
+
+/// 右键菜单中的“复制选区为…”，仅在单元格位于选区内时显示
+fn show_copy_as_items(
+    ui: &mut egui::Ui,
+    state: &mut DataGridState,
+    row_idx: usize,
+    col_idx: usize,
+    menu_btn: impl Fn(&mut egui::Ui, &str, &str, &str) -> bool,
+) {
+    if !state.is_in_selection(row_idx, col_idx) {
+        return;
+    }
+    for markup in TableMarkup::ALL {
+        let text = format!("复制选区为 {}", markup.display_name());
+        if menu_btn(ui, "📑", &text, "以文档表格格式复制选中区域，表头为列名")
+        {
+            state.pending_copy_as = Some(markup);
+            ui.close();
+        }
     }
 }
 
-fn format_cell_text(cell: &str, is_null: bool, is_cursor: bool) -> RichText {
-    let text = if is_null {
+/// 进入编辑时的初始值：已修改的单元格沿用修改，否则取原值
+fn edit_value(state: &DataGridState, cell: &DbValue, position: (usize, usize)) -> CellEdit {
+    state
+        .modified_cells
+        .get(&position)
+        .cloned()
+        .unwrap_or_else(|| CellEdit::from_value(cell))
+}
+
+fn format_cell_text(cell: &CellEdit, is_cursor: bool) -> RichText {
+    let text = match cell {
         // NULL 值使用斜体、特殊颜色和背景标记
-        RichText::new("∅ NULL").italics().color(COLOR_NULL)
-    } else if cell.len() > CELL_TRUNCATE_LEN {
-        RichText::new(format!("{}...", &cell[..CELL_TRUNCATE_LEN - 3]))
-    } else {
-        RichText::new(cell)
+        CellEdit::Null => RichText::new("∅ NULL").italics().color(COLOR_NULL),
+        CellEdit::Default => RichText::new("DEFAULT").italics().color(GRAY),
+        CellEdit::Text(text) if text.len() > CELL_TRUNCATE_LEN => {
+            RichText::new(format!("{}...", &text[..CELL_TRUNCATE_LEN - 3]))
+        }
+        CellEdit::Text(text) => RichText::new(text),
+        CellEdit::Bytes(_) => RichText::new(cell.display_text()).italics(),
     };
 
     if is_cursor { text.underline() } else { text }
@@ -397,7 +547,9 @@ const COLOR_NEW_ROW: Color32 = Color32::from_rgba_premultiplied(48, 96, 48, 60);
 /// 渲染新增行的单元格
 pub(crate) fn render_new_row_cell(
     ui: &mut egui::Ui,
-    cell: &str,
+    cell: &CellEdit,
+    type_info: &DbTypeInfo,
+    reference: Option<&ColumnReference>,
     row_idx: usize,
     col_idx: usize,
     _is_cursor_row: bool,
@@ -420,9 +572,18 @@ pub(crate) fn render_new_row_cell(
         .inner_margin(4.0)
         .show(ui, |ui| {
             if is_editing && state.mode == GridMode::Insert {
-                render_new_row_editing_cell(ui, state, row_idx, col_idx);
+                render_new_row_editing_cell(ui, state, type_info, reference, row_idx, col_idx);
             } else {
-                render_new_row_display_cell(ui, state, cell, row_idx, col_idx, is_cursor);
+                let invalid_reason = cell_input_value(cell, type_info, true).err();
+                render_new_row_display_cell(
+                    ui,
+                    state,
+                    cell,
+                    invalid_reason.as_deref(),
+                    row_idx,
+                    col_idx,
+                    is_cursor,
+                );
             }
         });
 }
@@ -430,46 +591,39 @@ pub(crate) fn render_new_row_cell(
 fn render_new_row_editing_cell(
     ui: &mut egui::Ui,
     state: &mut DataGridState,
+    type_info: &DbTypeInfo,
+    reference: Option<&ColumnReference>,
     row_idx: usize,
     col_idx: usize,
 ) {
-    let response = ui.add(
-        TextEdit::singleline(&mut state.edit_text)
-            .desired_width(ui.available_width() - 8.0)
-            .font(egui::TextStyle::Monospace),
-    );
-
-    let should_exit = consume_grid_edit_finish(ui);
-
-    if should_exit || response.lost_focus() {
-        // 计算新增行的索引（row_idx - 原始结果行数）
-        // 由于 new_rows 的修改需要通过特殊方式处理，这里直接保存到 edit_text
-        state.editing_cell = None;
-        state.mode = GridMode::Normal;
+    let Some(apply) = render_cell_editor(ui, state, type_info, reference, (row_idx, col_idx), true)
+    else {
+        return;
+    };
+    state.editing_cell = None;
+    state.mode = GridMode::Normal;
+    if apply {
         // 新增行的编辑会通过 pending_new_row_edit 处理
-        state.pending_new_row_edit = Some((row_idx, col_idx, state.edit_text.clone()));
+        state.pending_new_row_edit = Some((row_idx, col_idx, state.current_edit()));
     }
-
-    response.request_focus();
 }
 
 fn render_new_row_display_cell(
     ui: &mut egui::Ui,
     state: &mut DataGridState,
-    cell: &str,
+    cell: &CellEdit,
+    invalid_reason: Option<&str>,
     row_idx: usize,
     col_idx: usize,
     is_cursor: bool,
 ) {
-    let display_value = if cell.is_empty() { "(空)" } else { cell };
-
-    let cell_text = if cell.is_empty() {
-        RichText::new(display_value).italics().color(GRAY)
-    } else if is_cursor {
-        RichText::new(display_value).underline()
-    } else {
-        RichText::new(display_value)
+    let mut cell_text = match cell {
+        CellEdit::Text(text) if text.is_empty() => RichText::new("(空)").italics().color(GRAY),
+        cell => format_cell_text(cell, is_cursor),
     };
+    if invalid_reason.is_some() {
+        cell_text = cell_text.color(theme_error(ui.visuals()));
+    }
 
     let response = ui.add(egui::Label::new(cell_text).sense(Sense::click()));
 
@@ -479,10 +633,7 @@ fn render_new_row_display_cell(
     }
 
     if response.double_clicked() {
-        state.mode = GridMode::Insert;
-        state.editing_cell = Some((row_idx, col_idx));
-        state.edit_text = cell.to_string();
-        state.original_value = cell.to_string();
+        state.begin_cell_edit((row_idx, col_idx), cell.clone(), cell.clone());
     }
 
     // 右键菜单 - 无边框按钮
@@ -502,19 +653,31 @@ fn render_new_row_display_cell(
         };
 
         if menu_btn(ui, "✏", "编辑", "编辑单元格 (i)") {
-            state.mode = GridMode::Insert;
-            state.editing_cell = Some((row_idx, col_idx));
-            state.edit_text = cell.to_string();
-            state.original_value = cell.to_string();
+            state.begin_cell_edit((row_idx, col_idx), cell.clone(), cell.clone());
             ui.close();
         }
+        show_copy_as_items(ui, state, row_idx, col_idx, menu_btn);
         if menu_btn(ui, "📥", "粘贴", "粘贴内容 (p)") {
             if let Some(text) = &state.clipboard {
-                state.pending_new_row_edit = Some((row_idx, col_idx, text.clone()));
+                state.pending_new_row_edit = Some((row_idx, col_idx, CellEdit::Text(text.clone())));
             }
             ui.close();
         }
+        if !cell.is_null() && menu_btn(ui, "∅", "设为 NULL", "将单元格设为 NULL") {
+            state.pending_new_row_edit = Some((row_idx, col_idx, CellEdit::Null));
+            ui.close();
+        }
+        if *cell != CellEdit::Default
+            && menu_btn(ui, "⟲", "设为 DEFAULT", "插入时省略该列，使用数据库默认值")
+        {
+            state.pending_new_row_edit = Some((row_idx, col_idx, CellEdit::Default));
+            ui.close();
+        }
     });
+
+    if let Some(reason) = invalid_reason {
+        response.on_hover_text(reason);
+    }
 }
 
 fn consume_grid_edit_finish(ui: &mut egui::Ui) -> bool {
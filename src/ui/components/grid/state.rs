@@ -1,7 +1,14 @@
 //! 表格编辑状态
 
+use super::cell_edit::{CellDraft, CellEdit, CellEditKind};
 use super::filter::{ColumnFilter, FilterCache};
+use super::grouping::GroupViewState;
+use super::history::{EditHistory, PendingEdits};
 use super::mode::GridMode;
+use super::paste::PastePlan;
+use super::reference_picker::ReferencePickerState;
+use super::stats::StatsCache;
+use crate::domain::result::ResultSet;
 use std::collections::HashMap;
 
 /// 列宽缓存
@@ -48,6 +55,19 @@ impl ColumnWidthCache {
     }
 }
 
+/// 分页浏览状态（由应用层根据当前 Tab 的分页游标投影）
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GridPageInfo {
+    /// 模式栏显示文本
+    pub label: String,
+    /// 是否存在上一页
+    pub has_previous: bool,
+    /// 是否存在下一页
+    pub has_next: bool,
+    /// 是否已按筛选条件在数据库端重新查询
+    pub filtered: bool,
+}
+
 /// 表格编辑状态
 #[derive(Default, Clone)]
 pub struct DataGridState {
@@ -61,16 +81,24 @@ pub struct DataGridState {
     pub editing_cell: Option<(usize, usize)>,
     /// 编辑中的文本
     pub edit_text: String,
+    /// 编辑中的取值方式（值 / NULL / DEFAULT）
+    pub edit_kind: CellEditKind,
     /// 原始值（用于比较是否修改）
-    pub original_value: String,
+    pub original_value: CellEdit,
+    /// 进入编辑时编辑器的初始值（未改动时原样保留，如二进制值）
+    pub edit_initial: CellEdit,
     /// 已修改的单元格 (row, col) -> 新值
-    pub modified_cells: HashMap<(usize, usize), String>,
+    pub modified_cells: HashMap<(usize, usize), CellEdit>,
     /// 待删除的行索引列表
     pub rows_to_delete: Vec<usize>,
     /// 新增的行数据
-    pub new_rows: Vec<Vec<String>>,
+    pub new_rows: Vec<Vec<CellEdit>>,
+    /// 待保存修改的撤销 / 重做历史
+    pub edit_history: EditHistory,
     /// 筛选条件列表
     pub filters: Vec<ColumnFilter>,
+    /// 服务端筛选模式：筛选条件编译为 WHERE 子句重新查询表（仅分页浏览表时生效）
+    pub server_side_filter: bool,
     /// 剪贴板内容
     pub clipboard: Option<String>,
     /// 命令输入缓冲（用于组合键如 gg）
@@ -97,16 +125,30 @@ pub struct DataGridState {
     pub show_save_confirm: bool,
     /// 待确认的参数化变异批次（包含 DELETE 时使用）
     pub pending_mutation_batch: Option<crate::domain::mutation::MutationBatch>,
+    /// 待确认的多单元格粘贴
+    pub pending_paste: Option<PastePlan>,
     /// 筛选结果缓存
     pub filter_cache: FilterCache,
     /// 当前表的结构化元数据（从 SchemaCatalog 投影，取代 column_metadata）
     pub table_metadata: Option<std::sync::Arc<crate::domain::metadata::TableMetadata>>,
     /// 类型化数据（Phase 4 ResultSet）。
     pub result_set: Option<std::sync::Arc<crate::domain::result::ResultSet>>,
+    /// 右键菜单请求的“复制选区为…”，在下一帧按当前行视图生成
+    pub pending_copy_as: Option<crate::core::TableMarkup>,
     /// 待处理的新增行编辑 (虚拟行索引, 列索引, 新值)
-    pub pending_new_row_edit: Option<(usize, usize, String)>,
+    pub pending_new_row_edit: Option<(usize, usize, CellEdit)>,
+    /// Cell 检查器中尚未应用的编辑
+    pub inspector_draft: Option<CellDraft>,
+    /// 外键列的候选值选择器
+    pub reference_picker: ReferencePickerState,
     /// 列宽缓存
     pub column_width_cache: ColumnWidthCache,
+    /// 选区与列统计缓存
+    pub stats_cache: StatsCache,
+    /// 分组 / 透视视图
+    pub group_view: GroupViewState,
+    /// 分页浏览状态；`None` 表示普通查询结果
+    pub page_info: Option<GridPageInfo>,
 }
 
 impl DataGridState {
@@ -120,10 +162,14 @@ impl DataGridState {
     pub fn clear_edits(&mut self) {
         self.editing_cell = None;
         self.edit_text.clear();
-        self.original_value.clear();
+        self.edit_kind = CellEditKind::Value;
+        self.original_value = CellEdit::default();
+        self.edit_initial = CellEdit::default();
+        self.inspector_draft = None;
         self.modified_cells.clear();
         self.rows_to_delete.clear();
         self.new_rows.clear();
+        self.edit_history.clear();
         // 数据变化后清除列宽缓存
         self.column_width_cache.clear();
     }
@@ -134,6 +180,46 @@ impl DataGridState {
             || !self.new_rows.is_empty()
     }
 
+    /// 把自上次记录以来的修改记为一步撤销历史
+    pub(crate) fn record_edit_history(&mut self) {
+        self.edit_history.record(
+            &self.modified_cells,
+            &self.rows_to_delete,
+            &self.new_rows,
+            self.cursor,
+        );
+    }
+
+    /// 撤销最近一步修改，返回该步的简述
+    pub(crate) fn undo_edit(&mut self) -> Option<String> {
+        self.record_edit_history();
+        let (edits, cursor, label) = self.edit_history.undo()?;
+        self.restore_edits(edits, cursor);
+        Some(label)
+    }
+
+    /// 重做最近撤销的一步修改，返回该步的简述
+    pub(crate) fn redo_edit(&mut self) -> Option<String> {
+        self.record_edit_history();
+        let (edits, cursor, label) = self.edit_history.redo()?;
+        self.restore_edits(edits, cursor);
+        Some(label)
+    }
+
+    fn restore_edits(&mut self, edits: PendingEdits, cursor: (usize, usize)) {
+        self.modified_cells = edits.modified_cells;
+        self.rows_to_delete = edits.rows_to_delete;
+        self.new_rows = edits.new_rows;
+        self.inspector_draft = None;
+        self.cursor = cursor;
+        self.scroll_to_row = Some(cursor.0);
+    }
+
+    /// 筛选是否已下推到数据库（此时不再对已加载的行做客户端筛选）
+    pub fn filters_pushed_down(&self) -> bool {
+        self.server_side_filter && self.page_info.is_some()
+    }
+
     /// 清除保存状态（取消确认后调用）。保留编辑内容。
     pub fn clear_save_state(&mut self) {
         self.pending_mutation_batch = None;
@@ -141,6 +227,74 @@ impl DataGridState {
         self.show_save_confirm = false;
     }
 
+    /// 进入单元格编辑：`current` 为编辑器初始内容，`original` 用于判断是否修改
+    pub(crate) fn begin_cell_edit(
+        &mut self,
+        cell: (usize, usize),
+        original: CellEdit,
+        current: CellEdit,
+    ) {
+        self.mode = GridMode::Insert;
+        self.editing_cell = Some(cell);
+        self.edit_kind = current.kind();
+        self.edit_text = current.text().to_string();
+        self.edit_initial = current;
+        self.original_value = original;
+    }
+
+    /// 编辑器当前的值
+    pub(crate) fn current_edit(&self) -> CellEdit {
+        CellEdit::from_editor(self.edit_kind, &self.edit_text, &self.edit_initial)
+    }
+
+    /// 单元格当前的值（含未保存的修改）；行或列不存在时返回 `None`
+    pub(crate) fn cell_edit(&self, result: &ResultSet, cell: (usize, usize)) -> Option<CellEdit> {
+        let (row_key, col) = cell;
+        if col >= result.column_count() {
+            return None;
+        }
+        if row_key < result.row_count {
+            return Some(
+                self.modified_cells
+                    .get(&cell)
+                    .cloned()
+                    .unwrap_or_else(|| CellEdit::from_value(result.cell(row_key, col))),
+            );
+        }
+        self.new_rows
+            .get(row_key - result.row_count)
+            .and_then(|row| row.get(col))
+            .cloned()
+    }
+
+    /// 写入单元格的值；已有行改回原值时撤销该处修改
+    pub(crate) fn set_cell_edit(
+        &mut self,
+        result: &ResultSet,
+        cell: (usize, usize),
+        edit: CellEdit,
+    ) {
+        let (row_key, col) = cell;
+        if row_key >= result.row_count {
+            if let Some(slot) = self
+                .new_rows
+                .get_mut(row_key - result.row_count)
+                .and_then(|row| row.get_mut(col))
+            {
+                *slot = edit;
+            }
+            return;
+        }
+        if col >= result.column_count() {
+            return;
+        }
+        if edit == CellEdit::from_value(result.cell(row_key, col)) {
+            self.modified_cells.remove(&cell);
+        } else {
+            self.modified_cells.insert(cell, edit);
+        }
+    }
+
     /// 获取选择范围
     pub fn get_selection(&self) -> Option<((usize, usize), (usize, usize))> {
         self.select_anchor.map(|anchor| {
@@ -0,0 +1,454 @@
+//! 选区与整列的统计
+//!
+//! 状态栏的聚合条与右侧检查器的列概况共用这里的计算：计数、NULL 数、去重数，
+//! 数值列的合计与平均，按 `DbValue::cmp_semantic` 取的最小 / 最大值，以及出现最多的值。
+//! 统计覆盖当前显示的行（筛选后）并计入未保存的修改与新增行；
+//! 结果按范围与数据版本缓存，数据和修改不变时不重复计算。
+
+use super::DataGrid;
+use super::cell_edit::{CellEdit, column_edit_types, parse_cell_text};
+use super::state::DataGridState;
+use crate::domain::result::ResultSet;
+use crate::domain::value::{DbTypeFamily, DbTypeInfo, DbValue};
+use crate::ui::styles::{GRAY, theme_accent, theme_text};
+use egui::{self, RichText};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// 列出的高频值个数
+const TOP_VALUE_COUNT: usize = 10;
+/// 缓存的统计结果个数（状态栏的选区与检查器的范围各占一个）
+const STATS_CACHE_SIZE: usize = 4;
+/// 直方图每行的高度
+const HISTOGRAM_ROW_HEIGHT: f32 = 18.0;
+/// 直方图与状态栏中单个值最多显示的字符数
+const VALUE_PREVIEW_CHARS: usize = 40;
+
+/// 统计范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsScope {
+    /// 选区的左上与右下角 (row, col)，行为行键
+    Selection((usize, usize), (usize, usize)),
+    /// 整列（筛选后的行与新增行）
+    Column(usize),
+}
+
+/// 统计结果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValueStats {
+    /// 参与统计的行数
+    pub rows: usize,
+    /// 参与统计的单元格数（取 DEFAULT 的新增单元格不计）
+    pub count: usize,
+    pub nulls: usize,
+    /// 不同的非 NULL 值个数（按显示文本区分）
+    pub distinct: usize,
+    /// 数值列中参与合计的值个数
+    pub numeric_count: usize,
+    /// 数值列的合计；没有数值时为 `None`
+    pub sum: Option<f64>,
+    pub min: Option<DbValue>,
+    pub max: Option<DbValue>,
+    /// 出现最多的非 NULL 值及次数，次数相同时按值排序
+    pub top_values: Vec<(String, usize)>,
+}
+
+impl ValueStats {
+    pub fn average(&self) -> Option<f64> {
+        self.sum.map(|sum| sum / self.numeric_count as f64)
+    }
+
+    /// 状态栏中的一行摘要
+    pub fn summary(&self) -> String {
+        let mut parts = vec![
+            format!("计数 {}", self.count),
+            format!("空 {}", self.nulls),
+            format!("去重 {}", self.distinct),
+        ];
+        if let (Some(sum), Some(average)) = (self.sum, self.average()) {
+            parts.push(format!("Σ {}", format_number(sum)));
+            parts.push(format!("平均 {}", format_number(average)));
+        }
+        if let Some(min) = &self.min {
+            parts.push(format!("最小 {}", value_preview(&min.display())));
+        }
+        if let Some(max) = &self.max {
+            parts.push(format!("最大 {}", value_preview(&max.display())));
+        }
+        parts.join(" · ")
+    }
+}
+
+/// 数值的显示：整数不带小数，其余最多保留 6 位小数
+pub fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        return format!("{:.0}", value);
+    }
+    let text = format!("{:.6}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// 单行、截断后的值预览
+pub(super) fn value_preview(text: &str) -> String {
+    let line = text.lines().next().unwrap_or_default();
+    let mut preview: String = line.chars().take(VALUE_PREVIEW_CHARS).collect();
+    if preview.len() < text.len() {
+        preview.push('…');
+    }
+    preview
+}
+
+pub(super) fn is_numeric_family(family: DbTypeFamily) -> bool {
+    matches!(
+        family,
+        DbTypeFamily::Integer | DbTypeFamily::Float | DbTypeFamily::Decimal
+    )
+}
+
+fn numeric_value(value: &DbValue) -> Option<f64> {
+    match value {
+        DbValue::Int(value) => Some(*value as f64),
+        DbValue::UInt(value) => Some(*value as f64),
+        DbValue::Float(value) => value.is_finite().then_some(*value),
+        DbValue::Decimal(value) => value.parse().ok(),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct StatsBuilder {
+    stats: ValueStats,
+    counts: HashMap<String, usize>,
+}
+
+impl StatsBuilder {
+    fn push(&mut self, value: &DbValue, numeric: bool) {
+        self.stats.count += 1;
+        if matches!(value, DbValue::Null) {
+            self.stats.nulls += 1;
+            return;
+        }
+        if numeric && let Some(number) = numeric_value(value) {
+            self.stats.numeric_count += 1;
+            *self.stats.sum.get_or_insert(0.0) += number;
+        }
+        if self
+            .stats
+            .min
+            .as_ref()
+            .is_none_or(|min| value.cmp_semantic(min) == Ordering::Less)
+        {
+            self.stats.min = Some(value.clone());
+        }
+        if self
+            .stats
+            .max
+            .as_ref()
+            .is_none_or(|max| value.cmp_semantic(max) == Ordering::Greater)
+        {
+            self.stats.max = Some(value.clone());
+        }
+        *self.counts.entry(value.display()).or_default() += 1;
+    }
+
+    fn finish(mut self) -> ValueStats {
+        self.stats.distinct = self.counts.len();
+        let mut top_values: Vec<(String, usize)> = self.counts.into_iter().collect();
+        top_values.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top_values.truncate(TOP_VALUE_COUNT);
+        self.stats.top_values = top_values;
+        self.stats
+    }
+}
+
+/// 统计一组值，`numeric` 表示这些值来自数值列
+pub(super) fn value_stats<'a>(
+    values: impl IntoIterator<Item = &'a DbValue>,
+    numeric: bool,
+) -> ValueStats {
+    let mut builder = StatsBuilder::default();
+    for value in values {
+        builder.stats.rows += 1;
+        builder.push(value, numeric);
+    }
+    builder.finish()
+}
+
+/// 单元格的当前值：未修改的已有单元格直接借用结果集，修改过的按列类型解析，
+/// 无法解析的修改按文本统计，取 DEFAULT 的单元格没有值
+fn current_value<'a>(
+    state: &DataGridState,
+    result: &'a ResultSet,
+    cell: (usize, usize),
+    type_info: &DbTypeInfo,
+) -> Option<Cow<'a, DbValue>> {
+    let (row_key, col) = cell;
+    if row_key < result.row_count && !state.modified_cells.contains_key(&cell) {
+        return Some(Cow::Borrowed(result.cell(row_key, col)));
+    }
+    let value = match state.cell_edit(result, cell)? {
+        CellEdit::Null => DbValue::Null,
+        CellEdit::Default => return None,
+        CellEdit::Bytes(bytes) => DbValue::Bytes(bytes),
+        CellEdit::Text(text) => parse_cell_text(&text, type_info).unwrap_or(DbValue::Text(text)),
+    };
+    Some(Cow::Owned(value))
+}
+
+/// 按范围统计筛选后的行与新增行
+fn compute_stats(
+    state: &DataGridState,
+    result: &ResultSet,
+    filtered_rows: &[usize],
+    scope: StatsScope,
+) -> ValueStats {
+    let column_types = column_edit_types(result, state.table_metadata.as_deref());
+    let last_column = result.column_count().saturating_sub(1);
+    let (rows, columns) = match scope {
+        StatsScope::Selection((min_row, min_col), (max_row, max_col)) => {
+            (Some(min_row..=max_row), min_col..=max_col.min(last_column))
+        }
+        StatsScope::Column(col) => (None, col..=col.min(last_column)),
+    };
+    if result.column_count() == 0 {
+        return ValueStats::default();
+    }
+
+    let new_row_keys = result.row_count..result.row_count + state.new_rows.len();
+    let row_keys = filtered_rows
+        .iter()
+        .copied()
+        .chain(new_row_keys)
+        .filter(|row_key| rows.as_ref().is_none_or(|rows| rows.contains(row_key)));
+
+    let mut builder = StatsBuilder::default();
+    for row_key in row_keys {
+        builder.stats.rows += 1;
+        for col in columns.clone() {
+            let type_info = &column_types[col];
+            if let Some(value) = current_value(state, result, (row_key, col), type_info) {
+                builder.push(&value, is_numeric_family(type_info.family));
+            }
+        }
+    }
+    builder.finish()
+}
+
+/// 表格当前显示数据的版本，结果集、筛选或待保存的修改变化时随之改变
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct DataVersion {
+    result: usize,
+    data_hash: u64,
+    row_count: usize,
+    filtered_rows: usize,
+    edits: u64,
+}
+
+impl DataVersion {
+    pub(super) fn new(state: &DataGridState, result: &ResultSet, filtered_rows: &[usize]) -> Self {
+        Self {
+            result: std::ptr::from_ref(result) as usize,
+            data_hash: DataGrid::calculate_data_hash(
+                result,
+                filtered_rows,
+                filtered_rows.len().min(100),
+            ),
+            row_count: result.row_count,
+            filtered_rows: filtered_rows.len(),
+            edits: state.edit_history.revision(),
+        }
+    }
+}
+
+/// 统计结果缓存
+#[derive(Debug, Clone, Default)]
+pub struct StatsCache {
+    entries: Vec<(StatsScope, DataVersion, ValueStats)>,
+}
+
+impl StatsCache {
+    fn get(&self, scope: StatsScope, version: DataVersion) -> Option<&ValueStats> {
+        self.entries
+            .iter()
+            .find(|(cached_scope, cached_version, _)| {
+                *cached_scope == scope && *cached_version == version
+            })
+            .map(|(_, _, stats)| stats)
+    }
+
+    fn insert(&mut self, scope: StatsScope, version: DataVersion, stats: ValueStats) {
+        self.entries.retain(|(cached_scope, cached_version, _)| {
+            *cached_scope != scope && *cached_version == version
+        });
+        if self.entries.len() >= STATS_CACHE_SIZE {
+            self.entries.remove(0);
+        }
+        self.entries.push((scope, version, stats));
+    }
+}
+
+/// 统计表格当前显示的数据
+///
+/// 行范围取表格上一次筛选的结果，未筛选过时取全部行。
+pub fn grid_stats(state: &mut DataGridState, result: &ResultSet, scope: StatsScope) -> ValueStats {
+    let all_rows: Vec<usize>;
+    let cache = &state.filter_cache;
+    let filtered_rows: &[usize] = if cache.valid && cache.last_row_count == result.row_count {
+        &cache.filtered_indices
+    } else {
+        all_rows = (0..result.row_count).collect();
+        &all_rows
+    };
+    let version = DataVersion::new(state, result, filtered_rows);
+    if let Some(stats) = state.stats_cache.get(scope, version) {
+        return stats.clone();
+    }
+    let stats = compute_stats(state, result, filtered_rows, scope);
+    state.stats_cache.insert(scope, version, stats.clone());
+    stats
+}
+
+/// 高频值直方图：条形长度与出现次数成正比，悬停显示完整值
+pub fn show_value_histogram(ui: &mut egui::Ui, stats: &ValueStats) {
+    let Some(max_count) = stats.top_values.first().map(|(_, count)| *count) else {
+        ui.label(RichText::new("没有非 NULL 值").small().color(GRAY));
+        return;
+    };
+    let bar_color = theme_accent(ui.visuals()).gamma_multiply(0.35);
+    let text_color = theme_text(ui.visuals());
+    let font = egui::FontId::monospace(12.0);
+    for (value, count) in &stats.top_values {
+        let (rect, response) = ui.allocate_exact_size(
+            egui::vec2(ui.available_width(), HISTOGRAM_ROW_HEIGHT),
+            egui::Sense::hover(),
+        );
+        let painter = ui.painter();
+        let fraction = *count as f32 / max_count as f32;
+        painter.rect_filled(
+            egui::Rect::from_min_size(rect.min, egui::vec2(rect.width() * fraction, rect.height())),
+            2.0,
+            bar_color,
+        );
+
+        let share = *count as f64 * 100.0 / stats.count.max(1) as f64;
+        let count_galley =
+            painter.layout_no_wrap(format!("{} ({:.1}%)", count, share), font.clone(), GRAY);
+        let count_pos = egui::pos2(
+            rect.right() - count_galley.size().x - 4.0,
+            rect.center().y - count_galley.size().y / 2.0,
+        );
+        let value_clip =
+            egui::Rect::from_min_max(rect.min, egui::pos2(count_pos.x - 8.0, rect.bottom()));
+        painter.galley(count_pos, count_galley, GRAY);
+        painter.with_clip_rect(value_clip).text(
+            egui::pos2(rect.left() + 4.0, rect.center().y),
+            egui::Align2::LEFT_CENTER,
+            value_preview(value),
+            font.clone(),
+            text_color,
+        );
+        response.on_hover_text(value.as_str());
+    }
+    if stats.distinct > stats.top_values.len() {
+        ui.label(
+            RichText::new(format!(
+                "另有 {} 个不同的值",
+                stats.distinct - stats.top_values.len()
+            ))
+            .small()
+            .color(GRAY),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::result::{ResultColumn, ResultCompleteness};
+
+    fn type_info(family: DbTypeFamily) -> DbTypeInfo {
+        DbTypeInfo {
+            family,
+            native_name: String::new(),
+            nullable: None,
+        }
+    }
+
+    fn sample_result() -> ResultSet {
+        let column = |name: &str, family| ResultColumn {
+            name: name.into(),
+            type_info: type_info(family),
+        };
+        ResultSet {
+            columns: std::sync::Arc::new([
+                column("amount", DbTypeFamily::Decimal),
+                column("city", DbTypeFamily::Text),
+            ]),
+            cells: vec![
+                DbValue::Decimal("10.5".into()),
+                DbValue::Text("Oslo".into()),
+                DbValue::Int(2),
+                DbValue::Null,
+                DbValue::Null,
+                DbValue::Text("Oslo".into()),
+                DbValue::Int(7),
+                DbValue::Text("Bergen".into()),
+            ],
+            row_count: 4,
+            completeness: ResultCompleteness::Complete,
+        }
+    }
+
+    #[test]
+    fn column_stats_include_pending_edits_and_new_rows() {
+        let result = sample_result();
+        let mut state = DataGridState::default();
+        state.modified_cells.insert((3, 0), CellEdit::from("1.25"));
+        state
+            .new_rows
+            .push(vec![CellEdit::from("4"), CellEdit::Default]);
+
+        let stats = grid_stats(&mut state, &result, StatsScope::Column(0));
+        assert_eq!((stats.rows, stats.count, stats.nulls), (5, 5, 1));
+        assert_eq!(stats.distinct, 4);
+        assert_eq!(stats.numeric_count, 4);
+        assert_eq!(stats.sum, Some(17.75));
+        assert_eq!(
+            stats.average().map(format_number).as_deref(),
+            Some("4.4375")
+        );
+        assert_eq!(stats.min, Some(DbValue::Decimal("1.25".into())));
+        assert_eq!(stats.max, Some(DbValue::Decimal("10.5".into())));
+
+        let stats = grid_stats(&mut state, &result, StatsScope::Column(1));
+        assert_eq!((stats.count, stats.nulls, stats.sum), (4, 1, None));
+        assert_eq!(
+            stats.top_values,
+            vec![("Oslo".to_string(), 2), ("Bergen".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn selection_stats_follow_filtered_rows_and_refresh_after_edits() {
+        let result = sample_result();
+        let mut state = DataGridState::default();
+        state.filter_cache.valid = true;
+        state.filter_cache.last_row_count = result.row_count;
+        state.filter_cache.filtered_indices = vec![0, 1, 3];
+        let scope = StatsScope::Selection((1, 0), (3, 1));
+
+        let stats = grid_stats(&mut state, &result, scope);
+        assert_eq!((stats.rows, stats.count, stats.nulls), (2, 4, 1));
+        assert_eq!(stats.sum, Some(9.0));
+        assert_eq!(
+            stats.summary(),
+            "计数 4 · 空 1 · 去重 3 · Σ 9 · 平均 4.5 · 最小 2 · 最大 Bergen"
+        );
+
+        state.modified_cells.insert((1, 0), CellEdit::Null);
+        state.record_edit_history();
+        let stats = grid_stats(&mut state, &result, scope);
+        assert_eq!((stats.nulls, stats.sum), (2, Some(7.0)));
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! 将查询结果中的已有行和未保存的新行统一成同一种可导航视图。
 
+use super::cell_edit::CellEdit;
 use crate::domain::result::ResultSet;
 
 #[derive(Debug, Clone, Copy)]
@@ -12,7 +13,7 @@ pub(crate) enum GridVirtualRow<'a> {
     },
     PendingNew {
         row_key: usize,
-        row_data: &'a [String],
+        row_data: &'a [CellEdit],
     },
 }
 
@@ -26,15 +27,17 @@ impl GridVirtualRow<'_> {
     pub(crate) fn display_row(self) -> Vec<String> {
         match self {
             Self::Existing { row_data, .. } => row_data.iter().map(|cell| cell.display()).collect(),
-            Self::PendingNew { row_data, .. } => row_data.to_vec(),
+            Self::PendingNew { row_data, .. } => row_data
+                .iter()
+                .map(|cell| cell.display_text().to_string())
+                .collect(),
         }
     }
 
-    pub(crate) fn display_cell(self, column_index: usize) -> Option<String> {
+    /// 单元格未经修改时的编辑值
+    pub(crate) fn edit_cell(self, column_index: usize) -> Option<CellEdit> {
         match self {
-            Self::Existing { row_data, .. } => {
-                row_data.get(column_index).map(|cell| cell.display())
-            }
+            Self::Existing { row_data, .. } => row_data.get(column_index).map(CellEdit::from_value),
             Self::PendingNew { row_data, .. } => row_data.get(column_index).cloned(),
         }
     }
@@ -43,14 +46,14 @@ impl GridVirtualRow<'_> {
 pub(crate) struct GridVirtualRows<'a> {
     result: &'a ResultSet,
     filtered_row_indices: &'a [usize],
-    new_rows: &'a [Vec<String>],
+    new_rows: &'a [Vec<CellEdit>],
 }
 
 impl<'a> GridVirtualRows<'a> {
     pub(crate) fn new(
         result: &'a ResultSet,
         filtered_row_indices: &'a [usize],
-        new_rows: &'a [Vec<String>],
+        new_rows: &'a [Vec<CellEdit>],
     ) -> Self {
         Self {
             result,
@@ -2,6 +2,7 @@
 //!
 //! 包含所有可重用的 UI 组件
 
+mod bytes_viewer;
 pub mod er_diagram;
 mod grid;
 mod notifications;
@@ -15,16 +16,27 @@ mod welcome;
 pub use toolbar::{Toolbar, ToolbarActions, ToolbarFocusTransfer};
 
 // SQL 编辑器
-pub use sql_editor::{EditorMode, SqlEditor, SqlEditorActions};
+pub use sql_editor::{EditorMode, SqlEditor, SqlEditorActions, SqlEditorTransaction};
 
 // 数据表格（Helix 风格）
 pub use grid::{
-    ColumnFilter, DataGrid, DataGridState, FilterCache, FilterLogic, FilterOperator, FocusTransfer,
-    GridMode, check_filter_match, escape_identifier, escape_value, quote_identifier,
+    CellDraft, CellEdit, CellEditKind, ColumnFilter, DataGrid, DataGridState, FilterCache,
+    FilterClause, FilterLogic, FilterOperator, FocusTransfer, GridMode, GridPageInfo,
+    ReferenceCandidate, ReferenceCandidates, ReferenceLookup, ReferenceNavigation, StatsScope,
+    begin_filter_group, check_filter_match, column_edit_types, common_depth, compile_filter_clause,
+    escape_identifier, escape_value, format_number, grid_stats, group_brackets, indent_filter,
+    outdent_filter, quote_identifier, quote_table_identifier, show_cell_editor,
+    show_value_histogram,
 };
 pub(crate) use grid::{
     GridCommandShortcut, GridSequenceConflictKind, grid_command_sequence_conflict,
-    grid_command_shortcuts, normalize_grid_command_sequence,
+    grid_command_shortcuts, history_shortcut_pressed, normalize_grid_command_sequence,
+};
+
+// 二进制值查看器
+pub use bytes_viewer::{
+    BytesViewMode, BytesViewerAction, BytesViewerState, format_byte_size, image_format,
+    show_bytes_viewer,
 };
 
 // 欢迎页面
@@ -150,6 +150,17 @@ pub struct SqlEditorActions {
     pub request_focus: bool,
     /// Escape 键已被编辑器消费（用于退出 Insert 模式）
     pub escape_consumed: bool,
+    pub toggle_manual_commit: bool,
+    pub commit: bool,
+    pub rollback: bool,
+}
+
+/// 工具栏显示的当前 Tab 事务模式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SqlEditorTransaction {
+    pub manual_commit: bool,
+    /// 未提交事务中已执行的语句数；`None` 表示没有打开的事务
+    pub open_statements: Option<usize>,
 }
 const STATUS_MESSAGE_MAX_CHARS: usize = 50;
 const STATUS_MESSAGE_PREVIEW_CHARS: usize = 47;
@@ -219,6 +230,7 @@ impl SqlEditor {
         request_focus: &mut bool,
         is_focused: bool,
         editor_mode: &mut EditorMode,
+        transaction: SqlEditorTransaction,
     ) -> SqlEditorActions {
         let mut actions = SqlEditorActions::default();
         let execute_shortcut = local_shortcut_text(LocalShortcut::SqlExecute);
@@ -239,6 +251,7 @@ impl SqlEditor {
             &mut actions,
             toolbar_height,
             *editor_mode,
+            transaction,
         );
 
         ui.add_space(2.0);
@@ -552,6 +565,7 @@ impl SqlEditor {
         actions: &mut SqlEditorActions,
         height: f32,
         mode: EditorMode,
+        transaction: SqlEditorTransaction,
     ) {
         ui.horizontal(|ui| {
             ui.set_height(height);
@@ -617,6 +631,38 @@ impl SqlEditor {
             ui.label(RichText::new("|").small().color(toolbar_separator_color));
             ui.add_space(8.0);
 
+            // 事务模式：手动提交时语句在独占连接的事务中执行
+            let (commit_mode, commit_mode_color) = if transaction.manual_commit {
+                ("手动提交", ui.visuals().warn_fg_color)
+            } else {
+                ("自动提交", toolbar_muted_color)
+            };
+            if ui
+                .add_enabled(
+                    transaction.open_statements.is_none(),
+                    egui::Button::new(RichText::new(commit_mode).small().color(commit_mode_color))
+                        .frame(false),
+                )
+                .on_hover_text("切换自动提交 / 手动提交")
+                .on_disabled_hover_text("请先提交或回滚当前事务")
+                .clicked()
+            {
+                actions.toggle_manual_commit = true;
+            }
+            if let Some(statements) = transaction.open_statements {
+                if icon_btn(
+                    ui,
+                    "✔",
+                    !is_executing,
+                    &format!("提交事务（{} 条语句）", statements),
+                ) {
+                    actions.commit = true;
+                }
+                if icon_btn(ui, "↩", !is_executing, "回滚事务") {
+                    actions.rollback = true;
+                }
+            }
+
             ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                 if mode == EditorMode::Normal {
                     ui.label(
@@ -1176,6 +1222,7 @@ mod tests {
                     &mut request_focus,
                     false,
                     &mut editor_mode,
+                    Default::default(),
                 )
             })
             .inner;
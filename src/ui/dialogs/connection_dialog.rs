@@ -6,7 +6,6 @@ use crate::data::{ConnectionConfig, DatabaseType, MySqlSslMode, PostgresSslMode,
 use crate::ui::styles::{DANGER, GRAY, MUTED, SPACING_MD, SPACING_SM, SUCCESS};
 use crate::ui::{LocalShortcut, local_shortcut_text, local_shortcut_tooltip, local_shortcuts_text};
 use egui::{self, Color32, CornerRadius, RichText, TextEdit};
-use std::path::Path;
 
 /// 输入验证结果
 struct ValidationResult {
@@ -31,60 +30,9 @@ impl ValidationResult {
 /// 验证连接配置
 fn validate_config(config: &ConnectionConfig) -> ValidationResult {
     let mut result = ValidationResult::new();
-
-    // 验证连接名称
-    if config.name.is_empty() {
-        result.add_error("连接名称不能为空");
-    } else if config.name.len() > 64 {
-        result.add_error("连接名称不能超过 64 个字符");
-    }
-
-    match config.db_type {
-        DatabaseType::SQLite => {
-            // SQLite 验证
-            if config.database.is_empty() {
-                result.add_error("数据库文件路径不能为空");
-            } else {
-                let path = Path::new(&config.database);
-                // 检查父目录是否存在
-                if let Some(parent) = path.parent()
-                    && !parent.as_os_str().is_empty()
-                    && !parent.exists()
-                {
-                    result.add_error(format!("目录不存在: {}", parent.display()));
-                }
-                // 检查文件扩展名
-                if let Some(ext) = path.extension() {
-                    let ext_lower = ext.to_string_lossy().to_lowercase();
-                    if !["db", "sqlite", "sqlite3", "s3db"].contains(&ext_lower.as_str()) {
-                        // 只是警告，不阻止保存
-                    }
-                }
-            }
-        }
-        DatabaseType::PostgreSQL | DatabaseType::MySQL => {
-            // 主机验证
-            if config.host.is_empty() {
-                result.add_error("主机地址不能为空");
-            } else if config.host.contains(' ') {
-                result.add_error("主机地址不能包含空格");
-            } else if config.host.len() > 255 {
-                result.add_error("主机地址过长");
-            }
-
-            // 端口验证（u16 类型范围已确保 0-65535）
-            if config.port == 0 {
-                result.add_error("端口号不能为 0");
-            }
-            // 注: 小于 1024 的端口是系统保留端口，但某些数据库可能使用
-
-            // 用户名验证（可选但推荐）
-            if config.username.len() > 128 {
-                result.add_error("用户名过长");
-            }
-        }
+    for error in config.validation_errors() {
+        result.add_error(error);
     }
-
     result
 }
 
@@ -366,6 +314,12 @@ impl ConnectionDialog {
                                             }
                                         },
                                     );
+                                    DialogContent::section_with_description(
+                                        ui,
+                                        "Schema",
+                                        "侧边栏与自动补全会列出所有可访问的 schema。",
+                                        |ui| Self::show_postgres_schema_config(ui, config),
+                                    );
                                 }
                                 DatabaseType::SQLite => {}
                             }
@@ -777,6 +731,23 @@ impl ConnectionDialog {
         action
     }
 
+    /// PostgreSQL schema 配置
+    fn show_postgres_schema_config(ui: &mut egui::Ui, config: &mut ConnectionConfig) {
+        Self::show_responsive_labeled_row(ui, "search_path", |ui, row_class| {
+            let control_width = Self::control_width(ui, row_class, 320.0);
+            ui.add_sized(
+                [control_width, 0.0],
+                TextEdit::singleline(&mut config.search_path)
+                    .hint_text("public, app")
+                    .char_limit(512),
+            );
+        });
+        DialogContent::info_text(
+            ui,
+            "逗号分隔，连接建立时设置；未限定 schema 的表名按此顺序解析。留空使用服务器默认值。",
+        );
+    }
+
     /// SSH 隧道配置
     fn show_ssh_tunnel_config(
         ui: &mut egui::Ui,
@@ -3,7 +3,7 @@
 //! 支持的快捷键：
 //! - `Esc` / `q` - 关闭对话框
 //! - `Enter` - 导出（当配置有效时）
-//! - `1/2/3/4` - 快速选择格式 (CSV/TSV/SQL/JSON)
+//! - `1/2/3/4/5/6/7/8` - 快速选择格式 (CSV/TSV/SQL/JSON/Excel/Parquet/Arrow/NDJSON)
 //! - `h/l` - 切换格式
 //! - `j/k` - 在列选择中导航
 //! - `gg/G` - 跳转到首/末列
@@ -14,9 +14,9 @@ use super::common::{
     DialogContent, DialogFooter, DialogShortcutContext, DialogStatus, DialogStyle, DialogWindow,
 };
 use crate::core::{
-    ExportFormat, SqlDialect, TransferDelimitedOptions, TransferDirection, TransferFormatOptions,
-    TransferJsonOptions, TransferMapping, TransferRowWindow, TransferSchema, TransferSession,
-    TransferSqlOptions, preview_export_transfer,
+    ExportFormat, SqlDialect, TransferColumnarOptions, TransferDelimitedOptions, TransferDirection,
+    TransferFormatOptions, TransferJsonOptions, TransferMapping, TransferRowWindow, TransferSchema,
+    TransferSession, TransferSpreadsheetOptions, TransferSqlOptions, preview_export_transfer,
 };
 use crate::data::DatabaseType;
 use crate::domain::result::ResultSet;
@@ -50,6 +50,8 @@ pub struct ExportConfig {
     pub sql_batch_size: usize,
     /// JSON: 是否美化输出
     pub json_pretty: bool,
+    /// Parquet: 每个行组的行数
+    pub parquet_row_group_rows: usize,
     /// 键盘导航: 当前选中的列索引
     #[doc(hidden)]
     pub nav_column_index: usize,
@@ -68,6 +70,7 @@ impl Default for ExportConfig {
             sql_use_transaction: true,
             sql_batch_size: 100,
             json_pretty: true,
+            parquet_row_group_rows: TransferColumnarOptions::default().row_group_rows,
             nav_column_index: 0,
         }
     }
@@ -147,11 +150,28 @@ impl ExportConfig {
                     dialect: SqlDialect::from(db_type),
                     ..Default::default()
                 }),
-                ExportFormat::Json => TransferFormatOptions::Json(TransferJsonOptions {
-                    pretty: self.json_pretty,
-                    ..Default::default()
-                }),
+                ExportFormat::Json | ExportFormat::Ndjson => {
+                    TransferFormatOptions::Json(TransferJsonOptions {
+                        pretty: self.json_pretty,
+                        ..Default::default()
+                    })
+                }
+                ExportFormat::Xlsx => {
+                    TransferFormatOptions::Spreadsheet(TransferSpreadsheetOptions {
+                        include_header: self.csv_include_header,
+                        ..Default::default()
+                    })
+                }
+                ExportFormat::Parquet | ExportFormat::ArrowIpc => {
+                    TransferFormatOptions::Columnar(TransferColumnarOptions {
+                        row_group_rows: self.parquet_row_group_rows,
+                    })
+                }
+                ExportFormat::Markdown | ExportFormat::Html | ExportFormat::Latex => {
+                    TransferFormatOptions::Delimited(TransferDelimitedOptions::default())
+                }
             },
+            write: Default::default(),
         }
     }
 }
@@ -179,6 +199,13 @@ const CMD_EXPORT_FORMAT_CSV: &str = "dialog.export.format_csv";
 const CMD_EXPORT_FORMAT_TSV: &str = "dialog.export.format_tsv";
 const CMD_EXPORT_FORMAT_SQL: &str = "dialog.export.format_sql";
 const CMD_EXPORT_FORMAT_JSON: &str = "dialog.export.format_json";
+const CMD_EXPORT_FORMAT_XLSX: &str = "dialog.export.format_xlsx";
+const CMD_EXPORT_FORMAT_PARQUET: &str = "dialog.export.format_parquet";
+const CMD_EXPORT_FORMAT_ARROW: &str = "dialog.export.format_arrow";
+const CMD_EXPORT_FORMAT_NDJSON: &str = "dialog.export.format_ndjson";
+const CMD_EXPORT_FORMAT_MARKDOWN: &str = "dialog.export.format_markdown";
+const CMD_EXPORT_FORMAT_HTML: &str = "dialog.export.format_html";
+const CMD_EXPORT_FORMAT_LATEX: &str = "dialog.export.format_latex";
 const CMD_EXPORT_CYCLE_PREV: &str = "dialog.export.cycle_prev";
 const CMD_EXPORT_CYCLE_NEXT: &str = "dialog.export.cycle_next";
 const CMD_EXPORT_COLUMN_PREV: &str = "dialog.export.column_prev";
@@ -200,10 +227,17 @@ impl ExportDialog {
 
     fn previous_format(format: ExportFormat) -> ExportFormat {
         match format {
-            ExportFormat::Csv => ExportFormat::Json,
+            ExportFormat::Csv => ExportFormat::Latex,
             ExportFormat::Tsv => ExportFormat::Csv,
             ExportFormat::Sql => ExportFormat::Tsv,
             ExportFormat::Json => ExportFormat::Sql,
+            ExportFormat::Xlsx => ExportFormat::Json,
+            ExportFormat::Parquet => ExportFormat::Xlsx,
+            ExportFormat::ArrowIpc => ExportFormat::Parquet,
+            ExportFormat::Ndjson => ExportFormat::ArrowIpc,
+            ExportFormat::Markdown => ExportFormat::Ndjson,
+            ExportFormat::Html => ExportFormat::Markdown,
+            ExportFormat::Latex => ExportFormat::Html,
         }
     }
 
@@ -212,7 +246,14 @@ impl ExportDialog {
             ExportFormat::Csv => ExportFormat::Tsv,
             ExportFormat::Tsv => ExportFormat::Sql,
             ExportFormat::Sql => ExportFormat::Json,
-            ExportFormat::Json => ExportFormat::Csv,
+            ExportFormat::Json => ExportFormat::Xlsx,
+            ExportFormat::Xlsx => ExportFormat::Parquet,
+            ExportFormat::Parquet => ExportFormat::ArrowIpc,
+            ExportFormat::ArrowIpc => ExportFormat::Ndjson,
+            ExportFormat::Ndjson => ExportFormat::Markdown,
+            ExportFormat::Markdown => ExportFormat::Html,
+            ExportFormat::Html => ExportFormat::Latex,
+            ExportFormat::Latex => ExportFormat::Csv,
         }
     }
 
@@ -247,6 +288,34 @@ impl ExportDialog {
                 CMD_EXPORT_FORMAT_JSON,
                 ExportKeyAction::SetFormat(ExportFormat::Json),
             ),
+            (
+                CMD_EXPORT_FORMAT_XLSX,
+                ExportKeyAction::SetFormat(ExportFormat::Xlsx),
+            ),
+            (
+                CMD_EXPORT_FORMAT_PARQUET,
+                ExportKeyAction::SetFormat(ExportFormat::Parquet),
+            ),
+            (
+                CMD_EXPORT_FORMAT_ARROW,
+                ExportKeyAction::SetFormat(ExportFormat::ArrowIpc),
+            ),
+            (
+                CMD_EXPORT_FORMAT_NDJSON,
+                ExportKeyAction::SetFormat(ExportFormat::Ndjson),
+            ),
+            (
+                CMD_EXPORT_FORMAT_MARKDOWN,
+                ExportKeyAction::SetFormat(ExportFormat::Markdown),
+            ),
+            (
+                CMD_EXPORT_FORMAT_HTML,
+                ExportKeyAction::SetFormat(ExportFormat::Html),
+            ),
+            (
+                CMD_EXPORT_FORMAT_LATEX,
+                ExportKeyAction::SetFormat(ExportFormat::Latex),
+            ),
             (CMD_EXPORT_CYCLE_PREV, ExportKeyAction::CycleFormatPrev),
             (CMD_EXPORT_CYCLE_NEXT, ExportKeyAction::CycleFormatNext),
         ]) {
@@ -524,6 +593,48 @@ impl ExportDialog {
                     "JSON",
                     LocalShortcut::ExportFormatJson,
                 ),
+                (
+                    ExportFormat::Xlsx,
+                    "📗",
+                    "Excel",
+                    LocalShortcut::ExportFormatXlsx,
+                ),
+                (
+                    ExportFormat::Parquet,
+                    "🧱",
+                    "Parquet",
+                    LocalShortcut::ExportFormatParquet,
+                ),
+                (
+                    ExportFormat::ArrowIpc,
+                    "🏹",
+                    "Arrow",
+                    LocalShortcut::ExportFormatArrow,
+                ),
+                (
+                    ExportFormat::Ndjson,
+                    "📜",
+                    "NDJSON",
+                    LocalShortcut::ExportFormatNdjson,
+                ),
+                (
+                    ExportFormat::Markdown,
+                    "📑",
+                    "Markdown",
+                    LocalShortcut::ExportFormatMarkdown,
+                ),
+                (
+                    ExportFormat::Html,
+                    "🌐",
+                    "HTML",
+                    LocalShortcut::ExportFormatHtml,
+                ),
+                (
+                    ExportFormat::Latex,
+                    "📐",
+                    "LaTeX",
+                    LocalShortcut::ExportFormatLatex,
+                ),
             ]
             .iter()
             {
@@ -715,6 +826,13 @@ impl ExportDialog {
             ExportFormat::Tsv => Self::show_tsv_options(ui, config),
             ExportFormat::Sql => Self::show_sql_options(ui, config),
             ExportFormat::Json => Self::show_json_options(ui, config),
+            ExportFormat::Xlsx => Self::show_xlsx_options(ui, config),
+            ExportFormat::Parquet => Self::show_parquet_options(ui, config),
+            ExportFormat::ArrowIpc => Self::show_columnar_note(ui),
+            ExportFormat::Ndjson => Self::show_ndjson_options(ui),
+            ExportFormat::Markdown | ExportFormat::Html | ExportFormat::Latex => {
+                Self::show_table_markup_note(ui)
+            }
         }
     }
 
@@ -783,6 +901,67 @@ impl ExportDialog {
         });
     }
 
+    /// Excel 选项
+    fn show_xlsx_options(ui: &mut egui::Ui, config: &mut ExportConfig) {
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut config.csv_include_header, "包含表头");
+            ui.label(RichText::new("(加粗并冻结首行)").small().color(MUTED));
+        });
+        ui.label(
+            RichText::new("数字与日期按单元格类型写入，NULL 留空；超过 15 位的数字写为文本。")
+                .small()
+                .color(MUTED),
+        );
+    }
+
+    /// NDJSON 选项
+    fn show_ndjson_options(ui: &mut egui::Ui) {
+        ui.label(
+            RichText::new("每行一个 JSON 对象，JSON 列按原结构嵌套写出。")
+                .small()
+                .color(MUTED),
+        );
+    }
+
+    /// Parquet 选项
+    fn show_parquet_options(ui: &mut egui::Ui, config: &mut ExportConfig) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("行组:").small().color(GRAY));
+            for (label, rows) in [
+                ("1 万行", 10_000),
+                ("10 万行", 100_000),
+                ("100 万行", 1_000_000),
+            ] {
+                if ui
+                    .selectable_label(config.parquet_row_group_rows == rows, label)
+                    .clicked()
+                {
+                    config.parquet_row_group_rows = rows;
+                }
+            }
+            ui.label(RichText::new("(zstd 压缩)").small().color(MUTED));
+        });
+        Self::show_columnar_note(ui);
+    }
+
+    /// 列式格式的类型说明
+    fn show_columnar_note(ui: &mut egui::Ui) {
+        ui.label(
+            RichText::new("列类型按数据库类型写出，DECIMAL 保留精度；无法无损转换的值会中止导出。")
+                .small()
+                .color(MUTED),
+        );
+    }
+
+    /// Markdown / HTML / LaTeX 说明
+    fn show_table_markup_note(ui: &mut egui::Ui) {
+        ui.label(
+            RichText::new("始终包含表头；特殊字符按目标格式转义，NULL 写为空单元格。")
+                .small()
+                .color(MUTED),
+        );
+    }
+
     /// 导出预览（折叠面板）
     fn show_preview(
         ui: &mut egui::Ui,
@@ -817,6 +996,13 @@ impl ExportDialog {
             ExportFormat::Tsv => "TSV 固定使用制表符，仍可控制表头输出。",
             ExportFormat::Sql => "控制事务包裹和批量插入策略。",
             ExportFormat::Json => "控制 JSON 是否美化输出。",
+            ExportFormat::Xlsx => "写出带类型单元格的 Excel 工作簿。",
+            ExportFormat::Parquet => "写出带列类型的 Parquet 文件，可调整行组大小。",
+            ExportFormat::ArrowIpc => "写出带列类型的 Arrow IPC 文件。",
+            ExportFormat::Ndjson => "逐行写出 JSON 对象，适合日志与大数据量交换。",
+            ExportFormat::Markdown => "写出 GFM 管道表格，适合贴到 Issue 与 Wiki。",
+            ExportFormat::Html => "写出 HTML <table>，适合贴到文档与邮件。",
+            ExportFormat::Latex => "写出 LaTeX tabular 环境，适合论文与报告。",
         }
     }
 
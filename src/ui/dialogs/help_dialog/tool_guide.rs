@@ -1,6 +1,6 @@
 use super::*;
 use crate::core::{Action, KeyBindings};
-use crate::ui::{LocalShortcut, local_shortcut_text};
+use crate::ui::{LocalShortcut, local_shortcut_text, local_shortcuts_text};
 
 impl HelpDialog {
     pub(super) fn show_tool_guide(ui: &mut egui::Ui, keybindings: &KeyBindings) {
@@ -27,6 +27,12 @@ impl HelpDialog {
         let save = Self::binding_or(keybindings, Action::Save, "Ctrl+S");
         let add_filter = local_shortcut_text(LocalShortcut::FilterAdd);
         let clear_filters = local_shortcut_text(LocalShortcut::FilterClearAll);
+        let group_filters = local_shortcuts_text(&[
+            LocalShortcut::FilterGroupIndent,
+            LocalShortcut::FilterGroupOutdent,
+            LocalShortcut::FilterGroupBegin,
+        ]);
+        let save_filter_preset = local_shortcut_text(LocalShortcut::FilterSavePreset);
         let goto_line = Self::binding_or(keybindings, Action::GotoLine, "Ctrl+G");
         let zoom_in = Self::binding_or(keybindings, Action::ZoomIn, "Ctrl++");
         let zoom_out = Self::binding_or(keybindings, Action::ZoomOut, "Ctrl+-");
@@ -209,7 +215,10 @@ impl HelpDialog {
                 ("/ / f".into(), "打开筛选 / 为当前列添加筛选"),
                 ("o / O".into(), "在下方 / 上方插入新行"),
                 ("dd / yy / p".into(), "删除标记当前行 / 复制整行 / 粘贴"),
-                ("u / U".into(), "撤销修改 / 取消删除标记"),
+                ("Ctrl+V".into(), "从剪贴板粘贴 TSV / CSV 块（预览后写入）"),
+                ("u / Ctrl+Y".into(), "撤销 / 重做未保存的修改"),
+                ("U".into(), "取消删除标记"),
+                (":g".into(), "分组 / 透视视图（Esc 返回表格）"),
                 (save.clone(), "保存修改"),
             ],
             key_color,
@@ -279,6 +288,8 @@ impl HelpDialog {
             &[
                 (add_filter, "添加筛选条件"),
                 (clear_filters, "清空筛选条件"),
+                (group_filters, "缩进 / 移出 / 新建筛选分组"),
+                (save_filter_preset, "保存筛选预设（命令面板中按名称应用）"),
                 (export, "导出结果"),
                 (import, "导入数据"),
                 (refresh, "刷新当前结果或工作区"),
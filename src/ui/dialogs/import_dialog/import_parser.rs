@@ -28,6 +28,7 @@ pub fn parse_sql_file(content: &str, config: &SqlImportConfig) -> ImportPreview
             stop_on_error: config.stop_on_error,
             ..Default::default()
         }),
+        write: Default::default(),
     };
 
     preview_sql_transfer_content(content, &session)
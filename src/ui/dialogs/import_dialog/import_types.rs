@@ -1,10 +1,13 @@
 //! 导入相关类型定义
 
 use crate::core::{
-    SqlDialect, TransferDelimitedOptions, TransferDirection, TransferFormat as CoreTransferFormat,
+    ImportWriteMode, ImportWriteOptions, SheetHeader, SheetLayout, SqlDialect,
+    TransferDelimitedOptions, TransferDirection, TransferFormat as CoreTransferFormat,
     TransferFormatOptions, TransferJsonOptions, TransferPreview as CoreTransferPreview,
-    TransferRowWindow, TransferSchema, TransferSession, TransferSqlOptions,
+    TransferRowWindow, TransferSchema, TransferSession, TransferSpreadsheetOptions,
+    TransferSqlOptions,
 };
+use crate::data::DatabaseType;
 use std::path::PathBuf;
 
 /// 导入格式
@@ -15,6 +18,8 @@ pub enum ImportFormat {
     Csv,
     Tsv,
     Json,
+    Xlsx,
+    Ndjson,
 }
 
 impl ImportFormat {
@@ -24,15 +29,19 @@ impl ImportFormat {
             ImportFormat::Csv => CoreTransferFormat::Csv,
             ImportFormat::Tsv => CoreTransferFormat::Tsv,
             ImportFormat::Json => CoreTransferFormat::Json,
+            ImportFormat::Xlsx => CoreTransferFormat::Xlsx,
+            ImportFormat::Ndjson => CoreTransferFormat::Ndjson,
         }
     }
 
     pub fn previous(self) -> Self {
         match self {
-            ImportFormat::Sql => ImportFormat::Json,
+            ImportFormat::Sql => ImportFormat::Ndjson,
             ImportFormat::Csv => ImportFormat::Sql,
             ImportFormat::Tsv => ImportFormat::Csv,
             ImportFormat::Json => ImportFormat::Tsv,
+            ImportFormat::Xlsx => ImportFormat::Json,
+            ImportFormat::Ndjson => ImportFormat::Xlsx,
         }
     }
 
@@ -41,7 +50,9 @@ impl ImportFormat {
             ImportFormat::Sql => ImportFormat::Csv,
             ImportFormat::Csv => ImportFormat::Tsv,
             ImportFormat::Tsv => ImportFormat::Json,
-            ImportFormat::Json => ImportFormat::Sql,
+            ImportFormat::Json => ImportFormat::Xlsx,
+            ImportFormat::Xlsx => ImportFormat::Ndjson,
+            ImportFormat::Ndjson => ImportFormat::Sql,
         }
     }
 
@@ -50,6 +61,8 @@ impl ImportFormat {
             "csv" => ImportFormat::Csv,
             "tsv" | "tab" => ImportFormat::Tsv,
             "json" => ImportFormat::Json,
+            "ndjson" | "jsonl" => ImportFormat::Ndjson,
+            "xlsx" | "xlsm" | "xlsb" | "xls" | "ods" => ImportFormat::Xlsx,
             _ => ImportFormat::Sql,
         }
     }
@@ -68,6 +81,8 @@ impl ImportFormat {
             ImportFormat::Csv => "📊",
             ImportFormat::Tsv => "↹",
             ImportFormat::Json => "🔧",
+            ImportFormat::Xlsx => "📗",
+            ImportFormat::Ndjson => "📜",
         }
     }
 
@@ -77,6 +92,8 @@ impl ImportFormat {
             ImportFormat::Csv => "CSV",
             ImportFormat::Tsv => "TSV",
             ImportFormat::Json => "JSON",
+            ImportFormat::Xlsx => "Excel",
+            ImportFormat::Ndjson => "NDJSON",
         }
     }
 }
@@ -146,10 +163,10 @@ impl Default for CsvImportConfig {
     }
 }
 
-/// JSON 导入配置
+/// JSON / NDJSON 导入配置
 #[derive(Debug, Clone, Default)]
 pub struct JsonImportConfig {
-    /// JSON 路径（如 "data.items"）
+    /// JSON 路径（如 "data.items"），NDJSON 不使用
     pub json_path: String,
     /// 目标表名
     pub table_name: String,
@@ -157,6 +174,19 @@ pub struct JsonImportConfig {
     pub flatten_nested: bool,
 }
 
+/// Excel 导入配置
+#[derive(Debug, Clone, Default)]
+pub struct SpreadsheetImportConfig {
+    /// 目标表名
+    pub table_name: String,
+    /// 工作表名，未选择时读取第一个
+    pub sheet: Option<String>,
+    /// 表头行
+    pub header: SheetHeader,
+    /// 最近一次预览读到的工作簿结构
+    pub layout: Option<SheetLayout>,
+}
+
 /// 导入预览数据
 #[derive(Debug, Clone, Default)]
 pub struct ImportPreview {
@@ -207,6 +237,12 @@ pub struct ImportState {
     pub csv_config: CsvImportConfig,
     /// JSON 配置
     pub json_config: JsonImportConfig,
+    /// Excel 配置
+    pub xlsx_config: SpreadsheetImportConfig,
+    /// 结构化导入的写入方式
+    pub write_mode: ImportWriteMode,
+    /// 冲突判定列（主键或唯一键）
+    pub conflict_columns: Vec<String>,
     /// 预览数据
     pub preview: Option<ImportPreview>,
     /// 是否正在加载
@@ -220,17 +256,23 @@ impl ImportState {
         Self::default()
     }
 
-    pub fn to_transfer_session(&self, use_mysql_syntax: bool) -> TransferSession {
+    /// 结构化导入的目标表名；SQL 导入没有目标表
+    pub fn target_table_name(&self) -> Option<&str> {
+        match self.format {
+            ImportFormat::Sql => None,
+            ImportFormat::Csv | ImportFormat::Tsv => Some(&self.csv_config.table_name),
+            ImportFormat::Json | ImportFormat::Ndjson => Some(&self.json_config.table_name),
+            ImportFormat::Xlsx => Some(&self.xlsx_config.table_name),
+        }
+    }
+
+    pub fn to_transfer_session(&self, db_type: DatabaseType) -> TransferSession {
         let source_name = self
             .file_path
             .as_ref()
             .map(|path| path.display().to_string());
-        let target_name = match self.format {
-            ImportFormat::Sql => None,
-            ImportFormat::Csv | ImportFormat::Tsv => Some(self.csv_config.table_name.clone()),
-            ImportFormat::Json => Some(self.json_config.table_name.clone()),
-        };
-        let dialect = if use_mysql_syntax {
+        let target_name = self.target_table_name().map(str::to_string);
+        let dialect = if db_type == DatabaseType::MySQL {
             SqlDialect::MySql
         } else {
             SqlDialect::Standard
@@ -269,16 +311,38 @@ impl ImportState {
                         include_header: false,
                     })
                 }
-                ImportFormat::Json => TransferFormatOptions::Json(TransferJsonOptions {
-                    pretty: true,
-                    json_path: if self.json_config.json_path.trim().is_empty() {
-                        None
-                    } else {
-                        Some(self.json_config.json_path.clone())
-                    },
-                    flatten_nested: self.json_config.flatten_nested,
-                    max_rows: 0,
-                }),
+                ImportFormat::Json | ImportFormat::Ndjson => {
+                    TransferFormatOptions::Json(TransferJsonOptions {
+                        pretty: true,
+                        json_path: if self.format == ImportFormat::Ndjson
+                            || self.json_config.json_path.trim().is_empty()
+                        {
+                            None
+                        } else {
+                            Some(self.json_config.json_path.clone())
+                        },
+                        flatten_nested: self.json_config.flatten_nested,
+                        max_rows: 0,
+                    })
+                }
+                ImportFormat::Xlsx => {
+                    TransferFormatOptions::Spreadsheet(TransferSpreadsheetOptions {
+                        include_header: false,
+                        sheet: self.xlsx_config.sheet.clone(),
+                        header: self.xlsx_config.header,
+                        max_rows: 0,
+                    })
+                }
+            },
+            write: ImportWriteOptions {
+                mode: self.write_mode,
+                conflict_columns: self
+                    .conflict_columns
+                    .iter()
+                    .filter(|column| !column.is_empty())
+                    .cloned()
+                    .collect(),
+                db_type: Some(db_type),
             },
         }
     }
@@ -296,7 +360,12 @@ impl ImportState {
             .to_string();
 
         self.csv_config.table_name = table_name.clone();
-        self.json_config.table_name = table_name;
+        self.json_config.table_name = table_name.clone();
+        self.xlsx_config = SpreadsheetImportConfig {
+            table_name,
+            header: self.xlsx_config.header,
+            ..Default::default()
+        };
         if let Some(delimiter) = self.format.default_delimiter() {
             self.csv_config.delimiter = delimiter;
         }
@@ -316,10 +385,11 @@ mod tests {
 
     #[test]
     fn import_format_previous_cycles_backward() {
-        assert_eq!(ImportFormat::Sql.previous(), ImportFormat::Json);
+        assert_eq!(ImportFormat::Sql.previous(), ImportFormat::Ndjson);
         assert_eq!(ImportFormat::Csv.previous(), ImportFormat::Sql);
         assert_eq!(ImportFormat::Tsv.previous(), ImportFormat::Csv);
         assert_eq!(ImportFormat::Json.previous(), ImportFormat::Tsv);
+        assert_eq!(ImportFormat::Xlsx.previous(), ImportFormat::Json);
     }
 
     #[test]
@@ -327,7 +397,9 @@ mod tests {
         assert_eq!(ImportFormat::Sql.next(), ImportFormat::Csv);
         assert_eq!(ImportFormat::Csv.next(), ImportFormat::Tsv);
         assert_eq!(ImportFormat::Tsv.next(), ImportFormat::Json);
-        assert_eq!(ImportFormat::Json.next(), ImportFormat::Sql);
+        assert_eq!(ImportFormat::Json.next(), ImportFormat::Xlsx);
+        assert_eq!(ImportFormat::Xlsx.next(), ImportFormat::Ndjson);
+        assert_eq!(ImportFormat::Ndjson.next(), ImportFormat::Sql);
     }
 
     #[test]
@@ -335,6 +407,8 @@ mod tests {
         assert_eq!(ImportFormat::from_extension("tsv"), ImportFormat::Tsv);
         assert_eq!(ImportFormat::from_extension("TAB"), ImportFormat::Tsv);
         assert_eq!(ImportFormat::from_extension("csv"), ImportFormat::Csv);
+        assert_eq!(ImportFormat::from_extension("XLSX"), ImportFormat::Xlsx);
+        assert_eq!(ImportFormat::from_extension("jsonl"), ImportFormat::Ndjson);
     }
 }
 
@@ -1,9 +1,9 @@
-//! 数据导入对话框 - 支持 SQL/CSV/TSV/JSON 格式，提供预览和直接执行功能
+//! 数据导入对话框 - 支持 SQL/CSV/TSV/JSON/Excel/NDJSON 格式，提供预览和直接执行功能
 //!
 //! 支持的快捷键：
 //! - `Esc` - 关闭对话框
 //! - `Enter` - 执行导入/复制到编辑器
-//! - `1/2/3/4` - 快速选择格式 (SQL/CSV/TSV/JSON)
+//! - `1/2/3/4/5/6` - 快速选择格式 (SQL/CSV/TSV/JSON/Excel/NDJSON)
 //! - `h/l` - 切换格式
 //! - `Ctrl+R` - 刷新预览
 
@@ -17,6 +17,9 @@ use super::common::{
     DialogContent, DialogFooter, DialogShortcutContext, DialogStatus, DialogStyle, DialogWindow,
     FormDialogShell,
 };
+use crate::core::{ImportWriteMode, SheetHeader};
+use crate::data::DatabaseType;
+use crate::domain::metadata::TableMetadata;
 use crate::ui::styles::{DANGER, GRAY, MUTED, SPACING_SM};
 use crate::ui::{LocalShortcut, local_shortcut_text, local_shortcut_tooltip, local_shortcuts_text};
 use egui::{self, Color32, RichText, TextEdit};
@@ -41,6 +44,8 @@ const CMD_IMPORT_FORMAT_SQL: &str = "dialog.import.format_sql";
 const CMD_IMPORT_FORMAT_CSV: &str = "dialog.import.format_csv";
 const CMD_IMPORT_FORMAT_TSV: &str = "dialog.import.format_tsv";
 const CMD_IMPORT_FORMAT_JSON: &str = "dialog.import.format_json";
+const CMD_IMPORT_FORMAT_XLSX: &str = "dialog.import.format_xlsx";
+const CMD_IMPORT_FORMAT_NDJSON: &str = "dialog.import.format_ndjson";
 const CMD_IMPORT_CYCLE_PREV: &str = "dialog.import.cycle_prev";
 const CMD_IMPORT_CYCLE_NEXT: &str = "dialog.import.cycle_next";
 
@@ -111,6 +116,14 @@ impl ImportDialog {
                 CMD_IMPORT_FORMAT_JSON,
                 ImportKeyAction::SetFormat(ImportFormat::Json),
             ),
+            (
+                CMD_IMPORT_FORMAT_XLSX,
+                ImportKeyAction::SetFormat(ImportFormat::Xlsx),
+            ),
+            (
+                CMD_IMPORT_FORMAT_NDJSON,
+                ImportKeyAction::SetFormat(ImportFormat::Ndjson),
+            ),
             (CMD_IMPORT_CYCLE_PREV, ImportKeyAction::CycleFormatPrev),
             (CMD_IMPORT_CYCLE_NEXT, ImportKeyAction::CycleFormatNext),
         ]) {
@@ -128,7 +141,8 @@ impl ImportDialog {
         ctx: &egui::Context,
         show: &mut bool,
         state: &mut ImportState,
-        is_mysql: bool,
+        db_type: DatabaseType,
+        target_table: Option<&TableMetadata>,
     ) -> ImportAction {
         if !*show {
             return ImportAction::None;
@@ -246,12 +260,24 @@ impl ImportDialog {
                             |ui| match state.format {
                                 ImportFormat::Sql => Self::show_sql_options(ui, state),
                                 ImportFormat::Csv | ImportFormat::Tsv => {
-                                    Self::show_csv_options(ui, state, is_mysql)
+                                    Self::show_csv_options(ui, state)
+                                }
+                                ImportFormat::Json | ImportFormat::Ndjson => {
+                                    Self::show_json_options(ui, state)
                                 }
-                                ImportFormat::Json => Self::show_json_options(ui, state, is_mysql),
+                                ImportFormat::Xlsx => Self::show_xlsx_options(ui, state),
                             },
                         );
 
+                        if state.format != ImportFormat::Sql {
+                            DialogContent::section_with_description(
+                                ui,
+                                "写入方式",
+                                "目标表已有数据时，决定主键或唯一键冲突的行如何处理。",
+                                |ui| Self::show_write_options(ui, state, db_type, target_table),
+                            );
+                        }
+
                         DialogContent::section_with_description(
                             ui,
                             "导入预览",
@@ -376,6 +402,8 @@ impl ImportDialog {
                 ImportFormat::Csv,
                 ImportFormat::Tsv,
                 ImportFormat::Json,
+                ImportFormat::Xlsx,
+                ImportFormat::Ndjson,
             ];
 
             if matches!(row_class, ResponsiveRowClass::Narrow) {
@@ -464,7 +492,7 @@ impl ImportDialog {
     }
 
     /// CSV 选项
-    fn show_csv_options(ui: &mut egui::Ui, state: &mut ImportState, _is_mysql: bool) {
+    fn show_csv_options(ui: &mut egui::Ui, state: &mut ImportState) {
         Self::show_responsive_labeled_row(ui, "目标表", |ui, row_class| {
             let control_width = Self::control_width(ui, row_class, 220.0);
             ui.add_sized(
@@ -524,8 +552,8 @@ impl ImportDialog {
         Self::show_execute_options(ui, state);
     }
 
-    /// JSON 选项
-    fn show_json_options(ui: &mut egui::Ui, state: &mut ImportState, _is_mysql: bool) {
+    /// JSON / NDJSON 选项
+    fn show_json_options(ui: &mut egui::Ui, state: &mut ImportState) {
         let mut needs_refresh = false;
 
         Self::show_responsive_labeled_row(ui, "目标表", |ui, row_class| {
@@ -538,21 +566,24 @@ impl ImportDialog {
 
         ui.add_space(SPACING_SM);
 
-        Self::show_responsive_labeled_row(ui, "数据路径", |ui, row_class| {
-            let control_width = Self::control_width(ui, row_class, 320.0);
-            if ui
-                .add_sized(
-                    [control_width, 0.0],
-                    TextEdit::singleline(&mut state.json_config.json_path)
-                        .hint_text("例如: data.items (留空表示根数组)"),
-                )
-                .changed()
-            {
-                needs_refresh = true;
-            }
-        });
+        // NDJSON 每行一条记录，没有需要定位的根数组
+        if state.format == ImportFormat::Json {
+            Self::show_responsive_labeled_row(ui, "数据路径", |ui, row_class| {
+                let control_width = Self::control_width(ui, row_class, 320.0);
+                if ui
+                    .add_sized(
+                        [control_width, 0.0],
+                        TextEdit::singleline(&mut state.json_config.json_path)
+                            .hint_text("例如: data.items (留空表示根数组)"),
+                    )
+                    .changed()
+                {
+                    needs_refresh = true;
+                }
+            });
 
-        ui.add_space(SPACING_SM);
+            ui.add_space(SPACING_SM);
+        }
 
         if ui
             .checkbox(&mut state.json_config.flatten_nested, "展平嵌套对象")
@@ -569,6 +600,230 @@ impl ImportDialog {
         Self::show_execute_options(ui, state);
     }
 
+    /// Excel 选项
+    fn show_xlsx_options(ui: &mut egui::Ui, state: &mut ImportState) {
+        let mut needs_refresh = false;
+        let config = &mut state.xlsx_config;
+
+        Self::show_responsive_labeled_row(ui, "目标表", |ui, row_class| {
+            let control_width = Self::control_width(ui, row_class, 220.0);
+            ui.add_sized(
+                [control_width, 0.0],
+                TextEdit::singleline(&mut config.table_name).hint_text("表名"),
+            );
+        });
+
+        ui.add_space(SPACING_SM);
+
+        let sheets = config
+            .layout
+            .as_ref()
+            .map(|layout| layout.sheets.clone())
+            .unwrap_or_default();
+        let current_sheet = config
+            .sheet
+            .clone()
+            .or_else(|| config.layout.as_ref().map(|layout| layout.sheet.clone()))
+            .unwrap_or_else(|| "第一个工作表".to_string());
+        Self::show_responsive_labeled_row(ui, "工作表", |ui, row_class| {
+            let control_width = Self::control_width(ui, row_class, 220.0);
+            egui::ComboBox::from_id_salt("import_xlsx_sheet")
+                .width(control_width)
+                .selected_text(&current_sheet)
+                .show_ui(ui, |ui| {
+                    for sheet in &sheets {
+                        if ui
+                            .selectable_label(*sheet == current_sheet, sheet)
+                            .clicked()
+                            && *sheet != current_sheet
+                        {
+                            config.sheet = Some(sheet.clone());
+                            needs_refresh = true;
+                        }
+                    }
+                });
+        });
+
+        ui.add_space(SPACING_SM);
+
+        DialogContent::toolbar(ui, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                ui.label(RichText::new("表头:").color(GRAY));
+                let is_row = matches!(config.header, SheetHeader::Row(_));
+                if ui
+                    .selectable_label(config.header == SheetHeader::Detect, "自动检测")
+                    .clicked()
+                {
+                    config.header = SheetHeader::Detect;
+                    needs_refresh = true;
+                }
+                if ui.selectable_label(is_row, "指定行").clicked() && !is_row {
+                    // 从检测到的行开始调整
+                    let row = config
+                        .layout
+                        .as_ref()
+                        .and_then(|layout| layout.header_row)
+                        .unwrap_or(0);
+                    config.header = SheetHeader::Row(row);
+                    needs_refresh = true;
+                }
+                if ui
+                    .selectable_label(config.header == SheetHeader::None, "无表头")
+                    .clicked()
+                {
+                    config.header = SheetHeader::None;
+                    needs_refresh = true;
+                }
+
+                if let SheetHeader::Row(row) = &mut config.header {
+                    // 界面上的行号从 1 开始
+                    let mut display_row = *row + 1;
+                    if ui
+                        .add(egui::DragValue::new(&mut display_row).range(1..=1_048_576))
+                        .changed()
+                    {
+                        *row = display_row - 1;
+                        needs_refresh = true;
+                    }
+                } else if config.header == SheetHeader::Detect
+                    && state.preview.is_some()
+                    && let Some(layout) = &config.layout
+                {
+                    let detected = match layout.header_row {
+                        Some(row) => format!("(检测到第 {} 行)", row + 1),
+                        None => "(未检测到表头)".to_string(),
+                    };
+                    ui.label(RichText::new(detected).small().color(MUTED));
+                }
+            });
+        });
+
+        if needs_refresh {
+            state.preview = None;
+            state.error = None;
+        }
+
+        Self::show_execute_options(ui, state);
+    }
+
+    /// 写入方式与冲突键
+    fn show_write_options(
+        ui: &mut egui::Ui,
+        state: &mut ImportState,
+        db_type: DatabaseType,
+        target_table: Option<&TableMetadata>,
+    ) {
+        let mut needs_refresh = false;
+
+        Self::show_responsive_labeled_row(ui, "方式", |ui, _row_class| {
+            ui.horizontal_wrapped(|ui| {
+                for mode in ImportWriteMode::ALL {
+                    if ui
+                        .selectable_label(state.write_mode == mode, mode.label())
+                        .clicked()
+                        && state.write_mode != mode
+                    {
+                        state.write_mode = mode;
+                        needs_refresh = true;
+                    }
+                }
+            });
+        });
+
+        if state.write_mode.uses_conflict_key() {
+            let candidates = conflict_key_candidates(target_table);
+            // 需要冲突键时默认选中主键
+            if state.conflict_columns.is_empty()
+                && state.write_mode.requires_conflict_key(db_type)
+                && let Some((_, columns)) = candidates.first()
+            {
+                state.conflict_columns = columns.clone();
+                needs_refresh = true;
+            }
+
+            ui.add_space(SPACING_SM);
+
+            Self::show_responsive_labeled_row(ui, "冲突键", |ui, row_class| {
+                let control_width = Self::control_width(ui, row_class, 320.0);
+                if candidates.is_empty() {
+                    // 没有表结构时手动输入列名
+                    let mut text = state.conflict_columns.join(", ");
+                    if ui
+                        .add_sized(
+                            [control_width, 0.0],
+                            TextEdit::singleline(&mut text).hint_text("列名，以逗号分隔"),
+                        )
+                        .changed()
+                    {
+                        state.conflict_columns = if text.trim().is_empty() {
+                            Vec::new()
+                        } else {
+                            text.split(',').map(|c| c.trim().to_string()).collect()
+                        };
+                        needs_refresh = true;
+                    }
+                    return;
+                }
+
+                let selected_text = candidates
+                    .iter()
+                    .find(|(_, columns)| *columns == state.conflict_columns)
+                    .map(|(label, _)| label.clone())
+                    .unwrap_or_else(|| {
+                        if state.conflict_columns.is_empty() {
+                            "未选择".to_string()
+                        } else {
+                            state.conflict_columns.join(", ")
+                        }
+                    });
+                egui::ComboBox::from_id_salt("import_conflict_key")
+                    .width(control_width)
+                    .selected_text(selected_text)
+                    .show_ui(ui, |ui| {
+                        if !state.write_mode.requires_conflict_key(db_type)
+                            && ui
+                                .selectable_label(state.conflict_columns.is_empty(), "不指定")
+                                .clicked()
+                        {
+                            state.conflict_columns.clear();
+                            needs_refresh = true;
+                        }
+                        for (label, columns) in &candidates {
+                            if ui
+                                .selectable_label(state.conflict_columns == *columns, label)
+                                .clicked()
+                                && state.conflict_columns != *columns
+                            {
+                                state.conflict_columns = columns.clone();
+                                needs_refresh = true;
+                            }
+                        }
+                    });
+            });
+
+            if db_type == DatabaseType::MySQL {
+                ui.label(
+                    RichText::new("MySQL 按表上全部主键与唯一键判断冲突；UPSERT 不更新冲突键列")
+                        .small()
+                        .color(MUTED),
+                );
+            }
+        }
+
+        if state.write_mode == ImportWriteMode::TruncateInsert {
+            ui.label(
+                RichText::new("⚠ 导入前会清空目标表的全部数据")
+                    .small()
+                    .color(DANGER),
+            );
+        }
+
+        if needs_refresh {
+            state.preview = None;
+            state.error = None;
+        }
+    }
+
     /// 执行选项
     fn show_execute_options(ui: &mut egui::Ui, state: &mut ImportState) {
         if state.mode != ImportMode::Execute {
@@ -643,7 +898,7 @@ impl ImportDialog {
         }
     }
 
-    /// 表格预览（CSV/TSV/JSON）
+    /// 表格预览（CSV/TSV/JSON/NDJSON/Excel）
     fn show_table_preview(ui: &mut egui::Ui, preview: &ImportPreview) {
         use egui_extras::{Column, TableBuilder};
 
@@ -702,6 +957,8 @@ impl ImportDialog {
             ImportFormat::Csv => "CSV 解析选项",
             ImportFormat::Tsv => "TSV 解析选项",
             ImportFormat::Json => "JSON 解析选项",
+            ImportFormat::Xlsx => "Excel 解析选项",
+            ImportFormat::Ndjson => "NDJSON 解析选项",
         }
     }
 
@@ -711,6 +968,10 @@ impl ImportDialog {
             ImportFormat::Csv => "控制目标表、分隔符、表头和跳过行数。",
             ImportFormat::Tsv => "TSV 固定使用制表符，其余流程与 CSV 对齐。",
             ImportFormat::Json => "控制目标表、JSON 路径和嵌套对象展平策略。",
+            ImportFormat::Xlsx => "选择工作表和表头行，单元格按自身类型转换为 SQL 值。",
+            ImportFormat::Ndjson => {
+                "逐行读取，不限文件大小；嵌套对象写为 JSON 文本，无法解析的行在预览中列出并跳过。"
+            }
         }
     }
 
@@ -901,6 +1162,31 @@ impl ImportDialog {
     }
 }
 
+/// 目标表的主键与唯一键，作为冲突键候选：`(显示文本, 列)`
+fn conflict_key_candidates(table: Option<&TableMetadata>) -> Vec<(String, Vec<String>)> {
+    let Some(table) = table else {
+        return Vec::new();
+    };
+
+    let primary = table.primary_key.iter().map(|key| {
+        (
+            format!("主键 ({})", key.columns.join(", ")),
+            key.columns.clone(),
+        )
+    });
+    let unique = table.unique_keys.iter().map(|key| {
+        let label = match &key.name {
+            Some(name) => format!("唯一键 {} ({})", name, key.columns.join(", ")),
+            None => format!("唯一键 ({})", key.columns.join(", ")),
+        };
+        (label, key.columns.clone())
+    });
+    primary
+        .chain(unique)
+        .filter(|(_, columns)| !columns.is_empty())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
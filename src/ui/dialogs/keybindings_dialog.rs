@@ -1431,6 +1431,11 @@ fn scope_tree_entries() -> &'static [ScopeTreeEntry] {
             selection: ScopeTreeSelection::Scope("grid.insert"),
             title: "grid.insert",
         },
+        ScopeTreeEntry {
+            section: "表格",
+            selection: ScopeTreeSelection::Scope("grid.cell_editor"),
+            title: "grid.cell_editor",
+        },
     ]
 }
 
@@ -2460,6 +2465,13 @@ fn grid_command_description(command: GridCommandShortcut) -> &'static str {
         GridCommandShortcut::ScrollBottom => "表格滚动到底部",
         GridCommandShortcut::DeleteRow => "表格删除当前行",
         GridCommandShortcut::CopyRow => "表格复制当前行",
+        GridCommandShortcut::NextPage => "表格分页浏览下一页",
+        GridCommandShortcut::PreviousPage => "表格分页浏览上一页",
+        GridCommandShortcut::FollowReference => "表格打开外键引用的行",
+        GridCommandShortcut::ReferencingRows => "表格列出引用当前行的表",
+        GridCommandShortcut::Undo => "表格撤销修改",
+        GridCommandShortcut::Redo => "表格重做修改",
+        GridCommandShortcut::GroupView => "表格切换分组 / 透视视图",
     }
 }
 
@@ -2557,12 +2569,23 @@ fn local_shortcut_scope_tags(shortcut: LocalShortcut) -> &'static [&'static str]
         | LocalShortcut::FilterOperatorPrev
         | LocalShortcut::FilterLogicToggle
         | LocalShortcut::FilterFocusInput
-        | LocalShortcut::FilterCaseToggle => &["sidebar.filters.list", "sidebar.list"],
+        | LocalShortcut::FilterCaseToggle
+        | LocalShortcut::FilterGroupIndent
+        | LocalShortcut::FilterGroupOutdent
+        | LocalShortcut::FilterGroupBegin
+        | LocalShortcut::FilterSavePreset => &["sidebar.filters.list", "sidebar.list"],
         LocalShortcut::FilterInputDismiss => &["sidebar.filters.input"],
         LocalShortcut::ExportFormatCsv
         | LocalShortcut::ExportFormatTsv
         | LocalShortcut::ExportFormatSql
         | LocalShortcut::ExportFormatJson
+        | LocalShortcut::ExportFormatXlsx
+        | LocalShortcut::ExportFormatParquet
+        | LocalShortcut::ExportFormatArrow
+        | LocalShortcut::ExportFormatNdjson
+        | LocalShortcut::ExportFormatMarkdown
+        | LocalShortcut::ExportFormatHtml
+        | LocalShortcut::ExportFormatLatex
         | LocalShortcut::ExportCyclePrev
         | LocalShortcut::ExportCycleNext
         | LocalShortcut::ExportColumnPrev
@@ -2580,11 +2603,16 @@ fn local_shortcut_scope_tags(shortcut: LocalShortcut) -> &'static [&'static str]
         | LocalShortcut::SqlHistoryNext
         | LocalShortcut::SqlHistoryBrowse => &["editor.insert"],
         LocalShortcut::GridEditFinish => &["grid.insert"],
+        LocalShortcut::GridCellEditorApply | LocalShortcut::GridCellEditorCancel => {
+            &["grid.cell_editor"]
+        }
         LocalShortcut::ImportRefresh
         | LocalShortcut::ImportFormatSql
         | LocalShortcut::ImportFormatCsv
         | LocalShortcut::ImportFormatTsv
         | LocalShortcut::ImportFormatJson
+        | LocalShortcut::ImportFormatXlsx
+        | LocalShortcut::ImportFormatNdjson
         | LocalShortcut::ImportCyclePrev
         | LocalShortcut::ImportCycleNext => &["dialog.import", "dialog.common"],
         LocalShortcut::ConnectionTypeSqlite
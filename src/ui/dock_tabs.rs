@@ -364,8 +364,11 @@ impl TabViewer for WorkspaceViewer<'_> {
                 if self.app.tab_manager().tabs.len() <= 1 {
                     return OnCloseResponse::Ignore;
                 }
-                self.app.on_dock_tab_close(*index);
-                OnCloseResponse::Close
+                if self.app.on_dock_tab_close(*index) {
+                    OnCloseResponse::Close
+                } else {
+                    OnCloseResponse::Ignore
+                }
             }
             DockTab::Surface {
                 kind: WorkbenchSurfaceKind::ErDiagram,
@@ -380,9 +383,12 @@ impl TabViewer for WorkspaceViewer<'_> {
                 if self.app.tab_manager().tabs.len() <= 1 {
                     return OnCloseResponse::Ignore;
                 }
-                // 清理：持久化状态、取消查询、移除工作区
-                self.app.on_dock_tab_close(*index);
-                OnCloseResponse::Close
+                // 清理：持久化状态、取消查询、移除工作区；有未提交事务时等待确认
+                if self.app.on_dock_tab_close(*index) {
+                    OnCloseResponse::Close
+                } else {
+                    OnCloseResponse::Ignore
+                }
             }
             DockTab::ErDiagram => {
                 self.app.toggle_er_diagram_visibility();
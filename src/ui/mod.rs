@@ -11,16 +11,25 @@ pub mod workbench;
 // 重新导出常用组件
 #[allow(unused_imports)] // 公开 API，供外部使用
 pub use components::{
+    // 二进制值查看器
+    BytesViewMode,
+    BytesViewerAction,
+    BytesViewerState,
+    CellDraft,
+    CellEdit,
+    CellEditKind,
     ColumnFilter,
     DataGrid,
     DataGridState,
     // 其他组件
     EditorMode,
     FilterCache,
+    FilterClause,
     FilterLogic,
     FilterOperator,
     FocusTransfer,
     GridMode,
+    GridPageInfo,
     // 通知组件
     NotificationToast,
     // 进度指示器
@@ -29,8 +38,14 @@ pub use components::{
     QueryTab,
     QueryTabBar,
     QueryTabManager,
+    ReferenceCandidate,
+    ReferenceCandidates,
+    ReferenceLookup,
+    ReferenceNavigation,
     SqlEditor,
     SqlEditorActions,
+    SqlEditorTransaction,
+    StatsScope,
     TabBarActions,
     TabBarFocusTransfer,
     Toolbar,
@@ -43,7 +58,11 @@ pub use components::{
     WelcomeServiceState,
     WelcomeStatusSummary,
     // 数据表格相关
+    begin_filter_group,
     check_filter_match,
+    column_edit_types,
+    common_depth,
+    compile_filter_clause,
     // ER 关系图
     er_diagram::{
         ERCardDisplayMode, ERColumn, ERComponent, ERComponentDirection, ERDiagramResponse,
@@ -56,7 +75,18 @@ pub use components::{
     },
     escape_identifier,
     escape_value,
+    format_byte_size,
+    format_number,
+    grid_stats,
+    group_brackets,
+    image_format,
+    indent_filter,
+    outdent_filter,
     quote_identifier,
+    quote_table_identifier,
+    show_bytes_viewer,
+    show_cell_editor,
+    show_value_histogram,
 };
 #[allow(unused_imports)] // 公开 API，供外部使用
 pub use dialogs::{
@@ -120,10 +150,11 @@ pub use shortcut_tooltip::{
 };
 pub use workbench::{
     SurfaceAction, WorkbenchActivityBar, WorkbenchActivityBarResponse, WorkbenchBottomPanel,
-    WorkbenchBottomPanelResponse, WorkbenchRightInspector, WorkbenchRightInspectorResponse,
-    WorkbenchShell, WorkbenchStatusBarContent, WorkbenchSurfaceHeader,
-    WorkbenchSurfaceHeaderResponse, bottom_panel_tab_label, bottom_panel_tabs,
-    right_inspector_tab_label, right_inspector_tabs, surface_icon_button, surface_tooltip,
+    WorkbenchBottomPanelResponse, WorkbenchPlanView, WorkbenchRightInspector,
+    WorkbenchRightInspectorResponse, WorkbenchShell, WorkbenchStatusBarContent,
+    WorkbenchSurfaceHeader, WorkbenchSurfaceHeaderResponse, bottom_panel_tab_label,
+    bottom_panel_tabs, right_inspector_tab_label, right_inspector_tabs, surface_icon_button,
+    surface_tooltip,
 };
 
 /// 全局焦点区域
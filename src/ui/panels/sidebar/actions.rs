@@ -87,6 +87,8 @@ pub struct SidebarActions {
     pub focus_filter_input: Option<usize>,
     /// 切换筛选条件的列 (索引, true=下一个/false=上一个)
     pub cycle_filter_column: Option<(usize, bool)>,
+    /// 以给定名称把当前筛选条件保存为预设
+    pub save_filter_preset: Option<String>,
     /// 展开触发器面板时请求重新加载（避免显示 DDL 前的陈旧缓存——审计 SM-9）
     pub request_load_triggers: bool,
     /// 展开存储过程面板时请求重新加载（审计 SM-9）
@@ -117,5 +119,6 @@ impl SidebarActions {
             || self.toggle_filter_logic.is_some()
             || self.focus_filter_input.is_some()
             || self.cycle_filter_column.is_some()
+            || self.save_filter_preset.is_some()
     }
 }
@@ -1,8 +1,8 @@
 //! 连接列表渲染
 
 use super::{
-    DatabaseList, SidebarActions, SidebarDeleteTarget, SidebarPanelState, SidebarSelectionState,
-    TableList,
+    DatabaseList, SidebarActions, SidebarDeleteTarget, SidebarPanelState, SidebarSchemaObjects,
+    SidebarSelectionState, TableList,
 };
 use crate::core::{Action, KeyBindings};
 use crate::data::ConnectionManager;
@@ -200,6 +200,10 @@ impl ConnectionList {
                             is_nav_selected,
                             &panel_state.selection,
                             panel_state.loading_tables,
+                            panel_state
+                                .schema_objects
+                                .as_deref()
+                                .filter(|objects| objects.connection == *name),
                         );
                     }
                 }
@@ -352,6 +356,7 @@ impl ConnectionList {
         is_nav_selected: bool,
         selection: &SidebarSelectionState,
         loading_tables: bool,
+        schema_objects: Option<&SidebarSchemaObjects>,
     ) {
         // 先提取需要的数据，避免借用冲突
         let conn_data = {
@@ -418,6 +423,7 @@ impl ConnectionList {
                             focused_section,
                             selection,
                             loading_tables,
+                            schema_objects,
                         );
                     } else if conn_data.is_connected {
                         // SQLite 模式：直接显示表列表
@@ -433,6 +439,16 @@ impl ConnectionList {
                             selection,
                             loading_tables,
                         );
+                        if let Some(objects) = schema_objects {
+                            TableList::show_schema_objects(
+                                ui,
+                                name,
+                                objects,
+                                connection_manager,
+                                selected_table,
+                                actions,
+                            );
+                        }
                     }
 
                     // 错误显示
@@ -1,6 +1,8 @@
 //! 数据库列表渲染
 
-use super::{ConnectionList, SidebarActions, SidebarSelectionState, TableList};
+use super::{
+    ConnectionList, SidebarActions, SidebarSchemaObjects, SidebarSelectionState, TableList,
+};
 use crate::data::ConnectionManager;
 use crate::ui::SidebarSection;
 use crate::ui::styles::{MUTED, SPACING_LG, theme_accent, theme_selection_fill, theme_text};
@@ -25,6 +27,7 @@ impl DatabaseList {
         focused_section: SidebarSection,
         selection: &SidebarSelectionState,
         loading_tables: bool,
+        schema_objects: Option<&SidebarSchemaObjects>,
     ) {
         // 数据库区域是否高亮
         let highlight_databases = is_focused && focused_section == SidebarSection::Databases;
@@ -115,6 +118,7 @@ impl DatabaseList {
                         ui,
                         conn_name,
                         tables,
+                        schema_objects.and_then(|objects| objects.default_schema.as_deref()),
                         connection_manager,
                         selected_table,
                         actions,
@@ -133,6 +137,16 @@ impl DatabaseList {
                         }
                     });
                 }
+                if let Some(objects) = schema_objects {
+                    TableList::show_schema_objects(
+                        ui,
+                        conn_name,
+                        objects,
+                        connection_manager,
+                        selected_table,
+                        actions,
+                    );
+                }
             }
         }
     }
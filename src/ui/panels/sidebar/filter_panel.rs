@@ -6,16 +6,23 @@ use crate::core::KeyBindings;
 use crate::ui::styles::{GRAY, MUTED, SUCCESS, theme_selection_fill, theme_subtle_stroke};
 use crate::ui::{
     ColumnFilter, FilterLogic, FilterOperator, LocalShortcut, SidebarPanelState, SidebarSection,
-    consume_local_shortcut, local_shortcuts_tooltip,
+    common_depth, consume_local_shortcut, group_brackets, local_shortcuts_tooltip,
 };
 use egui::{self, Color32, CornerRadius, RichText, TextEdit, Vec2};
 
+/// 每层分组的缩进宽度
+const GROUP_INDENT: f32 = 10.0;
+/// 缩进的最大层数（更深的分组不再继续缩进）
+const MAX_GROUP_INDENT_LEVELS: usize = 6;
+
 /// 筛选面板
 pub(crate) struct FilterPanel;
 
 pub(crate) struct FilterPanelResult {
     pub changed: bool,
     pub clicked: bool,
+    /// 用户确认保存的预设名称
+    pub save_preset: Option<String>,
 }
 
 impl FilterPanel {
@@ -37,6 +44,7 @@ impl FilterPanel {
         let mut changed = false;
         let mut clicked = false;
         let mut filter_to_remove: Option<usize> = None;
+        let mut save_preset: Option<String> = None;
         panel_state.begin_filter_workspace_frame();
 
         // 标题栏
@@ -82,6 +90,27 @@ impl FilterPanel {
                     changed = true;
                 }
 
+                // 保存预设按钮
+                if !filters.is_empty()
+                    && ui
+                        .add(
+                            egui::Button::new(
+                                RichText::new("存")
+                                    .size(11.0)
+                                    .color(Color32::from_rgb(100, 160, 220)),
+                            )
+                            .frame(false)
+                            .min_size(Vec2::new(18.0, 18.0)),
+                        )
+                        .on_hover_text(local_shortcuts_tooltip(
+                            "保存为筛选预设",
+                            &[LocalShortcut::FilterSavePreset],
+                        ))
+                        .clicked()
+                {
+                    panel_state.filter_preset_name = Some(String::new());
+                }
+
                 // 清空按钮
                 if !filters.is_empty()
                     && ui
@@ -108,6 +137,34 @@ impl FilterPanel {
 
         ui.add_space(2.0);
 
+        // 预设名称输入：回车保存，Esc 或失去焦点取消
+        if let Some(name) = panel_state.filter_preset_name.as_mut() {
+            let mut finished = false;
+            ui.horizontal(|ui| {
+                ui.label(RichText::new("预设").size(10.0).color(GRAY));
+                let response = ui.add(
+                    TextEdit::singleline(name)
+                        .desired_width(ui.available_width())
+                        .font(egui::TextStyle::Small)
+                        .hint_text("名称，回车保存"),
+                );
+                if !response.has_focus() && !response.lost_focus() {
+                    response.request_focus();
+                }
+                if response.lost_focus() {
+                    let confirmed = ui.input(|input| input.key_pressed(egui::Key::Enter));
+                    if confirmed && !name.trim().is_empty() {
+                        save_preset = Some(name.trim().to_string());
+                    }
+                    finished = true;
+                }
+            });
+            if finished {
+                panel_state.filter_preset_name = None;
+            }
+            ui.add_space(2.0);
+        }
+
         if is_focused && focused_section == SidebarSection::Filters {
             ui.horizontal_wrapped(|ui| {
                 ui.spacing_mut().item_spacing.x = 6.0;
@@ -119,6 +176,10 @@ impl FilterPanel {
                 ui.label(RichText::new("启用").small().color(MUTED));
                 ui.label(RichText::new("o").small().color(GRAY));
                 ui.label(RichText::new("逻辑").small().color(MUTED));
+                ui.label(RichText::new("> < (").small().color(GRAY));
+                ui.label(RichText::new("分组").small().color(MUTED));
+                ui.label(RichText::new("S").small().color(GRAY));
+                ui.label(RichText::new("存预设").small().color(MUTED));
                 ui.label(RichText::new("[ ]").small().color(GRAY));
                 ui.label(RichText::new("列").small().color(MUTED));
                 ui.label(RichText::new("- =").small().color(GRAY));
@@ -167,8 +228,20 @@ impl FilterPanel {
                     });
                 } else {
                     let filters_len = filters.len();
+                    // (打开的括号数, 关闭的括号数, 与下一条件连接所在的层数)
+                    let nesting: Vec<(usize, usize, usize)> = (0..filters_len)
+                        .map(|idx| {
+                            let (opened, closed) = group_brackets(filters, idx);
+                            let link_depth = filters
+                                .get(idx + 1)
+                                .map_or(0, |next| common_depth(&filters[idx].groups, &next.groups));
+                            (opened, closed, link_depth)
+                        })
+                        .collect();
                     for (idx, filter) in filters.iter_mut().enumerate() {
                         let is_last = idx == filters_len - 1;
+                        let (opened, closed, link_depth) = nesting[idx];
+                        let row_indent = group_indent(filter.groups.len());
 
                         ui.push_id(format!("filter_{}", idx), |ui| {
                             let is_nav_selected = is_focused
@@ -204,6 +277,10 @@ impl FilterPanel {
                             };
 
                             let row_response = egui::Frame::NONE
+                                .outer_margin(egui::Margin {
+                                    left: row_indent as i8,
+                                    ..Default::default()
+                                })
                                 .fill(bg_color)
                                 .stroke(stroke)
                                 .corner_radius(CornerRadius::same(3))
@@ -225,6 +302,14 @@ impl FilterPanel {
                                             );
                                         }
 
+                                        if opened > 0 {
+                                            ui.label(
+                                                RichText::new("(".repeat(opened))
+                                                    .size(10.0)
+                                                    .color(GRAY),
+                                            );
+                                        }
+
                                         // 启用复选框
                                         let checkbox_response = ui
                                             .add(egui::Checkbox::without_text(&mut filter.enabled));
@@ -324,6 +409,14 @@ impl FilterPanel {
                                                 });
                                         });
 
+                                        if closed > 0 {
+                                            ui.label(
+                                                RichText::new(")".repeat(closed))
+                                                    .size(10.0)
+                                                    .color(GRAY),
+                                            );
+                                        }
+
                                         // 删除按钮
                                         ui.with_layout(
                                             egui::Layout::right_to_left(egui::Align::Center),
@@ -434,7 +527,7 @@ impl FilterPanel {
                             // AND/OR 逻辑（非最后一条）
                             if !is_last {
                                 ui.horizontal(|ui| {
-                                    ui.add_space(12.0);
+                                    ui.add_space(12.0 + group_indent(link_depth));
                                     let (logic_text, logic_color) = match filter.logic {
                                         FilterLogic::And => ("AND", Color32::from_rgb(80, 140, 80)),
                                         FilterLogic::Or => ("OR", Color32::from_rgb(180, 140, 60)),
@@ -481,10 +574,19 @@ impl FilterPanel {
             changed = true;
         }
 
-        FilterPanelResult { changed, clicked }
+        FilterPanelResult {
+            changed,
+            clicked,
+            save_preset,
+        }
     }
 }
 
+/// 分组层数对应的缩进宽度
+fn group_indent(depth: usize) -> f32 {
+    depth.min(MAX_GROUP_INDENT_LEVELS) as f32 * GROUP_INDENT
+}
+
 fn consume_filter_input_dismiss(ui: &mut egui::Ui) -> bool {
     ui.input_mut(|input| consume_local_shortcut(input, LocalShortcut::FilterInputDismiss))
 }
@@ -43,8 +43,8 @@ use connection_list::ConnectionList;
 use database_list::DatabaseList;
 use routine_panel::RoutinePanel;
 use state::{
-    SidebarWorkflowAction, SidebarWorkflowContext, SidebarWorkflowEffect, SidebarWorkflowReduction,
-    reduce_sidebar_workflow,
+    SidebarSchemaObjects, SidebarWorkflowAction, SidebarWorkflowContext, SidebarWorkflowEffect,
+    SidebarWorkflowReduction, reduce_sidebar_workflow,
 };
 use table_list::TableList;
 use trigger_panel::TriggerPanel;
@@ -92,6 +92,10 @@ enum SidebarKeyAction {
     FilterLogicToggle,
     FilterFocusInput,
     FilterCaseToggle,
+    FilterGroupIndent,
+    FilterGroupOutdent,
+    FilterGroupBegin,
+    SaveFilterPreset,
 }
 
 impl Sidebar {
@@ -184,6 +188,9 @@ impl Sidebar {
             if filter_panel_result.changed {
                 filter_changed = true;
             }
+            if let Some(name) = filter_panel_result.save_preset {
+                actions.save_filter_preset = Some(name);
+            }
             if filter_panel_result.clicked {
                 let workflow_context =
                     Self::sidebar_workflow_context(panel_state, connection_manager);
@@ -454,6 +461,11 @@ impl Sidebar {
         filters: &mut Vec<crate::ui::ColumnFilter>,
         actions: &mut SidebarActions,
     ) {
+        // 输入预设名称时按键交给文本框（回车保存，Esc 取消）
+        if panel_state.filter_preset_name.is_some() {
+            return;
+        }
+
         let workflow_context = Self::sidebar_workflow_context(panel_state, connection_manager);
 
         // 筛选输入是独立的 text-entry 工作区，只接受退出输入动作。
@@ -729,6 +741,29 @@ impl Sidebar {
                     actions.filter_changed = true;
                 }
             }
+            Some(SidebarKeyAction::FilterGroupIndent)
+                if focused_section == SidebarSection::Filters
+                    && crate::ui::indent_filter(filters, *selected_index) =>
+            {
+                actions.filter_changed = true;
+            }
+            Some(SidebarKeyAction::FilterGroupOutdent)
+                if focused_section == SidebarSection::Filters
+                    && crate::ui::outdent_filter(filters, *selected_index) =>
+            {
+                actions.filter_changed = true;
+            }
+            Some(SidebarKeyAction::FilterGroupBegin)
+                if focused_section == SidebarSection::Filters
+                    && crate::ui::begin_filter_group(filters, *selected_index) =>
+            {
+                actions.filter_changed = true;
+            }
+            Some(SidebarKeyAction::SaveFilterPreset)
+                if focused_section == SidebarSection::Filters && !filters.is_empty() =>
+            {
+                panel_state.filter_preset_name = Some(String::new());
+            }
             _ => {}
         }
 
@@ -892,6 +927,30 @@ impl Sidebar {
                 text_entry_active,
             ) {
                 Some(SidebarKeyAction::FilterFocusInput)
+            } else if consume_local_shortcut_with_text_priority(
+                i,
+                LocalShortcut::FilterGroupIndent,
+                text_entry_active,
+            ) {
+                Some(SidebarKeyAction::FilterGroupIndent)
+            } else if consume_local_shortcut_with_text_priority(
+                i,
+                LocalShortcut::FilterGroupOutdent,
+                text_entry_active,
+            ) {
+                Some(SidebarKeyAction::FilterGroupOutdent)
+            } else if consume_local_shortcut_with_text_priority(
+                i,
+                LocalShortcut::FilterGroupBegin,
+                text_entry_active,
+            ) {
+                Some(SidebarKeyAction::FilterGroupBegin)
+            } else if consume_local_shortcut_with_text_priority(
+                i,
+                LocalShortcut::FilterSavePreset,
+                text_entry_active,
+            ) {
+                Some(SidebarKeyAction::SaveFilterPreset)
             } else if consume_local_shortcut_with_text_priority(
                 i,
                 LocalShortcut::FilterCaseToggle,
@@ -1,6 +1,9 @@
 //! 侧边栏状态定义
 
+use std::sync::Arc;
+
 use crate::data::{RoutineInfo, TriggerInfo};
+use crate::domain::metadata::{SchemaCatalog, SequenceMetadata, ViewMetadata};
 use crate::ui::SidebarSection;
 
 /// 侧边栏各区域的选中索引
@@ -290,6 +293,23 @@ pub(crate) fn reduce_sidebar_workflow(
     }
 }
 
+/// 当前库的视图与序列，来自 SchemaCatalog（`get_tables` 只返回表）
+#[derive(Debug, Clone, Default)]
+pub struct SidebarSchemaObjects {
+    /// 所属连接名，仅在该连接下渲染
+    pub connection: String,
+    /// 默认 schema（PostgreSQL），用于侧边栏 schema 分组的标题
+    pub default_schema: Option<String>,
+    pub views: Vec<ViewMetadata>,
+    pub sequences: Vec<SequenceMetadata>,
+}
+
+impl SidebarSchemaObjects {
+    pub fn is_empty(&self) -> bool {
+        self.views.is_empty() && self.sequences.is_empty()
+    }
+}
+
 /// 侧边栏面板状态
 #[derive(Debug, Clone)]
 pub struct SidebarPanelState {
@@ -300,6 +320,8 @@ pub struct SidebarPanelState {
     pub connections_ratio: f32,
     /// 是否正在异步加载表列表（来自 `session.connecting`），用于区分"加载中"与"空 schema"——审计 SM-3
     pub loading_tables: bool,
+    /// 视图 / 物化视图 / 序列（面板状态每帧克隆，因此以 Arc 共享）
+    pub schema_objects: Option<Arc<SidebarSchemaObjects>>,
 
     // ===== 触发器面板 =====
     /// 触发器面板是否显示
@@ -334,6 +356,8 @@ pub struct SidebarPanelState {
     pub show_filters: bool,
     /// 筛选面板高度比例
     pub filters_ratio: f32,
+    /// 正在输入的筛选预设名称（`None` 表示未在保存预设）
+    pub filter_preset_name: Option<String>,
 
     // ===== 其他状态 =====
     /// 各区域的选中状态
@@ -355,6 +379,7 @@ impl Default for SidebarPanelState {
             show_connections: true,
             connections_ratio: 0.65,
             loading_tables: false,
+            schema_objects: None,
 
             // 触发器面板 - 默认关闭，按需展开
             show_triggers: false,
@@ -375,6 +400,7 @@ impl Default for SidebarPanelState {
             // 筛选面板 - 默认显示，和连接面板形成新手默认布局
             show_filters: true,
             filters_ratio: 0.35,
+            filter_preset_name: None,
 
             selection: SidebarSelectionState::default(),
             dragging_divider: None,
@@ -409,6 +435,21 @@ impl SidebarPanelState {
         self.workflow.filter_workspace == SidebarFilterWorkspaceMode::Input
     }
 
+    /// 用 SchemaCatalog 更新侧边栏的视图与序列
+    pub fn set_schema_objects(&mut self, connection: &str, catalog: &SchemaCatalog) {
+        self.schema_objects = Some(Arc::new(SidebarSchemaObjects {
+            connection: connection.to_string(),
+            default_schema: catalog.default_schema.clone(),
+            views: catalog.views.clone(),
+            sequences: catalog.sequences.clone(),
+        }));
+    }
+
+    /// 清空视图与序列（切换连接或数据库时调用）
+    pub fn clear_schema_objects(&mut self) {
+        self.schema_objects = None;
+    }
+
     /// 清空触发器列表
     pub fn clear_triggers(&mut self) {
         self.triggers.clear();
@@ -1,6 +1,6 @@
-//! 表列表渲染
+//! 表列表渲染（含 schema 分组、视图、物化视图与序列分组）
 
-use super::{ConnectionList, SidebarActions, SidebarSelectionState};
+use super::{ConnectionList, SidebarActions, SidebarSchemaObjects, SidebarSelectionState};
 use crate::data::ConnectionManager;
 use crate::ui::SidebarSection;
 use crate::ui::styles::{
@@ -121,84 +121,286 @@ impl TableList {
     }
 
     /// 显示嵌套的表列表（在数据库下方）
+    ///
+    /// PostgreSQL 的非默认 schema 表以 `schema.table` 命名；存在多个 schema 时按
+    /// schema 分组折叠显示，组内只显示表名，键盘导航仍按扁平下标计数。
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn show_nested(
         ui: &mut egui::Ui,
         conn_name: &str,
         tables: &[String],
+        default_schema: Option<&str>,
         connection_manager: &mut ConnectionManager,
         selected_table: &mut Option<String>,
         actions: &mut SidebarActions,
         highlight_tables: bool,
         nav_index: usize,
     ) {
-        // 表列表
+        let groups = Self::schema_groups(tables);
+        if groups.len() < 2 {
+            for (idx, table) in tables.iter().enumerate() {
+                let is_nav_selected = highlight_tables && idx == nav_index;
+                Self::show_nested_row(
+                    ui,
+                    conn_name,
+                    table,
+                    table,
+                    is_nav_selected,
+                    connection_manager,
+                    selected_table,
+                    actions,
+                );
+            }
+            return;
+        }
+
+        for (schema, range) in groups {
+            let title = match schema {
+                Some(schema) => schema.to_string(),
+                None => default_schema.unwrap_or("默认 schema").to_string(),
+            };
+            let holds_nav = highlight_tables && range.contains(&nav_index);
+            ui.indent(("sidebar_schema_group_indent", conn_name, &title), |ui| {
+                egui::CollapsingHeader::new(
+                    RichText::new(format!("{} ({})", title, range.len()))
+                        .small()
+                        .strong()
+                        .color(GRAY),
+                )
+                .id_salt(("sidebar_schema_group", conn_name, &title))
+                .default_open(schema.is_none())
+                .open(holds_nav.then_some(true))
+                .show(ui, |ui| {
+                    for idx in range {
+                        let table = &tables[idx];
+                        let label = table
+                            .split_once('.')
+                            .map_or(table.as_str(), |(_, name)| name);
+                        Self::show_nested_row(
+                            ui,
+                            conn_name,
+                            table,
+                            label,
+                            highlight_tables && idx == nav_index,
+                            connection_manager,
+                            selected_table,
+                            actions,
+                        );
+                    }
+                });
+            });
+        }
+    }
+
+    /// 按 schema 前缀把表切分为连续分组（`None` 表示默认 schema）
+    fn schema_groups(tables: &[String]) -> Vec<(Option<&str>, std::ops::Range<usize>)> {
+        let mut groups: Vec<(Option<&str>, std::ops::Range<usize>)> = Vec::new();
         for (idx, table) in tables.iter().enumerate() {
-            let is_nav_selected = highlight_tables && idx == nav_index;
-            let is_selected = selected_table.as_deref() == Some(table);
+            let schema = table.split_once('.').map(|(schema, _)| schema);
+            match groups.last_mut() {
+                Some((last, range)) if *last == schema => range.end = idx + 1,
+                _ => groups.push((schema, idx..idx + 1)),
+            }
+        }
+        groups
+    }
 
-            // 表项 - 带缩进
-            let row_response = ui
-                .horizontal(|ui| {
-                    ui.add_space(SPACING_LG);
+    #[allow(clippy::too_many_arguments)]
+    fn show_nested_row(
+        ui: &mut egui::Ui,
+        conn_name: &str,
+        table: &str,
+        label: &str,
+        is_nav_selected: bool,
+        connection_manager: &mut ConnectionManager,
+        selected_table: &mut Option<String>,
+        actions: &mut SidebarActions,
+    ) {
+        let is_selected = selected_table.as_deref() == Some(table);
 
-                    let table_bg = if is_nav_selected {
-                        theme_selection_fill(ui.visuals(), 35) // 键盘导航选中
-                    } else if is_selected {
-                        theme_selection_fill(ui.visuals(), 30)
+        // 表项 - 带缩进
+        let row_response = ui
+            .horizontal(|ui| {
+                ui.add_space(SPACING_LG);
+
+                let table_bg = if is_nav_selected {
+                    theme_selection_fill(ui.visuals(), 35) // 键盘导航选中
+                } else if is_selected {
+                    theme_selection_fill(ui.visuals(), 30)
+                } else {
+                    Color32::TRANSPARENT
+                };
+                let response = egui::Frame::NONE
+                    .fill(table_bg)
+                    .corner_radius(CornerRadius::same(4))
+                    .inner_margin(egui::Margin::symmetric(8, 4))
+                    .show(ui, |ui| {
+                        let text_color = if is_nav_selected {
+                            theme_accent(ui.visuals())
+                        } else if is_selected {
+                            theme_accent(ui.visuals()).gamma_multiply(0.85)
+                        } else {
+                            theme_text(ui.visuals())
+                        };
+                        let prefix = if is_nav_selected { "> " } else { "" };
+                        ui.label(RichText::new(format!("{}{}", prefix, label)).color(text_color));
+                    })
+                    .response
+                    .interact(egui::Sense::click());
+
+                // 左键点击 - 查询表数据
+                if response.clicked() {
+                    actions.section_change = Some(SidebarSection::Tables);
+                    *selected_table = Some(table.to_string());
+                    connection_manager.active = Some(conn_name.to_string());
+                    actions.query_table = Some(table.to_string());
+                }
+
+                // 右键菜单
+                response.context_menu(|ui| {
+                    if ui.button("查询前 100 行").clicked() {
+                        actions.query_table = Some(table.to_string());
+                        ui.close();
+                    }
+                    if ui.button("查看表结构").clicked() {
+                        actions.show_table_schema = Some(table.to_string());
+                        ui.close();
+                    }
+                    if ui.button("删除表").clicked() {
+                        ConnectionList::request_table_delete(conn_name, table, actions);
+                        ui.close();
+                    }
+                });
+            })
+            .response;
+
+        // 如果是选中项且有焦点，滚动到可见
+        if is_nav_selected {
+            row_response.scroll_to_me(Some(egui::Align::Center));
+        }
+    }
+
+    /// 显示视图 / 物化视图 / 序列分组（默认折叠，不参与键盘导航）
+    pub(crate) fn show_schema_objects(
+        ui: &mut egui::Ui,
+        conn_name: &str,
+        objects: &SidebarSchemaObjects,
+        connection_manager: &mut ConnectionManager,
+        selected_table: &mut Option<String>,
+        actions: &mut SidebarActions,
+    ) {
+        if objects.is_empty() {
+            return;
+        }
+        ui.add_space(SPACING_SM);
+
+        if !objects.views.is_empty() {
+            Self::show_object_group(ui, conn_name, "views", "视图", objects.views.len(), |ui| {
+                for view in &objects.views {
+                    let is_selected = selected_table.as_deref() == Some(view.name.as_str());
+                    let label = if view.materialized {
+                        format!("{} (物化)", view.name)
                     } else {
-                        Color32::TRANSPARENT
+                        view.name.clone()
                     };
-                    let response = egui::Frame::NONE
-                        .fill(table_bg)
-                        .corner_radius(CornerRadius::same(4))
-                        .inner_margin(egui::Margin::symmetric(8, 4))
-                        .show(ui, |ui| {
-                            let text_color = if is_nav_selected {
-                                theme_accent(ui.visuals())
-                            } else if is_selected {
-                                theme_accent(ui.visuals()).gamma_multiply(0.85)
-                            } else {
-                                theme_text(ui.visuals())
-                            };
-                            let prefix = if is_nav_selected { "> " } else { "" };
-                            ui.label(
-                                RichText::new(format!("{}{}", prefix, table)).color(text_color),
-                            );
-                        })
-                        .response
-                        .interact(egui::Sense::click());
+                    let mut response = Self::show_object_row(ui, &label, is_selected);
+                    if let Some(definition) = &view.definition {
+                        response = response.on_hover_text(definition.trim());
+                    }
 
-                    // 左键点击 - 查询表数据
+                    // 视图与表一样可以直接查询
                     if response.clicked() {
                         actions.section_change = Some(SidebarSection::Tables);
-                        *selected_table = Some(table.clone());
+                        *selected_table = Some(view.name.clone());
                         connection_manager.active = Some(conn_name.to_string());
-                        actions.query_table = Some(table.clone());
+                        actions.query_table = Some(view.name.clone());
                     }
-
-                    // 右键菜单
                     response.context_menu(|ui| {
                         if ui.button("查询前 100 行").clicked() {
-                            actions.query_table = Some(table.clone());
-                            ui.close();
-                        }
-                        if ui.button("查看表结构").clicked() {
-                            actions.show_table_schema = Some(table.clone());
+                            actions.query_table = Some(view.name.clone());
                             ui.close();
                         }
-                        if ui.button("删除表").clicked() {
-                            ConnectionList::request_table_delete(conn_name, table, actions);
+                        if ui.button("查看结构").clicked() {
+                            actions.show_table_schema = Some(view.name.clone());
                             ui.close();
                         }
                     });
-                })
-                .response;
+                }
+            });
+        }
 
-            // 如果是选中项且有焦点，滚动到可见
-            if is_nav_selected {
-                row_response.scroll_to_me(Some(egui::Align::Center));
-            }
+        if !objects.sequences.is_empty() {
+            Self::show_object_group(
+                ui,
+                conn_name,
+                "sequences",
+                "序列",
+                objects.sequences.len(),
+                |ui| {
+                    for sequence in &objects.sequences {
+                        let mut details = Vec::new();
+                        if let Some(data_type) = &sequence.data_type {
+                            details.push(format!("类型: {}", data_type));
+                        }
+                        if let Some(start) = sequence.start_value {
+                            details.push(format!("起始: {}", start));
+                        }
+                        if let Some(increment) = sequence.increment {
+                            details.push(format!("步长: {}", increment));
+                        }
+                        if let Some(owner) = &sequence.owned_by {
+                            details.push(format!("所属列: {}", owner));
+                        }
+                        let response = Self::show_object_row(ui, &sequence.name, false);
+                        if !details.is_empty() {
+                            response.on_hover_text(details.join("\n"));
+                        }
+                    }
+                },
+            );
         }
     }
+
+    fn show_object_group(
+        ui: &mut egui::Ui,
+        conn_name: &str,
+        id: &str,
+        title: &str,
+        count: usize,
+        add_body: impl FnOnce(&mut egui::Ui),
+    ) {
+        ui.indent(("sidebar_schema_objects_indent", conn_name, id), |ui| {
+            egui::CollapsingHeader::new(
+                RichText::new(format!("{} ({})", title, count))
+                    .small()
+                    .strong()
+                    .color(GRAY),
+            )
+            .id_salt(("sidebar_schema_objects", conn_name, id))
+            .default_open(false)
+            .show(ui, add_body);
+        });
+    }
+
+    fn show_object_row(ui: &mut egui::Ui, label: &str, is_selected: bool) -> egui::Response {
+        let fill = if is_selected {
+            theme_selection_fill(ui.visuals(), 25)
+        } else {
+            Color32::TRANSPARENT
+        };
+        egui::Frame::NONE
+            .fill(fill)
+            .corner_radius(CornerRadius::same(4))
+            .inner_margin(egui::Margin::symmetric(8, 2))
+            .show(ui, |ui| {
+                let color = if is_selected {
+                    theme_accent(ui.visuals()).gamma_multiply(0.85)
+                } else {
+                    theme_text(ui.visuals())
+                };
+                ui.label(RichText::new(label).color(color));
+            })
+            .response
+            .interact(egui::Sense::click())
+    }
 }
@@ -137,11 +137,22 @@ pub enum LocalShortcut {
     FilterLogicToggle,
     FilterFocusInput,
     FilterCaseToggle,
+    FilterGroupIndent,
+    FilterGroupOutdent,
+    FilterGroupBegin,
+    FilterSavePreset,
     FilterInputDismiss,
     ExportFormatCsv,
     ExportFormatTsv,
     ExportFormatSql,
     ExportFormatJson,
+    ExportFormatXlsx,
+    ExportFormatParquet,
+    ExportFormatArrow,
+    ExportFormatNdjson,
+    ExportFormatMarkdown,
+    ExportFormatHtml,
+    ExportFormatLatex,
     ExportCyclePrev,
     ExportCycleNext,
     ExportColumnPrev,
@@ -159,11 +170,15 @@ pub enum LocalShortcut {
     SqlHistoryNext,
     SqlHistoryBrowse,
     GridEditFinish,
+    GridCellEditorApply,
+    GridCellEditorCancel,
     ImportRefresh,
     ImportFormatSql,
     ImportFormatCsv,
     ImportFormatTsv,
     ImportFormatJson,
+    ImportFormatXlsx,
+    ImportFormatNdjson,
     ImportCyclePrev,
     ImportCycleNext,
     ConnectionTypeSqlite,
@@ -279,11 +294,22 @@ impl LocalShortcut {
             Self::FilterLogicToggle,
             Self::FilterFocusInput,
             Self::FilterCaseToggle,
+            Self::FilterGroupIndent,
+            Self::FilterGroupOutdent,
+            Self::FilterGroupBegin,
+            Self::FilterSavePreset,
             Self::FilterInputDismiss,
             Self::ExportFormatCsv,
             Self::ExportFormatTsv,
             Self::ExportFormatSql,
             Self::ExportFormatJson,
+            Self::ExportFormatXlsx,
+            Self::ExportFormatParquet,
+            Self::ExportFormatArrow,
+            Self::ExportFormatNdjson,
+            Self::ExportFormatMarkdown,
+            Self::ExportFormatHtml,
+            Self::ExportFormatLatex,
             Self::ExportCyclePrev,
             Self::ExportCycleNext,
             Self::ExportColumnPrev,
@@ -301,11 +327,15 @@ impl LocalShortcut {
             Self::SqlHistoryNext,
             Self::SqlHistoryBrowse,
             Self::GridEditFinish,
+            Self::GridCellEditorApply,
+            Self::GridCellEditorCancel,
             Self::ImportRefresh,
             Self::ImportFormatSql,
             Self::ImportFormatCsv,
             Self::ImportFormatTsv,
             Self::ImportFormatJson,
+            Self::ImportFormatXlsx,
+            Self::ImportFormatNdjson,
             Self::ImportCyclePrev,
             Self::ImportCycleNext,
             Self::ConnectionTypeSqlite,
@@ -421,11 +451,22 @@ impl LocalShortcut {
             LocalShortcut::FilterLogicToggle => "sidebar.filters.logic_toggle",
             LocalShortcut::FilterFocusInput => "sidebar.filters.focus_input",
             LocalShortcut::FilterCaseToggle => "sidebar.filters.case_toggle",
+            LocalShortcut::FilterGroupIndent => "sidebar.filters.group_indent",
+            LocalShortcut::FilterGroupOutdent => "sidebar.filters.group_outdent",
+            LocalShortcut::FilterGroupBegin => "sidebar.filters.group_begin",
+            LocalShortcut::FilterSavePreset => "sidebar.filters.save_preset",
             LocalShortcut::FilterInputDismiss => "sidebar.filters.input.dismiss",
             LocalShortcut::ExportFormatCsv => "dialog.export.format_csv",
             LocalShortcut::ExportFormatTsv => "dialog.export.format_tsv",
             LocalShortcut::ExportFormatSql => "dialog.export.format_sql",
             LocalShortcut::ExportFormatJson => "dialog.export.format_json",
+            LocalShortcut::ExportFormatXlsx => "dialog.export.format_xlsx",
+            LocalShortcut::ExportFormatParquet => "dialog.export.format_parquet",
+            LocalShortcut::ExportFormatArrow => "dialog.export.format_arrow",
+            LocalShortcut::ExportFormatNdjson => "dialog.export.format_ndjson",
+            LocalShortcut::ExportFormatMarkdown => "dialog.export.format_markdown",
+            LocalShortcut::ExportFormatHtml => "dialog.export.format_html",
+            LocalShortcut::ExportFormatLatex => "dialog.export.format_latex",
             LocalShortcut::ExportCyclePrev => "dialog.export.cycle_prev",
             LocalShortcut::ExportCycleNext => "dialog.export.cycle_next",
             LocalShortcut::ExportColumnPrev => "dialog.export.column_prev",
@@ -443,11 +484,15 @@ impl LocalShortcut {
             LocalShortcut::SqlHistoryNext => "editor.insert.history_next",
             LocalShortcut::SqlHistoryBrowse => "editor.insert.history_browse",
             LocalShortcut::GridEditFinish => "grid.insert.finish_edit",
+            LocalShortcut::GridCellEditorApply => "grid.cell_editor.apply",
+            LocalShortcut::GridCellEditorCancel => "grid.cell_editor.cancel",
             LocalShortcut::ImportRefresh => "dialog.import.refresh",
             LocalShortcut::ImportFormatSql => "dialog.import.format_sql",
             LocalShortcut::ImportFormatCsv => "dialog.import.format_csv",
             LocalShortcut::ImportFormatTsv => "dialog.import.format_tsv",
             LocalShortcut::ImportFormatJson => "dialog.import.format_json",
+            LocalShortcut::ImportFormatXlsx => "dialog.import.format_xlsx",
+            LocalShortcut::ImportFormatNdjson => "dialog.import.format_ndjson",
             LocalShortcut::ImportCyclePrev => "dialog.import.cycle_prev",
             LocalShortcut::ImportCycleNext => "dialog.import.cycle_next",
             LocalShortcut::ConnectionTypeSqlite => "dialog.connection.type_sqlite",
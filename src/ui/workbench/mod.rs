@@ -2,6 +2,7 @@
 
 mod activity_bar;
 mod bottom_panel;
+mod plan_view;
 mod right_inspector;
 mod shell;
 mod status_bar;
@@ -11,6 +12,7 @@ pub use activity_bar::{WorkbenchActivityBar, WorkbenchActivityBarResponse};
 pub use bottom_panel::{
     WorkbenchBottomPanel, WorkbenchBottomPanelResponse, bottom_panel_tab_label, bottom_panel_tabs,
 };
+pub use plan_view::WorkbenchPlanView;
 pub use right_inspector::{
     WorkbenchRightInspector, WorkbenchRightInspectorResponse, right_inspector_tab_label,
     right_inspector_tabs,
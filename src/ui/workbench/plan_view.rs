@@ -0,0 +1,125 @@
+//! Explain 面板的执行计划树。
+
+use eframe::egui;
+
+use crate::domain::plan::{PlanNode, QueryPlan};
+use crate::ui::styles::{theme_error, theme_muted_text, theme_warn};
+
+pub struct WorkbenchPlanView;
+
+impl WorkbenchPlanView {
+    /// 渲染计划摘要与可折叠的节点树
+    pub fn show(ui: &mut egui::Ui, plan: &QueryPlan, elapsed_ms: u64) {
+        let hottest = plan.hottest_path();
+
+        ui.horizontal_wrapped(|ui| {
+            ui.label(egui::RichText::new(format!("{} 个节点", plan.node_count())).strong());
+            let full_scans = plan.full_scan_count();
+            if full_scans > 0 {
+                ui.label(
+                    egui::RichText::new(format!("{} 处全表扫描", full_scans))
+                        .color(theme_warn(ui.visuals())),
+                );
+            }
+            if let Some(planning) = plan.planning_time_ms {
+                ui.label(format!("规划 {:.2}ms", planning));
+            }
+            if let Some(execution) = plan.execution_time_ms {
+                ui.label(format!("执行 {:.2}ms", execution));
+            }
+            ui.label(
+                egui::RichText::new(format!("耗时 {}ms", elapsed_ms))
+                    .color(theme_muted_text(ui.visuals())),
+            );
+        });
+        ui.separator();
+
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for (index, root) in plan.roots.iter().enumerate() {
+                    let mut path = vec![index];
+                    show_node(ui, root, plan.analyzed, &mut path, hottest.as_deref());
+                }
+            });
+    }
+}
+
+fn show_node(
+    ui: &mut egui::Ui,
+    node: &PlanNode,
+    analyzed: bool,
+    path: &mut Vec<usize>,
+    hottest: Option<&[usize]>,
+) {
+    let is_hottest = hottest == Some(path.as_slice());
+    let mut title = egui::RichText::new(node_title(node)).monospace();
+    if is_hottest {
+        title = title.strong().color(theme_error(ui.visuals()));
+    } else if node.full_scan {
+        title = title.color(theme_warn(ui.visuals()));
+    }
+
+    let id = ui.make_persistent_id(("query_plan_node", path.as_slice()));
+    if node.children.is_empty() {
+        ui.horizontal(|ui| {
+            ui.add_space(ui.spacing().indent);
+            ui.label(title);
+        });
+        show_node_metrics(ui, node, analyzed, is_hottest);
+        return;
+    }
+
+    egui::CollapsingHeader::new(title)
+        .id_salt(id)
+        .default_open(true)
+        .show(ui, |ui| {
+            show_node_metrics(ui, node, analyzed, is_hottest);
+            for (index, child) in node.children.iter().enumerate() {
+                path.push(index);
+                show_node(ui, child, analyzed, path, hottest);
+                path.pop();
+            }
+        });
+}
+
+fn node_title(node: &PlanNode) -> String {
+    match &node.relation {
+        Some(relation) => format!("{} on {}", node.operation, relation),
+        None => node.operation.clone(),
+    }
+}
+
+fn show_node_metrics(ui: &mut egui::Ui, node: &PlanNode, analyzed: bool, is_hottest: bool) {
+    let mut parts = Vec::new();
+    if let Some(cost) = node.total_cost {
+        parts.push(format!("cost {:.2}", cost));
+    }
+    if let Some(rows) = node.estimated_rows {
+        parts.push(format!("rows≈{:.0}", rows));
+    }
+    if analyzed {
+        if let Some(rows) = node.actual_rows {
+            parts.push(format!("actual rows {:.0}", rows));
+        }
+        if let Some(time) = node.actual_time_ms {
+            parts.push(format!("time {:.3}ms", time));
+        }
+    }
+    if node.full_scan {
+        parts.push("全表扫描".to_string());
+    }
+    if is_hottest {
+        parts.push("开销最高".to_string());
+    }
+
+    let muted = theme_muted_text(ui.visuals());
+    ui.indent("query_plan_metrics", |ui| {
+        if !parts.is_empty() {
+            ui.label(egui::RichText::new(parts.join(" · ")).small().color(muted));
+        }
+        if let Some(detail) = &node.detail {
+            ui.label(egui::RichText::new(detail).small().monospace().color(muted));
+        }
+    });
+}
@@ -80,12 +80,13 @@ impl WorkbenchRightInspector {
     }
 }
 
-pub const fn right_inspector_tabs() -> [RightInspectorTab; 6] {
+pub const fn right_inspector_tabs() -> [RightInspectorTab; 7] {
     [
         RightInspectorTab::Properties,
         RightInspectorTab::Schema,
         RightInspectorTab::Row,
         RightInspectorTab::Cell,
+        RightInspectorTab::Profile,
         RightInspectorTab::ErSelection,
         RightInspectorTab::Connection,
     ]
@@ -97,6 +98,7 @@ pub const fn right_inspector_tab_label(tab: RightInspectorTab) -> &'static str {
         RightInspectorTab::Schema => "结构",
         RightInspectorTab::Row => "行",
         RightInspectorTab::Cell => "单元",
+        RightInspectorTab::Profile => "统计",
         RightInspectorTab::ErSelection => "ER",
         RightInspectorTab::Connection => "连接",
     }
@@ -115,6 +117,7 @@ mod tests {
                 RightInspectorTab::Schema,
                 RightInspectorTab::Row,
                 RightInspectorTab::Cell,
+                RightInspectorTab::Profile,
                 RightInspectorTab::ErSelection,
                 RightInspectorTab::Connection,
             ]
@@ -7,6 +7,8 @@ pub struct WorkbenchStatusBarContent {
     pub status_line: String,
     pub query_time_ms: Option<u64>,
     pub row_count: Option<usize>,
+    /// 当前 Tab 未提交事务的提示（手动提交模式）
+    pub transaction: Option<String>,
 }
 
 impl WorkbenchStatusBarContent {
@@ -15,6 +17,7 @@ impl WorkbenchStatusBarContent {
             status_line: status_line.into(),
             query_time_ms: None,
             row_count: None,
+            transaction: None,
         }
     }
 }
@@ -38,6 +41,14 @@ pub(crate) fn show_status_bar(ui: &mut egui::Ui, content: &WorkbenchStatusBarCon
                     if let Some(query_time_ms) = content.query_time_ms {
                         ui.label(egui::RichText::new(format!("{} ms", query_time_ms)).small());
                     }
+                    if let Some(transaction) = &content.transaction {
+                        ui.label(
+                            egui::RichText::new(transaction)
+                                .small()
+                                .strong()
+                                .color(visuals.warn_fg_color),
+                        );
+                    }
                 });
             });
         });
@@ -151,6 +151,34 @@ fn test_table_completion() {
     assert!(completions.iter().any(|c| c.label == "users"));
 }
 
+#[test]
+fn test_schema_qualified_table_completion() {
+    let mut ac = AutoComplete::new();
+    ac.set_tables(vec![
+        "users".to_string(),
+        "audit.events".to_string(),
+        "audit.users".to_string(),
+    ]);
+    ac.set_default_schema(Some("public".to_string()));
+
+    // schema 限定后只列出该 schema 的表，插入表名部分
+    let sql = "SELECT * FROM audit.u";
+    let completions = ac.get_completions(sql, sql.len());
+    let tables: Vec<_> = completions.iter().map(|c| c.insert_text.as_str()).collect();
+    assert_eq!(tables, vec!["users"]);
+
+    // 默认 schema 限定对应未限定的表
+    let sql = "SELECT * FROM public.";
+    let completions = ac.get_completions(sql, sql.len());
+    let tables: Vec<_> = completions.iter().map(|c| c.insert_text.as_str()).collect();
+    assert_eq!(tables, vec!["users"]);
+
+    // 未限定前缀同时匹配其他 schema 的表名部分，插入完整名称
+    let sql = "SELECT * FROM ev";
+    let completions = ac.get_completions(sql, sql.len());
+    assert!(completions.iter().any(|c| c.insert_text == "audit.events"));
+}
+
 // ============================================================================
 // Formatter 测试
 // ============================================================================
@@ -297,6 +325,18 @@ fn test_db_error_query_display() {
     assert!(err.to_string().contains("查询错误"));
 }
 
+#[test]
+fn test_db_error_kind_follows_classification() {
+    let err = gridix::data::DbError::classify_connection(
+        "password authentication failed for user \"app\"",
+    );
+    assert_eq!(err.kind(), "authentication");
+    assert_eq!(
+        gridix::data::DbError::classify_connection("connection refused").kind(),
+        "connection"
+    );
+}
+
 // ============================================================================
 // Formatter 边界测试
 // ============================================================================
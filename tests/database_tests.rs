@@ -89,6 +89,25 @@ fn test_pool_key_changes_with_password_and_ssl_postgres() {
     let mut changed_ssl = base.clone();
     changed_ssl.postgres_ssl_mode = PostgresSslMode::Require;
     assert_ne!(base.pool_key(), changed_ssl.pool_key());
+
+    let mut changed_search_path = base.clone();
+    changed_search_path.search_path = "app,public".to_string();
+    assert_ne!(base.pool_key(), changed_search_path.pool_key());
+}
+
+#[test]
+fn test_postgres_startup_options_normalize_search_path() {
+    let mut config = ConnectionConfig {
+        db_type: DatabaseType::PostgreSQL,
+        ..Default::default()
+    };
+    assert_eq!(config.postgres_startup_options(), None);
+
+    config.search_path = " app , ,\"My Schema\" ".to_string();
+    assert_eq!(
+        config.postgres_startup_options().as_deref(),
+        Some("-c search_path=app,\"My\\ Schema\"")
+    );
 }
 
 #[test]
@@ -3,7 +3,7 @@
 //! 测试 SQL 标识符转义、值转义、列宽缓存等功能
 
 use gridix::domain::mutation::MutationBatch;
-use gridix::ui::{DataGridState, escape_identifier, escape_value, quote_identifier};
+use gridix::ui::{CellEdit, DataGridState, escape_identifier, escape_value, quote_identifier};
 
 // ============================================================================
 // 标识符转义测试
@@ -91,7 +91,9 @@ mod grid_state {
     #[test]
     fn test_clear_edits() {
         let mut state = DataGridState::new();
-        state.modified_cells.insert((0, 0), "new_value".to_string());
+        state
+            .modified_cells
+            .insert((0, 0), CellEdit::Text("new_value".to_string()));
         state.rows_to_delete.push(1);
 
         assert!(state.has_changes());
@@ -106,9 +108,13 @@ mod grid_state {
     #[test]
     fn test_clear_save_state_preserves_pending_user_edits() {
         let mut state = DataGridState::new();
-        state.modified_cells.insert((0, 0), "new_value".to_string());
+        state
+            .modified_cells
+            .insert((0, 0), CellEdit::Text("new_value".to_string()));
         state.rows_to_delete.push(1);
-        state.new_rows.push(vec!["draft".to_string()]);
+        state
+            .new_rows
+            .push(vec![CellEdit::Text("draft".to_string())]);
         state.pending_mutation_batch = Some(MutationBatch::new());
         state.pending_save = true;
         state.show_save_confirm = true;
@@ -125,7 +131,9 @@ mod grid_state {
         let mut state = DataGridState::new();
         assert!(!state.has_changes());
 
-        state.modified_cells.insert((0, 0), "value".to_string());
+        state
+            .modified_cells
+            .insert((0, 0), CellEdit::Text("value".to_string()));
         assert!(state.has_changes());
 
         state.modified_cells.clear();
@@ -133,7 +141,7 @@ mod grid_state {
         assert!(state.has_changes());
 
         state.rows_to_delete.clear();
-        state.new_rows.push(vec!["a".to_string()]);
+        state.new_rows.push(vec![CellEdit::Text("a".to_string())]);
         assert!(state.has_changes());
     }
 
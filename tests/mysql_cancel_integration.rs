@@ -3,7 +3,8 @@
 use std::time::{Duration, Instant};
 
 use gridix::data::{
-    ConnectionConfig, DatabaseType, DbError, execute_typed, execute_typed_cancellable,
+    ConnectionConfig, DatabaseType, DbError, PinnedSession, execute_typed,
+    execute_typed_cancellable,
 };
 use gridix::domain::execution::StatementOutcome;
 use mysql_async::prelude::Queryable;
@@ -53,6 +54,17 @@ async fn marker_is_visible(
         .any(|statement| statement.contains(marker)))
 }
 
+async fn session_connection_id(session: &PinnedSession) -> String {
+    let outcome = session
+        .execute("SELECT CONNECTION_ID() AS id", &CancellationToken::new())
+        .await
+        .expect("pinned session query must succeed");
+    let StatementOutcome::ResultSet(result_set) = &outcome.statements[0] else {
+        panic!("CONNECTION_ID() must return a result set");
+    };
+    result_set.cell(0, 0).display()
+}
+
 async fn wait_for_marker(observer: &mut mysql_async::Conn, marker: &str, should_be_visible: bool) {
     let deadline = Instant::now() + OBSERVER_TIMEOUT;
     loop {
@@ -161,3 +173,86 @@ async fn execute_typed_cancellable_pre_cancel_returns_cancelled_without_dispatch
         "pre-cancelled query must never be dispatched"
     );
 }
+
+#[tokio::test]
+async fn pinned_session_drops_killed_connection_and_reconnects() {
+    let url = match std::env::var("GRIDIX_TEST_MYSQL_URL") {
+        Ok(url) => url,
+        Err(std::env::VarError::NotPresent) => {
+            eprintln!("SKIP: GRIDIX_TEST_MYSQL_URL not set");
+            return;
+        }
+        Err(error) => panic!("GRIDIX_TEST_MYSQL_URL could not be read: {}", error),
+    };
+    let config = parse_mysql_url(&url).expect("GRIDIX_TEST_MYSQL_URL must be valid");
+    let opts = mysql_async::Opts::from_url(&url).expect("observer MySQL URL must be valid");
+    let mut observer = mysql_async::Conn::new(opts)
+        .await
+        .expect("observer MySQL connection must succeed");
+
+    let (session, driver) = PinnedSession::new(config);
+    tokio::spawn(driver);
+    let connection_id = session_connection_id(&session).await;
+    assert!(session.in_transaction());
+
+    observer
+        .query_drop(format!("KILL CONNECTION {}", connection_id))
+        .await
+        .expect("observer KILL CONNECTION must succeed");
+
+    let result = session.execute("SELECT 1", &CancellationToken::new()).await;
+    assert!(
+        result.is_err(),
+        "statement on a killed connection must fail"
+    );
+    assert!(
+        !session.in_transaction(),
+        "a dropped connection must not leave the session in a transaction"
+    );
+    assert_ne!(
+        session_connection_id(&session).await,
+        connection_id,
+        "the next statement must run on a new connection"
+    );
+}
+
+#[tokio::test]
+async fn pinned_session_leaves_transaction_after_failed_rollback() {
+    let url = match std::env::var("GRIDIX_TEST_MYSQL_URL") {
+        Ok(url) => url,
+        Err(std::env::VarError::NotPresent) => {
+            eprintln!("SKIP: GRIDIX_TEST_MYSQL_URL not set");
+            return;
+        }
+        Err(error) => panic!("GRIDIX_TEST_MYSQL_URL could not be read: {}", error),
+    };
+    let config = parse_mysql_url(&url).expect("GRIDIX_TEST_MYSQL_URL must be valid");
+    let opts = mysql_async::Opts::from_url(&url).expect("observer MySQL URL must be valid");
+    let mut observer = mysql_async::Conn::new(opts)
+        .await
+        .expect("observer MySQL connection must succeed");
+
+    let (session, driver) = PinnedSession::new(config);
+    tokio::spawn(driver);
+    let connection_id = session_connection_id(&session).await;
+    assert!(session.in_transaction());
+
+    observer
+        .query_drop(format!("KILL CONNECTION {}", connection_id))
+        .await
+        .expect("observer KILL CONNECTION must succeed");
+
+    assert!(
+        session.rollback().await.is_err(),
+        "ROLLBACK on a killed connection must fail"
+    );
+    assert!(
+        !session.in_transaction(),
+        "a failed rollback must not keep the session in a transaction"
+    );
+    assert_ne!(
+        session_connection_id(&session).await,
+        connection_id,
+        "the session must reconnect after a failed rollback"
+    );
+}
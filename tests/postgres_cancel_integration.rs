@@ -4,8 +4,10 @@ use std::time::{Duration, Instant};
 
 use gridix::data::{
     ConnectionConfig, DatabaseType, DbError, execute_typed, execute_typed_cancellable,
+    explain_query,
 };
 use gridix::domain::execution::StatementOutcome;
+use gridix::domain::plan::ExplainMode;
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
@@ -174,3 +176,51 @@ async fn execute_typed_cancellable_pre_cancel_returns_cancelled_without_dispatch
         "pre-cancelled query must never be dispatched"
     );
 }
+
+#[tokio::test]
+async fn explain_analyze_measures_dml_and_always_rolls_back() {
+    let Some((_url, config)) = pg_config().expect("GRIDIX_TEST_PG_URL could not be read") else {
+        eprintln!("SKIP: GRIDIX_TEST_PG_URL not set");
+        return;
+    };
+    let table = format!("gridix_explain_{}", Uuid::new_v4().simple());
+    execute_typed(
+        &config,
+        &format!("CREATE TABLE {} (id integer PRIMARY KEY)", table),
+    )
+    .await
+    .expect("test table must be created");
+    execute_typed(
+        &config,
+        &format!("INSERT INTO {} SELECT generate_series(1, 10)", table),
+    )
+    .await
+    .expect("test rows must be inserted");
+
+    let plan = explain_query(
+        &config,
+        &format!("DELETE FROM {}", table),
+        ExplainMode::Analyze,
+        &CancellationToken::new(),
+    )
+    .await
+    .expect("EXPLAIN ANALYZE must succeed");
+    assert!(plan.analyzed, "ANALYZE plan must carry actual timings");
+    assert!(plan.execution_time_ms.is_some());
+
+    let outcome = execute_typed(&config, &format!("SELECT count(*) FROM {}", table))
+        .await
+        .expect("count must succeed");
+    let StatementOutcome::ResultSet(result_set) = &outcome.statements[0] else {
+        panic!("count must return a result set");
+    };
+    assert_eq!(
+        result_set.cell(0, 0).display(),
+        "10",
+        "EXPLAIN ANALYZE must not delete rows"
+    );
+
+    execute_typed(&config, &format!("DROP TABLE {}", table))
+        .await
+        .expect("test table must be dropped");
+}
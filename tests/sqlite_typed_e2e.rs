@@ -9,11 +9,13 @@
 //! - 大结果集与截断语义
 //! - DEFAULT 值
 //! - Schema 目录加载
+//! - 流式执行分批与提前停止
 
 use gridix::data::{
-    ConnectionConfig, DatabaseType, apply_mutations, execute_typed, load_schema_catalog,
+    ConnectionConfig, DatabaseType, apply_mutations, execute_streaming, execute_typed,
+    load_schema_catalog,
 };
-use gridix::domain::execution::{ExecutionOutcome, StatementOutcome};
+use gridix::domain::execution::{ExecutionOutcome, StatementOutcome, StreamOutcome};
 use gridix::domain::ids::SchemaRevision;
 use gridix::domain::mutation::{
     ColumnRef, ExpectedRows, InputValue, Mutation, MutationBatch, RowIdentity,
@@ -478,3 +480,125 @@ async fn catalog_load() {
     let logs = catalog.table("logs").expect("logs table must exist");
     assert!(logs.primary_key.is_none(), "logs has no PK");
 }
+
+// ═══════════════════════════════════════════════════════════════════
+// Test 6: 流式执行分批与提前停止
+// ═══════════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn streaming_batches_and_early_stop() {
+    let db = NamedTempFile::new().unwrap();
+    let config = sqlite_config(db.path());
+    let cancellation = tokio_util::sync::CancellationToken::new();
+
+    execute_typed(&config, "CREATE TABLE stream_rows (id INT, label TEXT)")
+        .await
+        .unwrap();
+    const N: usize = 2500;
+    let mut insert_sql = String::from("INSERT INTO stream_rows (id, label) VALUES ");
+    for i in 1..=N {
+        if i > 1 {
+            insert_sql.push(',');
+        }
+        insert_sql.push_str(&format!("({}, 'row{}')", i, i));
+    }
+    execute_typed(&config, &insert_sql).await.unwrap();
+
+    // 1. 完整读取：2500 行按 1000 行一批，共 3 批
+    let (sink, mut batches) = tokio::sync::mpsc::channel::<gridix::domain::result::ResultBatch>(8);
+    let consumer = tokio::spawn(async move {
+        let mut sizes = Vec::new();
+        let mut last_id = None;
+        while let Some(batch) = batches.recv().await {
+            assert_eq!(batch.cells.len(), batch.row_count * batch.columns.len());
+            if batch.row_count > 0 {
+                last_id = Some(batch.cells[(batch.row_count - 1) * 2].clone());
+            }
+            sizes.push(batch.row_count);
+        }
+        (sizes, last_id)
+    });
+    let outcome = execute_streaming(
+        &config,
+        "SELECT id, label FROM stream_rows ORDER BY id",
+        1000,
+        sink,
+        &cancellation,
+    )
+    .await
+    .unwrap();
+    let (sizes, last_id) = consumer.await.unwrap();
+    assert_eq!(
+        outcome,
+        StreamOutcome::Rows {
+            total: N,
+            stopped_early: false
+        }
+    );
+    assert_eq!(sizes, vec![1000, 1000, 500]);
+    assert_eq!(last_id, Some(DbValue::Int(N as i64)));
+
+    // 2. 空结果仍发送一个携带列信息的空批次
+    let (sink, mut batches) = tokio::sync::mpsc::channel::<gridix::domain::result::ResultBatch>(8);
+    let outcome = execute_streaming(
+        &config,
+        "SELECT id, label FROM stream_rows WHERE id < 0",
+        1000,
+        sink,
+        &cancellation,
+    )
+    .await
+    .unwrap();
+    let empty = batches.recv().await.expect("column-only batch");
+    assert_eq!(empty.row_count, 0);
+    assert_eq!(empty.columns.len(), 2);
+    assert!(batches.recv().await.is_none());
+    assert_eq!(
+        outcome,
+        StreamOutcome::Rows {
+            total: 0,
+            stopped_early: false
+        }
+    );
+
+    // 3. 接收端关闭后停止拉取
+    let (sink, mut batches) = tokio::sync::mpsc::channel::<gridix::domain::result::ResultBatch>(1);
+    let consumer = tokio::spawn(async move {
+        let first = batches.recv().await.expect("first batch");
+        drop(batches);
+        first.row_count
+    });
+    let outcome = execute_streaming(
+        &config,
+        "SELECT id, label FROM stream_rows ORDER BY id",
+        100,
+        sink,
+        &cancellation,
+    )
+    .await
+    .unwrap();
+    assert_eq!(consumer.await.unwrap(), 100);
+    match outcome {
+        StreamOutcome::Rows {
+            total,
+            stopped_early,
+        } => {
+            assert!(stopped_early);
+            assert!(total < N);
+        }
+        other => panic!("expected Rows, got {:?}", other),
+    }
+
+    // 4. 非查询语句直接返回影响行数
+    let (sink, _batches) = tokio::sync::mpsc::channel::<gridix::domain::result::ResultBatch>(1);
+    let outcome = execute_streaming(
+        &config,
+        "DELETE FROM stream_rows WHERE id <= 10",
+        1000,
+        sink,
+        &cancellation,
+    )
+    .await
+    .unwrap();
+    assert_eq!(outcome, StreamOutcome::AffectedRows { rows: 10 });
+}